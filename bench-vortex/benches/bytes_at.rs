@@ -29,7 +29,14 @@ fn array_view_fixture() -> VarBinViewArray {
     let mut buffer = Vec::new();
 
     let writer = StreamArrayWriter::new(&mut buffer);
-    block_on(writer.write_array(array_data.into_array())).unwrap();
+    block_on(async {
+        writer
+            .write_array(array_data.into_array())
+            .await?
+            .finish()
+            .await
+    })
+    .unwrap();
 
     let ctx = Arc::new(Context::default());
     let reader = block_on(StreamArrayReader::try_new(Cursor::new(buffer), ctx.clone())).unwrap();
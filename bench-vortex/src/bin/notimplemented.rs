@@ -183,7 +183,7 @@ fn compute_funcs(encodings: &[Array]) {
             "fill_forward",
             "filter",
             "scalar_at",
-            "subtract_scalar",
+            "binary_scalar",
             "search_sorted",
             "slice",
             "take",
@@ -200,9 +200,7 @@ fn compute_funcs(encodings: &[Array]) {
         impls.push(bool_to_cell(arr.with_dyn(|a| a.fill_forward().is_some())));
         impls.push(bool_to_cell(arr.with_dyn(|a| a.filter().is_some())));
         impls.push(bool_to_cell(arr.with_dyn(|a| a.scalar_at().is_some())));
-        impls.push(bool_to_cell(
-            arr.with_dyn(|a| a.subtract_scalar().is_some()),
-        ));
+        impls.push(bool_to_cell(arr.with_dyn(|a| a.binary_scalar().is_some())));
         impls.push(bool_to_cell(arr.with_dyn(|a| a.search_sorted().is_some())));
         impls.push(bool_to_cell(arr.with_dyn(|a| a.slice().is_some())));
         impls.push(bool_to_cell(arr.with_dyn(|a| a.take().is_some())));
@@ -59,6 +59,9 @@ pub fn data_vortex_uncompressed(fname_out: &str, downloaded_data: PathBuf) -> Pa
                 StreamArrayWriter::new(write)
                     .write_array(array)
                     .await
+                    .unwrap()
+                    .finish()
+                    .await
                     .unwrap();
                 Ok::<(), VortexError>(())
             })
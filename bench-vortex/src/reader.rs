@@ -73,7 +73,7 @@ pub async fn rewrite_parquet_as_vortex<W: VortexWrite>(
         .await?;
 
     let layout = written.array_layouts()[0].clone();
-    let mut w = written.into_inner();
+    let mut w = written.finish().await?;
     let mut s = flexbuffers::FlexbufferSerializer::new();
     VortexFooter {
         byte_offsets: layout.chunks.byte_offsets,
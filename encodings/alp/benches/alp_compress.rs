@@ -30,7 +30,7 @@ fn decompress_alp<T: ALPFloat>(bencher: Bencher, n: usize) {
 #[divan::bench(types = [f32, f64], args = [100_000, 10_000_000])]
 fn compress_rd<T: ALPRDFloat>(bencher: Bencher, n: usize) {
     let values: Vec<T> = vec![T::from(1.23).unwrap(); n];
-    let primitive = PrimitiveArray::from(values);
+    let primitive = PrimitiveArray::from_vec(values, Validity::NonNullable);
     let encoder = RDEncoder::new(&[T::from(1.23).unwrap()]);
 
     bencher.bench_local(|| encoder.encode(&primitive));
@@ -39,7 +39,7 @@ fn compress_rd<T: ALPRDFloat>(bencher: Bencher, n: usize) {
 #[divan::bench(types = [f32, f64], args = [100_000, 1_000_000, 10_000_000])]
 fn decompress_rd<T: ALPRDFloat>(bencher: Bencher, n: usize) {
     let values: Vec<T> = vec![T::from(1.23).unwrap(); n];
-    let primitive = PrimitiveArray::from(values);
+    let primitive = PrimitiveArray::from_vec(values, Validity::NonNullable);
     let encoder = RDEncoder::new(&[T::from(1.23).unwrap()]);
     let encoded = encoder.encode(&primitive);
 
@@ -3,10 +3,10 @@ use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
-use vortex::array::PrimitiveArray;
+use vortex::array::{PrimitiveArray, SparseArray};
 use vortex::encoding::ids;
 use vortex::iter::{Accessor, AccessorRef};
-use vortex::stats::ArrayStatisticsCompute;
+use vortex::stats::{ArrayStatistics, ArrayStatisticsCompute};
 use vortex::validity::{ArrayValidity, LogicalValidity, Validity};
 use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
 use vortex::{impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArray, IntoCanonical};
@@ -103,6 +103,31 @@ impl ALPArray {
         })
     }
 
+    /// Number of values that ALP couldn't encode exactly and are stored in the patches instead.
+    pub fn exception_count(&self) -> usize {
+        self.patches().map_or(0, |patches| {
+            SparseArray::try_from(&patches)
+                .map(|sparse| sparse.indices().len())
+                .unwrap_or_else(|_| {
+                    patches.len()
+                        - patches
+                            .statistics()
+                            .compute_null_count()
+                            .vortex_expect("Failed to compute null count of ALP patches")
+                })
+        })
+    }
+
+    /// Fraction of the values that are exceptions, see [`exception_count`](Self::exception_count).
+    ///
+    /// A high rate means ALP is a poor fit for the values, ALP-RD may encode them better.
+    pub fn exception_rate(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        self.exception_count() as f64 / self.len() as f64
+    }
+
     #[inline]
     pub fn ptype(&self) -> PType {
         self.dtype()
@@ -129,7 +154,25 @@ impl ALPArray {
     }
 }
 
-impl ArrayTrait for ALPArray {}
+impl ArrayTrait for ALPArray {
+    fn validate(&self) -> VortexResult<()> {
+        let max_exponent = match self.dtype() {
+            DType::Primitive(PType::F32, _) => <f32 as ALPFloat>::MAX_EXPONENT,
+            DType::Primitive(PType::F64, _) => <f64 as ALPFloat>::MAX_EXPONENT,
+            d => vortex_bail!(MismatchedTypes: "f32 or f64", d),
+        };
+        let exponents = self.exponents();
+        if exponents.e > max_exponent || exponents.f > exponents.e {
+            vortex_bail!(
+                "Invalid ALP exponents e={}, f={} for {}",
+                exponents.e,
+                exponents.f,
+                self.dtype()
+            );
+        }
+        Ok(())
+    }
+}
 
 impl ArrayVariants for ALPArray {
     fn as_primitive_array(&self) -> Option<&dyn PrimitiveArrayTrait> {
@@ -283,3 +326,29 @@ impl AcceptArrayVisitor for ALPArray {
 }
 
 impl ArrayStatisticsCompute for ALPArray {}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::PrimitiveArray;
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::{alp_encode, ALPArray, ALPMetadata, Exponents};
+
+    #[test]
+    fn validate_exponents() {
+        let encoded = alp_encode(&PrimitiveArray::from(vec![1.5f64, 2.5, 1e100])).unwrap();
+        encoded.as_ref().validate().unwrap();
+
+        let invalid = ALPArray::try_from_parts(
+            DType::Primitive(PType::F64, Nullability::NonNullable),
+            3,
+            ALPMetadata {
+                exponents: Exponents { e: 40, f: 0 },
+            },
+            [encoded.encoded()].into(),
+            Default::default(),
+        )
+        .unwrap();
+        assert!(invalid.as_ref().validate().is_err());
+    }
+}
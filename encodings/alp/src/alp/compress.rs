@@ -109,6 +109,7 @@ fn decompress_primitive<T: NativePType + ALPFloat>(
 mod tests {
     use core::f64;
 
+    use vortex::compute::slice;
     use vortex::compute::unary::scalar_at;
 
     use super::*;
@@ -164,6 +165,33 @@ mod tests {
         assert_eq!(values, decoded.maybe_null_slice::<f64>());
     }
 
+    #[test]
+    #[allow(clippy::approx_constant)] // ALP doesn't like E
+    fn test_exception_rate() {
+        let array = PrimitiveArray::from(vec![1.234f64, 2.718, std::f64::consts::PI, 4.0]);
+        let encoded = alp_encode(&array).unwrap();
+        assert_eq!(encoded.exception_count(), 1);
+        assert_eq!(encoded.exception_rate(), 0.25);
+
+        let sliced = ALPArray::try_from(slice(encoded.as_ref(), 0, 2).unwrap()).unwrap();
+        assert_eq!(sliced.exception_count(), 0);
+
+        let no_exceptions = alp_encode(&PrimitiveArray::from(vec![1.234f32; 8])).unwrap();
+        assert_eq!(no_exceptions.exception_count(), 0);
+        assert_eq!(no_exceptions.exception_rate(), 0.0);
+    }
+
+    #[test]
+    fn test_negative_zero_patched() {
+        let array = PrimitiveArray::from(vec![1.5f64, -0.0, 0.0, 2.5]);
+        let encoded = alp_encode(&array).unwrap();
+        assert!(encoded.patches().is_some());
+
+        let decoded = decompress(encoded).unwrap();
+        assert!(decoded.maybe_null_slice::<f64>()[1].is_sign_negative());
+        assert!(decoded.maybe_null_slice::<f64>()[2].is_sign_positive());
+    }
+
     #[test]
     #[allow(clippy::approx_constant)] // ALP doesn't like E
     fn test_nullable_patched_scalar_at() {
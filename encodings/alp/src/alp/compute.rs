@@ -38,7 +38,13 @@ impl ScalarAtFn for ALPArray {
         Ok(self.scalar_at_unchecked(index))
     }
 
+    /// Nulls of the encoded array take precedence over patches, which take precedence over the
+    /// decoded value, as a null position may still have a patch if its value wasn't encodable.
     fn scalar_at_unchecked(&self, index: usize) -> Scalar {
+        if !self.encoded().with_dyn(|a| a.is_valid(index)) {
+            return Scalar::null(self.dtype().clone());
+        }
+
         if let Some(patches) = self.patches() {
             if patches.with_dyn(|a| a.is_valid(index)) {
                 // We need to make sure the value is actually in the patches array
@@ -148,13 +154,37 @@ where
 
 #[cfg(test)]
 mod tests {
-    use vortex::array::PrimitiveArray;
-    use vortex::IntoArrayVariant;
+    use vortex::array::{Constant, PrimitiveArray};
+    use vortex::compute::compare_scalar;
+    use vortex::{ArrayDef, IntoArrayVariant};
     use vortex_dtype::{DType, Nullability, PType};
 
     use super::*;
     use crate::alp_encode;
 
+    #[test]
+    #[allow(clippy::approx_constant)] // ALP doesn't like PI
+    fn scalar_at_null_patch_precedence() {
+        let array = PrimitiveArray::from_vec(
+            vec![1.234f64, std::f64::consts::PI, std::f64::consts::PI, 4.0],
+            Validity::from(vec![true, true, false, true]),
+        );
+        let encoded = alp_encode(&array).unwrap();
+        let patches = encoded.patches().unwrap();
+        assert!(patches.with_dyn(|a| a.is_valid(2)));
+
+        assert_eq!(
+            ScalarAtFn::scalar_at_unchecked(&encoded, 0),
+            Scalar::primitive(1.234f64, Nullability::Nullable)
+        );
+        assert_eq!(
+            ScalarAtFn::scalar_at_unchecked(&encoded, 1),
+            Scalar::primitive(std::f64::consts::PI, Nullability::Nullable)
+        );
+        assert!(ScalarAtFn::scalar_at_unchecked(&encoded, 2).is_null());
+        assert!(scalar_at(&encoded, 2).unwrap().is_null());
+    }
+
     #[test]
     fn basic_comparison_test() {
         let array = PrimitiveArray::from(vec![1.234f32; 1025]);
@@ -221,4 +251,26 @@ mod tests {
             assert!(!v);
         }
     }
+
+    #[test]
+    fn compare_scalar_encoded() {
+        let exponents = alp_encode(&PrimitiveArray::from(vec![1.234f32; 1025]))
+            .unwrap()
+            .exponents();
+        let encoded = f32::encode_single(1.234, exponents).unwrap();
+        let array = ALPArray::try_new(
+            ConstantArray::new(encoded, 1025).into_array(),
+            exponents,
+            None,
+        )
+        .unwrap();
+
+        // Only comparing the constant encoded values keeps the result constant
+        let r = compare_scalar(array.as_ref(), &1.234f32.into(), Operator::Eq).unwrap();
+        assert!(r.is_encoding(Constant::ID));
+        assert_eq!(
+            scalar_at(&r, 0).unwrap(),
+            Scalar::bool(true, Nullability::Nullable)
+        );
+    }
 }
@@ -48,6 +48,12 @@ pub trait ALPFloat: private::Sealed + Float + Display + 'static {
         (self + Self::SWEET) - Self::SWEET
     }
 
+    /// Whether the values are equal, distinguishing `-0.0` from `0.0` so that the sign survives a round trip.
+    #[inline]
+    fn is_identical(self, other: Self) -> bool {
+        self == other && self.is_sign_negative() == other.is_sign_negative()
+    }
+
     /// Equivalent to calling `as` to cast the primitive float to the target integer type.
     fn as_int(self) -> Self::ALPInt;
 
@@ -143,7 +149,7 @@ pub trait ALPFloat: private::Sealed + Float + Display + 'static {
     fn encode_single(value: Self, exponents: Exponents) -> Result<Self::ALPInt, Self> {
         let encoded = unsafe { Self::encode_single_unchecked(value, exponents) };
         let decoded = Self::decode_single(encoded, exponents);
-        if decoded == value {
+        if decoded.is_identical(value) {
             return Ok(encoded);
         }
         Err(value)
@@ -191,7 +197,7 @@ fn encode_chunk_unchecked<T: ALPFloat>(
     encoded_output.extend(chunk.iter().map(|v| {
         let encoded = unsafe { T::encode_single_unchecked(*v, exp) };
         let decoded = T::decode_single(encoded, exp);
-        let neq = (decoded != *v) as usize;
+        let neq = !decoded.is_identical(*v) as usize;
         chunk_patch_count += neq;
         encoded
     }));
@@ -213,7 +219,7 @@ fn encode_chunk_unchecked<T: ALPFloat>(
             // write() is only safe to call more than once because the values are primitive (i.e., Drop is a no-op)
             patch_indices_mut[chunk_patch_index].write(i as u64);
             patch_values_mut[chunk_patch_index].write(chunk[i - num_prev_encoded]);
-            chunk_patch_index += (decoded != chunk[i - num_prev_encoded]) as usize;
+            chunk_patch_index += !decoded.is_identical(chunk[i - num_prev_encoded]) as usize;
         }
         assert_eq!(chunk_patch_index, chunk_patch_count);
         unsafe {
@@ -28,6 +28,7 @@ mod test {
     use rstest::rstest;
     use vortex::array::{BoolArray, PrimitiveArray};
     use vortex::compute::filter;
+    use vortex::validity::Validity;
     use vortex::IntoArrayVariant;
 
     use crate::{ALPRDFloat, RDEncoder};
@@ -36,7 +37,7 @@ mod test {
     #[case(0.1f32, 0.2f32, 3e25f32)]
     #[case(0.1f64, 0.2f64, 3e100f64)]
     fn test_filter<T: ALPRDFloat>(#[case] a: T, #[case] b: T, #[case] outlier: T) {
-        let array = PrimitiveArray::from(vec![a, b, outlier]);
+        let array = PrimitiveArray::from_vec(vec![a, b, outlier], Validity::NonNullable);
         let encoded = RDEncoder::new(&[a, b]).encode(&array);
 
         // Make sure that we're testing the exception pathway.
@@ -40,6 +40,7 @@ mod test {
     use rstest::rstest;
     use vortex::array::PrimitiveArray;
     use vortex::compute::unary::scalar_at;
+    use vortex::validity::Validity;
     use vortex_scalar::Scalar;
 
     use crate::{ALPRDFloat, RDEncoder};
@@ -52,7 +53,7 @@ mod test {
         #[case] b: T,
         #[case] outlier: T,
     ) {
-        let array = PrimitiveArray::from(vec![a, b, outlier]);
+        let array = PrimitiveArray::from_vec(vec![a, b, outlier], Validity::NonNullable);
         let encoded = RDEncoder::new(&[a, b]).encode(&array);
 
         // Make sure that we're testing the exception pathway.
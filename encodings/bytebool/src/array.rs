@@ -4,12 +4,12 @@ use std::mem::ManuallyDrop;
 use arrow_buffer::BooleanBuffer;
 use serde::{Deserialize, Serialize};
 use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
-use vortex::array::BoolArray;
+use vortex::array::{BoolArray, BoolRepresentation};
 use vortex::encoding::ids;
 use vortex::stats::StatsSet;
 use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use vortex::variants::{ArrayVariants, BoolArrayTrait};
-use vortex::{impl_encoding, ArrayTrait, Canonical, IntoCanonical, TypedArray};
+use vortex::{impl_encoding, ArrayTrait, Canonical, CanonicalOptions, IntoCanonical, TypedArray};
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_error::{VortexExpect as _, VortexResult};
@@ -123,10 +123,24 @@ impl From<Vec<Option<bool>>> for ByteBoolArray {
 
 impl IntoCanonical for ByteBoolArray {
     fn into_canonical(self) -> VortexResult<Canonical> {
-        let boolean_buffer = BooleanBuffer::from(self.maybe_null_slice());
-        let validity = self.validity();
-
-        BoolArray::try_new(boolean_buffer, validity).map(Canonical::Bool)
+        self.into_canonical_with(CanonicalOptions::default())
+    }
+
+    fn into_canonical_with(self, options: CanonicalOptions) -> VortexResult<Canonical> {
+        match options.bool_representation {
+            BoolRepresentation::Bits => {
+                let bytes = self.buffer().as_slice();
+                BoolArray::try_new(
+                    BooleanBuffer::collect_bool(self.len(), |idx| bytes[idx] != 0),
+                    self.validity(),
+                )
+            }
+            // The values are already a byte per bool, they're handed out without packing them
+            BoolRepresentation::Bytes => {
+                BoolArray::try_new_bytes(self.buffer().clone(), self.validity())
+            }
+        }
+        .map(Canonical::Bool)
     }
 }
 
@@ -149,6 +163,8 @@ impl AcceptArrayVisitor for ByteBoolArray {
 
 #[cfg(test)]
 mod tests {
+    use vortex::IntoArray;
+
     use super::*;
 
     #[test]
@@ -181,4 +197,29 @@ mod tests {
         }
         assert_eq!(arr.len(), 2);
     }
+
+    #[test]
+    fn canonical_keeps_bytes_when_asked() {
+        let arr = ByteBoolArray::from(vec![Some(true), None, Some(false)]);
+        let bits = arr.clone().into_canonical().unwrap().into_bool().unwrap();
+        assert_eq!(bits.representation(), BoolRepresentation::Bits);
+        assert_eq!(
+            bits.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, false]
+        );
+        assert!(!bits.is_valid(1));
+
+        let canonical = arr
+            .clone()
+            .into_array()
+            .into_canonical_with(
+                CanonicalOptions::default().with_bool_representation(BoolRepresentation::Bytes),
+            )
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(canonical.representation(), BoolRepresentation::Bytes);
+        assert_eq!(canonical.buffer().as_ptr(), arr.buffer().as_ptr());
+        assert!(!canonical.is_valid(1));
+    }
 }
@@ -7,7 +7,7 @@ use vortex::array::BoolArray;
 use vortex::compute::take;
 use vortex::compute::unary::scalar_at;
 use vortex::encoding::ids;
-use vortex::stats::StatsSet;
+use vortex::stats::{ArrayStatistics, Stat, StatsSet};
 use vortex::validity::{ArrayValidity, LogicalValidity};
 use vortex::{
     impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArray, IntoArrayVariant,
@@ -63,7 +63,25 @@ impl DictArray {
     }
 }
 
-impl ArrayTrait for DictArray {}
+impl ArrayTrait for DictArray {
+    fn validate(&self) -> VortexResult<()> {
+        let codes = self.codes();
+        if codes.len() != self.len() {
+            vortex_bail!(
+                "Codes have length {}, but the array has length {}",
+                codes.len(),
+                self.len()
+            );
+        }
+        let values_len = self.values().len();
+        if let Some(max_code) = codes.statistics().compute_as_cast::<u64>(Stat::Max) {
+            if max_code >= values_len as u64 {
+                vortex_bail!("Code {max_code} out of bounds of {values_len} values");
+            }
+        }
+        Ok(())
+    }
+}
 
 impl IntoCanonical for DictArray {
     fn into_canonical(self) -> VortexResult<Canonical> {
@@ -110,3 +128,20 @@ impl AcceptArrayVisitor for DictArray {
         visitor.visit_child("codes", &self.codes())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex::IntoArray;
+
+    use crate::DictArray;
+
+    #[test]
+    fn validate_codes_in_bounds() {
+        let values = vec![10i32, 20].into_array();
+        let valid = DictArray::try_new(vec![0u8, 1, 1].into_array(), values.clone()).unwrap();
+        valid.as_ref().validate().unwrap();
+
+        let invalid = DictArray::try_new(vec![0u8, 2].into_array(), values).unwrap();
+        assert!(invalid.as_ref().validate().is_err());
+    }
+}
@@ -4,11 +4,14 @@ use hashbrown::hash_map::{Entry, RawEntryMut};
 use hashbrown::{DefaultHashBuilder, HashMap};
 use num_traits::AsPrimitive;
 use vortex::accessor::ArrayAccessor;
-use vortex::array::{PrimitiveArray, VarBinArray, VarBinViewArray};
+use vortex::array::{Primitive, PrimitiveArray, VarBin, VarBinArray, VarBinView, VarBinViewArray};
+use vortex::compute::unary::try_cast;
 use vortex::validity::Validity;
-use vortex::{ArrayDType, IntoArray, IntoCanonical};
-use vortex_dtype::{match_each_native_ptype, DType, NativePType, ToBytes};
-use vortex_error::{VortexExpect as _, VortexUnwrap};
+use vortex::{Array, ArrayDType, ArrayDef, Canonical, IntoArray, IntoCanonical};
+use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability, PType, ToBytes};
+use vortex_error::{vortex_bail, VortexExpect as _, VortexResult, VortexUnwrap};
+
+use crate::DictArray;
 
 /// Statically assigned code for a null value.
 pub const NULL_CODE: u64 = 0;
@@ -30,6 +33,57 @@ impl<T: ToBytes> PartialEq<Self> for Value<T> {
 
 impl<T: ToBytes> Eq for Value<T> {}
 
+/// Dictionary encode a primitive, varbin or varbinview array, or any array that canonicalizes to
+/// a primitive or varbinview array.
+///
+/// Codes use the narrowest unsigned integer type that can index every value of the dictionary.
+pub fn dict_encode(array: &Array) -> VortexResult<DictArray> {
+    let (codes, values) = match array.encoding().id() {
+        Primitive::ID => {
+            let (codes, values) = dict_encode_primitive(&PrimitiveArray::try_from(array)?);
+            (codes, values.into_array())
+        }
+        VarBin::ID => {
+            let (codes, values) = dict_encode_varbin(&VarBinArray::try_from(array)?);
+            (codes, values.into_array())
+        }
+        VarBinView::ID => {
+            let (codes, values) = dict_encode_varbinview(&VarBinViewArray::try_from(array)?);
+            (codes, values.into_array())
+        }
+        _ => match array.clone().into_canonical()? {
+            Canonical::Primitive(p) => {
+                let (codes, values) = dict_encode_primitive(&p);
+                (codes, values.into_array())
+            }
+            Canonical::VarBinView(vb) => {
+                let (codes, values) = dict_encode_varbinview(&vb);
+                (codes, values.into_array())
+            }
+            _ => vortex_bail!(
+                "Cannot dictionary encode {} array of type {}",
+                array.encoding().id(),
+                array.dtype()
+            ),
+        },
+    };
+
+    let code_ptype = narrowest_code_ptype(values.len());
+    let codes = try_cast(
+        codes,
+        &DType::Primitive(code_ptype, Nullability::NonNullable),
+    )?;
+    DictArray::try_new(codes, values)
+}
+
+pub(crate) fn narrowest_code_ptype(num_values: usize) -> PType {
+    let max_code = num_values.saturating_sub(1) as u64;
+    [PType::U8, PType::U16, PType::U32]
+        .into_iter()
+        .find(|ptype| max_code <= ptype.max_value())
+        .unwrap_or(PType::U64)
+}
+
 pub fn dict_encode_primitive(array: &PrimitiveArray) -> (PrimitiveArray, PrimitiveArray) {
     match_each_native_ptype!(array.ptype(), |$P| {
         dict_encode_typed_primitive::<$P>(array)
@@ -192,13 +246,14 @@ mod test {
     use std::str;
 
     use vortex::accessor::ArrayAccessor;
-    use vortex::array::{PrimitiveArray, VarBinArray};
+    use vortex::array::{BoolArray, PrimitiveArray, VarBinArray};
     use vortex::compute::unary::scalar_at;
-    use vortex_dtype::Nullability::Nullable;
+    use vortex::{ArrayDType, IntoArrayVariant};
+    use vortex_dtype::Nullability::{NonNullable, Nullable};
     use vortex_dtype::{DType, PType};
     use vortex_scalar::Scalar;
 
-    use crate::compress::{dict_encode_typed_primitive, dict_encode_varbin};
+    use crate::compress::{dict_encode, dict_encode_typed_primitive, dict_encode_varbin};
 
     #[test]
     fn encode_primitive() {
@@ -281,6 +336,31 @@ mod test {
             .unwrap();
     }
 
+    #[test]
+    fn encode_to_dict_array() {
+        let arr = PrimitiveArray::from((0..1000).map(|i| i % 300).collect::<Vec<i64>>());
+        let dict = dict_encode(arr.as_ref()).unwrap();
+        assert_eq!(
+            dict.codes().dtype(),
+            &DType::Primitive(PType::U16, NonNullable)
+        );
+        assert_eq!(dict.values().len(), 300);
+        assert_eq!(
+            dict.into_primitive().unwrap().maybe_null_slice::<i64>(),
+            arr.maybe_null_slice::<i64>()
+        );
+
+        let arr = VarBinArray::from(vec!["a", "b", "a"]);
+        let dict = dict_encode(arr.as_ref()).unwrap();
+        assert_eq!(
+            dict.codes().dtype(),
+            &DType::Primitive(PType::U8, NonNullable)
+        );
+        assert_eq!(scalar_at(&dict, 2).unwrap(), scalar_at(&arr, 2).unwrap());
+
+        assert!(dict_encode(BoolArray::from(vec![true]).as_ref()).is_err());
+    }
+
     #[test]
     fn repeated_values() {
         let arr = VarBinArray::from(vec!["a", "a", "b", "b", "a", "b", "a", "b"]);
@@ -1,12 +1,25 @@
+use vortex::array::ConstantArray;
 use vortex::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
-use vortex::compute::{filter, slice, take, ArrayCompute, FilterFn, SliceFn, TakeFn};
-use vortex::{Array, IntoArray};
+use vortex::compute::{
+    compare_scalar, filter, slice, take, ArrayCompute, CompareScalarFn, FilterFn, MaybeCompareFn,
+    Operator, SliceFn, TakeFn,
+};
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
 use vortex_error::{VortexExpect, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::DictArray;
 
 impl ArrayCompute for DictArray {
+    fn compare_scalar(&self) -> Option<&dyn CompareScalarFn> {
+        Some(self)
+    }
+
+    fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        MaybeCompareFn::maybe_compare(self, other, operator)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -24,6 +37,58 @@ impl ArrayCompute for DictArray {
     }
 }
 
+impl MaybeCompareFn for DictArray {
+    fn maybe_compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        let is_constant = ConstantArray::try_from(other).is_ok()
+            || other
+                .statistics()
+                .get_as::<bool>(Stat::IsConstant)
+                .unwrap_or_default();
+        if other.is_empty() || !is_constant {
+            return None;
+        }
+
+        Some(
+            scalar_at(other, 0)
+                .and_then(|value| CompareScalarFn::compare_scalar(self, &value, operator)),
+        )
+    }
+}
+
+/// Compare the dictionary against the value once and then map the per-value result to every row
+/// through the codes, avoiding decoding the dictionary.
+impl CompareScalarFn for DictArray {
+    fn compare_scalar(&self, value: &Scalar, operator: Operator) -> VortexResult<Array> {
+        let values = self.values();
+        let values_cmp = compare_scalar(&values, value, operator)?;
+
+        // Dictionary values are unique, if none of them is null at most one code can be equal to
+        // the value and the rows can be found by comparing integer codes.
+        if operator == Operator::Eq && values.with_dyn(|a| a.logical_validity().all_valid()) {
+            let values_cmp = values_cmp.clone().into_bool()?;
+            let matching = values_cmp.boolean_buffer();
+            let mut matching = matching.set_indices();
+            match (matching.next(), matching.next()) {
+                (None, _) => {
+                    return Ok(ConstantArray::new(
+                        Scalar::bool(false, values_cmp.dtype().nullability()),
+                        self.len(),
+                    )
+                    .into_array())
+                }
+                (Some(code), None) => {
+                    let codes = self.codes();
+                    let code = Scalar::from(code as u64).cast(codes.dtype())?;
+                    return compare_scalar(&codes, &code, Operator::Eq);
+                }
+                _ => {}
+            }
+        }
+
+        take(values_cmp, self.codes())
+    }
+}
+
 impl ScalarAtFn for DictArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         let dict_index: usize = scalar_at(self.codes(), index)?.as_ref().try_into()?;
@@ -67,11 +132,15 @@ impl SliceFn for DictArray {
 #[cfg(test)]
 mod test {
     use vortex::accessor::ArrayAccessor;
-    use vortex::array::{PrimitiveArray, VarBinViewArray};
+    use vortex::array::{ConstantArray, PrimitiveArray, VarBinArray, VarBinViewArray};
+    use vortex::compute::unary::scalar_at;
+    use vortex::compute::{compare, take_map, Operator};
     use vortex::{IntoArray, IntoArrayVariant, ToArray};
     use vortex_dtype::{DType, Nullability};
 
-    use crate::{dict_encode_typed_primitive, dict_encode_varbinview, DictArray};
+    use crate::{
+        dict_encode_typed_primitive, dict_encode_varbin, dict_encode_varbinview, DictArray,
+    };
 
     #[test]
     fn flatten_nullable_primitive() {
@@ -112,4 +181,68 @@ mod test {
                 .unwrap(),
         );
     }
+
+    fn compare_to_constant(dict: &DictArray, value: &str, operator: Operator) -> Vec<bool> {
+        compare(
+            dict.as_ref(),
+            ConstantArray::new(value, dict.len()).as_ref(),
+            operator,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap()
+        .boolean_buffer()
+        .iter()
+        .collect()
+    }
+
+    #[test]
+    fn compare_codes() {
+        let reference = VarBinArray::from(vec!["US", "DE", "US", "FR", "DE"]);
+        let (codes, values) = dict_encode_varbin(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+
+        assert_eq!(
+            compare_to_constant(&dict, "US", Operator::Eq),
+            vec![true, false, true, false, false]
+        );
+        assert_eq!(
+            compare_to_constant(&dict, "GB", Operator::Eq),
+            vec![false; 5]
+        );
+        assert_eq!(
+            compare_to_constant(&dict, "DE", Operator::NotEq),
+            vec![true, false, true, true, false]
+        );
+        assert_eq!(
+            compare_to_constant(&dict, "FR", Operator::Lt),
+            vec![false, true, false, false, true]
+        );
+    }
+
+    #[test]
+    fn take_shares_values() {
+        let long_values = ["a", "b", "c"].map(|v| v.repeat(1000));
+        let reference = VarBinArray::from(
+            (0..10_000)
+                .map(|i| long_values[i % 3].as_str())
+                .collect::<Vec<_>>(),
+        );
+        let (codes, values) = dict_encode_varbin(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let indices = PrimitiveArray::from((0..5_000u32).map(|i| i * 2).collect::<Vec<_>>());
+
+        let taken = take_map(&dict, &indices, false).unwrap();
+        let taken_dict = DictArray::try_from(&taken).unwrap();
+        assert_eq!(taken_dict.values().nbytes(), dict.values().nbytes());
+        assert!(taken.nbytes() <= 5_000 * 8 + dict.values().nbytes());
+
+        let materialized = take_map(&dict, &indices, true).unwrap();
+        assert!(DictArray::try_from(&materialized).is_err());
+        assert!(taken.nbytes() < materialized.nbytes());
+        assert_eq!(
+            scalar_at(&taken, 1).unwrap(),
+            scalar_at(&materialized, 1).unwrap()
+        );
+    }
 }
@@ -4,9 +4,11 @@
 //! [DictionaryArray](https://docs.rs/arrow/latest/arrow/array/struct.DictionaryArray.html).
 pub use array::*;
 pub use compress::*;
+pub use take::{take_dict, DictTakeOptions};
 
 mod array;
 mod compress;
 mod compute;
 mod stats;
+mod take;
 mod variants;
@@ -0,0 +1,152 @@
+use hashbrown::hash_map::Entry;
+use hashbrown::HashMap;
+use vortex::array::PrimitiveArray;
+use vortex::compute::take;
+use vortex::compute::unary::try_cast;
+use vortex::stats::ArrayStatistics;
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::compress::narrowest_code_ptype;
+use crate::DictArray;
+
+/// When [`take_dict`] returns a dictionary instead of gathering every row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DictTakeOptions {
+    /// Return a dictionary at all, otherwise [`take_dict`] is a plain [`take`]
+    pub enabled: bool,
+    /// Largest number of distinct indices, as a fraction of the number of indices, for which a
+    /// dictionary is returned
+    pub max_distinct_ratio: f64,
+}
+
+impl Default for DictTakeOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_distinct_ratio: 0.25,
+        }
+    }
+}
+
+/// Take the rows of `array` at `indices`, as a [`DictArray`] if the indices repeat enough.
+///
+/// The values of the dictionary are the rows at the distinct indices, gathered once, and its codes
+/// are the indices remapped to them. Whether the indices repeat enough is decided on their
+/// estimated distinct count, see [`DictTakeOptions`].
+pub fn take_dict(
+    array: impl AsRef<Array>,
+    indices: impl AsRef<Array>,
+    options: &DictTakeOptions,
+) -> VortexResult<Array> {
+    let array = array.as_ref();
+    let indices = indices.as_ref();
+    if !options.enabled || indices.is_empty() {
+        return take(array, indices);
+    }
+    if !indices.dtype().is_int() || indices.dtype().is_nullable() {
+        vortex_bail!(
+            "Take indices must be a non-nullable integer type, got {}",
+            indices.dtype()
+        );
+    }
+
+    let max_distinct = (indices.len() as f64 * options.max_distinct_ratio) as u64;
+    let repeated = indices
+        .statistics()
+        .compute_approx_distinct()
+        .is_some_and(|distinct| distinct <= max_distinct);
+    if !repeated {
+        return take(array, indices);
+    }
+
+    let indices = try_cast(
+        indices,
+        &DType::Primitive(PType::U64, Nullability::NonNullable),
+    )?
+    .into_primitive()?;
+    let mut code_of: HashMap<u64, u64> = HashMap::new();
+    let mut distinct = Vec::new();
+    let codes = indices
+        .maybe_null_slice::<u64>()
+        .iter()
+        .map(|&idx| match code_of.entry(idx) {
+            Entry::Occupied(o) => *o.get(),
+            Entry::Vacant(v) => {
+                distinct.push(idx);
+                *v.insert(distinct.len() as u64 - 1)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let values = take(array, PrimitiveArray::from(distinct).into_array())?;
+    let codes = try_cast(
+        PrimitiveArray::from(codes).into_array(),
+        &DType::Primitive(narrowest_code_ptype(values.len()), Nullability::NonNullable),
+    )?;
+    Ok(DictArray::try_new(codes, values)?.into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{PrimitiveArray, VarBinArray};
+    use vortex::compute::take;
+    use vortex::compute::unary::scalar_at;
+    use vortex::{ArrayDef, IntoArray};
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::{take_dict, Dict, DictArray, DictTakeOptions};
+
+    #[test]
+    fn repeated_indices_take_dictionary() {
+        let array = VarBinArray::from_iter(
+            (0..100).map(|i| Some(format!("value{i}"))),
+            DType::Utf8(Nullability::NonNullable),
+        )
+        .into_array();
+        let indices = PrimitiveArray::from(
+            (0..1000u32)
+                .map(|i| [3, 97, 42][i as usize % 3])
+                .collect::<Vec<_>>(),
+        )
+        .into_array();
+
+        let taken = take_dict(&array, &indices, &DictTakeOptions::default()).unwrap();
+        assert_eq!(taken.encoding().id(), Dict::ID);
+        assert_eq!(taken.len(), 1000);
+        let dict = DictArray::try_from(taken.clone()).unwrap();
+        assert_eq!(dict.values().len(), 3);
+
+        let expected = take(&array, &indices).unwrap();
+        for idx in 0..expected.len() {
+            assert_eq!(
+                scalar_at(&taken, idx).unwrap(),
+                scalar_at(&expected, idx).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn distinct_indices_take_rows() {
+        let array = PrimitiveArray::from((0..100i64).collect::<Vec<_>>()).into_array();
+        let indices = PrimitiveArray::from((0..100u8).rev().collect::<Vec<_>>()).into_array();
+        let taken = take_dict(&array, &indices, &DictTakeOptions::default()).unwrap();
+        assert_ne!(taken.encoding().id(), Dict::ID);
+
+        let repeated = PrimitiveArray::from(vec![5u8; 100]).into_array();
+        let disabled = DictTakeOptions {
+            enabled: false,
+            ..Default::default()
+        };
+        let taken = take_dict(&array, &repeated, &disabled).unwrap();
+        assert_ne!(taken.encoding().id(), Dict::ID);
+        assert_eq!(
+            take_dict(&array, &repeated, &DictTakeOptions::default())
+                .unwrap()
+                .encoding()
+                .id(),
+            Dict::ID
+        );
+    }
+}
@@ -241,6 +241,40 @@ impl AcceptArrayVisitor for BitPackedArray {
 impl ArrayStatisticsCompute for BitPackedArray {}
 
 impl ArrayTrait for BitPackedArray {
+    fn validate(&self) -> VortexResult<()> {
+        let ptype = PType::try_from(self.dtype())?;
+        if self.bit_width() as usize > ptype.bit_width() {
+            vortex_bail!("Bit width {} is wider than {ptype}", self.bit_width());
+        }
+        if self.offset() > 1023 {
+            vortex_bail!(
+                "Offset must be less than full block, i.e. 1024, got {}",
+                self.offset()
+            );
+        }
+
+        let expected_packed_size = ((self.len() + self.offset() as usize + 1023) / 1024)
+            * (128 * self.bit_width() as usize);
+        if self.packed().len() != expected_packed_size {
+            vortex_bail!(
+                "Expected {} packed bytes, got {}",
+                expected_packed_size,
+                self.packed().len()
+            );
+        }
+
+        if let Some(patches) = self.patches() {
+            if patches.len() != self.len() {
+                vortex_bail!(
+                    "Patches have length {}, but the array has length {}",
+                    patches.len(),
+                    self.len()
+                );
+            }
+        }
+        Ok(())
+    }
+
     fn nbytes(&self) -> usize {
         // Ignore any overheads like padding or the bit-width flag.
         let packed_size = ((self.bit_width() as usize * self.len()) + 7) / 8;
@@ -287,4 +321,22 @@ mod test {
         let _packed = BitPackedArray::encode(uncompressed.as_ref(), 9)
             .expect_err("Cannot pack value into larger width");
     }
+
+    #[test]
+    fn validate_bit_width() {
+        let values = PrimitiveArray::from(vec![1u8, 2, 3]);
+        let packed = BitPackedArray::encode(values.as_ref(), 2).unwrap();
+        packed.as_ref().validate().unwrap();
+
+        let too_wide = BitPackedArray::try_new(
+            vortex_buffer::Buffer::from(vec![0u8; 128 * 16]),
+            vortex_dtype::PType::U8,
+            vortex::validity::Validity::NonNullable,
+            None,
+            16,
+            3,
+        )
+        .unwrap();
+        assert!(too_wide.as_ref().validate().is_err());
+    }
 }
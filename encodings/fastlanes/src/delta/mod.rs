@@ -64,7 +64,7 @@ impl Display for DeltaMetadata {
 /// non-SIMD-amenable, delta-encoded vector.
 impl DeltaArray {
     pub fn try_from_vec<T: NativePType>(vec: Vec<T>) -> VortexResult<Self> {
-        Self::try_from_primitive_array(&PrimitiveArray::from(vec))
+        Self::try_from_primitive_array(&PrimitiveArray::from_vec(vec, Validity::NonNullable))
     }
 
     pub fn try_from_primitive_array(array: &PrimitiveArray) -> VortexResult<Self> {
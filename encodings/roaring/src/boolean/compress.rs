@@ -1,14 +1,41 @@
+use arrow_buffer::BooleanBuffer;
 use croaring::Bitmap;
 use vortex::array::BoolArray;
-use vortex_error::VortexResult;
+use vortex::compute::unary::try_cast;
+use vortex::{Array, ArrayDType, IntoArrayVariant};
+use vortex_dtype::PType;
+use vortex_error::{vortex_bail, VortexResult};
 
 use crate::RoaringBoolArray;
 
 pub fn roaring_bool_encode(bool_array: BoolArray) -> VortexResult<RoaringBoolArray> {
+    roaring_bool_from_buffer(&bool_array.boolean_buffer())
+}
+
+pub(crate) fn roaring_bool_from_buffer(buffer: &BooleanBuffer) -> VortexResult<RoaringBoolArray> {
     let mut bitmap = Bitmap::new();
-    bitmap.extend(bool_array.boolean_buffer().set_indices().map(|i| i as u32));
+    bitmap.extend(buffer.set_indices().map(|i| i as u32));
     bitmap.run_optimize();
     bitmap.shrink_to_fit();
 
-    RoaringBoolArray::try_new(bitmap, bool_array.len())
+    RoaringBoolArray::try_new(bitmap, buffer.len())
+}
+
+/// Like [`indices_to_mask`](vortex::compute::indices_to_mask), but builds the bitmap of the mask
+/// straight from the indices.
+pub fn roaring_indices_to_mask(indices: &Array, length: usize) -> VortexResult<RoaringBoolArray> {
+    if !indices.dtype().is_int() {
+        vortex_bail!("Indices must be integers, have type {}", indices.dtype())
+    }
+
+    let indices = try_cast(indices, &PType::U32.into())?.into_primitive()?;
+    let indices = indices.maybe_null_slice::<u32>();
+    if let Some(idx) = indices.iter().find(|idx| **idx as usize >= length) {
+        vortex_bail!(OutOfBounds: *idx as usize, 0, length)
+    }
+
+    let mut bitmap = Bitmap::of(indices);
+    bitmap.run_optimize();
+    bitmap.shrink_to_fit();
+    RoaringBoolArray::try_new(bitmap, length)
 }
@@ -1,13 +1,17 @@
 use croaring::Bitmap;
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::{ArrayCompute, SliceFn};
+use vortex::compute::{ArrayCompute, FilterFn, SliceFn};
 use vortex::{Array, IntoArray};
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
-use crate::RoaringBoolArray;
+use crate::{roaring_mask_to_indices, RoaringBoolArray};
 
 impl ArrayCompute for RoaringBoolArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -25,6 +29,38 @@ impl ScalarAtFn for RoaringBoolArray {
     fn scalar_at_unchecked(&self, index: usize) -> Scalar {
         self.bitmap().contains(index as u32).into()
     }
+
+    fn scalar_at_many(&self, indices: &[usize]) -> VortexResult<Vec<Scalar>> {
+        let bitmap = self.bitmap();
+        Ok(indices
+            .iter()
+            .map(|idx| bitmap.contains(*idx as u32).into())
+            .collect())
+    }
+}
+
+/// The set positions that are selected are renumbered by their rank among the selected positions,
+/// so filtering by a roaring predicate never decodes either bitmap into a dense mask.
+impl FilterFn for RoaringBoolArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let selected = selected_positions(predicate)?;
+        let mut bitmap = self
+            .bitmap()
+            .and(&selected)
+            .iter()
+            .map(|position| (selected.rank(position) - 1) as u32)
+            .collect::<Bitmap>();
+        bitmap.run_optimize();
+        Self::try_new(bitmap, selected.cardinality() as usize).map(IntoArray::into_array)
+    }
+}
+
+/// Positions selected by the `predicate` of a filter, as a bitmap
+pub(crate) fn selected_positions(predicate: &Array) -> VortexResult<Bitmap> {
+    match RoaringBoolArray::try_from(predicate) {
+        Ok(roaring) => Ok(roaring.bitmap()),
+        Err(_) => roaring_mask_to_indices(predicate).map(|indices| indices.owned_bitmap()),
+    }
 }
 
 impl SliceFn for RoaringBoolArray {
@@ -38,13 +74,13 @@ impl SliceFn for RoaringBoolArray {
 
 #[cfg(test)]
 mod tests {
-    use vortex::array::BoolArray;
-    use vortex::compute::slice;
-    use vortex::compute::unary::scalar_at;
+    use vortex::array::{BoolArray, PrimitiveArray};
+    use vortex::compute::unary::{scalar_at, scalar_at_many};
+    use vortex::compute::{filter, slice};
     use vortex::{IntoArray, IntoArrayVariant};
     use vortex_scalar::Scalar;
 
-    use crate::RoaringBoolArray;
+    use crate::{roaring_indices_to_mask, roaring_mask_to_indices, RoaringBoolArray};
 
     #[test]
     #[cfg_attr(miri, ignore)]
@@ -59,6 +95,62 @@ mod tests {
         assert_eq!(scalar_at(&array, 1).unwrap(), falsy);
         assert_eq!(scalar_at(&array, 2).unwrap(), truthy);
         assert_eq!(scalar_at(&array, 3).unwrap(), truthy);
+        assert_eq!(
+            scalar_at_many(&array, &[3, 1, 0]).unwrap(),
+            vec![truthy.clone(), falsy, truthy]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn mask_indices_roundtrip() {
+        let indices = PrimitiveArray::from(vec![5u16, 1, 3]).into_array();
+        let mask = roaring_indices_to_mask(&indices, 8).unwrap();
+        assert_eq!(mask.len(), 8);
+        assert_eq!(mask.bitmap().to_vec(), vec![1, 3, 5]);
+        assert!(roaring_indices_to_mask(&indices, 5).is_err());
+
+        let roaring_indices = roaring_mask_to_indices(mask.as_ref()).unwrap();
+        assert_eq!(
+            roaring_indices
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[1, 3, 5]
+        );
+
+        let bools = BoolArray::from(vec![false, true, true]);
+        let from_bools = roaring_mask_to_indices(bools.as_ref()).unwrap();
+        assert_eq!(from_bools.owned_bitmap().to_vec(), vec![1, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn test_filter() {
+        let array =
+            RoaringBoolArray::encode(BoolArray::from(vec![true, false, true, true, false]).into())
+                .unwrap();
+        let predicate =
+            RoaringBoolArray::encode(BoolArray::from(vec![false, true, true, true, false]).into())
+                .unwrap();
+
+        let filtered = RoaringBoolArray::try_from(filter(&array, &predicate).unwrap()).unwrap();
+        assert_eq!(filtered.len(), 3);
+        assert_eq!(filtered.bitmap().to_vec(), vec![1, 2]);
+
+        // Chaining filters keeps the selection roaring
+        let chained = filter(
+            &filtered,
+            BoolArray::from(vec![true, false, true]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(
+            RoaringBoolArray::try_from(chained)
+                .unwrap()
+                .bitmap()
+                .to_vec(),
+            vec![1]
+        );
     }
 
     #[test]
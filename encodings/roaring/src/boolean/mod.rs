@@ -1,8 +1,10 @@
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 
+use arrow_array::{Array as _, BooleanArray};
 use arrow_buffer::{BooleanBuffer, MutableBuffer};
 pub use compress::*;
+pub(crate) use compute::selected_positions;
 use croaring::Native;
 pub use croaring::{Bitmap, Portable};
 use serde::{Deserialize, Serialize};
@@ -24,8 +26,42 @@ mod stats;
 
 impl_encoding!("vortex.roaring_bool", ids::ROARING_BOOL, RoaringBool);
 
+/// Serialization of the bitmap in the buffer of a [`RoaringBoolArray`].
+///
+/// Arrays are written in the portable format, which is also used by `RoaringIntArray`. Arrays
+/// written before the format was recorded in the metadata used the native format, which is only
+/// read for compatibility.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoaringBoolFormat {
+    Native,
+    #[default]
+    Portable,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RoaringBoolMetadata;
+#[serde(from = "VersionedRoaringBoolMetadata")]
+pub struct RoaringBoolMetadata {
+    format: RoaringBoolFormat,
+}
+
+/// The metadata of older arrays is a unit struct, which means a natively serialized bitmap
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum VersionedRoaringBoolMetadata {
+    Current { format: RoaringBoolFormat },
+    Legacy(()),
+}
+
+impl From<VersionedRoaringBoolMetadata> for RoaringBoolMetadata {
+    fn from(value: VersionedRoaringBoolMetadata) -> Self {
+        match value {
+            VersionedRoaringBoolMetadata::Current { format } => Self { format },
+            VersionedRoaringBoolMetadata::Legacy(()) => Self {
+                format: RoaringBoolFormat::Native,
+            },
+        }
+    }
+}
 
 impl Display for RoaringBoolMetadata {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -57,8 +93,10 @@ impl RoaringBoolArray {
                 typed: TypedArray::try_from_parts(
                     DType::Bool(NonNullable),
                     length,
-                    RoaringBoolMetadata,
-                    Some(Buffer::from(bitmap.serialize::<Native>())),
+                    RoaringBoolMetadata {
+                        format: RoaringBoolFormat::Portable,
+                    },
+                    Some(Buffer::from(bitmap.serialize::<Portable>())),
                     vec![].into(),
                     stats,
                 )?,
@@ -68,7 +106,27 @@ impl RoaringBoolArray {
 
     pub fn bitmap(&self) -> Bitmap {
         //TODO(@jdcasale): figure out a way to avoid this deserialization per-call
-        Bitmap::deserialize::<Native>(self.buffer().as_ref())
+        match self.format() {
+            RoaringBoolFormat::Native => Bitmap::deserialize::<Native>(self.buffer().as_ref()),
+            RoaringBoolFormat::Portable => Bitmap::deserialize::<Portable>(self.buffer().as_ref()),
+        }
+    }
+
+    pub fn format(&self) -> RoaringBoolFormat {
+        self.metadata().format
+    }
+
+    /// Encode an Arrow boolean array, reading the set positions straight from its bit buffer.
+    ///
+    /// Roaring bool arrays can't be nullable, so arrays with nulls are rejected.
+    pub fn from_arrow(array: &BooleanArray) -> VortexResult<Self> {
+        if array.null_count() > 0 {
+            vortex_bail!(
+                "RoaringBool can't encode an Arrow array with {} nulls",
+                array.null_count()
+            )
+        }
+        roaring_bool_from_buffer(array.values())
     }
 
     pub fn encode(array: Array) -> VortexResult<Array> {
@@ -86,7 +144,26 @@ impl RoaringBoolArray {
     }
 }
 
-impl ArrayTrait for RoaringBoolArray {}
+impl ArrayTrait for RoaringBoolArray {
+    fn validate(&self) -> VortexResult<()> {
+        let bitmap = match self.format() {
+            RoaringBoolFormat::Native => Bitmap::try_deserialize::<Native>(self.buffer().as_ref()),
+            RoaringBoolFormat::Portable => {
+                Bitmap::try_deserialize::<Portable>(self.buffer().as_ref())
+            }
+        }
+        .ok_or_else(|| vortex_err!("Buffer is not a valid roaring bitmap"))?;
+        if let Some(max) = bitmap.maximum() {
+            if max as usize >= self.len() {
+                vortex_bail!(
+                    "Bitmap contains {max}, out of bounds of array of length {}",
+                    self.len()
+                );
+            }
+        }
+        Ok(())
+    }
+}
 
 impl ArrayVariants for RoaringBoolArray {
     fn as_bool_array(&self) -> Option<&dyn BoolArrayTrait> {
@@ -151,10 +228,19 @@ impl IntoCanonical for RoaringBoolArray {
 mod test {
     use std::iter;
 
+    use arrow_array::BooleanArray;
+    use croaring::{Bitmap, Native};
     use vortex::array::BoolArray;
-    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex::stats::StatsSet;
+    use vortex::{
+        IntoArray, IntoArrayVariant, TryDeserializeArrayMetadata, TrySerializeArrayMetadata,
+        TypedArray,
+    };
+    use vortex_buffer::Buffer;
+    use vortex_dtype::DType;
+    use vortex_dtype::Nullability::NonNullable;
 
-    use crate::RoaringBoolArray;
+    use crate::{RoaringBoolArray, RoaringBoolFormat, RoaringBoolMetadata};
 
     #[test]
     #[cfg_attr(miri, ignore)]
@@ -180,4 +266,60 @@ mod test {
         let bool_arr = round_trip.into_bool().unwrap();
         assert_eq!(bool_arr.len(), 102);
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn from_arrow() {
+        let arrow = BooleanArray::from(vec![false, true, true, false, true]);
+        let array = RoaringBoolArray::from_arrow(&arrow).unwrap();
+        assert_eq!(array.len(), 5);
+        assert_eq!(array.bitmap().to_vec(), vec![1, 2, 4]);
+
+        let sliced = arrow.slice(1, 3);
+        let array = RoaringBoolArray::from_arrow(&sliced).unwrap();
+        assert_eq!(array.bitmap().to_vec(), vec![0, 1]);
+
+        let nullable = BooleanArray::from(vec![Some(true), None]);
+        assert!(RoaringBoolArray::from_arrow(&nullable).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn legacy_native_format() {
+        let legacy = ().try_serialize_metadata().unwrap();
+        let metadata = RoaringBoolMetadata::try_deserialize_metadata(Some(&legacy)).unwrap();
+        assert_eq!(metadata.format, RoaringBoolFormat::Native);
+
+        let current = RoaringBoolMetadata {
+            format: RoaringBoolFormat::Portable,
+        }
+        .try_serialize_metadata()
+        .unwrap();
+        let metadata = RoaringBoolMetadata::try_deserialize_metadata(Some(&current)).unwrap();
+        assert_eq!(metadata.format, RoaringBoolFormat::Portable);
+
+        let bitmap = Bitmap::of(&[1, 5, 7]);
+        let array = RoaringBoolArray::try_new(bitmap.clone(), 10).unwrap();
+        assert_eq!(array.format(), RoaringBoolFormat::Portable);
+        assert_eq!(array.bitmap(), bitmap);
+
+        let legacy = RoaringBoolArray {
+            typed: TypedArray::try_from_parts(
+                DType::Bool(NonNullable),
+                10,
+                RoaringBoolMetadata {
+                    format: RoaringBoolFormat::Native,
+                },
+                Some(Buffer::from(bitmap.serialize::<Native>())),
+                vec![].into(),
+                StatsSet::new(),
+            )
+            .unwrap(),
+        };
+        assert_eq!(legacy.bitmap(), bitmap);
+        assert_eq!(
+            legacy.into_bool().unwrap().boolean_buffer(),
+            array.into_bool().unwrap().boolean_buffer()
+        );
+    }
 }
@@ -1,10 +1,12 @@
 use croaring::Bitmap;
 use num_traits::NumCast;
 use vortex::array::PrimitiveArray;
+use vortex::compute::mask_to_indices;
+use vortex::{Array, IntoArrayVariant};
 use vortex_dtype::{NativePType, PType};
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
-use crate::RoaringIntArray;
+use crate::{RoaringBoolArray, RoaringIntArray};
 
 pub fn roaring_int_encode(parray: PrimitiveArray) -> VortexResult<RoaringIntArray> {
     match parray.ptype() {
@@ -16,6 +18,15 @@ pub fn roaring_int_encode(parray: PrimitiveArray) -> VortexResult<RoaringIntArra
     }
 }
 
+/// Like [`mask_to_indices`], but returns the `u64` indices as a roaring bitmap. Roaring bool masks
+/// are converted without decoding their bitmap.
+pub fn roaring_mask_to_indices(mask: &Array) -> VortexResult<RoaringIntArray> {
+    if let Ok(roaring) = RoaringBoolArray::try_from(mask) {
+        return RoaringIntArray::try_new(roaring.bitmap(), PType::U64);
+    }
+    roaring_int_encode(mask_to_indices(mask)?.into_primitive()?)
+}
+
 fn roaring_encode_primitive<T: NumCast + NativePType>(
     values: &[T],
 ) -> VortexResult<RoaringIntArray> {
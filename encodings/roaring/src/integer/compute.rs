@@ -1,14 +1,25 @@
+use arrow_buffer::BooleanBuffer;
 use croaring::Bitmap;
+use vortex::array::BoolArray;
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::{ArrayCompute, SliceFn};
+use vortex::compute::{ArrayCompute, CompareScalarFn, FilterFn, Operator, SliceFn};
+use vortex::validity::Validity;
 use vortex::{Array, IntoArray};
-use vortex_dtype::PType;
+use vortex_dtype::{DType, Nullability, PType};
 use vortex_error::{vortex_err, VortexResult, VortexUnwrap as _};
 use vortex_scalar::Scalar;
 
-use crate::RoaringIntArray;
+use crate::{selected_positions, RoaringIntArray};
 
 impl ArrayCompute for RoaringIntArray {
+    fn compare_scalar(&self) -> Option<&dyn CompareScalarFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -39,6 +50,68 @@ impl ScalarAtFn for RoaringIntArray {
     }
 }
 
+/// The values are a sorted set, so every comparison matches a single range of positions, found by
+/// the rank of the value in the bitmap.
+impl CompareScalarFn for RoaringIntArray {
+    fn compare_scalar(&self, scalar: &Scalar, operator: Operator) -> VortexResult<Array> {
+        let len = self.len();
+        if scalar.is_null() {
+            return BoolArray::try_new(BooleanBuffer::new_unset(len), Validity::AllInvalid)
+                .map(IntoArray::into_array);
+        }
+
+        // Values are unsigned, so a negative scalar is below all of them
+        let negative = matches!(scalar.dtype(), DType::Primitive(ptype, _) if ptype.is_signed_int())
+            && i64::try_from(
+                &scalar.cast(&DType::Primitive(PType::I64, Nullability::NonNullable))?,
+            )? < 0;
+        let bitmap = self.owned_bitmap();
+        // Number of values less than, and less than or equal to, the scalar
+        let (lt, lte) = if negative {
+            (0, 0)
+        } else {
+            let value = u64::try_from(
+                &scalar.cast(&DType::Primitive(PType::U64, Nullability::NonNullable))?,
+            )?;
+            match u32::try_from(value) {
+                Ok(value) => {
+                    let lte = bitmap.rank(value) as usize;
+                    (lte - bitmap.contains(value) as usize, lte)
+                }
+                Err(_) => (len, len),
+            }
+        };
+
+        let matching = match operator {
+            Operator::Eq | Operator::NotEq => lt..lte,
+            Operator::Gt => lte..len,
+            Operator::Gte => lt..len,
+            Operator::Lt => 0..lt,
+            Operator::Lte => 0..lte,
+        };
+        let negate = operator == Operator::NotEq;
+        let buffer = BooleanBuffer::collect_bool(len, |idx| matching.contains(&idx) != negate);
+        BoolArray::try_new(buffer, Validity::AllValid).map(IntoArray::into_array)
+    }
+}
+
+/// Any subset of the values is still a sorted set, so the filtered values stay a bitmap.
+impl FilterFn for RoaringIntArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let values = self.owned_bitmap();
+        let mut bitmap = selected_positions(predicate)?
+            .iter()
+            .map(|position| {
+                values
+                    .select(position)
+                    .ok_or_else(|| vortex_err!(OutOfBounds: position as usize, 0, self.len()))
+            })
+            .collect::<VortexResult<Bitmap>>()?;
+        bitmap.run_optimize();
+        Self::try_new(bitmap, self.ptype()).map(IntoArray::into_array)
+    }
+}
+
 impl SliceFn for RoaringIntArray {
     fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
         let mut bitmap = self.owned_bitmap();
@@ -61,10 +134,13 @@ impl SliceFn for RoaringIntArray {
 #[cfg(test)]
 mod tests {
     use vortex::array::PrimitiveArray;
-    use vortex::compute::slice;
     use vortex::compute::unary::scalar_at;
+    use vortex::compute::{compare_scalar, filter, slice};
+    use vortex::validity::ArrayValidity;
+    use vortex::IntoArrayVariant;
 
     use super::*;
+    use crate::RoaringBoolArray;
 
     #[test]
     #[cfg_attr(miri, ignore)]
@@ -76,6 +152,90 @@ mod tests {
         assert_eq!(scalar_at(&array, 1).unwrap(), 12u32.into());
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_compare_scalar() {
+        let array =
+            RoaringIntArray::encode(PrimitiveArray::from(vec![2u32, 12, 22, 32]).into_array())
+                .unwrap();
+        let matches = |value: u32, operator: Operator| {
+            compare_scalar(&array, &value.into(), operator)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(matches(12, Operator::Eq), vec![false, true, false, false]);
+        assert_eq!(matches(13, Operator::Eq), vec![false; 4]);
+        assert_eq!(matches(12, Operator::NotEq), vec![true, false, true, true]);
+        assert_eq!(matches(12, Operator::Gt), vec![false, false, true, true]);
+        assert_eq!(matches(12, Operator::Gte), vec![false, true, true, true]);
+        assert_eq!(matches(13, Operator::Lt), vec![true, true, false, false]);
+        assert_eq!(matches(22, Operator::Lte), vec![true, true, true, false]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_compare_negative_and_null_scalar() {
+        let array = RoaringIntArray::try_new(Bitmap::of(&[2, 12, 22, 32]), PType::U32).unwrap();
+        // Call the kernel directly rather than through the canonicalizing fallback
+        let compare = |scalar: &Scalar, operator: Operator| {
+            <RoaringIntArray as CompareScalarFn>::compare_scalar(&array, scalar, operator)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+        };
+
+        for (operator, expected) in [
+            (Operator::Eq, false),
+            (Operator::NotEq, true),
+            (Operator::Gt, true),
+            (Operator::Gte, true),
+            (Operator::Lt, false),
+            (Operator::Lte, false),
+        ] {
+            let result = compare(&(-5i64).into(), operator);
+            assert_eq!(
+                result.boolean_buffer().iter().collect::<Vec<_>>(),
+                vec![expected; 4],
+                "{operator}"
+            );
+        }
+
+        let null = Scalar::null(DType::Primitive(PType::U32, Nullability::Nullable));
+        let result = compare(&null, Operator::Eq);
+        assert_eq!(result.len(), 4);
+        assert!((0..4).all(|idx| !result.is_valid(idx)));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_filter() {
+        let array =
+            RoaringIntArray::encode(PrimitiveArray::from(vec![2u32, 12, 22, 32]).into_array())
+                .unwrap();
+        let predicate =
+            RoaringBoolArray::encode(BoolArray::from(vec![true, false, false, true]).into_array())
+                .unwrap();
+
+        let filtered = RoaringIntArray::try_from(filter(&array, &predicate).unwrap()).unwrap();
+        assert_eq!(filtered.ptype(), PType::U32);
+        assert_eq!(filtered.owned_bitmap().to_vec(), vec![2, 32]);
+
+        let filtered = filter(
+            &array,
+            BoolArray::from(vec![false, true, true, false]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(
+            filtered.into_primitive().unwrap().maybe_null_slice::<u32>(),
+            &[12, 22]
+        );
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_slice() {
@@ -168,7 +168,31 @@ impl RunEndArray {
     }
 }
 
-impl ArrayTrait for RunEndArray {}
+impl ArrayTrait for RunEndArray {
+    fn validate(&self) -> VortexResult<()> {
+        let ends = self.ends();
+        let values = self.values();
+        if values.len() != ends.len() {
+            vortex_bail!("{} values for {} runs", values.len(), ends.len());
+        }
+        if !ends.statistics().compute_is_strict_sorted().unwrap_or(true) {
+            vortex_bail!("Ends array must be strictly sorted");
+        }
+
+        let last_end: usize = if ends.is_empty() {
+            0
+        } else {
+            scalar_at(&ends, ends.len() - 1)?.as_ref().try_into()?
+        };
+        if last_end < self.offset() + self.len() {
+            vortex_bail!(
+                "Runs end at {last_end}, before the end of the array at {}",
+                self.offset() + self.len()
+            );
+        }
+        Ok(())
+    }
+}
 
 impl ArrayVariants for RunEndArray {
     fn as_primitive_array(&self) -> Option<&dyn PrimitiveArrayTrait> {
@@ -238,4 +262,25 @@ mod tests {
         assert_eq!(scalar_at(arr.as_ref(), 5).unwrap(), 3.into());
         assert_eq!(scalar_at(arr.as_ref(), 9).unwrap(), 3.into());
     }
+
+    #[test]
+    fn validate_runs_cover_array() {
+        let valid = RunEndArray::try_new(
+            vec![2u32, 5].into_array(),
+            vec![1i32, 2].into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap();
+        valid.as_ref().validate().unwrap();
+
+        let invalid = RunEndArray::with_offset_and_length(
+            vec![2u32, 5].into_array(),
+            vec![1i32, 2].into_array(),
+            Validity::NonNullable,
+            0,
+            8,
+        )
+        .unwrap();
+        assert!(invalid.as_ref().validate().is_err());
+    }
 }
@@ -1,5 +1,5 @@
 use vortex::array::{ConstantArray, PrimitiveArray, SparseArray};
-use vortex::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
+use vortex::compute::unary::{scalar_at, scalar_at_many, scalar_at_unchecked, ScalarAtFn};
 use vortex::compute::{filter, slice, take, ArrayCompute, SliceFn, TakeFn};
 use vortex::validity::Validity;
 use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
@@ -34,6 +34,14 @@ impl ScalarAtFn for RunEndArray {
             .vortex_expect("Search must be implemented for the underlying index array");
         scalar_at_unchecked(self.values(), idx)
     }
+
+    fn scalar_at_many(&self, indices: &[usize]) -> VortexResult<Vec<Scalar>> {
+        let offset_indices = indices
+            .iter()
+            .map(|idx| (idx + self.offset()) as u64)
+            .collect::<Vec<_>>();
+        scalar_at_many(self.values(), &self.find_physical_indices(&offset_indices)?)
+    }
 }
 
 impl TakeFn for RunEndArray {
@@ -113,7 +121,7 @@ impl SliceFn for RunEndArray {
 #[cfg(test)]
 mod test {
     use vortex::array::{BoolArray, PrimitiveArray};
-    use vortex::compute::unary::{scalar_at, try_cast};
+    use vortex::compute::unary::{scalar_at, scalar_at_many, try_cast};
     use vortex::compute::{slice, take};
     use vortex::validity::{ArrayValidity, Validity};
     use vortex::{ArrayDType, IntoArray, IntoArrayVariant, ToArray};
@@ -142,6 +150,15 @@ mod test {
         );
     }
 
+    #[test]
+    fn ree_scalar_at_many() {
+        let sliced = slice(ree_array().as_ref(), 2, 10).unwrap();
+        assert_eq!(
+            scalar_at_many(&sliced, &[0, 7, 1, 4]).unwrap(),
+            vec![1.into(), 5.into(), 4.into(), 2.into()]
+        );
+    }
+
     #[test]
     fn ree_take_end() {
         let taken = take(
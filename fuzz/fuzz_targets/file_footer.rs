@@ -0,0 +1,27 @@
+#![no_main]
+
+use futures_executor::block_on;
+use libfuzzer_sys::{fuzz_target, Corpus};
+use vortex_serde::layouts::{LayoutDescriptorReader, LayoutDeserializer, LayoutReaderBuilder};
+
+fuzz_target!(|data: &[u8]| -> Corpus {
+    let data = data.to_vec();
+    let Ok(footer) = block_on(
+        LayoutDescriptorReader::new(LayoutDeserializer::default())
+            .read_footer(&data, data.len() as u64),
+    ) else {
+        return Corpus::Reject;
+    };
+
+    // Malformed files may fail at any step but must never panic
+    let _ = footer.dtype();
+    let _ = block_on(async {
+        LayoutReaderBuilder::new(data, LayoutDeserializer::default())
+            .with_footer(footer)
+            .build()
+            .await?
+            .read_all()
+            .await
+    });
+    Corpus::Keep
+});
@@ -4,7 +4,7 @@ use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{IntoPyDict, PyInt, PyList};
 use vortex::array::ChunkedArray;
-use vortex::compute::unary::{fill_forward, scalar_at};
+use vortex::compute::unary::{fill_forward, scalar_at_wrapping};
 use vortex::compute::{compare, slice, take, Operator};
 use vortex::{Array, ArrayDType, IntoCanonical};
 
@@ -395,15 +395,13 @@ impl PyArray {
     /// ValueError: index 10 out of bounds from 0 to 4
     /// ...
     ///
-    /// Unlike Python, negative indices are not supported:
+    /// As in Python, negative indices count back from the end of the array:
     ///
     /// >>> vortex.encoding.array([10, 42, 999, 1992]).scalar_at(-2)
-    /// Traceback (most recent call last):
-    /// ...
-    /// OverflowError: can't convert negative int to unsigned
+    /// 999
     ///
     fn scalar_at(&self, index: &Bound<PyInt>) -> PyResult<PyObject> {
-        scalar_at(&self.inner, index.extract()?)
+        scalar_at_wrapping(&self.inner, index.extract()?)
             .map_err(PyVortexError::map_err)
             .and_then(|scalar| scalar_into_py(index.py(), scalar, false))
     }
@@ -539,7 +537,7 @@ impl PyArray {
     ///       metadata: PrimitiveMetadata { validity: Array }
     ///       buffer: 32 B
     ///       validity: vortex.bool(0x02)(bool, len=4) nbytes=1 B (3.03%)
-    ///         metadata: BoolMetadata { validity: NonNullable, first_byte_bit_offset: 0 }
+    ///         metadata: BoolMetadata { validity: NonNullable, first_byte_bit_offset: 0, representation: Bits }
     ///         buffer: 1 B
     ///     <BLANKLINE>
     ///
@@ -5,6 +5,7 @@ use itertools::Itertools;
 use rand::distributions::Uniform;
 use rand::{thread_rng, Rng};
 use vortex::array::ChunkedArray;
+use vortex::compute::unary::OverflowPolicy;
 use vortex::IntoArray;
 use vortex_error::VortexError;
 
@@ -28,8 +29,12 @@ fn scalar_subtract(c: &mut Criterion) {
 
     group.bench_function("vortex", |b| {
         b.iter(|| {
-            let array =
-                vortex::compute::unary::subtract_scalar(&chunked, &to_subtract.into()).unwrap();
+            let array = vortex::compute::unary::subtract_scalar(
+                &chunked,
+                &to_subtract.into(),
+                OverflowPolicy::Wrapping,
+            )
+            .unwrap();
 
             let chunked = ChunkedArray::try_from(array).unwrap();
             black_box(chunked);
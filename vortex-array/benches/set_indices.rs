@@ -0,0 +1,38 @@
+#![allow(clippy::unwrap_used)]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use itertools::Itertools;
+use rand::distributions::Bernoulli;
+use rand::{thread_rng, Rng};
+use vortex::array::BoolArray;
+use vortex::variants::BoolArrayTrait;
+
+fn set_indices(c: &mut Criterion) {
+    let mut group = c.benchmark_group("set_indices");
+
+    let mut rng = thread_rng();
+    for selectivity in [0.01, 0.5, 0.99] {
+        let mask = BoolArray::from(
+            (&mut rng)
+                .sample_iter(Bernoulli::new(selectivity).unwrap())
+                .take(10_000_000)
+                .collect_vec(),
+        );
+
+        group.bench_function(format!("naive_{selectivity}"), |b| {
+            b.iter(|| {
+                black_box(
+                    mask.maybe_null_indices_iter()
+                        .map(|i| i as u64)
+                        .collect_vec(),
+                )
+            });
+        });
+        group.bench_function(format!("words_{selectivity}"), |b| {
+            b.iter(|| black_box(mask.set_indices()));
+        });
+    }
+}
+
+criterion_group!(benches, set_indices);
+criterion_main!(benches);
@@ -45,5 +45,12 @@ fn bench_varbinview(c: &mut Criterion) {
     });
 }
 
-criterion_group!(bench_take, bench_varbin, bench_varbinview);
+fn bench_varbin_1m(c: &mut Criterion) {
+    let array = fixture(1_000_000);
+    let indices = indices(1_000_000);
+
+    c.bench_function("varbin_1m", |b| b.iter(|| take(&array, &indices).unwrap()));
+}
+
+criterion_group!(bench_take, bench_varbin, bench_varbinview, bench_varbin_1m);
 criterion_main!(bench_take);
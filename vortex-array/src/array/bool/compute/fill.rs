@@ -1,8 +1,9 @@
 use arrow_buffer::BooleanBuffer;
+use vortex_buffer::Buffer;
 use vortex_dtype::Nullability;
 use vortex_error::{vortex_err, VortexResult};
 
-use crate::array::BoolArray;
+use crate::array::{BoolArray, BoolRepresentation};
 use crate::compute::unary::FillForwardFn;
 use crate::validity::{ArrayValidity, Validity};
 use crate::{Array, ArrayDType, IntoArray};
@@ -14,23 +15,49 @@ impl FillForwardFn for BoolArray {
         if self.dtype().nullability() == Nullability::NonNullable {
             return Ok(self.clone().into());
         }
+        let bytes = self.representation() == BoolRepresentation::Bytes;
         // all valid, but we need to convert to non-nullable
         if validity.all_valid() {
-            return Ok(
-                Self::try_new(self.boolean_buffer().clone(), Validity::AllValid)?.into_array(),
-            );
+            return Ok(if bytes {
+                Self::try_new_bytes_unchecked(self.buffer().clone(), Validity::AllValid)?
+            } else {
+                Self::try_new(self.boolean_buffer().clone(), Validity::AllValid)?
+            }
+            .into_array());
         }
         // all invalid => fill with default value (false)
         if validity.all_invalid() {
-            return Ok(
+            return Ok(if bytes {
+                Self::try_new_bytes_unchecked(
+                    Buffer::from(vec![0u8; self.len()]),
+                    Validity::AllValid,
+                )?
+            } else {
                 Self::try_new(BooleanBuffer::new_unset(self.len()), Validity::AllValid)?
-                    .into_array(),
-            );
+            }
+            .into_array());
         }
 
         let validity = validity
             .to_null_buffer()?
             .ok_or_else(|| vortex_err!("Failed to convert array validity to null buffer"))?;
+        if bytes {
+            let mut last_value = 0;
+            let filled = self
+                .buffer()
+                .as_slice()
+                .iter()
+                .zip(validity.inner().iter())
+                .map(|(v, valid)| {
+                    if valid {
+                        last_value = *v;
+                    }
+                    last_value
+                })
+                .collect::<Vec<_>>();
+            return Self::try_new_bytes_unchecked(Buffer::from(filled), Validity::AllValid)
+                .map(IntoArray::into_array);
+        }
         let bools = self.boolean_buffer();
         let mut last_value = false;
         let filled = bools
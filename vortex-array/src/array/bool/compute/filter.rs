@@ -1,7 +1,8 @@
 use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
+use vortex_buffer::Buffer;
 use vortex_error::{vortex_err, VortexResult};
 
-use crate::array::BoolArray;
+use crate::array::{BoolArray, BoolRepresentation};
 use crate::compute::FilterFn;
 use crate::variants::BoolArrayTrait;
 use crate::{Array, IntoArray};
@@ -20,6 +21,13 @@ fn filter_select_bool(arr: &BoolArray, predicate: &Array) -> VortexResult<BoolAr
             predicate.encoding().id()
         ))?;
         let selection_count = predicate.true_count();
+        if arr.representation() == BoolRepresentation::Bytes {
+            return BoolArray::try_new_bytes_unchecked(
+                filter_select_bytes(arr.buffer(), predicate, selection_count),
+                validity,
+            );
+        }
+
         let out = if selection_count * 2 > arr.len() {
             filter_select_bool_by_slice(&arr.boolean_buffer(), predicate, selection_count)
         } else {
@@ -41,6 +49,19 @@ fn filter_select_bool_by_slice(
     out_buf.finish()
 }
 
+fn filter_select_bytes(
+    values: &Buffer,
+    predicate: &dyn BoolArrayTrait,
+    selection_count: usize,
+) -> Buffer {
+    let values = values.as_slice();
+    let mut out = Vec::with_capacity(selection_count);
+    predicate
+        .maybe_null_slices_iter()
+        .for_each(|(start, end)| out.extend_from_slice(&values[start..end]));
+    Buffer::from(out)
+}
+
 fn filter_select_bool_by_index(
     values: &BooleanBuffer,
     predicate: &dyn BoolArrayTrait,
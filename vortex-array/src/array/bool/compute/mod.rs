@@ -1,6 +1,6 @@
 use crate::array::BoolArray;
 use crate::compute::unary::{FillForwardFn, ScalarAtFn};
-use crate::compute::{AndFn, ArrayCompute, OrFn, SliceFn, TakeFn};
+use crate::compute::{AndFn, ArrayCompute, FilterFn, OrFn, SliceFn, TakeFn};
 
 mod boolean;
 
@@ -8,6 +8,7 @@ mod fill;
 mod filter;
 mod flatten;
 mod scalar_at;
+mod set_indices;
 mod slice;
 mod take;
 
@@ -16,6 +17,10 @@ impl ArrayCompute for BoolArray {
         Some(self)
     }
 
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -1,7 +1,7 @@
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
-use crate::array::BoolArray;
+use crate::array::{BoolArray, BoolRepresentation};
 use crate::compute::unary::ScalarAtFn;
 use crate::ArrayDType;
 
@@ -11,13 +11,12 @@ impl ScalarAtFn for BoolArray {
     }
 
     fn scalar_at_unchecked(&self, index: usize) -> Scalar {
-        // SAFETY:
-        // `scalar_at_unchecked` is fine with undefined behavior, so it should be acceptable here
-        unsafe {
-            Scalar::bool(
-                self.boolean_buffer().value_unchecked(index),
-                self.dtype().nullability(),
-            )
-        }
+        let value = match self.representation() {
+            // SAFETY:
+            // `scalar_at_unchecked` is fine with undefined behavior, so it should be acceptable here
+            BoolRepresentation::Bits => unsafe { self.boolean_buffer().value_unchecked(index) },
+            BoolRepresentation::Bytes => self.buffer()[index] != 0,
+        };
+        Scalar::bool(value, self.dtype().nullability())
     }
 }
@@ -0,0 +1,119 @@
+use arrow_buffer::BooleanBuffer;
+#[cfg(feature = "simd")]
+use wide::u64x4;
+
+use crate::array::BoolArray;
+
+/// Number of 64 bit words compared at once
+const LANES: usize = 4;
+
+impl BoolArray {
+    /// Positions of the true values, e.g. to turn a filter predicate into take indices.
+    pub fn set_indices(&self) -> Vec<u64> {
+        set_indices(&self.boolean_buffer())
+    }
+}
+
+/// Scans the buffer 256 bits at a time, skipping blocks that are all false and emitting whole
+/// ranges for blocks that are all true. The words of the remaining blocks are walked one set bit
+/// at a time using their trailing zero count. With the `simd` feature, blocks are compared with
+/// SIMD instructions.
+pub(crate) fn set_indices(buffer: &BooleanBuffer) -> Vec<u64> {
+    let mut indices = Vec::with_capacity(buffer.count_set_bits());
+    let chunks = buffer.inner().bit_chunks(buffer.offset(), buffer.len());
+    let mut words = chunks.iter();
+    let mut base = 0u64;
+
+    let mut block = [0u64; LANES];
+    for _ in 0..chunks.chunk_len() / LANES {
+        for word in block.iter_mut() {
+            *word = words.next().unwrap_or_default();
+        }
+        let (all, none) = block_state(block);
+        if all {
+            indices.extend(base..base + (LANES * 64) as u64);
+        } else if !none {
+            for (i, word) in block.iter().enumerate() {
+                push_word_indices(&mut indices, *word, base + (i * 64) as u64);
+            }
+        }
+        base += (LANES * 64) as u64;
+    }
+
+    for word in words {
+        push_word_indices(&mut indices, word, base);
+        base += 64;
+    }
+    // Bits past the end of the buffer are zero
+    push_word_indices(&mut indices, chunks.remainder_bits(), base);
+    indices
+}
+
+/// Whether all bits of the block are set, and whether none are
+#[cfg(feature = "simd")]
+#[inline]
+fn block_state(block: [u64; LANES]) -> (bool, bool) {
+    let lanes = u64x4::new(block);
+    (lanes == u64x4::splat(u64::MAX), lanes == u64x4::default())
+}
+
+#[cfg(not(feature = "simd"))]
+#[inline]
+fn block_state(block: [u64; LANES]) -> (bool, bool) {
+    (block == [u64::MAX; LANES], block == [0; LANES])
+}
+
+#[inline]
+fn push_word_indices(indices: &mut Vec<u64>, mut word: u64, base: u64) {
+    while word != 0 {
+        indices.push(base + word.trailing_zeros() as u64);
+        word &= word - 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_buffer::BooleanBuffer;
+    use itertools::Itertools;
+
+    use crate::array::bool::compute::set_indices::set_indices;
+    use crate::array::BoolArray;
+    use crate::compute::slice;
+    use crate::variants::BoolArrayTrait;
+    use crate::IntoArrayVariant;
+
+    #[test]
+    fn matches_naive_indices() {
+        // Mix of empty, full and partially set blocks with a remainder
+        let bools = (0..2000)
+            .map(|i| (256..512).contains(&i) || (i > 1024 && i % 7 == 0))
+            .collect_vec();
+        let array = BoolArray::from(bools);
+        let expected = array
+            .maybe_null_indices_iter()
+            .map(|i| i as u64)
+            .collect_vec();
+        assert_eq!(array.set_indices(), expected);
+    }
+
+    #[test]
+    fn sliced_buffer() {
+        let array = BoolArray::from((0..1000).map(|i| i % 3 == 0).collect_vec());
+        let sliced = slice(array.as_ref(), 5, 700).unwrap().into_bool().unwrap();
+        let expected = (5..700)
+            .filter(|i| i % 3 == 0)
+            .map(|i| (i - 5) as u64)
+            .collect_vec();
+        assert_eq!(sliced.set_indices(), expected);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(set_indices(&BooleanBuffer::new_unset(0)).is_empty());
+        assert!(set_indices(&BooleanBuffer::new_unset(300)).is_empty());
+        assert_eq!(
+            set_indices(&BooleanBuffer::new_set(300)),
+            (0..300).collect_vec()
+        );
+    }
+}
@@ -1,11 +1,19 @@
 use vortex_error::VortexResult;
 
-use crate::array::BoolArray;
+use crate::array::{BoolArray, BoolRepresentation};
 use crate::compute::SliceFn;
 use crate::{Array, IntoArray};
 
 impl SliceFn for BoolArray {
     fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        if self.representation() == BoolRepresentation::Bytes {
+            return Self::try_new_bytes_unchecked(
+                self.buffer().slice(start..stop),
+                self.validity().slice(start, stop)?,
+            )
+            .map(|a| a.into_array());
+        }
+
         Self::try_new(
             self.boolean_buffer().slice(start, stop - start),
             self.validity().slice(start, stop)?,
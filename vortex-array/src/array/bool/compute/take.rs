@@ -1,9 +1,10 @@
 use arrow_buffer::BooleanBuffer;
 use num_traits::AsPrimitive;
+use vortex_buffer::Buffer;
 use vortex_dtype::match_each_integer_ptype;
 use vortex_error::VortexResult;
 
-use crate::array::BoolArray;
+use crate::array::{BoolArray, BoolRepresentation};
 use crate::compute::TakeFn;
 use crate::{Array, IntoArray, IntoArrayVariant};
 
@@ -12,10 +13,18 @@ impl TakeFn for BoolArray {
         let validity = self.validity();
         let indices = indices.clone().into_primitive()?;
         match_each_integer_ptype!(indices.ptype(), |$I| {
-            Ok(BoolArray::try_new(
-                take_bool(&self.boolean_buffer(), indices.maybe_null_slice::<$I>()),
-                validity.take(indices.as_ref())?,
-            )?.into_array())
+            let indices_slice = indices.maybe_null_slice::<$I>();
+            let taken = match self.representation() {
+                BoolRepresentation::Bits => BoolArray::try_new(
+                    take_bool(&self.boolean_buffer(), indices_slice),
+                    validity.take(indices.as_ref())?,
+                )?,
+                BoolRepresentation::Bytes => BoolArray::try_new_bytes_unchecked(
+                    take_bytes(self.buffer(), indices_slice),
+                    validity.take(indices.as_ref())?,
+                )?,
+            };
+            Ok(taken.into_array())
         })
     }
 }
@@ -24,6 +33,16 @@ fn take_bool<I: AsPrimitive<usize>>(bools: &BooleanBuffer, indices: &[I]) -> Boo
     BooleanBuffer::collect_bool(indices.len(), |idx| bools.value(indices[idx].as_()))
 }
 
+fn take_bytes<I: AsPrimitive<usize>>(bytes: &Buffer, indices: &[I]) -> Buffer {
+    let bytes = bytes.as_slice();
+    Buffer::from(
+        indices
+            .iter()
+            .map(|idx| bytes[idx.as_()])
+            .collect::<Vec<_>>(),
+    )
+}
+
 #[cfg(test)]
 mod test {
     use crate::array::primitive::PrimitiveArray;
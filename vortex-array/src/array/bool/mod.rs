@@ -6,7 +6,7 @@ use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
-use vortex_error::{VortexExpect as _, VortexResult};
+use vortex_error::{vortex_bail, VortexExpect as _, VortexResult};
 
 use crate::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use crate::encoding::ids;
@@ -25,6 +25,18 @@ impl_encoding!("vortex.bool", ids::BOOL, Bool);
 pub struct BoolMetadata {
     validity: ValidityMetadata,
     first_byte_bit_offset: u8,
+    #[serde(default)]
+    representation: BoolRepresentation,
+}
+
+/// How the values of a [`BoolArray`] are stored in its buffer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BoolRepresentation {
+    /// Packed bits, as in Arrow
+    #[default]
+    Bits,
+    /// A byte of 0 or 1 per value, for kernels that work on bytes
+    Bytes,
 }
 
 impl Display for BoolMetadata {
@@ -34,18 +46,55 @@ impl Display for BoolMetadata {
 }
 
 impl BoolArray {
+    /// The buffer of values, in the [representation](Self::representation) of the array
     pub fn buffer(&self) -> &Buffer {
         self.as_ref()
             .buffer()
             .vortex_expect("Missing buffer in BoolArray")
     }
 
+    pub fn representation(&self) -> BoolRepresentation {
+        self.metadata().representation
+    }
+
+    /// The values as packed bits, packing them if the array stores bytes
     pub fn boolean_buffer(&self) -> BooleanBuffer {
-        BooleanBuffer::new(
-            self.buffer().clone().into_arrow(),
-            self.metadata().first_byte_bit_offset as usize,
-            self.len(),
-        )
+        match self.representation() {
+            BoolRepresentation::Bits => BooleanBuffer::new(
+                self.buffer().clone().into_arrow(),
+                self.metadata().first_byte_bit_offset as usize,
+                self.len(),
+            ),
+            BoolRepresentation::Bytes => {
+                let bytes = self.buffer().as_slice();
+                BooleanBuffer::collect_bool(self.len(), |idx| bytes[idx] != 0)
+            }
+        }
+    }
+
+    /// The values as a byte of 0 or 1 each, unpacking them if the array stores bits
+    pub fn byte_buffer(&self) -> Buffer {
+        match self.representation() {
+            BoolRepresentation::Bits => Buffer::from(
+                self.boolean_buffer()
+                    .iter()
+                    .map(u8::from)
+                    .collect::<Vec<_>>(),
+            ),
+            BoolRepresentation::Bytes => self.buffer().clone(),
+        }
+    }
+
+    /// The same values stored in the given representation, without copying if they already are
+    pub fn into_representation(self, representation: BoolRepresentation) -> VortexResult<Self> {
+        if self.representation() == representation {
+            return Ok(self);
+        }
+
+        match representation {
+            BoolRepresentation::Bits => Self::try_new(self.boolean_buffer(), self.validity()),
+            BoolRepresentation::Bytes => Self::try_new_bytes(self.byte_buffer(), self.validity()),
+        }
     }
 
     pub fn validity(&self) -> Validity {
@@ -73,6 +122,7 @@ impl BoolArray {
                 BoolMetadata {
                     validity: validity.to_metadata(buffer_len)?,
                     first_byte_bit_offset,
+                    representation: BoolRepresentation::Bits,
                 },
                 Some(Buffer::from(inner)),
                 validity.into_array().into_iter().collect_vec().into(),
@@ -81,13 +131,58 @@ impl BoolArray {
         })
     }
 
+    /// Array of the values of `bytes`, which must each be 0 or 1, stored as bytes
+    pub fn try_new_bytes(bytes: Buffer, validity: Validity) -> VortexResult<Self> {
+        if bytes.as_slice().iter().any(|b| *b > 1) {
+            vortex_bail!("Byte bools must be 0 or 1");
+        }
+        Self::try_new_bytes_unchecked(bytes, validity)
+    }
+
+    /// [`try_new_bytes`](Self::try_new_bytes) for bytes known to be 0 or 1, e.g. taken from
+    /// another byte bool array, without checking every value again
+    pub(crate) fn try_new_bytes_unchecked(bytes: Buffer, validity: Validity) -> VortexResult<Self> {
+        let len = bytes.len();
+        Ok(Self {
+            typed: TypedArray::try_from_parts(
+                DType::Bool(validity.nullability()),
+                len,
+                BoolMetadata {
+                    validity: validity.to_metadata(len)?,
+                    first_byte_bit_offset: 0,
+                    representation: BoolRepresentation::Bytes,
+                },
+                Some(bytes),
+                validity.into_array().into_iter().collect_vec().into(),
+                StatsSet::new(),
+            )?,
+        })
+    }
+
     pub fn from_vec(bools: Vec<bool>, validity: Validity) -> Self {
         let buffer = BooleanBuffer::from(bools);
         Self::try_new(buffer, validity).vortex_expect("Failed to create BoolArray from vec")
     }
 }
 
-impl ArrayTrait for BoolArray {}
+impl ArrayTrait for BoolArray {
+    fn validate(&self) -> VortexResult<()> {
+        let required = match self.representation() {
+            BoolRepresentation::Bits => {
+                (self.metadata().first_byte_bit_offset as usize + self.len()).div_ceil(8)
+            }
+            BoolRepresentation::Bytes => self.len(),
+        };
+        if self.buffer().len() < required {
+            vortex_bail!(
+                "Buffer of {} bytes doesn't hold {} values",
+                self.buffer().len(),
+                self.len()
+            );
+        }
+        Ok(())
+    }
+}
 
 impl ArrayVariants for BoolArray {
     fn as_bool_array(&self) -> Option<&dyn BoolArrayTrait> {
@@ -97,11 +192,26 @@ impl ArrayVariants for BoolArray {
 
 impl BoolArrayTrait for BoolArray {
     fn maybe_null_indices_iter<'a>(&'a self) -> Box<dyn Iterator<Item = usize> + 'a> {
-        Box::new(BitIndexIterator::new(self.buffer(), 0, self.len()))
+        match self.representation() {
+            BoolRepresentation::Bits => {
+                Box::new(BitIndexIterator::new(self.buffer(), 0, self.len()))
+            }
+            BoolRepresentation::Bytes => {
+                Box::new(self.buffer().as_slice().iter().positions(|b| *b != 0))
+            }
+        }
     }
 
     fn maybe_null_slices_iter<'a>(&'a self) -> Box<dyn Iterator<Item = (usize, usize)> + 'a> {
-        Box::new(BitSliceIterator::new(self.buffer(), 0, self.len()))
+        match self.representation() {
+            BoolRepresentation::Bits => {
+                Box::new(BitSliceIterator::new(self.buffer(), 0, self.len()))
+            }
+            BoolRepresentation::Bytes => {
+                let bits = self.boolean_buffer();
+                Box::new(bits.set_slices().collect_vec().into_iter())
+            }
+        }
     }
 }
 
@@ -162,12 +272,14 @@ impl AcceptArrayVisitor for BoolArray {
 #[cfg(test)]
 mod tests {
     use itertools::Itertools;
+    use vortex_buffer::Buffer;
 
-    use crate::array::BoolArray;
-    use crate::compute::unary::scalar_at;
-    use crate::validity::Validity;
+    use crate::array::{BoolArray, BoolRepresentation, PrimitiveArray};
+    use crate::compute::unary::{fill_forward, scalar_at};
+    use crate::compute::{filter, slice, take};
+    use crate::validity::{ArrayValidity, Validity};
     use crate::variants::BoolArrayTrait;
-    use crate::IntoArray;
+    use crate::{CanonicalOptions, IntoArray, IntoCanonical};
 
     #[test]
     fn bool_array() {
@@ -234,4 +346,53 @@ mod tests {
         assert_eq!(0, arr.maybe_null_indices_iter().collect_vec().len());
         assert_eq!(0, arr.maybe_null_slices_iter().collect_vec().len());
     }
+
+    #[test]
+    fn byte_representation() {
+        let bits = BoolArray::from_iter([Some(true), None, Some(false), Some(true)]);
+        let bytes = bits
+            .clone()
+            .into_representation(BoolRepresentation::Bytes)
+            .unwrap();
+        assert_eq!(bytes.representation(), BoolRepresentation::Bytes);
+        assert_eq!(bytes.buffer().as_slice(), &[1, 0, 0, 1]);
+        assert_eq!(bytes.boolean_buffer(), bits.boolean_buffer());
+        assert_eq!(bytes.maybe_null_indices_iter().collect_vec(), vec![0, 3]);
+        assert!(!bytes.is_valid(1));
+
+        let sliced = BoolArray::try_from(slice(&bytes, 1, 4).unwrap()).unwrap();
+        assert_eq!(sliced.representation(), BoolRepresentation::Bytes);
+        assert_eq!(sliced.byte_buffer().as_slice(), &[0, 0, 1]);
+
+        // Kernels work on the bytes and keep them
+        assert_eq!(scalar_at(&bytes, 3).unwrap(), scalar_at(&bits, 3).unwrap());
+        let taken =
+            BoolArray::try_from(take(&bytes, PrimitiveArray::from(vec![3u32, 0, 1])).unwrap())
+                .unwrap();
+        assert_eq!(taken.representation(), BoolRepresentation::Bytes);
+        assert_eq!(taken.buffer().as_slice(), &[1, 1, 0]);
+        assert!(!taken.is_valid(2));
+        let filtered = BoolArray::try_from(
+            filter(&bytes, BoolArray::from(vec![true, false, true, true])).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(filtered.representation(), BoolRepresentation::Bytes);
+        assert_eq!(filtered.buffer().as_slice(), &[1, 0, 1]);
+        let filled = BoolArray::try_from(fill_forward(&bytes).unwrap()).unwrap();
+        assert_eq!(filled.representation(), BoolRepresentation::Bytes);
+        assert_eq!(filled.buffer().as_slice(), &[1, 1, 0, 1]);
+
+        let canonical = bytes
+            .into_array()
+            .into_canonical_with(CanonicalOptions::default())
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(canonical.representation(), BoolRepresentation::Bits);
+        assert_eq!(canonical.boolean_buffer(), bits.boolean_buffer());
+
+        assert!(
+            BoolArray::try_new_bytes(Buffer::from(vec![0u8, 2]), Validity::NonNullable).is_err()
+        );
+    }
 }
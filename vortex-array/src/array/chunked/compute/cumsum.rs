@@ -0,0 +1,42 @@
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+
+use crate::array::ChunkedArray;
+use crate::compute::unary::{running_total, CumSumFn, RunningTotal};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+impl CumSumFn for ChunkedArray {
+    fn cumsum(&self) -> VortexResult<Array> {
+        let mut total = RunningTotal::new(self.dtype().try_into()?);
+        let chunks = self
+            .chunks()
+            .map(|chunk| Ok(running_total(&chunk.into_primitive()?, &mut total)?.into_array()))
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        ChunkedArray::try_new(
+            chunks,
+            DType::Primitive(total.ptype(), self.dtype().nullability()),
+        )
+        .map(IntoArray::into_array)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{ChunkedArray, PrimitiveArray};
+    use crate::compute::unary::cumsum;
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn cumsum_across_chunks() {
+        let chunked = ChunkedArray::from_iter([
+            PrimitiveArray::from(vec![1i8, 2, 3]).into_array(),
+            PrimitiveArray::from(vec![100i8, -50]).into_array(),
+        ]);
+        let sums = cumsum(chunked.into_array())
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(sums.maybe_null_slice::<i64>(), &[1, 3, 6, 106, 56]);
+    }
+}
@@ -202,7 +202,7 @@ fn filter_indices<'a>(
 
 // Mirrors the find_chunk_idx method on ChunkedArray, but avoids all of the overhead
 // from scalars, dtypes, and metadata cloning.
-fn find_chunk_idx(idx: usize, chunk_ends: &[u64]) -> (usize, usize) {
+pub(super) fn find_chunk_idx(idx: usize, chunk_ends: &[u64]) -> (usize, usize) {
     let chunk_id = chunk_ends
         .search_sorted(&(idx as u64), SearchSortedSide::Right)
         .to_ends_index(chunk_ends.len())
@@ -1,32 +1,43 @@
 use vortex_dtype::{DType, Nullability};
 use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
 
 use crate::array::chunked::ChunkedArray;
-use crate::compute::unary::{try_cast, CastFn, ScalarAtFn, SubtractScalarFn};
+use crate::compute::unary::{try_cast, BinaryScalarFn, CastFn, CumSumFn, ScalarAtFn};
 use crate::compute::{
-    compare, slice, ArrayCompute, CompareFn, FilterFn, Operator, SliceFn, TakeFn,
+    compare, compare_scalar, slice, ArrayCompute, CompareFn, CompareScalarFn, FilterFn, Operator,
+    SliceFn, TakeFn,
 };
 use crate::{Array, IntoArray};
 
+mod cumsum;
 mod filter;
 mod scalar_at;
 mod slice;
 mod take;
 
 impl ArrayCompute for ChunkedArray {
+    fn binary_scalar(&self) -> Option<&dyn BinaryScalarFn> {
+        Some(self)
+    }
+
     fn cast(&self) -> Option<&dyn CastFn> {
         Some(self)
     }
 
-    fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
-        Some(CompareFn::compare(self, other, operator))
+    fn compare_scalar(&self) -> Option<&dyn CompareScalarFn> {
+        Some(self)
     }
 
-    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+    fn cumsum(&self) -> Option<&dyn CumSumFn> {
         Some(self)
     }
 
-    fn subtract_scalar(&self) -> Option<&dyn SubtractScalarFn> {
+    fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        Some(CompareFn::compare(self, other, operator))
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
@@ -45,12 +56,9 @@ impl ArrayCompute for ChunkedArray {
 
 impl CastFn for ChunkedArray {
     fn cast(&self, dtype: &DType) -> VortexResult<Array> {
-        let mut cast_chunks = Vec::new();
-        for chunk in self.chunks() {
-            cast_chunks.push(try_cast(&chunk, dtype)?);
-        }
-
-        Ok(ChunkedArray::try_new(cast_chunks, dtype.clone())?.into_array())
+        Ok(self
+            .map_chunks_as(dtype.clone(), |chunk| try_cast(chunk, dtype))?
+            .into_array())
     }
 }
 
@@ -71,6 +79,16 @@ impl CompareFn for ChunkedArray {
     }
 }
 
+impl CompareScalarFn for ChunkedArray {
+    fn compare_scalar(&self, scalar: &Scalar, operator: Operator) -> VortexResult<Array> {
+        Ok(self
+            .map_chunks_as(DType::Bool(Nullability::Nullable), |chunk| {
+                compare_scalar(chunk, scalar, operator)
+            })?
+            .into_array())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use vortex_dtype::{DType, Nullability, PType};
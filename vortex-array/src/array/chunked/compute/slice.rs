@@ -18,17 +18,23 @@ impl SliceFn for ChunkedArray {
             .into_array());
         }
 
+        // Chunks in the middle of the range are reused as they are, only the first and last
+        // chunks are sliced, and only if they aren't fully covered by the range.
         let mut chunks = (offset_chunk..length_chunk + 1)
             .map(|i| self.chunk(i))
             .collect::<VortexResult<Vec<_>>>()?;
-        if let Some(c) = chunks.first_mut() {
-            *c = slice(&*c, offset_in_first_chunk, c.len())?;
+        if offset_in_first_chunk > 0 {
+            if let Some(c) = chunks.first_mut() {
+                *c = slice(&*c, offset_in_first_chunk, c.len())?;
+            }
         }
 
         if length_in_last_chunk == 0 {
             chunks.pop();
         } else if let Some(c) = chunks.last_mut() {
-            *c = slice(&*c, 0, length_in_last_chunk)?;
+            if length_in_last_chunk < c.len() {
+                *c = slice(&*c, 0, length_in_last_chunk)?;
+            }
         }
 
         Self::try_new(chunks, self.dtype().clone()).map(|a| a.into_array())
@@ -99,6 +105,24 @@ mod tests {
         assert_equal_slices(slice(chunked_array().as_ref(), 7, 8).unwrap(), &[8u64]);
     }
 
+    #[test]
+    pub fn slice_across_three_chunks() {
+        let chunked = chunked_array();
+        let sliced = ChunkedArray::try_from(slice(chunked.as_ref(), 1, 8).unwrap()).unwrap();
+        assert_eq!(sliced.nchunks(), 3);
+        assert_eq!(
+            sliced.chunks().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![2, 3, 2]
+        );
+
+        // The middle chunk is not copied
+        let middle = sliced.chunk(1).unwrap().into_primitive().unwrap();
+        let original = chunked.chunk(1).unwrap().into_primitive().unwrap();
+        assert_eq!(middle.buffer().as_ptr(), original.buffer().as_ptr());
+
+        assert_equal_slices(sliced.into_array(), &[2u64, 3, 4, 5, 6, 7, 8]);
+    }
+
     #[test]
     pub fn slice_exactly_end() {
         assert_equal_slices(
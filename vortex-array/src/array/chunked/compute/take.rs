@@ -1,16 +1,33 @@
 use itertools::Itertools;
-use vortex_dtype::PType;
-use vortex_error::VortexResult;
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
+#[cfg(feature = "rayon")]
+use super::filter::find_chunk_idx;
 use crate::array::chunked::ChunkedArray;
-use crate::compute::unary::{scalar_at, subtract_scalar, try_cast};
+use crate::array::PrimitiveArray;
+use crate::arrow::FromArrowArray;
+use crate::compute::unary::{scalar_at, subtract_scalar, try_cast, OverflowPolicy};
 use crate::compute::{search_sorted, slice, take, SearchSortedSide, TakeFn};
 use crate::stats::ArrayStatistics;
-use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant, ToArray};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical, ToArray};
 
 impl TakeFn for ChunkedArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
+        if !indices.with_dyn(|a| a.logical_validity().all_valid()) {
+            return take_nullable(self, indices);
+        }
+
+        let u64_indices = try_cast(indices, PType::U64.into())?.into_primitive()?;
+        if let Some(idx) = u64_indices
+            .maybe_null_slice::<u64>()
+            .iter()
+            .find(|&&idx| idx >= self.len() as u64)
+        {
+            vortex_bail!(OutOfBounds: *idx as usize, 0, self.len());
+        }
+
         // Fast path for strict sorted indices.
         if indices
             .statistics()
@@ -24,35 +41,131 @@ impl TakeFn for ChunkedArray {
             return take_strict_sorted(self, indices);
         }
 
-        let indices = try_cast(indices, PType::U64.into())?.into_primitive()?;
-
-        // While the chunk idx remains the same, accumulate a list of chunk indices.
-        let mut chunks = Vec::new();
-        let mut indices_in_chunk = Vec::new();
-        let mut prev_chunk_idx = self
-            .find_chunk_idx(indices.maybe_null_slice::<u64>()[0] as usize)
-            .0;
-        for idx in indices.maybe_null_slice::<u64>() {
-            let (chunk_idx, idx_in_chunk) = self.find_chunk_idx(*idx as usize);
-
-            if chunk_idx != prev_chunk_idx {
-                // Start a new chunk
-                let indices_in_chunk_array = indices_in_chunk.clone().into_array();
-                chunks.push(take(&self.chunk(prev_chunk_idx)?, &indices_in_chunk_array)?);
-                indices_in_chunk = Vec::new();
-            }
-
-            indices_in_chunk.push(idx_in_chunk as u64);
-            prev_chunk_idx = chunk_idx;
+        #[cfg(feature = "rayon")]
+        if u64_indices.len() >= PARALLEL_TAKE_MIN_INDICES && self.nchunks() > 1 {
+            return take_parallel(self, &u64_indices);
         }
 
-        if !indices_in_chunk.is_empty() {
-            let indices_in_chunk_array = indices_in_chunk.into_array();
-            chunks.push(take(&self.chunk(prev_chunk_idx)?, &indices_in_chunk_array)?);
+        take_serial(self, &u64_indices)
+    }
+}
+
+/// Takes from one chunk at a time while consecutive indices fall into the same chunk
+fn take_serial(chunked: &ChunkedArray, indices: &PrimitiveArray) -> VortexResult<Array> {
+    // While the chunk idx remains the same, accumulate a list of chunk indices.
+    let mut chunks = Vec::new();
+    let mut indices_in_chunk = Vec::new();
+    let mut prev_chunk_idx = chunked
+        .find_chunk_idx(indices.maybe_null_slice::<u64>()[0] as usize)
+        .0;
+    for idx in indices.maybe_null_slice::<u64>() {
+        let (chunk_idx, idx_in_chunk) = chunked.find_chunk_idx(*idx as usize);
+
+        if chunk_idx != prev_chunk_idx {
+            // Start a new chunk
+            let indices_in_chunk_array = indices_in_chunk.clone().into_array();
+            chunks.push(take(
+                &chunked.chunk(prev_chunk_idx)?,
+                &indices_in_chunk_array,
+            )?);
+            indices_in_chunk = Vec::new();
         }
 
-        Ok(Self::try_new(chunks, self.dtype().clone())?.into_array())
+        indices_in_chunk.push(idx_in_chunk as u64);
+        prev_chunk_idx = chunk_idx;
     }
+
+    if !indices_in_chunk.is_empty() {
+        let indices_in_chunk_array = indices_in_chunk.into_array();
+        chunks.push(take(
+            &chunked.chunk(prev_chunk_idx)?,
+            &indices_in_chunk_array,
+        )?);
+    }
+
+    Ok(ChunkedArray::try_new(chunks, chunked.dtype().clone())?.into_array())
+}
+
+/// Null indices take a null value, which arrow's take does on the decoded array
+fn take_nullable(chunked: &ChunkedArray, indices: &Array) -> VortexResult<Array> {
+    let indices = try_cast(
+        indices,
+        &DType::Primitive(PType::U64, Nullability::Nullable),
+    )?
+    .into_primitive()?;
+    let validity = indices.validity();
+    if let Some(idx) = indices
+        .maybe_null_slice::<u64>()
+        .iter()
+        .enumerate()
+        .find(|(i, &idx)| idx >= chunked.len() as u64 && validity.is_valid(*i))
+        .map(|(_, idx)| *idx)
+    {
+        vortex_bail!(OutOfBounds: idx as usize, 0, chunked.len());
+    }
+
+    let values = chunked.clone().into_canonical()?.into_arrow()?;
+    let indices = indices.into_canonical()?.into_arrow()?;
+    let taken = arrow_select::take::take(&values, &indices, None)?;
+    Ok(Array::from_arrow(taken, true))
+}
+
+/// Minimum number of indices for which the take is spread over the rayon pool
+#[cfg(feature = "rayon")]
+const PARALLEL_TAKE_MIN_INDICES: usize = 1 << 14;
+
+/// Buckets the indices by chunk and takes from every chunk in parallel, the gathered values are
+/// then put back in the order of the indices with a final take.
+#[cfg(feature = "rayon")]
+fn take_parallel(chunked: &ChunkedArray, indices: &PrimitiveArray) -> VortexResult<Array> {
+    use rayon::prelude::*;
+
+    let chunk_ends = chunked.chunk_offsets().into_primitive()?;
+    let chunk_ends = chunk_ends.maybe_null_slice::<u64>();
+
+    let mut indices_by_chunk = vec![Vec::new(); chunked.nchunks()];
+    let mut index_chunks = Vec::with_capacity(indices.len());
+    for idx in indices.maybe_null_slice::<u64>() {
+        let (chunk_idx, idx_in_chunk) = find_chunk_idx(*idx as usize, chunk_ends);
+        indices_by_chunk[chunk_idx].push(idx_in_chunk as u64);
+        index_chunks.push(chunk_idx);
+    }
+
+    // Position of the next value of every chunk in the gathered array
+    let mut gathered_pos = indices_by_chunk
+        .iter()
+        .scan(0u64, |pos, chunk_indices| {
+            let start = *pos;
+            *pos += chunk_indices.len() as u64;
+            Some(start)
+        })
+        .collect_vec();
+    let order = index_chunks
+        .into_iter()
+        .map(|chunk_idx| {
+            let pos = gathered_pos[chunk_idx];
+            gathered_pos[chunk_idx] += 1;
+            pos
+        })
+        .collect_vec();
+
+    let chunks = indices_by_chunk
+        .into_par_iter()
+        .enumerate()
+        .filter(|(_, chunk_indices)| !chunk_indices.is_empty())
+        .map(|(chunk_idx, chunk_indices)| {
+            take(&chunked.chunk(chunk_idx)?, chunk_indices.into_array())
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+    let gathered =
+        Array::from(ChunkedArray::try_new(chunks, chunked.dtype().clone())?.into_canonical()?);
+
+    // Chunked like the result of the serial take
+    Ok(ChunkedArray::try_new(
+        vec![take(&gathered, order.into_array())?],
+        chunked.dtype().clone(),
+    )?
+    .into_array())
 }
 
 /// When the indices are non-null and strict-sorted, we can do better
@@ -82,12 +195,17 @@ fn take_strict_sorted(chunked: &ChunkedArray, indices: &Array) -> VortexResult<A
                 subtract_scalar(
                     &chunk_indices,
                     &Scalar::from(chunk_begin).cast(chunk_indices.dtype())?,
+                    OverflowPolicy::Checked,
                 )?
             } else {
                 // Note. this try_cast (memory copy) is unnecessary, could instead upcast in the subtract fn.
                 //  and avoid an extra
                 let u64_chunk_indices = try_cast(&chunk_indices, PType::U64.into())?;
-                subtract_scalar(&u64_chunk_indices, &chunk_begin.into())?
+                subtract_scalar(
+                    &u64_chunk_indices,
+                    &chunk_begin.into(),
+                    OverflowPolicy::Checked,
+                )?
             };
 
         indices_by_chunk[chunk_idx] = Some(chunk_indices);
@@ -128,4 +246,96 @@ mod test {
             .unwrap();
         assert_eq!(result.maybe_null_slice::<i32>(), &[1, 1, 1, 2]);
     }
+
+    #[test]
+    fn take_strict_sorted_narrow_indices() {
+        let chunks = (0..3)
+            .map(|c| (c * 200..(c + 1) * 200).collect::<Vec<i32>>().into_array())
+            .collect::<Vec<_>>();
+        let arr = ChunkedArray::from_iter(chunks);
+        let indices = vec![0u8, 199, 200, 255].into_array();
+
+        let result = take(arr.as_ref(), &indices)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(result.maybe_null_slice::<i32>(), &[0, 199, 200, 255]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_take_parallel() {
+        use itertools::Itertools;
+
+        let chunks = (0..4)
+            .map(|c| (c * 10_000..(c + 1) * 10_000).collect_vec().into_array())
+            .collect_vec();
+        let arr = ChunkedArray::try_new(chunks, vortex_dtype::PType::I32.into()).unwrap();
+        let indices = (0..30_000u64).map(|i| (i * 7919) % 40_000).collect_vec();
+
+        let result = take(arr.as_ref(), indices.clone().into_array())
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(
+            result.maybe_null_slice::<i32>(),
+            indices.iter().map(|i| *i as i32).collect_vec()
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn take_parallel_matches_serial() {
+        use itertools::Itertools;
+
+        use super::{take_parallel, take_serial};
+        use crate::array::{Chunked, PrimitiveArray};
+        use crate::ArrayDef;
+
+        let chunks = (0..5)
+            .map(|c| (c * 7_000..(c + 1) * 7_000).collect_vec().into_array())
+            .collect_vec();
+        let arr = ChunkedArray::try_new(chunks, vortex_dtype::PType::I32.into()).unwrap();
+        let indices =
+            PrimitiveArray::from((0..20_000u64).map(|i| (i * 104_729) % 35_000).collect_vec());
+
+        let parallel = take_parallel(&arr, &indices).unwrap();
+        let serial = take_serial(&arr, &indices).unwrap();
+        assert!(parallel.is_encoding(Chunked::ID));
+        assert!(serial.is_encoding(Chunked::ID));
+        assert_eq!(
+            parallel.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            serial.into_primitive().unwrap().maybe_null_slice::<i32>()
+        );
+    }
+
+    #[test]
+    fn take_out_of_bounds() {
+        let a = vec![1i32, 2, 3].into_array();
+        let arr = ChunkedArray::try_new(vec![a.clone(), a.clone()], a.dtype().clone()).unwrap();
+
+        assert!(take(arr.as_ref(), vec![0u64, 7].into_array()).is_err());
+        assert!(take(arr.as_ref(), vec![6u64].into_array()).is_err());
+    }
+
+    #[test]
+    fn take_nullable_indices() {
+        use crate::array::PrimitiveArray;
+        use crate::compute::TakeFn;
+        use crate::validity::Validity;
+
+        let a = vec![1i32, 2, 3].into_array();
+        let arr = ChunkedArray::try_new(vec![a.clone(), a.clone()], a.dtype().clone()).unwrap();
+        // The value under the null index is out of bounds and ignored
+        let indices =
+            PrimitiveArray::from_vec(vec![4u64, 100, 0], Validity::from(vec![true, false, true]));
+
+        let result = TakeFn::take(&arr, indices.as_ref())
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(result.maybe_null_slice::<i32>()[0], 2);
+        assert_eq!(result.maybe_null_slice::<i32>()[2], 1);
+        assert!(!result.validity().is_valid(1));
+    }
 }
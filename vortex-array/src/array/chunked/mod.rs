@@ -3,8 +3,10 @@
 //! Vortex is a chunked array library that's able to
 
 use std::fmt::{Debug, Display};
+use std::mem;
+use std::pin::pin;
 
-use futures_util::stream;
+use futures_util::{stream, TryStreamExt};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use vortex_dtype::{DType, Nullability, PType};
@@ -13,7 +15,9 @@ use vortex_scalar::Scalar;
 
 use crate::array::primitive::PrimitiveArray;
 use crate::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
-use crate::compute::unary::{scalar_at, scalar_at_unchecked, subtract_scalar, SubtractScalarFn};
+use crate::compute::unary::{
+    binary_scalar, scalar_at, scalar_at_unchecked, BinaryOp, BinaryScalarFn, OverflowPolicy,
+};
 use crate::compute::{search_sorted, SearchSortedSide};
 use crate::encoding::ids;
 use crate::iter::{ArrayIterator, ArrayIteratorAdapter};
@@ -46,8 +50,8 @@ impl ChunkedArray {
 
     pub fn try_new(chunks: Vec<Array>, dtype: DType) -> VortexResult<Self> {
         for chunk in &chunks {
-            if chunk.dtype() != &dtype {
-                vortex_bail!(MismatchedTypes: dtype, chunk.dtype());
+            if let Err(mismatch) = dtype.is_compatible_with(chunk.dtype()) {
+                vortex_bail!(MismatchedTypes: dtype, format!("{} ({mismatch})", chunk.dtype()));
             }
         }
 
@@ -78,6 +82,25 @@ impl ChunkedArray {
         )
     }
 
+    /// Collect the arrays of `stream` as the chunks of a chunked array, without concatenating them.
+    ///
+    /// Fails as soon as the stream fails or yields a chunk whose dtype isn't that of the stream.
+    pub async fn try_from_stream(stream: impl ArrayStream) -> VortexResult<Self> {
+        let dtype = stream.dtype().clone();
+        let mut stream = pin!(stream);
+        let mut chunks = Vec::new();
+        while let Some(chunk) = stream.try_next().await? {
+            if let Err(mismatch) = dtype.is_compatible_with(chunk.dtype()) {
+                vortex_bail!(
+                    MismatchedTypes: dtype,
+                    format!("{} ({mismatch}) of chunk {}", chunk.dtype(), chunks.len())
+                );
+            }
+            chunks.push(chunk);
+        }
+        Self::try_new(chunks, dtype)
+    }
+
     #[inline]
     pub fn chunk(&self, idx: usize) -> VortexResult<Array> {
         if idx >= self.nchunks() {
@@ -141,6 +164,36 @@ impl ChunkedArray {
         ArrayStreamAdapter::new(self.dtype().clone(), stream::iter(self.chunks().map(Ok)))
     }
 
+    /// Apply `f` to every chunk, returning the results as a new chunked array.
+    ///
+    /// The results must all have the same dtype, which is the dtype of the new array. Without any
+    /// chunks to infer it from, the dtype of this array is kept, use [`Self::map_chunks_as`] if `f`
+    /// changes it.
+    pub fn map_chunks(&self, f: impl Fn(&Array) -> VortexResult<Array>) -> VortexResult<Self> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| f(&chunk))
+            .collect::<VortexResult<Vec<_>>>()?;
+        let dtype = chunks
+            .first()
+            .map_or_else(|| self.dtype().clone(), |c| c.dtype().clone());
+        Self::try_new(chunks, dtype)
+    }
+
+    /// Apply `f`, which returns arrays of type `dtype`, to every chunk, see [`Self::map_chunks`].
+    pub fn map_chunks_as(
+        &self,
+        dtype: DType,
+        f: impl Fn(&Array) -> VortexResult<Array>,
+    ) -> VortexResult<Self> {
+        Self::try_new(
+            self.chunks()
+                .map(|chunk| f(&chunk))
+                .collect::<VortexResult<Vec<_>>>()?,
+            dtype,
+        )
+    }
+
     pub fn rechunk(&self, target_bytesize: usize, target_rowsize: usize) -> VortexResult<Self> {
         let mut new_chunks = Vec::new();
         let mut chunks_to_combine = Vec::new();
@@ -154,15 +207,10 @@ impl ChunkedArray {
                 || new_chunk_n_elements + n_elements > target_rowsize)
                 && !chunks_to_combine.is_empty()
             {
-                new_chunks.push(
-                    ChunkedArray::try_new(chunks_to_combine, self.dtype().clone())?
-                        .into_canonical()?
-                        .into(),
-                );
+                new_chunks.push(self.combine_chunks(mem::take(&mut chunks_to_combine))?);
 
                 new_chunk_n_bytes = 0;
                 new_chunk_n_elements = 0;
-                chunks_to_combine = Vec::new();
             }
 
             if n_bytes > target_bytesize || n_elements > target_rowsize {
@@ -175,18 +223,73 @@ impl ChunkedArray {
         }
 
         if !chunks_to_combine.is_empty() {
-            new_chunks.push(
-                ChunkedArray::try_new(chunks_to_combine, self.dtype().clone())?
-                    .into_canonical()?
-                    .into(),
-            );
+            new_chunks.push(self.combine_chunks(chunks_to_combine)?);
+        }
+
+        Self::try_new(new_chunks, self.dtype().clone())
+    }
+
+    /// Merge runs of adjacent chunks shorter than `min_chunk_rows` into chunks of at least
+    /// `min_chunk_rows` rows, where possible.
+    ///
+    /// Chunks that already have `min_chunk_rows` rows and short chunks that can't be merged with a
+    /// neighbour are left untouched, merged chunks are canonicalized.
+    pub fn coalesce(&self, min_chunk_rows: usize) -> VortexResult<Self> {
+        let mut new_chunks = Vec::new();
+        let mut chunks_to_combine = Vec::new();
+        let mut new_chunk_n_elements = 0;
+        for chunk in self.chunks() {
+            if chunk.len() >= min_chunk_rows {
+                if !chunks_to_combine.is_empty() {
+                    new_chunks.push(self.merge_chunks(mem::take(&mut chunks_to_combine))?);
+                    new_chunk_n_elements = 0;
+                }
+                new_chunks.push(chunk);
+                continue;
+            }
+
+            new_chunk_n_elements += chunk.len();
+            chunks_to_combine.push(chunk);
+            if new_chunk_n_elements >= min_chunk_rows {
+                new_chunks.push(self.merge_chunks(mem::take(&mut chunks_to_combine))?);
+                new_chunk_n_elements = 0;
+            }
+        }
+
+        if !chunks_to_combine.is_empty() {
+            new_chunks.push(self.merge_chunks(chunks_to_combine)?);
         }
 
         Self::try_new(new_chunks, self.dtype().clone())
     }
+
+    /// Like [`combine_chunks`](Self::combine_chunks), but passes a lone chunk through as is
+    fn merge_chunks(&self, mut chunks: Vec<Array>) -> VortexResult<Array> {
+        if chunks.len() == 1 {
+            return Ok(chunks.remove(0));
+        }
+        self.combine_chunks(chunks)
+    }
+
+    fn combine_chunks(&self, chunks: Vec<Array>) -> VortexResult<Array> {
+        Ok(ChunkedArray::try_new(chunks, self.dtype().clone())?
+            .into_canonical()?
+            .into())
+    }
 }
 
-impl ArrayTrait for ChunkedArray {}
+impl ArrayTrait for ChunkedArray {
+    fn validate(&self) -> VortexResult<()> {
+        let end = usize::try_from(&scalar_at(self.chunk_offsets(), self.nchunks())?)?;
+        if end != self.len() {
+            vortex_bail!(
+                "Chunks hold {end} values, but the array has length {}",
+                self.len()
+            );
+        }
+        Ok(())
+    }
+}
 
 impl FromIterator<Array> for ChunkedArray {
     fn from_iter<T: IntoIterator<Item = Array>>(iter: T) -> Self {
@@ -228,23 +331,29 @@ impl ArrayValidity for ChunkedArray {
     }
 }
 
-impl SubtractScalarFn for ChunkedArray {
-    fn subtract_scalar(&self, to_subtract: &Scalar) -> VortexResult<Array> {
-        let chunks = self
-            .chunks()
-            .map(|chunk| subtract_scalar(&chunk, to_subtract))
-            .collect::<VortexResult<Vec<_>>>()?;
-        Ok(Self::try_new(chunks, self.dtype().clone())?.into_array())
+impl BinaryScalarFn for ChunkedArray {
+    fn binary_scalar(
+        &self,
+        op: BinaryOp,
+        operand: &Scalar,
+        overflow: OverflowPolicy,
+    ) -> VortexResult<Array> {
+        Ok(self
+            .map_chunks(|chunk| binary_scalar(chunk, op, operand, overflow))?
+            .into_array())
     }
 }
 
 #[cfg(test)]
 mod test {
+    use futures_util::{stream, FutureExt};
     use vortex_dtype::{DType, Nullability, PType};
     use vortex_error::VortexResult;
 
     use crate::array::chunked::ChunkedArray;
-    use crate::compute::unary::{scalar_at, subtract_scalar};
+    use crate::array::{ConstantArray, PrimitiveArray};
+    use crate::compute::unary::{mul_scalar, scalar_at, subtract_scalar, try_cast, OverflowPolicy};
+    use crate::stream::ArrayStreamAdapter;
     use crate::{assert_arrays_eq, Array, ArrayDType, IntoArray, IntoArrayVariant};
 
     fn chunked_array() -> ChunkedArray {
@@ -263,7 +372,8 @@ mod test {
     fn test_scalar_subtract() {
         let chunked = chunked_array();
         let to_subtract = 1u64;
-        let array = subtract_scalar(&chunked, &to_subtract.into()).unwrap();
+        let array =
+            subtract_scalar(&chunked, &to_subtract.into(), OverflowPolicy::Checked).unwrap();
 
         let chunked = ChunkedArray::try_from(array).unwrap();
         let mut chunks_out = chunked.chunks();
@@ -294,6 +404,66 @@ mod test {
         assert_eq!(results, &[6u64, 7, 8]);
     }
 
+    #[test]
+    fn test_map_chunks() {
+        let chunked = chunked_array();
+        let doubled = chunked
+            .map_chunks(|chunk| mul_scalar(chunk, &2u64.into(), OverflowPolicy::Checked))
+            .unwrap();
+        assert_eq!(doubled.nchunks(), 3);
+        assert_eq!(
+            doubled
+                .into_array()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[2, 4, 6, 8, 10, 12, 14, 16, 18]
+        );
+
+        let empty = ChunkedArray::try_new(
+            vec![],
+            DType::Primitive(PType::U64, Nullability::NonNullable),
+        )
+        .unwrap();
+        let cast = empty
+            .map_chunks_as(PType::I32.into(), |chunk| {
+                try_cast(chunk, &PType::I32.into())
+            })
+            .unwrap();
+        assert_eq!(cast.dtype(), &DType::from(PType::I32));
+    }
+
+    #[test]
+    fn test_try_from_stream() {
+        let dtype = DType::Primitive(PType::U64, Nullability::NonNullable);
+        let chunks = vec![vec![1u64, 2].into_array(), vec![3u64].into_array()];
+        let chunked = ChunkedArray::try_from_stream(ArrayStreamAdapter::new(
+            dtype.clone(),
+            stream::iter(chunks.into_iter().map(Ok)),
+        ))
+        .now_or_never()
+        .unwrap()
+        .unwrap();
+        assert_eq!(chunked.nchunks(), 2);
+        assert_eq!(
+            chunked
+                .into_array()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[1, 2, 3]
+        );
+
+        let mismatched = vec![vec![1u64].into_array(), vec![2i32].into_array()];
+        assert!(ChunkedArray::try_from_stream(ArrayStreamAdapter::new(
+            dtype,
+            stream::iter(mismatched.into_iter().map(Ok)),
+        ))
+        .now_or_never()
+        .unwrap()
+        .is_err());
+    }
+
     #[test]
     fn test_rechunk_one_chunk() {
         let chunked = ChunkedArray::try_new(
@@ -307,6 +477,20 @@ mod test {
         assert_arrays_eq!(chunked, rechunked);
     }
 
+    #[test]
+    fn test_rechunk_canonicalizes_one_chunk() {
+        let chunked = ChunkedArray::try_new(
+            vec![ConstantArray::new(42u64, 3).into_array()],
+            DType::Primitive(PType::U64, Nullability::NonNullable),
+        )
+        .unwrap();
+
+        let rechunked = chunked.rechunk(1 << 16, 1 << 16).unwrap();
+
+        assert!(PrimitiveArray::try_from(rechunked.chunk(0).unwrap()).is_ok());
+        assert_arrays_eq!(chunked, rechunked);
+    }
+
     #[test]
     fn test_rechunk_two_chunks() {
         let chunked = ChunkedArray::try_new(
@@ -356,4 +540,46 @@ mod test {
         assert_eq!(rechunked.nchunks(), 4);
         assert_arrays_eq!(chunked, rechunked);
     }
+
+    #[test]
+    fn test_coalesce() {
+        let chunked = ChunkedArray::try_new(
+            vec![
+                vec![0u64, 1].into_array(),
+                vec![2u64].into_array(),
+                vec![3u64, 4].into_array(),
+                vec![42_u64; 6].into_array(),
+                vec![5u64].into_array(),
+                vec![6u64, 7, 8, 9].into_array(),
+                vec![10u64].into_array(),
+            ],
+            DType::Primitive(PType::U64, Nullability::NonNullable),
+        )
+        .unwrap();
+
+        let coalesced = chunked.coalesce(4).unwrap();
+        // [0, 1, 2, 3, 4] [42, 42, 42, 42, 42, 42] [5] [6, 7, 8, 9] [10]
+        assert_eq!(
+            coalesced.chunks().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![5, 6, 1, 4, 1]
+        );
+        assert_arrays_eq!(chunked, coalesced);
+    }
+
+    #[test]
+    fn test_coalesce_keeps_unmerged_chunks() {
+        let chunked = ChunkedArray::try_new(
+            vec![
+                vec![0u64; 4].into_array(),
+                ConstantArray::new(42u64, 3).into_array(),
+            ],
+            DType::Primitive(PType::U64, Nullability::NonNullable),
+        )
+        .unwrap();
+
+        let coalesced = chunked.coalesce(4).unwrap();
+
+        assert!(ConstantArray::try_from(coalesced.chunk(1).unwrap()).is_ok());
+        assert_arrays_eq!(chunked, coalesced);
+    }
 }
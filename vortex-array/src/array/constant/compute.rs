@@ -7,13 +7,17 @@ use vortex_scalar::Scalar;
 use crate::array::constant::ConstantArray;
 use crate::compute::unary::{scalar_at, ScalarAtFn};
 use crate::compute::{
-    scalar_cmp, AndFn, ArrayCompute, FilterFn, MaybeCompareFn, Operator, OrFn, SearchResult,
-    SearchSortedFn, SearchSortedSide, SliceFn, TakeFn,
+    scalar_cmp, AndFn, ArrayCompute, CompareScalarFn, FilterFn, MaybeCompareFn, Operator, OrFn,
+    SearchResult, SearchSortedFn, SearchSortedSide, SliceFn, TakeFn,
 };
 use crate::stats::{ArrayStatistics, Stat};
 use crate::{Array, ArrayDType, IntoArray};
 
 impl ArrayCompute for ConstantArray {
+    fn compare_scalar(&self) -> Option<&dyn CompareScalarFn> {
+        Some(self)
+    }
+
     fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
         MaybeCompareFn::maybe_compare(self, other, operator)
     }
@@ -57,6 +61,12 @@ impl ScalarAtFn for ConstantArray {
     }
 }
 
+impl CompareScalarFn for ConstantArray {
+    fn compare_scalar(&self, scalar: &Scalar, operator: Operator) -> VortexResult<Array> {
+        Ok(Self::new(scalar_cmp(self.scalar(), scalar, operator), self.len()).into_array())
+    }
+}
+
 impl TakeFn for ConstantArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         Ok(Self::new(self.owned_scalar(), indices.len()).into_array())
@@ -111,9 +121,8 @@ impl MaybeCompareFn for ConstantArray {
                 .get_as::<bool>(Stat::IsConstant)
                 .unwrap_or_default())
         .then(|| {
-            let lhs = self.owned_scalar();
             let rhs = scalar_at(other, 0).vortex_expect("Expected scalar");
-            let scalar = scalar_cmp(&lhs, &rhs, operator);
+            let scalar = scalar_cmp(self.scalar(), &rhs, operator);
             Ok(ConstantArray::new(scalar, self.len()).into_array())
         })
     }
@@ -3,7 +3,7 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 use vortex_error::{vortex_panic, VortexResult};
-use vortex_scalar::{Scalar, ScalarValue};
+use vortex_scalar::{Scalar, ScalarRef, ScalarValue};
 
 use crate::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use crate::encoding::ids;
@@ -72,6 +72,11 @@ impl ConstantArray {
         &self.metadata().scalar_value
     }
 
+    /// The constant value, borrowed from the array
+    pub fn scalar(&self) -> ScalarRef<'_> {
+        ScalarRef::new(self.dtype(), self.scalar_value())
+    }
+
     /// Construct an owned [`vortex_scalar::Scalar`] with a value equal to [`Self::scalar_value()`].
     pub fn owned_scalar(&self) -> Scalar {
         Scalar::new(self.dtype().clone(), self.scalar_value().clone())
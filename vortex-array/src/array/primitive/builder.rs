@@ -0,0 +1,146 @@
+use std::any::Any;
+
+use arrow_buffer::NullBufferBuilder;
+use vortex_dtype::{DType, NativePType, Nullability};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::{ArrayBuilder, PrimitiveArray};
+use crate::validity::Validity;
+use crate::{Array, IntoArray};
+
+/// Builds a [`PrimitiveArray`] one value at a time, without collecting the values first
+pub struct PrimitiveBuilder<T: NativePType> {
+    values: Vec<T>,
+    validity: NullBufferBuilder,
+    nullability: Nullability,
+}
+
+impl<T: NativePType> PrimitiveBuilder<T> {
+    pub fn new(nullability: Nullability) -> Self {
+        Self::with_capacity(nullability, 0)
+    }
+
+    pub fn with_capacity(nullability: Nullability, capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+            validity: NullBufferBuilder::new(capacity),
+            nullability,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: Option<T>) {
+        match value {
+            Some(v) => self.push_value(v),
+            None => self.push_null(),
+        }
+    }
+
+    #[inline]
+    pub fn push_value(&mut self, value: T) {
+        self.values.push(value);
+        self.validity.append_non_null();
+    }
+
+    #[inline]
+    pub fn push_values(&mut self, values: &[T]) {
+        self.values.extend_from_slice(values);
+        self.validity.append_n_non_nulls(values.len());
+    }
+
+    /// Push a null, which is only allowed if the builder is nullable
+    #[inline]
+    #[allow(clippy::same_name_method)]
+    pub fn push_null(&mut self) {
+        self.values.push(T::default());
+        self.validity.append_null();
+    }
+
+    /// The built array, failing if a null was pushed to a non-nullable builder
+    pub fn finish(mut self) -> VortexResult<PrimitiveArray> {
+        let validity = finish_validity(&mut self.validity, self.nullability)?;
+        Ok(PrimitiveArray::from_vec(self.values, validity))
+    }
+}
+
+/// Validity of the values pushed to `nulls`, checking that there are no nulls if non-nullable
+pub(crate) fn finish_validity(
+    nulls: &mut NullBufferBuilder,
+    nullability: Nullability,
+) -> VortexResult<Validity> {
+    let nulls = nulls.finish();
+    Ok(match nullability {
+        Nullability::Nullable => nulls.map(Validity::from).unwrap_or(Validity::AllValid),
+        Nullability::NonNullable => {
+            if nulls.is_some() {
+                vortex_bail!("Pushed a null to a non-nullable builder");
+            }
+            Validity::NonNullable
+        }
+    })
+}
+
+impl<T: NativePType + 'static> ArrayBuilder for PrimitiveBuilder<T> {
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn finish_array(self: Box<Self>, _dtype: &DType) -> VortexResult<Array> {
+        self.finish().map(IntoArray::into_array)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::Nullability;
+
+    use crate::array::PrimitiveBuilder;
+    use crate::ArrayDType;
+
+    #[test]
+    fn build_nullable() {
+        let mut builder = PrimitiveBuilder::<i64>::with_capacity(Nullability::Nullable, 4);
+        builder.push_value(1);
+        builder.push_null();
+        builder.push_values(&[3, 4]);
+        builder.push(None);
+        let array = builder.finish().unwrap();
+
+        assert!(array.dtype().is_nullable());
+        assert_eq!(
+            array.iter::<i64>().collect::<Vec<_>>(),
+            vec![Some(1), None, Some(3), Some(4), None]
+        );
+    }
+
+    #[test]
+    fn build_non_nullable() {
+        let mut builder = PrimitiveBuilder::<u8>::new(Nullability::NonNullable);
+        builder.push_values(&[1, 2]);
+        builder.push_value(3);
+        let array = builder.finish().unwrap();
+
+        assert!(!array.dtype().is_nullable());
+        assert_eq!(array.maybe_null_slice::<u8>(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn null_in_non_nullable() {
+        let mut builder = PrimitiveBuilder::<u8>::new(Nullability::NonNullable);
+        builder.push_value(1);
+        builder.push_null();
+        assert!(builder.finish().is_err());
+    }
+}
@@ -0,0 +1,392 @@
+use num_traits::{
+    CheckedDiv, PrimInt, Saturating, SaturatingMul, WrappingAdd, WrappingMul, WrappingSub,
+};
+use vortex_dtype::{match_each_float_ptype, match_each_integer_ptype, NativePType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::array::constant::ConstantArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::unary::{BinaryOp, BinaryScalarFn, OverflowPolicy};
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, IntoArray};
+
+impl BinaryScalarFn for PrimitiveArray {
+    fn binary_scalar(
+        &self,
+        op: BinaryOp,
+        operand: &Scalar,
+        overflow: OverflowPolicy,
+    ) -> VortexResult<Array> {
+        if !self.dtype().eq_ignore_nullability(operand.dtype()) {
+            vortex_bail!(MismatchedTypes: self.dtype(), operand.dtype())
+        }
+
+        let nullable = self.dtype().is_nullable() || operand.dtype().is_nullable();
+        if operand.is_null() || self.logical_validity().all_invalid() {
+            return Ok(ConstantArray::new(
+                Scalar::null(self.dtype().with_nullability(nullable.into())),
+                self.len(),
+            )
+            .into_array());
+        }
+
+        let validity = if nullable {
+            self.validity().into_nullable()
+        } else {
+            self.validity()
+        };
+        let result = if operand.dtype().is_int() {
+            match_each_integer_ptype!(self.ptype(), |$T| {
+                let operand: $T = PrimitiveScalar::try_from(operand)?
+                    .typed_value::<$T>()
+                    .ok_or_else(|| vortex_err!("expected primitive"))?;
+                integer_arithmetic::<$T>(self, op, operand, overflow, validity)?
+            })
+        } else {
+            match_each_float_ptype!(self.ptype(), |$T| {
+                let operand: $T = PrimitiveScalar::try_from(operand)?
+                    .typed_value::<$T>()
+                    .ok_or_else(|| vortex_err!("expected primitive"))?;
+                PrimitiveArray::from_vec(
+                    self.maybe_null_slice::<$T>()
+                        .iter()
+                        .map(|&v| float(op, v, operand))
+                        .collect(),
+                    validity,
+                )
+            })
+        };
+
+        Ok(result.into_array())
+    }
+}
+
+fn float<T: NativePType>(op: BinaryOp, value: T, operand: T) -> T {
+    match op {
+        BinaryOp::Add => value + operand,
+        BinaryOp::Sub => value - operand,
+        BinaryOp::Mul => value * operand,
+        BinaryOp::Div => value / operand,
+    }
+}
+
+trait IntegerArithmetic:
+    NativePType + PrimInt + WrappingAdd + WrappingSub + WrappingMul + SaturatingMul
+{
+    fn wrapping(op: BinaryOp, value: Self, operand: Self) -> Self {
+        match op {
+            BinaryOp::Add => value.wrapping_add(&operand),
+            BinaryOp::Sub => value.wrapping_sub(&operand),
+            BinaryOp::Mul => value.wrapping_mul(&operand),
+            // Only MIN / -1 overflows, which wraps back to MIN
+            BinaryOp::Div => CheckedDiv::checked_div(&value, &operand).unwrap_or(value),
+        }
+    }
+
+    fn checked(op: BinaryOp, value: Self, operand: Self) -> Option<Self> {
+        match op {
+            BinaryOp::Add => value.checked_add(&operand),
+            BinaryOp::Sub => value.checked_sub(&operand),
+            BinaryOp::Mul => value.checked_mul(&operand),
+            BinaryOp::Div => CheckedDiv::checked_div(&value, &operand),
+        }
+    }
+
+    fn saturating(op: BinaryOp, value: Self, operand: Self) -> Self {
+        match op {
+            BinaryOp::Add => Saturating::saturating_add(value, operand),
+            BinaryOp::Sub => Saturating::saturating_sub(value, operand),
+            BinaryOp::Mul => SaturatingMul::saturating_mul(&value, &operand),
+            // Only MIN / -1 overflows, which saturates to MAX
+            BinaryOp::Div => {
+                CheckedDiv::checked_div(&value, &operand).unwrap_or_else(Self::max_value)
+            }
+        }
+    }
+}
+
+impl<T: NativePType + PrimInt + WrappingAdd + WrappingSub + WrappingMul + SaturatingMul>
+    IntegerArithmetic for T
+{
+}
+
+fn integer_arithmetic<T: IntegerArithmetic>(
+    array: &PrimitiveArray,
+    op: BinaryOp,
+    operand: T,
+    overflow: OverflowPolicy,
+    validity: Validity,
+) -> VortexResult<PrimitiveArray> {
+    let identity = match op {
+        BinaryOp::Add | BinaryOp::Sub => T::zero(),
+        BinaryOp::Mul | BinaryOp::Div => T::one(),
+    };
+    if operand == identity {
+        // if the operation doesn't change the values, skip it
+        return Ok(PrimitiveArray::new(
+            array.buffer().clone(),
+            array.ptype(),
+            validity,
+        ));
+    }
+    if op == BinaryOp::Div && operand.is_zero() {
+        vortex_bail!(ComputeError: "Integer division by zero");
+    }
+
+    let values = array.maybe_null_slice::<T>();
+    let result = match overflow {
+        OverflowPolicy::Wrapping => values
+            .iter()
+            .map(|&v| T::wrapping(op, v, operand))
+            .collect(),
+        OverflowPolicy::Saturating => values
+            .iter()
+            .map(|&v| T::saturating(op, v, operand))
+            .collect(),
+        OverflowPolicy::Checked => {
+            let nulls = array.logical_validity().to_null_buffer()?;
+            values
+                .iter()
+                .enumerate()
+                .map(|(idx, &v)| {
+                    T::checked(op, v, operand)
+                        .or_else(|| {
+                            nulls
+                                .as_ref()
+                                .is_some_and(|n| n.is_null(idx))
+                                .then(|| T::wrapping(op, v, operand))
+                        })
+                        .ok_or_else(|| {
+                            vortex_err!(ComputeError: "{v} {op} {operand} overflows {}", T::PTYPE)
+                        })
+                })
+                .collect::<VortexResult<_>>()?
+        }
+    };
+    Ok(PrimitiveArray::from_vec(result, validity))
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_error::VortexResult;
+    use vortex_scalar::Scalar;
+
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::unary::{
+        add_scalar, div_scalar, mul_scalar, subtract_scalar, OverflowPolicy,
+    };
+    use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn test_scalar_subtract_unsigned() {
+        let values = vec![1u16, 2, 3].into_array();
+        let results = subtract_scalar(&values, &1u16.into(), OverflowPolicy::Wrapping)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u16>()
+            .to_vec();
+        assert_eq!(results, &[0u16, 1, 2]);
+    }
+
+    #[test]
+    fn test_scalar_subtract_signed() {
+        let values = vec![1i64, 2, 3].into_array();
+        let results = subtract_scalar(&values, &(-1i64).into(), OverflowPolicy::Wrapping)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<i64>()
+            .to_vec();
+        assert_eq!(results, &[2i64, 3, 4]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_subtract_scalar_fn_shim() {
+        use crate::compute::unary::SubtractScalarFn as _;
+
+        let values = PrimitiveArray::from(vec![5u8, 6, 7]);
+        let results = values
+            .subtract_scalar(&5u8.into(), OverflowPolicy::Checked)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u8>()
+            .to_vec();
+        assert_eq!(results, &[0u8, 1, 2]);
+    }
+
+    #[test]
+    fn test_scalar_subtract_nullable() {
+        let values = PrimitiveArray::from_nullable_vec(vec![Some(1u16), Some(2), None, Some(3)])
+            .into_array();
+        let flattened = subtract_scalar(&values, &Some(1u16).into(), OverflowPolicy::Wrapping)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+
+        let results = flattened.maybe_null_slice::<u16>().to_vec();
+        assert_eq!(results, &[0u16, 1, 65535, 2]);
+        let valid_indices = flattened
+            .validity()
+            .to_logical(flattened.len())
+            .to_null_buffer()
+            .unwrap()
+            .unwrap()
+            .valid_indices()
+            .collect_vec();
+        assert_eq!(valid_indices, &[0, 1, 3]);
+    }
+
+    #[test]
+    fn test_scalar_subtract_float() {
+        let values = vec![1.0f64, 2.0, 3.0].into_array();
+        let to_subtract = -1f64;
+        let results = subtract_scalar(&values, &to_subtract.into(), OverflowPolicy::Wrapping)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<f64>()
+            .to_vec();
+        assert_eq!(results, &[2.0f64, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_scalar_subtract_float_underflow_is_ok() {
+        let values = vec![f32::MIN, 2.0, 3.0].into_array();
+        let _results = subtract_scalar(&values, &1.0f32.into(), OverflowPolicy::Wrapping).unwrap();
+        let _results =
+            subtract_scalar(&values, &f32::MAX.into(), OverflowPolicy::Wrapping).unwrap();
+    }
+
+    #[test]
+    fn test_scalar_subtract_overflow_policy() {
+        let values = PrimitiveArray::from_nullable_vec(vec![Some(1u8), None, Some(200)]);
+        let subtract = |overflow| {
+            subtract_scalar(&values, &Some(2u8).into(), overflow)
+                .map(|a| a.into_primitive().unwrap().iter::<u8>().collect_vec())
+        };
+        assert_eq!(
+            subtract(OverflowPolicy::Wrapping).unwrap(),
+            vec![Some(255), None, Some(198)]
+        );
+        assert_eq!(
+            subtract(OverflowPolicy::Saturating).unwrap(),
+            vec![Some(0), None, Some(198)]
+        );
+        assert!(subtract(OverflowPolicy::Checked).is_err());
+
+        // The null position underflows too, but isn't an error
+        let values = PrimitiveArray::from_nullable_vec(vec![Some(3u8), None, Some(200)]);
+        assert_eq!(
+            subtract_scalar(&values, &Some(2u8).into(), OverflowPolicy::Checked)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .iter::<u8>()
+                .collect_vec(),
+            vec![Some(1), None, Some(198)]
+        );
+    }
+
+    #[test]
+    fn test_scalar_subtract_type_mismatch_fails() {
+        let values = vec![1u64, 2, 3].into_array();
+        // Subtracting incompatible dtypes should fail
+        let _results = subtract_scalar(&values, &1.5f64.into(), OverflowPolicy::Wrapping)
+            .expect_err("Expected type mismatch error");
+    }
+
+    #[test]
+    fn add_scalar_overflow_policy() {
+        let values = PrimitiveArray::from(vec![1i8, 100, -128]);
+        let add = |overflow| {
+            add_scalar(&values, &50i8.into(), overflow).map(|a| {
+                a.into_primitive()
+                    .unwrap()
+                    .maybe_null_slice::<i8>()
+                    .to_vec()
+            })
+        };
+        assert_eq!(add(OverflowPolicy::Wrapping).unwrap(), vec![51, -106, -78]);
+        assert_eq!(add(OverflowPolicy::Saturating).unwrap(), vec![51, 127, -78]);
+        assert!(add(OverflowPolicy::Checked).is_err());
+
+        let floats = PrimitiveArray::from_nullable_vec(vec![Some(0.5f64), None]);
+        assert_eq!(
+            add_scalar(&floats, &Some(1.0f64).into(), OverflowPolicy::Checked)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .iter::<f64>()
+                .collect_vec(),
+            vec![Some(1.5), None]
+        );
+    }
+
+    #[test]
+    fn mul_div_scalar() {
+        let values = PrimitiveArray::from_nullable_vec(vec![Some(-128i8), None, Some(7)]);
+        let apply = |f: fn(&PrimitiveArray, &Scalar, OverflowPolicy) -> VortexResult<Array>,
+                     operand: i8,
+                     overflow| {
+            f(&values, &Some(operand).into(), overflow)
+                .map(|a| a.into_primitive().unwrap().iter::<i8>().collect_vec())
+        };
+        let mul = |a: &PrimitiveArray, s: &Scalar, o| mul_scalar(a, s, o);
+        let div = |a: &PrimitiveArray, s: &Scalar, o| div_scalar(a, s, o);
+
+        assert_eq!(
+            apply(mul, 2, OverflowPolicy::Wrapping).unwrap(),
+            vec![Some(0), None, Some(14)]
+        );
+        assert_eq!(
+            apply(mul, 2, OverflowPolicy::Saturating).unwrap(),
+            vec![Some(-128), None, Some(14)]
+        );
+        assert!(apply(mul, 2, OverflowPolicy::Checked).is_err());
+
+        assert_eq!(
+            apply(div, -1, OverflowPolicy::Wrapping).unwrap(),
+            vec![Some(-128), None, Some(-7)]
+        );
+        assert_eq!(
+            apply(div, -1, OverflowPolicy::Saturating).unwrap(),
+            vec![Some(127), None, Some(-7)]
+        );
+        assert!(apply(div, -1, OverflowPolicy::Checked).is_err());
+        assert_eq!(
+            apply(div, 2, OverflowPolicy::Checked).unwrap(),
+            vec![Some(-64), None, Some(3)]
+        );
+        assert!(apply(div, 0, OverflowPolicy::Wrapping).is_err());
+    }
+
+    #[test]
+    fn null_operand() {
+        let values = PrimitiveArray::from(vec![1u32, 2, 3]);
+        let result = mul_scalar(
+            &values,
+            &Scalar::null(DType::Primitive(PType::U32, Nullability::Nullable)),
+            OverflowPolicy::Checked,
+        )
+        .unwrap();
+        assert!(result.dtype().is_nullable());
+        assert_eq!(
+            result.into_primitive().unwrap().iter::<u32>().collect_vec(),
+            vec![None, None, None]
+        );
+
+        // A nullable operand makes the result nullable
+        let result = add_scalar(&values, &Some(1u32).into(), OverflowPolicy::Checked).unwrap();
+        assert!(result.dtype().is_nullable());
+        assert_eq!(
+            result.into_primitive().unwrap().iter::<u32>().collect_vec(),
+            vec![Some(2), Some(3), Some(4)]
+        );
+    }
+}
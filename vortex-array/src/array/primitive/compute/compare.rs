@@ -1,18 +1,18 @@
 use arrow_buffer::bit_util::ceil;
 use arrow_buffer::{BooleanBuffer, MutableBuffer};
 use vortex_dtype::{match_each_native_ptype, NativePType};
-use vortex_error::{vortex_err, VortexExpect, VortexResult};
-use vortex_scalar::PrimitiveScalar;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
 
 use crate::array::primitive::PrimitiveArray;
 use crate::array::{BoolArray, ConstantArray};
-use crate::compute::{MaybeCompareFn, Operator};
-use crate::{Array, ArrayDType, IntoArray};
+use crate::compute::{CompareScalarFn, MaybeCompareFn, Operator};
+use crate::{Array, IntoArray};
 
 impl MaybeCompareFn for PrimitiveArray {
     fn maybe_compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
         if let Ok(const_array) = ConstantArray::try_from(other) {
-            return Some(primitive_const_compare(self, const_array, operator));
+            return Some(self.compare_scalar(&const_array.owned_scalar(), operator));
         }
 
         if let Ok(primitive) = PrimitiveArray::try_from(other) {
@@ -36,21 +36,18 @@ impl MaybeCompareFn for PrimitiveArray {
     }
 }
 
-fn primitive_const_compare(
-    this: &PrimitiveArray,
-    other: ConstantArray,
-    operator: Operator,
-) -> VortexResult<Array> {
-    let primitive_scalar = PrimitiveScalar::try_new(other.dtype(), other.scalar_value())
-        .vortex_expect("Expected a primitive scalar");
-
-    let buffer = match_each_native_ptype!(this.ptype(), |$T| {
-        let typed_value = primitive_scalar.typed_value::<$T>()
-            .ok_or_else(|| vortex_err!("Type mismatch between array and constant"))?;
-        primitive_value_compare::<$T>(this, typed_value, operator)
-    });
-
-    Ok(BoolArray::try_new(buffer, this.validity().into_nullable())?.into_array())
+impl CompareScalarFn for PrimitiveArray {
+    fn compare_scalar(&self, scalar: &Scalar, operator: Operator) -> VortexResult<Array> {
+        let primitive_scalar = PrimitiveScalar::try_from(scalar)?;
+
+        let buffer = match_each_native_ptype!(self.ptype(), |$T| {
+            let typed_value = primitive_scalar.typed_value::<$T>()
+                .ok_or_else(|| vortex_err!("Type mismatch between array and constant"))?;
+            primitive_value_compare::<$T>(self, typed_value, operator)
+        });
+
+        Ok(BoolArray::try_new(buffer, self.validity().into_nullable())?.into_array())
+    }
 }
 
 fn primitive_value_compare<T: NativePType>(
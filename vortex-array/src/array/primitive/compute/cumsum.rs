@@ -0,0 +1,38 @@
+use vortex_error::VortexResult;
+
+use crate::array::PrimitiveArray;
+use crate::compute::unary::{running_total, CumSumFn, RunningTotal};
+use crate::{Array, IntoArray};
+
+impl CumSumFn for PrimitiveArray {
+    fn cumsum(&self) -> VortexResult<Array> {
+        running_total(self, &mut RunningTotal::new(self.ptype())).map(IntoArray::into_array)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::PrimitiveArray;
+    use crate::compute::unary::cumsum;
+    use crate::IntoArrayVariant;
+
+    #[test]
+    fn cumsum_skips_nulls() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1.5f32), None, Some(2.0), None]);
+        let sums = cumsum(&array).unwrap().into_primitive().unwrap();
+        assert_eq!(
+            sums.iter::<f64>().collect::<Vec<_>>(),
+            vec![Some(1.5), None, Some(3.5), None]
+        );
+    }
+
+    #[test]
+    fn cumsum_overflow() {
+        let widened = cumsum(PrimitiveArray::from(vec![u8::MAX, u8::MAX])).unwrap();
+        assert_eq!(
+            widened.into_primitive().unwrap().maybe_null_slice::<u64>(),
+            &[255, 510]
+        );
+        assert!(cumsum(PrimitiveArray::from(vec![u64::MAX, 1])).is_err());
+    }
+}
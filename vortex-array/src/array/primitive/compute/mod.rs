@@ -1,21 +1,31 @@
 use vortex_error::VortexResult;
 
 use crate::array::primitive::PrimitiveArray;
-use crate::compute::unary::{CastFn, FillForwardFn, ScalarAtFn, SubtractScalarFn};
-use crate::compute::{ArrayCompute, MaybeCompareFn, Operator, SearchSortedFn, SliceFn, TakeFn};
+use crate::compute::unary::{
+    BinaryScalarFn, CastFn, CumSumFn, FillForwardFn, RollingFn, ScalarAtFn,
+};
+use crate::compute::{
+    ArrayCompute, CompareScalarFn, MaybeCompareFn, Operator, SearchSortedFn, SliceFn, TakeFn,
+};
 use crate::Array;
 
+mod binary_scalar;
 mod cast;
 mod compare;
+mod cumsum;
 mod fill;
 mod filter;
+mod rolling;
 mod scalar_at;
 mod search_sorted;
 mod slice;
-mod subtract_scalar;
 mod take;
 
 impl ArrayCompute for PrimitiveArray {
+    fn binary_scalar(&self) -> Option<&dyn BinaryScalarFn> {
+        Some(self)
+    }
+
     fn cast(&self) -> Option<&dyn CastFn> {
         Some(self)
     }
@@ -24,15 +34,23 @@ impl ArrayCompute for PrimitiveArray {
         MaybeCompareFn::maybe_compare(self, other, operator)
     }
 
+    fn compare_scalar(&self) -> Option<&dyn CompareScalarFn> {
+        Some(self)
+    }
+
+    fn cumsum(&self) -> Option<&dyn CumSumFn> {
+        Some(self)
+    }
+
     fn fill_forward(&self) -> Option<&dyn FillForwardFn> {
         Some(self)
     }
 
-    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+    fn rolling(&self) -> Option<&dyn RollingFn> {
         Some(self)
     }
 
-    fn subtract_scalar(&self) -> Option<&dyn SubtractScalarFn> {
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
@@ -0,0 +1,425 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+use vortex_dtype::{match_each_native_ptype, NativePType};
+use vortex_error::{vortex_err, VortexResult};
+
+use crate::array::PrimitiveArray;
+use crate::compute::unary::{RollingAgg, RollingFn, RunningTotal};
+use crate::validity::Validity;
+use crate::{Array, IntoArray};
+
+impl RollingFn for PrimitiveArray {
+    fn rolling(&self, window: usize, agg: RollingAgg) -> VortexResult<Array> {
+        let values = match_each_native_ptype!(self.ptype(), |$T| {
+            let values = self.iter::<$T>().collect::<Vec<_>>();
+            match agg {
+                RollingAgg::Sum => match RunningTotal::new(self.ptype()) {
+                    RunningTotal::Signed(_) => rolling_sum::<$T, i64>(
+                        &values, window, i64::checked_add, i64::checked_sub,
+                    )?.into_array(),
+                    RunningTotal::Unsigned(_) => rolling_sum::<$T, u64>(
+                        &values, window, u64::checked_add, u64::checked_sub,
+                    )?.into_array(),
+                    RunningTotal::Float(_) => {
+                        PrimitiveArray::from_nullable_vec(rolling_float_sum(&values, window)?)
+                            .into_array()
+                    }
+                },
+                RollingAgg::Mean => rolling_mean(&values, window)?.into_array(),
+                RollingAgg::Min => rolling_extremum(&values, window, Ordering::Less).into_array(),
+                RollingAgg::Max => rolling_extremum(&values, window, Ordering::Greater).into_array(),
+            }
+        });
+        Ok(values)
+    }
+}
+
+/// Sum of the window, kept up to date by adding the value entering it and subtracting the value
+/// leaving it.
+///
+/// Only exact for integers, floats go through [rolling_float_sum].
+fn rolling_sum<T: NativePType, A: NativePType>(
+    values: &[Option<T>],
+    window: usize,
+    add: impl Fn(A, A) -> Option<A>,
+    sub: impl Fn(A, A) -> Option<A>,
+) -> VortexResult<PrimitiveArray> {
+    let cast = |value: T| {
+        num_traits::cast::<T, A>(value)
+            .ok_or_else(|| vortex_err!("Cannot sum {value} as {}", A::PTYPE))
+    };
+    let overflow = || vortex_err!(ComputeError: "Rolling sum overflows {}", A::PTYPE);
+
+    let mut sum = A::zero();
+    let mut count = 0;
+    let mut sums = Vec::with_capacity(values.len());
+    for (idx, value) in values.iter().enumerate() {
+        // Subtract first so that sums of the window that fit the type don't overflow in between
+        if idx >= window {
+            if let Some(leaving) = values[idx - window] {
+                sum = sub(sum, cast(leaving)?).ok_or_else(overflow)?;
+                count -= 1;
+            }
+        }
+        if let Some(value) = value {
+            sum = add(sum, cast(*value)?).ok_or_else(overflow)?;
+            count += 1;
+        }
+        sums.push((idx + 1 >= window && count > 0).then_some(sum));
+    }
+    Ok(PrimitiveArray::from_nullable_vec(sums))
+}
+
+/// Sum of the window, kept up to date like [rolling_sum] but compensating for rounding.
+///
+/// Finite values go into a [CompensatedSum], which is recomputed from the values of the window
+/// every `window` values so that the rounding errors of values that already left don't accumulate.
+/// Infinities and NaNs are counted instead, subtracting an infinity that left the window would
+/// yield NaN.
+fn rolling_float_sum<T: NativePType>(
+    values: &[Option<T>],
+    window: usize,
+) -> VortexResult<Vec<Option<f64>>> {
+    let values = values
+        .iter()
+        .map(|value| {
+            value
+                .map(|v| {
+                    num_traits::cast::<T, f64>(v)
+                        .ok_or_else(|| vortex_err!("Cannot sum {v} as {}", f64::PTYPE))
+                })
+                .transpose()
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+
+    let mut sum = WindowSum::default();
+    let mut count = 0;
+    let mut sums = Vec::with_capacity(values.len());
+    for (idx, value) in values.iter().enumerate() {
+        if idx >= window {
+            if let Some(leaving) = values[idx - window] {
+                sum.remove(leaving);
+                count -= 1;
+            }
+        }
+        if let Some(value) = value {
+            sum.add(*value);
+            count += 1;
+        }
+        if (idx + 1) % window == 0 {
+            sum.finite = values[idx + 1 - window..=idx]
+                .iter()
+                .flatten()
+                .filter(|v| v.is_finite())
+                .fold(CompensatedSum::default(), |mut acc, v| {
+                    acc.add(*v);
+                    acc
+                });
+        }
+        sums.push((idx + 1 >= window && count > 0).then(|| sum.value()));
+    }
+    Ok(sums)
+}
+
+/// Sum of the finite values of a window, with counts of the non-finite ones
+#[derive(Debug, Default)]
+struct WindowSum {
+    finite: CompensatedSum,
+    pos_inf: usize,
+    neg_inf: usize,
+    nan: usize,
+}
+
+impl WindowSum {
+    fn add(&mut self, value: f64) {
+        match value {
+            v if v.is_nan() => self.nan += 1,
+            f64::INFINITY => self.pos_inf += 1,
+            f64::NEG_INFINITY => self.neg_inf += 1,
+            v => self.finite.add(v),
+        }
+    }
+
+    fn remove(&mut self, value: f64) {
+        match value {
+            v if v.is_nan() => self.nan -= 1,
+            f64::INFINITY => self.pos_inf -= 1,
+            f64::NEG_INFINITY => self.neg_inf -= 1,
+            v => self.finite.add(-v),
+        }
+    }
+
+    fn value(&self) -> f64 {
+        if self.nan > 0 || (self.pos_inf > 0 && self.neg_inf > 0) {
+            f64::NAN
+        } else if self.pos_inf > 0 {
+            f64::INFINITY
+        } else if self.neg_inf > 0 {
+            f64::NEG_INFINITY
+        } else {
+            self.finite.value()
+        }
+    }
+}
+
+/// Neumaier's compensated summation, keeping the low order bits lost by each addition
+#[derive(Debug, Default)]
+struct CompensatedSum {
+    sum: f64,
+    compensation: f64,
+}
+
+impl CompensatedSum {
+    fn add(&mut self, value: f64) {
+        let total = self.sum + value;
+        self.compensation += if self.sum.abs() >= value.abs() {
+            (self.sum - total) + value
+        } else {
+            (value - total) + self.sum
+        };
+        self.sum = total;
+    }
+
+    fn value(&self) -> f64 {
+        self.sum + self.compensation
+    }
+}
+
+/// Mean of the window, the float sum divided by the count, or for integers the exact sum of the
+/// window kept in an `i128` that no window of 64 bit values can overflow.
+fn rolling_mean<T: NativePType>(
+    values: &[Option<T>],
+    window: usize,
+) -> VortexResult<PrimitiveArray> {
+    let counts = rolling_count(values, window);
+    let sums = if T::PTYPE.is_float() {
+        rolling_float_sum(values, window)?
+    } else {
+        let cast = |value: T| {
+            num_traits::cast::<T, i128>(value)
+                .ok_or_else(|| vortex_err!("Cannot sum {value} as i128"))
+        };
+        let mut sum = 0i128;
+        let mut sums = Vec::with_capacity(values.len());
+        for (idx, value) in values.iter().enumerate() {
+            if idx >= window {
+                if let Some(leaving) = values[idx - window] {
+                    sum -= cast(leaving)?;
+                }
+            }
+            if let Some(value) = value {
+                sum += cast(*value)?;
+            }
+            sums.push(Some(sum as f64));
+        }
+        sums
+    };
+    let means = sums
+        .into_iter()
+        .zip(counts)
+        .enumerate()
+        .map(|(idx, (sum, count))| {
+            (idx + 1 >= window && count > 0)
+                .then_some(sum)
+                .flatten()
+                .map(|sum| sum / count as f64)
+        })
+        .collect::<Vec<_>>();
+    Ok(PrimitiveArray::from_nullable_vec(means))
+}
+
+/// Number of non-null values in the window ending at each position
+fn rolling_count<T>(values: &[Option<T>], window: usize) -> Vec<usize> {
+    let mut count = 0;
+    values
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            count += usize::from(value.is_some());
+            if idx >= window {
+                count -= usize::from(values[idx - window].is_some());
+            }
+            count
+        })
+        .collect()
+}
+
+/// Minimum or maximum of the window, the one of `ordering` against the other values.
+///
+/// Keeps the indices of the values that may still become the extremum of a later window, whose
+/// values are monotonic, so the extremum of the current window is always at the front.
+fn rolling_extremum<T: NativePType>(
+    values: &[Option<T>],
+    window: usize,
+    ordering: Ordering,
+) -> PrimitiveArray {
+    let mut candidates: VecDeque<usize> = VecDeque::with_capacity(window);
+    let mut extrema = Vec::with_capacity(values.len());
+    for (idx, value) in values.iter().enumerate() {
+        if candidates
+            .front()
+            .is_some_and(|&front| front + window <= idx)
+        {
+            candidates.pop_front();
+        }
+        if let Some(value) = value {
+            while candidates.back().is_some_and(|&back| {
+                values[back].is_some_and(|candidate| value.compare(candidate) != ordering.reverse())
+            }) {
+                candidates.pop_back();
+            }
+            candidates.push_back(idx);
+        }
+        extrema.push(if idx + 1 >= window {
+            candidates.front().and_then(|&front| values[front])
+        } else {
+            None
+        });
+    }
+    PrimitiveArray::from_vec(
+        extrema.iter().map(|v| v.unwrap_or_default()).collect(),
+        Validity::from(extrema.iter().map(Option::is_some).collect::<Vec<_>>()),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::PrimitiveArray;
+    use crate::compute::unary::{rolling, RollingAgg};
+    use crate::IntoArrayVariant;
+
+    fn roll<T: vortex_dtype::NativePType>(
+        array: &PrimitiveArray,
+        window: usize,
+        agg: RollingAgg,
+    ) -> Vec<Option<T>> {
+        rolling(array, window, agg)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .iter::<T>()
+            .collect()
+    }
+
+    #[test]
+    fn rolling_aggregates() {
+        let array = PrimitiveArray::from(vec![3i32, 1, 4, 1, 5, 9, 2]);
+        assert_eq!(
+            roll::<i64>(&array, 3, RollingAgg::Sum),
+            vec![None, None, Some(8), Some(6), Some(10), Some(15), Some(16)]
+        );
+        assert_eq!(
+            roll::<i32>(&array, 3, RollingAgg::Min),
+            vec![None, None, Some(1), Some(1), Some(1), Some(1), Some(2)]
+        );
+        assert_eq!(
+            roll::<i32>(&array, 3, RollingAgg::Max),
+            vec![None, None, Some(4), Some(4), Some(5), Some(9), Some(9)]
+        );
+        assert_eq!(
+            roll::<f64>(&array, 2, RollingAgg::Mean),
+            vec![
+                None,
+                Some(2.0),
+                Some(2.5),
+                Some(2.5),
+                Some(3.0),
+                Some(7.0),
+                Some(5.5)
+            ]
+        );
+        assert_eq!(
+            roll::<i64>(&array, 1, RollingAgg::Sum),
+            vec![
+                Some(3),
+                Some(1),
+                Some(4),
+                Some(1),
+                Some(5),
+                Some(9),
+                Some(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn rolling_skips_nulls() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(2.0f64), None, None, Some(4.0), Some(1.0)]);
+        assert_eq!(
+            roll::<f64>(&array, 2, RollingAgg::Sum),
+            vec![None, Some(2.0), None, Some(4.0), Some(5.0)]
+        );
+        assert_eq!(
+            roll::<f64>(&array, 2, RollingAgg::Mean),
+            vec![None, Some(2.0), None, Some(4.0), Some(2.5)]
+        );
+        assert_eq!(
+            roll::<f64>(&array, 3, RollingAgg::Max),
+            vec![None, None, Some(2.0), Some(4.0), Some(4.0)]
+        );
+
+        assert!(rolling(&array, 0, RollingAgg::Sum).is_err());
+        assert!(rolling(PrimitiveArray::from(vec![u64::MAX, 1]), 2, RollingAgg::Sum).is_err());
+    }
+
+    #[test]
+    fn rolling_sum_removes_leaving_value_first() {
+        let array = PrimitiveArray::from(vec![u64::MAX, 1]);
+        assert_eq!(
+            roll::<u64>(&array, 1, RollingAgg::Sum),
+            vec![Some(u64::MAX), Some(1)]
+        );
+    }
+
+    #[test]
+    fn rolling_float_sum_recovers_after_inf_leaves() {
+        let array = PrimitiveArray::from(vec![1.0f64, f64::INFINITY, 1.0, 1.0]);
+        assert_eq!(
+            roll::<f64>(&array, 2, RollingAgg::Sum),
+            vec![None, Some(f64::INFINITY), Some(f64::INFINITY), Some(2.0)]
+        );
+        assert_eq!(
+            roll::<f64>(&array, 2, RollingAgg::Mean),
+            vec![None, Some(f64::INFINITY), Some(f64::INFINITY), Some(1.0)]
+        );
+    }
+
+    #[test]
+    fn rolling_float_sum_keeps_small_values_after_large_leaves() {
+        let array = PrimitiveArray::from(vec![1e20f64, 1.0, 1.0]);
+        assert_eq!(
+            roll::<f64>(&array, 2, RollingAgg::Sum),
+            vec![None, Some(1e20), Some(2.0)]
+        );
+        assert_eq!(
+            roll::<f64>(&array, 2, RollingAgg::Mean),
+            vec![None, Some(5e19), Some(1.0)]
+        );
+    }
+
+    #[test]
+    fn rolling_float_sum_doesnt_drift() {
+        // Every large value leaves a rounding error behind it when it leaves the window
+        let mut values = [1e16f64, 3.0].repeat(1000);
+        values.extend([1.0, 1.0]);
+        let sums = roll::<f64>(&PrimitiveArray::from(values), 3, RollingAgg::Sum);
+        assert_eq!(sums.last(), Some(&Some(5.0)));
+        assert_eq!(sums[2], Some(1e16 + 3.0 + 1e16));
+    }
+
+    #[test]
+    fn rolling_integer_mean_is_exact() {
+        let array = PrimitiveArray::from(vec![i64::MAX, i64::MAX, 1, 3]);
+        assert_eq!(
+            roll::<f64>(&array, 2, RollingAgg::Mean),
+            vec![
+                None,
+                Some(i64::MAX as f64),
+                Some(i64::MAX as f64 / 2.0),
+                Some(2.0)
+            ]
+        );
+    }
+}
@@ -9,6 +9,7 @@ use itertools::Itertools;
 use num_traits::AsPrimitive;
 use serde::{Deserialize, Serialize};
 use vortex_buffer::Buffer;
+use vortex_dtype::half::f16;
 use vortex_dtype::{match_each_native_ptype, DType, NativePType, PType};
 use vortex_error::{vortex_bail, vortex_panic, VortexError, VortexExpect as _, VortexResult};
 
@@ -24,9 +25,13 @@ use crate::{
 };
 
 mod accessor;
+mod builder;
 mod compute;
 mod stats;
 
+pub(crate) use builder::finish_validity;
+pub use builder::PrimitiveBuilder;
+
 impl_encoding!("vortex.primitive", ids::PRIMITIVE, Primitive);
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -113,6 +118,12 @@ impl PrimitiveArray {
             .vortex_expect("Missing buffer in PrimitiveArray")
     }
 
+    /// Whether the values start at an [`ALIGNMENT`](vortex_buffer::ALIGNMENT) aligned address,
+    /// letting kernels use aligned loads instead of handling a misaligned head.
+    pub fn is_aligned(&self) -> bool {
+        self.buffer().is_aligned()
+    }
+
     pub fn maybe_null_slice<T: NativePType>(&self) -> &[T] {
         assert_eq!(
             T::PTYPE,
@@ -128,6 +139,30 @@ impl PrimitiveArray {
         unsafe { std::slice::from_raw_parts(raw_slice.as_ptr().cast(), typed_len) }
     }
 
+    /// Iterate the values, with `None` for the nulls.
+    pub fn iter<'a, T: NativePType + 'a>(&'a self) -> impl Iterator<Item = Option<T>> + 'a {
+        let nulls = self
+            .logical_validity()
+            .to_null_buffer()
+            .vortex_expect("Failed to get null buffer of PrimitiveArray");
+        self.maybe_null_slice::<T>()
+            .iter()
+            .enumerate()
+            .map(move |(idx, value)| {
+                nulls
+                    .as_ref()
+                    .map_or(true, |n| n.is_valid(idx))
+                    .then_some(*value)
+            })
+    }
+
+    /// Iterate the values of an array known to have no nulls, without checking validity.
+    ///
+    /// The values at null positions are unspecified.
+    pub fn iter_values<'a, T: NativePType + 'a>(&'a self) -> impl Iterator<Item = T> + 'a {
+        self.maybe_null_slice::<T>().iter().copied()
+    }
+
     /// Convert the array into a mutable vec of the given type.
     /// If possible, this will be zero-copy.
     pub fn into_maybe_null_slice<T: NativePType + ArrowNativeType>(self) -> Vec<T> {
@@ -191,7 +226,20 @@ impl PrimitiveArray {
     }
 }
 
-impl ArrayTrait for PrimitiveArray {}
+impl ArrayTrait for PrimitiveArray {
+    fn validate(&self) -> VortexResult<()> {
+        let expected = self.len() * self.ptype().byte_width();
+        if self.buffer().len() != expected {
+            vortex_bail!(
+                "Buffer of {} bytes doesn't hold {} values of type {}",
+                self.buffer().len(),
+                self.len(),
+                self.ptype()
+            );
+        }
+        Ok(())
+    }
+}
 
 impl ArrayVariants for PrimitiveArray {
     fn as_primitive_array(&self) -> Option<&dyn PrimitiveArrayTrait> {
@@ -290,15 +338,38 @@ impl PrimitiveArrayTrait for PrimitiveArray {
     }
 }
 
-impl<T: NativePType> From<Vec<T>> for PrimitiveArray {
-    fn from(values: Vec<T>) -> Self {
-        Self::from_vec(values, Validity::NonNullable)
+// Implemented per native type, a blanket `From<Vec<T>>` would overlap with `From<Vec<Option<T>>>`
+macro_rules! primitive_array_from_vec {
+    ($($T:ty),*) => {
+        $(
+            impl From<Vec<$T>> for PrimitiveArray {
+                fn from(values: Vec<$T>) -> Self {
+                    Self::from_vec(values, Validity::NonNullable)
+                }
+            }
+        )*
+    };
+}
+
+primitive_array_from_vec!(u8, u16, u32, u64, i8, i16, i32, i64, f16, f32, f64);
+
+impl<T: NativePType> From<Vec<Option<T>>> for PrimitiveArray {
+    /// Nullable array with the `None`s as nulls, see [`PrimitiveArray::from_nullable_vec`]
+    fn from(values: Vec<Option<T>>) -> Self {
+        Self::from_nullable_vec(values)
+    }
+}
+
+impl<T: NativePType> FromIterator<Option<T>> for PrimitiveArray {
+    /// Nullable array with the `None`s as nulls, see [`PrimitiveArray::from_nullable_vec`]
+    fn from_iter<I: IntoIterator<Item = Option<T>>>(iter: I) -> Self {
+        Self::from_nullable_vec(iter.into_iter().collect())
     }
 }
 
 impl<T: NativePType> IntoArray for Vec<T> {
     fn into_array(self) -> Array {
-        PrimitiveArray::from(self).into_array()
+        PrimitiveArray::from_vec(self, Validity::NonNullable).into_array()
     }
 }
 
@@ -465,9 +536,73 @@ fn process_batch<I: NativePType, U: NativePType, O: NativePType, F: Fn(I, U) ->
 
 #[cfg(test)]
 mod tests {
+    use vortex_dtype::Nullability;
     use vortex_scalar::Scalar;
 
     use super::*;
+    use crate::array::StructArray;
+
+    #[test]
+    fn validate_buffer_length() {
+        let array = PrimitiveArray::from(vec![1u32, 2, 3]);
+        array.as_ref().validate().unwrap();
+
+        let truncated = PrimitiveArray {
+            typed: TypedArray::try_from_parts(
+                DType::Primitive(PType::U32, Nullability::NonNullable),
+                4,
+                PrimitiveMetadata {
+                    validity: Validity::NonNullable.to_metadata(4).unwrap(),
+                },
+                Some(array.buffer().clone()),
+                [].into(),
+                StatsSet::new(),
+            )
+            .unwrap(),
+        };
+        assert!(truncated.as_ref().validate().is_err());
+
+        // Children are validated too
+        let st = StructArray::from_fields(&[("numbers", truncated.into_array())]).unwrap();
+        assert!(st.as_ref().validate().is_err());
+    }
+
+    #[test]
+    fn from_options() {
+        let array = PrimitiveArray::from_iter([Some(1u32), None, Some(3)]);
+        assert!(array.dtype().is_nullable());
+        assert_eq!(array.maybe_null_slice::<u32>(), &[1, 0, 3]);
+        assert_eq!(
+            array.iter::<u32>().collect::<Vec<_>>(),
+            vec![Some(1), None, Some(3)]
+        );
+
+        let array = PrimitiveArray::from(vec![None, Some(2i64), None]);
+        assert!(array.dtype().is_nullable());
+        assert_eq!(
+            array.iter::<i64>().collect::<Vec<_>>(),
+            vec![None, Some(2), None]
+        );
+    }
+
+    #[test]
+    fn iter_nullable() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(-3), None]);
+        assert_eq!(
+            array.iter::<i32>().collect::<Vec<_>>(),
+            vec![Some(1), None, Some(-3), None]
+        );
+
+        let array = PrimitiveArray::from(vec![1i32, 2, 3]);
+        assert_eq!(
+            array.iter::<i32>().collect::<Vec<_>>(),
+            vec![Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            array.iter_values::<i32>().collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
 
     #[test]
     fn batched_iter() {
@@ -553,4 +688,12 @@ mod tests {
             assert_eq!(o.unwrap(), 3);
         }
     }
+
+    #[test]
+    fn as_typed() {
+        let array = PrimitiveArray::from(vec![1u8, 2, 3]).into_array();
+        let typed = array.as_typed::<Primitive>().unwrap();
+        assert_eq!(typed.maybe_null_slice::<u8>(), &[1, 2, 3]);
+        assert!(array.as_typed::<crate::array::Bool>().is_none());
+    }
 }
@@ -10,7 +10,8 @@ use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::array::primitive::PrimitiveArray;
-use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
+use crate::compute::FloatOrdering;
+use crate::stats::{equi_depth_histogram, ArrayStatisticsCompute, HyperLogLog, Stat, StatsSet};
 use crate::validity::{ArrayValidity, LogicalValidity};
 use crate::{ArrayDType, IntoArrayVariant};
 
@@ -20,6 +21,28 @@ impl<T: NativePType + Into<Scalar> + BitWidth> PStatsType for T {}
 
 impl ArrayStatisticsCompute for PrimitiveArray {
     fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        if stat == Stat::Histogram {
+            return Ok(match_each_native_ptype!(self.ptype(), |$P| {
+                equi_depth_histogram(self.iter::<$P>().flatten().collect())
+                    .map(|boundaries| {
+                        let values = boundaries.into_iter().map(|v| Scalar::from(v).into_value()).collect();
+                        StatsSet::of(
+                            Stat::Histogram,
+                            Scalar::list(DType::Primitive($P::PTYPE, Nullability::NonNullable), values),
+                        )
+                    })
+                    .unwrap_or_default()
+            }));
+        }
+
+        if stat == Stat::ApproxDistinct {
+            let mut hll = HyperLogLog::new();
+            match_each_native_ptype!(self.ptype(), |$P| {
+                self.iter::<$P>().flatten().for_each(|v| hll.insert_native(v))
+            });
+            return Ok(StatsSet::of(Stat::ApproxDistinct, hll.to_scalar()));
+        }
+
         match_each_native_ptype!(self.ptype(), |$P| {
             match self.logical_validity() {
                 LogicalValidity::AllValid(_) => self.maybe_null_slice::<$P>().compute_statistics(stat),
@@ -193,14 +216,14 @@ impl<T: PStatsType> StatsAccumulator<T> {
         if next.is_eq(self.prev) {
             self.is_strict_sorted = false;
         } else {
-            if matches!(next.compare(self.prev), Ordering::Less) {
+            if nans_last(next, self.prev) == Ordering::Less {
                 self.is_sorted = false;
             }
             self.run_count += 1;
         }
-        if matches!(next.compare(self.min), Ordering::Less) {
+        if nans_last(next, self.min) == Ordering::Less {
             self.min = next;
-        } else if matches!(next.compare(self.max), Ordering::Greater) {
+        } else if nans_last(next, self.max) == Ordering::Greater {
             self.max = next;
         }
         self.prev = next;
@@ -227,6 +250,14 @@ impl<T: PStatsType> StatsAccumulator<T> {
     }
 }
 
+/// Values are ordered like [`FloatOrdering::NansLast`], so NaN is the maximum of float arrays that
+/// hold one.
+fn nans_last<T: NativePType>(a: T, b: T) -> Ordering {
+    FloatOrdering::NansLast
+        .compare(a, b)
+        .unwrap_or_else(|| unreachable!("Ordering NaNs last never fails"))
+}
+
 #[cfg(test)]
 mod test {
     use vortex_dtype::{DType, Nullability, PType};
@@ -301,4 +332,16 @@ mod test {
         assert_eq!(min, Some(null_i32.clone()));
         assert_eq!(max, Some(null_i32));
     }
+
+    #[test]
+    fn nans_are_max() {
+        let arr = PrimitiveArray::from(vec![1.0f64, f64::NAN, -0.0, -f64::NAN, 0.0]);
+        let min: f64 = arr.statistics().compute_min().unwrap();
+        let max: f64 = arr.statistics().compute_max().unwrap();
+        assert!(min.is_sign_negative() && min == 0.0);
+        assert!(max.is_nan());
+
+        let sorted = PrimitiveArray::from(vec![-0.0f32, 0.0, 1.0, f32::NAN]);
+        assert!(sorted.statistics().compute_is_strict_sorted().unwrap());
+    }
 }
@@ -91,7 +91,7 @@ fn canonicalize_sparse_primitives<
     let array = if fill_value.is_null() {
         PrimitiveArray::from_vec(result, Validity::from(validity))
     } else {
-        PrimitiveArray::from(result)
+        PrimitiveArray::from_vec(result, Validity::NonNullable)
     };
     Ok(Canonical::Primitive(array))
 }
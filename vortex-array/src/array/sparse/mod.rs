@@ -170,7 +170,24 @@ impl SparseArray {
     }
 }
 
-impl ArrayTrait for SparseArray {}
+impl ArrayTrait for SparseArray {
+    fn validate(&self) -> VortexResult<()> {
+        let indices = self.indices();
+        if indices.is_empty() {
+            return Ok(());
+        }
+        let first = usize::try_from(&scalar_at(&indices, 0)?)?;
+        let last = usize::try_from(&scalar_at(&indices, indices.len() - 1)?)?;
+        if first < self.indices_offset() || last - self.indices_offset() >= self.len() {
+            vortex_bail!(
+                "Indices [{first}, {last}] out of bounds of array of length {} at offset {}",
+                self.len(),
+                self.indices_offset()
+            );
+        }
+        Ok(())
+    }
+}
 
 impl AcceptArrayVisitor for SparseArray {
     fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
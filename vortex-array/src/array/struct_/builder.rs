@@ -0,0 +1,225 @@
+use std::any::Any;
+
+use arrow_buffer::NullBufferBuilder;
+use vortex_dtype::{match_each_native_ptype, DType, Nullability, StructDType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::builder::VarBinBuilder;
+use crate::array::primitive::finish_validity;
+use crate::array::{PrimitiveBuilder, StructArray};
+use crate::{Array, IntoArray};
+
+/// A builder of any array type, so that builders of different types can be combined, see
+/// [`StructBuilder`].
+pub trait ArrayBuilder: Send {
+    /// Push a null, which is only allowed if the builder is nullable
+    fn push_null(&mut self);
+
+    /// Finish the array, which must be of type `dtype`, failing if it can't hold the pushed values
+    fn finish_array(self: Box<Self>, dtype: &DType) -> VortexResult<Array>;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+/// Builds a [`StructArray`] row by row, with a builder per field.
+///
+/// A row is pushed by pushing a value to every field builder, see [`StructBuilder::field`], and
+/// then calling [`StructBuilder::push_value`].
+pub struct StructBuilder {
+    dtype: StructDType,
+    nullability: Nullability,
+    fields: Vec<Box<dyn ArrayBuilder>>,
+    validity: NullBufferBuilder,
+    len: usize,
+}
+
+impl StructBuilder {
+    /// Builder of arrays of type `dtype`, fields can be primitives, strings, binary and structs
+    pub fn new(dtype: StructDType, nullability: Nullability) -> VortexResult<Self> {
+        Self::with_capacity(dtype, nullability, 0)
+    }
+
+    pub fn with_capacity(
+        dtype: StructDType,
+        nullability: Nullability,
+        capacity: usize,
+    ) -> VortexResult<Self> {
+        let fields = dtype
+            .dtypes()
+            .iter()
+            .map(|dtype| builder_for(dtype, capacity))
+            .collect::<VortexResult<_>>()?;
+        Ok(Self {
+            dtype,
+            nullability,
+            fields,
+            validity: NullBufferBuilder::new(capacity),
+            len: 0,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The builder of the field at `idx`, if it exists and is a `B`.
+    ///
+    /// Primitive fields are built by a [`PrimitiveBuilder`] of their native type, strings and
+    /// binary by a `VarBinBuilder<i32>` and nested structs by a [`StructBuilder`].
+    pub fn field<B: ArrayBuilder + 'static>(&mut self, idx: usize) -> Option<&mut B> {
+        self.fields
+            .get_mut(idx)
+            .and_then(|f| f.as_any_mut().downcast_mut::<B>())
+    }
+
+    /// Complete a row after a value has been pushed to each of the fields
+    pub fn push_value(&mut self) {
+        self.len += 1;
+        self.validity.append_non_null();
+    }
+
+    /// Push a null row, pushing a null to each of the fields, so they have to be nullable as well
+    #[allow(clippy::same_name_method)]
+    pub fn push_null(&mut self) {
+        for field in &mut self.fields {
+            field.push_null();
+        }
+        self.len += 1;
+        self.validity.append_null();
+    }
+
+    /// The built array, failing if a null was pushed to a non-nullable builder or if a row was
+    /// completed without pushing a value to each of the fields
+    pub fn finish(mut self) -> VortexResult<StructArray> {
+        let validity = finish_validity(&mut self.validity, self.nullability)?;
+        let fields = self
+            .fields
+            .into_iter()
+            .zip(self.dtype.dtypes().iter())
+            .map(|(field, dtype)| field.finish_array(dtype))
+            .collect::<VortexResult<_>>()?;
+        StructArray::try_new(self.dtype.names().clone(), fields, self.len, validity)
+    }
+}
+
+fn builder_for(dtype: &DType, capacity: usize) -> VortexResult<Box<dyn ArrayBuilder>> {
+    Ok(match dtype {
+        DType::Primitive(ptype, nullability) => {
+            match_each_native_ptype!(ptype, |$T| {
+                Box::new(PrimitiveBuilder::<$T>::with_capacity(*nullability, capacity))
+            })
+        }
+        DType::Utf8(_) | DType::Binary(_) => {
+            Box::new(VarBinBuilder::<i32>::with_capacity(capacity))
+        }
+        DType::Struct(st, nullability) => Box::new(StructBuilder::with_capacity(
+            st.clone(),
+            *nullability,
+            capacity,
+        )?),
+        _ => vortex_bail!("No builder for arrays of type {dtype}"),
+    })
+}
+
+impl ArrayBuilder for StructBuilder {
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn finish_array(self: Box<Self>, _dtype: &DType) -> VortexResult<Array> {
+        self.finish().map(IntoArray::into_array)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType, StructDType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::builder::VarBinBuilder;
+    use crate::array::{PrimitiveBuilder, StructBuilder};
+    use crate::compute::unary::scalar_at;
+    use crate::variants::StructArrayTrait;
+    use crate::{ArrayDType, IntoArrayVariant};
+
+    #[test]
+    fn build_rows() {
+        let dtype = StructDType::new(
+            ["id".into(), "name".into()].into(),
+            vec![
+                DType::Primitive(PType::U32, Nullability::NonNullable),
+                DType::Utf8(Nullability::Nullable),
+            ],
+        );
+        let mut builder = StructBuilder::new(dtype.clone(), Nullability::NonNullable).unwrap();
+        for (id, name) in [(1, Some("a")), (2, None), (3, Some("c"))] {
+            builder
+                .field::<PrimitiveBuilder<u32>>(0)
+                .unwrap()
+                .push_value(id);
+            builder
+                .field::<VarBinBuilder<i32>>(1)
+                .unwrap()
+                .push(name.map(str::as_bytes));
+            builder.push_value();
+        }
+        assert!(builder.field::<PrimitiveBuilder<u64>>(0).is_none());
+
+        let array = builder.finish().unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(
+            array.dtype(),
+            &DType::Struct(dtype, Nullability::NonNullable)
+        );
+        assert_eq!(
+            array
+                .field(0)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u32>(),
+            &[1, 2, 3]
+        );
+        let names = array.field(1).unwrap();
+        assert_eq!(
+            scalar_at(&names, 0).unwrap(),
+            Scalar::utf8("a".to_string(), Nullability::Nullable)
+        );
+        assert!(scalar_at(&names, 1).unwrap().is_null());
+    }
+
+    #[test]
+    fn incomplete_row() {
+        let dtype = StructDType::new(
+            ["a".into(), "b".into()].into(),
+            vec![
+                DType::Primitive(PType::I32, Nullability::NonNullable),
+                DType::Primitive(PType::I32, Nullability::NonNullable),
+            ],
+        );
+        let mut builder = StructBuilder::new(dtype, Nullability::NonNullable).unwrap();
+        builder
+            .field::<PrimitiveBuilder<i32>>(0)
+            .unwrap()
+            .push_value(1);
+        builder.push_value();
+        assert!(builder.finish().is_err());
+    }
+
+    #[test]
+    fn unsupported_field() {
+        let dtype = StructDType::new(
+            ["b".into()].into(),
+            vec![DType::Bool(Nullability::NonNullable)],
+        );
+        assert!(StructBuilder::new(dtype, Nullability::NonNullable).is_err());
+    }
+}
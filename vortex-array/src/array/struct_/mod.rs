@@ -12,7 +12,12 @@ use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata
 use crate::variants::{ArrayVariants, StructArrayTrait};
 use crate::{impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArray, IntoCanonical};
 
+mod builder;
 mod compute;
+mod rows;
+
+pub use builder::{ArrayBuilder, StructBuilder};
+pub use rows::Row;
 
 impl_encoding!("vortex.struct", ids::STRUCT, Struct);
 
@@ -56,10 +61,10 @@ impl StructArray {
             vortex_bail!("Got {} names and {} fields", names.len(), fields.len());
         }
 
-        for field in fields.iter() {
+        for (name, field) in names.iter().zip(fields.iter()) {
             if field.len() != length {
                 vortex_bail!(
-                    "Expected all struct fields to have length {length}, found {}",
+                    "Expected all struct fields to have length {length}, field {name} has length {}",
                     field.len()
                 );
             }
@@ -86,6 +91,9 @@ impl StructArray {
         )
     }
 
+    /// Non-nullable struct of the named fields, whose dtypes are those of the arrays.
+    ///
+    /// Fails if there are no fields or if they don't all have the same length.
     pub fn from_fields<N: AsRef<str>>(items: &[(N, Array)]) -> VortexResult<Self> {
         let names: Vec<FieldName> = items
             .iter()
@@ -194,7 +202,7 @@ impl ArrayStatisticsCompute for StructArray {}
 #[cfg(test)]
 mod test {
     use vortex_dtype::field::Field;
-    use vortex_dtype::{DType, FieldName, FieldNames, Nullability};
+    use vortex_dtype::{DType, FieldName, FieldNames, Nullability, PType};
 
     use crate::array::primitive::PrimitiveArray;
     use crate::array::struct_::StructArray;
@@ -204,6 +212,26 @@ mod test {
     use crate::variants::StructArrayTrait;
     use crate::IntoArray;
 
+    #[test]
+    fn from_fields() {
+        let xs = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None]).into_array();
+        let ys = VarBinArray::from(vec!["a", "b"]).into_array();
+        let st = StructArray::from_fields(&[("xs", xs.clone()), ("ys", ys)]).unwrap();
+        assert_eq!(st.len(), 2);
+        assert_eq!(
+            st.dtypes(),
+            &[
+                DType::Primitive(PType::I32, Nullability::Nullable),
+                DType::Utf8(Nullability::NonNullable)
+            ]
+        );
+
+        let ragged = PrimitiveArray::from(vec![1u8, 2, 3]).into_array();
+        let err = StructArray::from_fields(&[("xs", xs), ("ragged", ragged)]).unwrap_err();
+        assert!(err.to_string().contains("field ragged has length 3"));
+        assert!(StructArray::from_fields::<&str>(&[]).is_err());
+    }
+
     #[test]
     fn test_project() {
         let xs = PrimitiveArray::from_vec(vec![0i64, 1, 2, 3, 4], Validity::NonNullable);
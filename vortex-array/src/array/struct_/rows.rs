@@ -0,0 +1,104 @@
+use vortex_error::{vortex_err, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::StructArray;
+use crate::compute::unary::scalar_at;
+use crate::variants::StructArrayTrait;
+
+impl StructArray {
+    /// Iterate the array one row at a time, e.g. to feed a row based API.
+    ///
+    /// Values are only read when they're accessed, one [`scalar_at`] per value, so this is a lot
+    /// slower than working with the columns directly.
+    pub fn rows(&self) -> impl Iterator<Item = Row<'_>> {
+        (0..self.len()).map(move |index| Row { array: self, index })
+    }
+}
+
+/// A row of a [`StructArray`], see [`StructArray::rows`]
+#[derive(Debug, Clone, Copy)]
+pub struct Row<'a> {
+    array: &'a StructArray,
+    index: usize,
+}
+
+impl<'a> Row<'a> {
+    /// Position of the row in the array
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The value of column `col`
+    pub fn scalar(&self, col: usize) -> VortexResult<Scalar> {
+        let field = self
+            .array
+            .field(col)
+            .ok_or_else(|| vortex_err!(OutOfBounds: col, 0, self.array.nfields()))?;
+        scalar_at(field, self.index)
+    }
+
+    /// The value of column `col` converted to `T`, failing if it's null or of another type
+    pub fn get<T>(&self, col: usize) -> VortexResult<T>
+    where
+        T: for<'s> TryFrom<&'s Scalar, Error = VortexError>,
+    {
+        T::try_from(&self.scalar(col)?)
+    }
+
+    /// The value of the column called `name` converted to `T`, see [`Row::get`]
+    pub fn get_by_name<T>(&self, name: &str) -> VortexResult<T>
+    where
+        T: for<'s> TryFrom<&'s Scalar, Error = VortexError>,
+    {
+        let col = self
+            .array
+            .names()
+            .iter()
+            .position(|n| n.as_ref() == name)
+            .ok_or_else(|| vortex_err!("Unknown column {name}"))?;
+        self.get(col)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{PrimitiveArray, StructArray, VarBinArray};
+    use crate::IntoArray;
+
+    #[test]
+    fn iterate_rows() {
+        let array = StructArray::from_fields(&[
+            ("id", PrimitiveArray::from(vec![1u32, 2, 3]).into_array()),
+            ("name", VarBinArray::from(vec!["a", "b", "c"]).into_array()),
+            (
+                "score",
+                PrimitiveArray::from_nullable_vec(vec![Some(0.5f64), None, Some(1.5)]).into_array(),
+            ),
+        ])
+        .unwrap();
+
+        let rows = array
+            .rows()
+            .map(|row| {
+                (
+                    row.get::<u32>(0).unwrap(),
+                    row.get_by_name::<String>("name").unwrap(),
+                    row.scalar(2).unwrap().is_null(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                (1, "a".to_string(), false),
+                (2, "b".to_string(), true),
+                (3, "c".to_string(), false),
+            ]
+        );
+
+        let row = array.rows().nth(1).unwrap();
+        assert!(row.get::<f64>(2).is_err());
+        assert!(row.get::<u32>(3).is_err());
+        assert!(row.get_by_name::<u32>("missing").is_err());
+    }
+}
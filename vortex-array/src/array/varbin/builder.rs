@@ -1,13 +1,16 @@
+use std::any::Any;
+
 use arrow_buffer::NullBufferBuilder;
 use bytes::BytesMut;
 use num_traits::AsPrimitive;
 use vortex_dtype::{DType, NativePType};
-use vortex_error::{vortex_panic, VortexExpect as _};
+use vortex_error::{vortex_bail, vortex_panic, VortexExpect as _, VortexResult};
 
 use crate::array::primitive::PrimitiveArray;
 use crate::array::varbin::VarBinArray;
+use crate::array::ArrayBuilder;
 use crate::validity::Validity;
-use crate::IntoArray;
+use crate::{Array, IntoArray};
 
 pub struct VarBinBuilder<O: NativePType> {
     offsets: Vec<O>,
@@ -27,15 +30,28 @@ impl<O: NativePType> VarBinBuilder<O> {
     }
 
     pub fn with_capacity(len: usize) -> Self {
+        Self::with_capacities(len, 0)
+    }
+
+    /// Builder with room for `len` values holding `data_len` bytes in total
+    pub fn with_capacities(len: usize, data_len: usize) -> Self {
         let mut offsets = Vec::with_capacity(len + 1);
         offsets.push(O::zero());
         Self {
             offsets,
-            data: BytesMut::new(),
+            data: BytesMut::with_capacity(data_len),
             validity: NullBufferBuilder::new(len),
         }
     }
 
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     #[inline]
     pub fn push(&mut self, value: Option<&[u8]>) {
         match value {
@@ -61,6 +77,7 @@ impl<O: NativePType> VarBinBuilder<O> {
     }
 
     #[inline]
+    #[allow(clippy::same_name_method)]
     pub fn push_null(&mut self) {
         self.offsets.push(self.offsets[self.offsets.len() - 1]);
         self.validity.append_null();
@@ -79,7 +96,7 @@ impl<O: NativePType> VarBinBuilder<O> {
     }
 
     pub fn finish(mut self, dtype: DType) -> VarBinArray {
-        let offsets = PrimitiveArray::from(self.offsets);
+        let offsets = PrimitiveArray::from_vec(self.offsets, Validity::NonNullable);
         let data = PrimitiveArray::from_bytes(self.data.freeze(), Validity::NonNullable);
         let nulls = self.validity.finish();
 
@@ -95,6 +112,23 @@ impl<O: NativePType> VarBinBuilder<O> {
     }
 }
 
+impl<O: NativePType + 'static> ArrayBuilder for VarBinBuilder<O> {
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn finish_array(self: Box<Self>, dtype: &DType) -> VortexResult<Array> {
+        if !dtype.is_nullable() && self.validity.as_slice().is_some() {
+            vortex_bail!("Pushed a null to a builder of non-nullable {dtype}");
+        }
+        Ok(self.finish(dtype.clone()).into_array())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
 #[cfg(test)]
 mod test {
     use vortex_dtype::DType;
@@ -15,3 +15,33 @@ impl SliceFn for VarBinArray {
         .map(|a| a.into_array())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::VarBinArray;
+    use crate::compute::slice;
+    use crate::compute::unary::scalar_at;
+    use crate::IntoArrayVariant;
+
+    #[test]
+    fn slice_shares_bytes() {
+        let array = VarBinArray::from_iter(
+            [Some("hello"), None, Some("vortex"), Some("world")],
+            DType::Utf8(Nullability::Nullable),
+        );
+        let sliced = VarBinArray::try_from(slice(array.as_ref(), 1, 3).unwrap()).unwrap();
+        assert_eq!(sliced.len(), 2);
+
+        let bytes = array.bytes().into_primitive().unwrap();
+        let sliced_bytes = sliced.bytes().into_primitive().unwrap();
+        assert_eq!(sliced_bytes.buffer().as_ptr(), bytes.buffer().as_ptr());
+
+        assert!(scalar_at(sliced.as_ref(), 0).unwrap().is_null());
+        assert_eq!(
+            scalar_at(sliced.as_ref(), 1).unwrap(),
+            scalar_at(array.as_ref(), 2).unwrap()
+        );
+    }
+}
@@ -39,7 +39,8 @@ fn take<I: NativePType, O: NativePType>(
         return Ok(take_nullable(dtype, offsets, data, indices, v));
     }
 
-    let mut builder = VarBinBuilder::<O>::with_capacity(indices.len());
+    let mut builder =
+        VarBinBuilder::<O>::with_capacities(indices.len(), taken_bytes(offsets, indices, None)?);
     for &idx in indices {
         let idx = idx
             .to_usize()
@@ -62,7 +63,9 @@ fn take_nullable<I: NativePType, O: NativePType>(
     indices: &[I],
     null_buffer: NullBuffer,
 ) -> VarBinArray {
-    let mut builder = VarBinBuilder::<O>::with_capacity(indices.len());
+    let data_len = taken_bytes(offsets, indices, Some(&null_buffer))
+        .unwrap_or_else(|err| vortex_panic!(err, "Failed to compute size of taken data"));
+    let mut builder = VarBinBuilder::<O>::with_capacities(indices.len(), data_len);
     for &idx in indices {
         let idx = idx
             .to_usize()
@@ -81,3 +84,64 @@ fn take_nullable<I: NativePType, O: NativePType>(
     }
     builder.finish(dtype)
 }
+
+/// Total number of bytes of the valid values at `indices`, so only they are copied, into a buffer
+/// allocated once.
+fn taken_bytes<I: NativePType, O: NativePType>(
+    offsets: &[O],
+    indices: &[I],
+    null_buffer: Option<&NullBuffer>,
+) -> VortexResult<usize> {
+    let mut data_len = 0;
+    for &idx in indices {
+        let idx = idx
+            .to_usize()
+            .ok_or_else(|| vortex_err!("Failed to convert index to usize: {}", idx))?;
+        if null_buffer.is_some_and(|n| n.is_null(idx)) {
+            continue;
+        }
+        let start = offsets[idx]
+            .to_usize()
+            .ok_or_else(|| vortex_err!("Failed to convert offset to usize: {}", offsets[idx]))?;
+        let stop = offsets[idx + 1].to_usize().ok_or_else(|| {
+            vortex_err!("Failed to convert offset to usize: {}", offsets[idx + 1])
+        })?;
+        data_len += stop - start;
+    }
+    Ok(data_len)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::accessor::ArrayAccessor;
+    use crate::array::VarBinArray;
+    use crate::compute::take;
+    use crate::IntoArray;
+
+    #[test]
+    fn take_nullable() {
+        let array = VarBinArray::from_iter(
+            [Some("hello"), None, Some("vortex"), Some("")],
+            DType::Utf8(Nullability::Nullable),
+        );
+        let taken =
+            VarBinArray::try_from(take(array.as_ref(), vec![2u32, 1, 0, 2].into_array()).unwrap())
+                .unwrap();
+        assert_eq!(taken.bytes().len(), 17);
+        assert_eq!(
+            taken
+                .with_iterator(|iter| iter
+                    .map(|v| v.map(|b| String::from_utf8(b.to_vec()).unwrap()))
+                    .collect::<Vec<_>>())
+                .unwrap(),
+            vec![
+                Some("vortex".to_string()),
+                None,
+                Some("hello".to_string()),
+                Some("vortex".to_string())
+            ]
+        );
+    }
+}
@@ -2,6 +2,7 @@ use std::fmt::{Debug, Display};
 
 use num_traits::AsPrimitive;
 use serde::{Deserialize, Serialize};
+pub(crate) use stats::approx_distinct;
 pub use stats::compute_stats;
 use vortex_buffer::Buffer;
 use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability, PType};
@@ -219,7 +220,19 @@ impl VarBinArray {
     }
 }
 
-impl ArrayTrait for VarBinArray {}
+impl ArrayTrait for VarBinArray {
+    fn validate(&self) -> VortexResult<()> {
+        let first = self.offset_at(0);
+        let last = self.offset_at(self.len());
+        if first > last || last > self.bytes().len() {
+            vortex_bail!(
+                "Offsets [{first}, {last}] out of bounds of {} bytes",
+                self.bytes().len()
+            );
+        }
+        Ok(())
+    }
+}
 
 impl From<Vec<&[u8]>> for VarBinArray {
     fn from(value: Vec<&[u8]>) -> Self {
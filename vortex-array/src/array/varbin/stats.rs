@@ -7,11 +7,14 @@ use vortex_error::VortexResult;
 
 use crate::accessor::ArrayAccessor;
 use crate::array::varbin::{varbin_scalar, VarBinArray};
-use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
+use crate::stats::{ArrayStatisticsCompute, HyperLogLog, Stat, StatsSet};
 use crate::ArrayDType;
 
 impl ArrayStatisticsCompute for VarBinArray {
-    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        if stat == Stat::ApproxDistinct {
+            return self.with_iterator(approx_distinct);
+        }
         if self.is_empty() {
             return Ok(StatsSet::new());
         }
@@ -19,6 +22,12 @@ impl ArrayStatisticsCompute for VarBinArray {
     }
 }
 
+pub(crate) fn approx_distinct(iter: &mut dyn Iterator<Item = Option<&[u8]>>) -> StatsSet {
+    let mut hll = HyperLogLog::new();
+    iter.flatten().for_each(|v| hll.insert_bytes(v));
+    StatsSet::of(Stat::ApproxDistinct, hll.to_scalar())
+}
+
 pub fn compute_stats(iter: &mut dyn Iterator<Item = Option<&[u8]>>, dtype: &DType) -> StatsSet {
     let mut leading_nulls: usize = 0;
     let mut first_value: Option<&[u8]> = None;
@@ -1,13 +1,16 @@
 use vortex_error::VortexResult;
 
 use crate::accessor::ArrayAccessor;
-use crate::array::varbin::compute_stats;
+use crate::array::varbin::{approx_distinct, compute_stats};
 use crate::array::varbinview::VarBinViewArray;
 use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
 use crate::ArrayDType;
 
 impl ArrayStatisticsCompute for VarBinViewArray {
-    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        if stat == Stat::ApproxDistinct {
+            return self.with_iterator(approx_distinct);
+        }
         if self.is_empty() {
             return Ok(StatsSet::new());
         }
@@ -28,3 +28,52 @@ pub trait ArrayVisitor {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::array::{BoolArray, PrimitiveArray, SparseArray, StructArray};
+    use crate::validity::Validity;
+    use crate::IntoArray;
+
+    #[test]
+    fn named_children_and_fold() {
+        let sparse = SparseArray::try_new(
+            PrimitiveArray::from(vec![1u64]).into_array(),
+            PrimitiveArray::from(vec![5i32]).into_array(),
+            3,
+            0i32.into(),
+        )
+        .unwrap()
+        .into_array();
+        let bools = BoolArray::from_vec(
+            vec![true, false, true],
+            Validity::Array(BoolArray::from(vec![true, true, false]).into_array()),
+        )
+        .into_array();
+        let st = StructArray::from_fields(&[("a", sparse), ("b", bools)])
+            .unwrap()
+            .into_array();
+
+        let names = |array: &crate::Array| {
+            array
+                .named_children()
+                .unwrap()
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&st), vec!["\"a\"", "\"b\""]);
+        let children = st.children();
+        assert_eq!(names(&children[0]), vec!["indices", "values"]);
+        assert_eq!(names(&children[1]), vec!["validity"]);
+
+        let narrays = st.fold(0, |count, _| Ok(count + 1)).unwrap();
+        assert_eq!(narrays, 6);
+        let buffer_bytes = st
+            .fold(0, |bytes, array| {
+                Ok(bytes + array.buffer().map(|b| b.len()).unwrap_or_default())
+            })
+            .unwrap();
+        assert_eq!(buffer_bytes, st.nbytes());
+    }
+}
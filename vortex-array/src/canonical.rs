@@ -1,5 +1,6 @@
 //! Encodings that enable zero-copy sharing of data with Arrow.
 
+use std::fmt::{self, Display, Formatter};
 use std::sync::Arc;
 
 use arrow_array::types::{
@@ -14,19 +15,21 @@ use arrow_array::{
     TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray,
 };
 use arrow_buffer::ScalarBuffer;
-use arrow_schema::{Field, Fields};
+use arrow_cast::{can_cast_types, cast};
+use arrow_schema::{DataType, Field, Fields};
 use vortex_datetime_dtype::{is_temporal_ext_type, TemporalMetadata, TimeUnit};
-use vortex_dtype::{DType, NativePType, PType};
+use vortex_dtype::{DType, NativePType, Nullability, PType};
 use vortex_error::{vortex_bail, VortexResult};
 
 use crate::array::{
-    varbinview_as_arrow, BoolArray, ExtensionArray, NullArray, PrimitiveArray, StructArray,
-    TemporalArray, VarBinViewArray,
+    varbinview_as_arrow, BoolArray, BoolRepresentation, ExtensionArray, NullArray, PrimitiveArray,
+    StructArray, TemporalArray, VarBinViewArray,
 };
+use crate::arrow::FromArrowArray;
 use crate::compute::unary::try_cast;
 use crate::encoding::ArrayEncoding;
-use crate::validity::ArrayValidity;
-use crate::variants::StructArrayTrait;
+use crate::validity::{ArrayValidity, Validity};
+use crate::variants::{ExtensionArrayTrait, StructArrayTrait};
 use crate::{Array, ArrayDType, IntoArray};
 
 /// The set of canonical array encodings, also the set of encodings that can be transferred to
@@ -52,6 +55,11 @@ use crate::{Array, ArrayDType, IntoArray};
 /// fully supported by the Datafusion query engine. We use them as our canonical string encoding
 /// for all `Utf8` and `Binary` typed arrays in Vortex.
 ///
+/// # Bool representation
+///
+/// A canonical [`BoolArray`] holds packed bits unless it already stores a byte per value, see
+/// [`BoolRepresentation`]. Use [`IntoCanonical::into_canonical_with`] to request a byte per value,
+/// which encodings holding bytes then decode to without packing them.
 #[derive(Debug, Clone)]
 pub enum Canonical {
     Null(NullArray),
@@ -296,6 +304,15 @@ fn temporal_to_arrow(temporal_array: TemporalArray) -> VortexResult<ArrayRef> {
 /// The DType of the array will be unchanged by canonicalization.
 pub trait IntoCanonical {
     fn into_canonical(self) -> VortexResult<Canonical>;
+
+    /// Canonicalize in the representation chosen by `options`. Encodings that can decode to more
+    /// than one representation override this to decode straight to the requested one.
+    fn into_canonical_with(self, options: CanonicalOptions) -> VortexResult<Canonical>
+    where
+        Self: Sized,
+    {
+        self.into_canonical()?.with_options(options)
+    }
 }
 
 /// Trait for types that can be converted from an owned type into an owned array variant.
@@ -354,6 +371,165 @@ impl IntoCanonical for Array {
     fn into_canonical(self) -> VortexResult<Canonical> {
         ArrayEncoding::canonicalize(self.encoding(), self)
     }
+
+    fn into_canonical_with(self, options: CanonicalOptions) -> VortexResult<Canonical> {
+        ArrayEncoding::canonicalize_with(self.encoding(), self, options)
+    }
+}
+
+/// Physical form an array can be normalized to with [`Array::canonicalize_to`], the
+/// [`Canonical`] variants plus the alternative forms of some of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CanonicalKind {
+    Null,
+    Bool(BoolRepresentation),
+    Primitive,
+    Struct,
+    /// Offsets and bytes, see [`VarBinArray`](crate::array::VarBinArray)
+    VarBin,
+    VarBinView,
+    Extension,
+}
+
+impl Display for CanonicalKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CanonicalKind::Null => write!(f, "null"),
+            CanonicalKind::Bool(BoolRepresentation::Bits) => write!(f, "bool bits"),
+            CanonicalKind::Bool(BoolRepresentation::Bytes) => write!(f, "bool bytes"),
+            CanonicalKind::Primitive => write!(f, "primitive"),
+            CanonicalKind::Struct => write!(f, "struct"),
+            CanonicalKind::VarBin => write!(f, "varbin"),
+            CanonicalKind::VarBinView => write!(f, "varbinview"),
+            CanonicalKind::Extension => write!(f, "extension"),
+        }
+    }
+}
+
+/// Options for [`Array::into_canonical_with`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CanonicalOptions {
+    pub bool_representation: BoolRepresentation,
+}
+
+impl CanonicalOptions {
+    pub fn with_bool_representation(mut self, representation: BoolRepresentation) -> Self {
+        self.bool_representation = representation;
+        self
+    }
+}
+
+impl Canonical {
+    /// Convert to the representation chosen by `options`, without copying if it already is
+    pub fn with_options(self, options: CanonicalOptions) -> VortexResult<Self> {
+        Ok(match self {
+            Self::Bool(a) => Self::Bool(a.into_representation(options.bool_representation)?),
+            canonical => canonical,
+        })
+    }
+}
+
+impl Array {
+    /// Decode the array to the physical form `target`, whatever its current encoding, e.g. to
+    /// normalize arrays before compressing them or to test against a known layout.
+    ///
+    /// Fails if `target` can't hold arrays of the array's dtype.
+    pub fn canonicalize_to(&self, target: CanonicalKind) -> VortexResult<Array> {
+        let canonical = self.clone().into_canonical()?;
+        Ok(match (canonical, target) {
+            (Canonical::Null(a), CanonicalKind::Null) => a.into_array(),
+            (Canonical::Bool(a), CanonicalKind::Bool(representation)) => {
+                a.into_representation(representation)?.into_array()
+            }
+            (Canonical::Primitive(a), CanonicalKind::Primitive) => a.into_array(),
+            (Canonical::Struct(a), CanonicalKind::Struct) => a.into_array(),
+            (Canonical::VarBinView(a), CanonicalKind::VarBinView) => a.into_array(),
+            (Canonical::VarBinView(a), CanonicalKind::VarBin) => {
+                let target = match self.dtype() {
+                    DType::Utf8(_) => DataType::Utf8,
+                    _ => DataType::Binary,
+                };
+                let arrow = cast(&varbinview_as_arrow(&a), &target)?;
+                Array::from_arrow(arrow, self.dtype().is_nullable())
+            }
+            (Canonical::Extension(a), CanonicalKind::Extension) => a.into_array(),
+            _ => vortex_bail!(
+                "Array of type {} can't be canonicalized to {target}",
+                self.dtype()
+            ),
+        })
+    }
+
+    /// Convert the array to Arrow as the given `target` type instead of its canonical Arrow type,
+    /// e.g. to export an integer column as `Date32`.
+    ///
+    /// The array is canonicalized and then cast with [`arrow_cast::cast`], values that can't be
+    /// represented in the target type become null.
+    pub fn to_arrow_with(&self, target: &DataType) -> VortexResult<ArrayRef> {
+        let arrow = self.clone().into_canonical()?.into_arrow()?;
+        if arrow.data_type() == target {
+            return Ok(arrow);
+        }
+        if !can_cast_types(arrow.data_type(), target) {
+            vortex_bail!(
+                "Cannot convert array of type {} to Arrow {target}, it can't be cast from {}",
+                self.dtype(),
+                arrow.data_type()
+            )
+        }
+        Ok(cast(&arrow, target)?)
+    }
+
+    /// Convert to the nullable variant of the array's dtype, with all values of a non-nullable
+    /// array valid. Used to give arrays the same nullability before combining them.
+    pub fn into_nullable(self) -> VortexResult<Array> {
+        with_nullability(self, Nullability::Nullable)
+    }
+
+    /// Convert to the non-nullable variant of the array's dtype, failing if any value is null.
+    pub fn into_nonnullable(self) -> VortexResult<Array> {
+        with_nullability(self, Nullability::NonNullable)
+    }
+}
+
+fn with_nullability(array: Array, nullability: Nullability) -> VortexResult<Array> {
+    if array.dtype().nullability() == nullability {
+        return Ok(array);
+    }
+
+    let len = array.len();
+    let validity = |v: Validity| match nullability {
+        Nullability::Nullable => Ok(v.into_nullable()),
+        Nullability::NonNullable => v.into_non_nullable(len),
+    };
+    Ok(match array.into_canonical()? {
+        Canonical::Null(_) => vortex_bail!("Null arrays can only be nullable"),
+        Canonical::Bool(a) => {
+            BoolArray::try_new(a.boolean_buffer(), validity(a.validity())?)?.into_array()
+        }
+        Canonical::Primitive(a) => {
+            PrimitiveArray::new(a.buffer().clone(), a.ptype(), validity(a.validity())?).into_array()
+        }
+        Canonical::Struct(a) => StructArray::try_new(
+            a.names().clone(),
+            a.children().collect(),
+            len,
+            validity(a.validity())?,
+        )?
+        .into_array(),
+        Canonical::VarBinView(a) => VarBinViewArray::try_new(
+            a.views(),
+            a.buffers().collect(),
+            a.dtype().with_nullability(nullability),
+            validity(a.validity())?,
+        )?
+        .into_array(),
+        Canonical::Extension(a) => ExtensionArray::new(
+            a.ext_dtype().clone(),
+            with_nullability(a.storage(), nullability)?,
+        )
+        .into_array(),
+    })
 }
 
 /// This conversion is always "free" and should not touch underlying data. All it does is create an
@@ -379,18 +555,21 @@ mod test {
     use std::sync::Arc;
 
     use arrow_array::cast::AsArray;
-    use arrow_array::types::{Int32Type, Int64Type, UInt64Type};
+    use arrow_array::types::{Date32Type, Int32Type, Int64Type, UInt64Type};
     use arrow_array::{
         Array, PrimitiveArray as ArrowPrimitiveArray, StringViewArray,
         StructArray as ArrowStructArray,
     };
     use arrow_buffer::NullBufferBuilder;
     use arrow_schema::{DataType, Field};
+    use vortex_dtype::{DType, Nullability};
 
-    use crate::array::{PrimitiveArray, SparseArray, StructArray};
+    use crate::array::{
+        Primitive, PrimitiveArray, SparseArray, StructArray, VarBinArray, VarBinViewArray,
+    };
     use crate::arrow::FromArrowArray;
-    use crate::validity::Validity;
-    use crate::{IntoArray, IntoCanonical};
+    use crate::validity::{ArrayValidity, Validity};
+    use crate::{ArrayDType, ArrayDef, CanonicalKind, IntoArray, IntoArrayVariant, IntoCanonical};
 
     #[test]
     fn test_canonicalize_nested_struct() {
@@ -505,4 +684,69 @@ mod test {
                 .as_struct()
         );
     }
+
+    #[test]
+    fn test_to_arrow_with() {
+        let days = PrimitiveArray::from(vec![0i32, 19000]).into_array();
+        let dates = days.to_arrow_with(&DataType::Date32).unwrap();
+        assert_eq!(dates.data_type(), &DataType::Date32);
+        assert_eq!(dates.as_primitive::<Date32Type>().values(), &[0, 19000]);
+
+        let wide = PrimitiveArray::from(vec![1u8, 2, 3])
+            .into_array()
+            .to_arrow_with(&DataType::Int64)
+            .unwrap();
+        assert_eq!(wide.as_primitive::<Int64Type>().values(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_to_arrow_with_incompatible() {
+        let st = StructArray::from_fields(&[("a", PrimitiveArray::from(vec![1i32]).into_array())])
+            .unwrap()
+            .into_array();
+        assert!(st.to_arrow_with(&DataType::Int32).is_err());
+    }
+
+    #[test]
+    fn test_nullability_coercion() {
+        let array = PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        let nullable = array.clone().into_nullable().unwrap();
+        assert!(nullable.dtype().is_nullable());
+        assert!(nullable.with_dyn(|a| a.logical_validity().all_valid()));
+        assert_eq!(nullable.into_nonnullable().unwrap().dtype(), array.dtype());
+
+        let names = VarBinViewArray::from_iter_str(["a", "b"]).into_array();
+        let names = names.into_nullable().unwrap();
+        assert_eq!(names.dtype(), &DType::Utf8(Nullability::Nullable));
+
+        let with_nulls = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None]).into_array();
+        assert!(with_nulls.into_nonnullable().is_err());
+    }
+
+    #[test]
+    fn canonicalize_to_kind() {
+        let sparse = SparseArray::try_new(
+            PrimitiveArray::from(vec![1u64, 3]).into_array(),
+            PrimitiveArray::from(vec![10i32, 30]).into_array(),
+            4,
+            0i32.into(),
+        )
+        .unwrap()
+        .into_array();
+        let dense = sparse.canonicalize_to(CanonicalKind::Primitive).unwrap();
+        assert_eq!(dense.encoding().id(), Primitive::ID);
+        assert_eq!(
+            dense.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[0, 10, 0, 30]
+        );
+        assert!(sparse.canonicalize_to(CanonicalKind::Struct).is_err());
+
+        let views =
+            VarBinViewArray::from_iter_nullable_str([Some("a"), None, Some("ccc")]).into_array();
+        let varbin =
+            VarBinArray::try_from(views.canonicalize_to(CanonicalKind::VarBin).unwrap()).unwrap();
+        assert_eq!(varbin.dtype(), views.dtype());
+        assert_eq!(varbin.bytes_at(2).unwrap().as_slice(), b"ccc");
+        assert!(!varbin.is_valid(1));
+    }
 }
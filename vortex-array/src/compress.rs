@@ -1,13 +1,20 @@
 use std::collections::HashSet;
 
-use vortex_error::VortexResult;
+use vortex_error::{vortex_bail, VortexResult};
 
-use crate::encoding::EncodingRef;
+use crate::encoding::{EncodingId, EncodingRef};
 use crate::Array;
 
 pub trait CompressionStrategy {
     fn compress(&self, array: &Array) -> VortexResult<Array>;
 
+    /// Compress `array` into the given encoding instead of choosing one, failing if the encoding
+    /// can't represent the array.
+    fn compress_with_encoding(&self, array: &Array, encoding: EncodingId) -> VortexResult<Array> {
+        let _ = array;
+        vortex_bail!("Compression strategy can't force the {encoding} encoding")
+    }
+
     fn used_encodings(&self) -> HashSet<EncodingRef>;
 }
 
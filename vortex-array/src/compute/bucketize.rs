@@ -0,0 +1,125 @@
+use vortex_dtype::{match_each_native_ptype, DType};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::PrimitiveArray;
+use crate::compute::unary::scalar_at;
+use crate::compute::{SearchSorted, SearchSortedSide};
+use crate::validity::Validity;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+/// Bucket of every element of the array, as a `u64` array.
+///
+/// `boundaries` must be sorted ascending and split the values into `boundaries.len() + 1` buckets,
+/// bucket `i` holds the values `v` with `boundaries[i - 1] <= v < boundaries[i]`. Null elements have
+/// a null bucket.
+pub fn bucketize(array: impl AsRef<Array>, boundaries: &[Scalar]) -> VortexResult<Array> {
+    let array = array.as_ref();
+    let buckets = buckets(array, boundaries)?;
+    Ok(if array.dtype().is_nullable() {
+        PrimitiveArray::from_nullable_vec(buckets)
+    } else {
+        PrimitiveArray::from_vec(
+            buckets.into_iter().map(Option::unwrap_or_default).collect(),
+            Validity::NonNullable,
+        )
+    }
+    .into_array())
+}
+
+/// Number of non-null elements of the array in each of the buckets of [`bucketize`].
+pub fn bucket_counts(array: impl AsRef<Array>, boundaries: &[Scalar]) -> VortexResult<Vec<u64>> {
+    let mut counts = vec![0u64; boundaries.len() + 1];
+    for bucket in buckets(array.as_ref(), boundaries)?.into_iter().flatten() {
+        counts[bucket as usize] += 1;
+    }
+    Ok(counts)
+}
+
+fn buckets(array: &Array, boundaries: &[Scalar]) -> VortexResult<Vec<Option<u64>>> {
+    let dtype = array.dtype().as_nonnullable();
+    let boundaries = boundaries
+        .iter()
+        .map(|b| b.cast(&dtype))
+        .collect::<VortexResult<Vec<_>>>()?;
+    if !boundaries.windows(2).all(|w| w[0] <= w[1]) {
+        vortex_bail!("Bucket boundaries must be sorted ascending");
+    }
+
+    if let DType::Primitive(ptype, _) = dtype {
+        let primitive = array.clone().into_primitive()?;
+        return match_each_native_ptype!(ptype, |$T| {
+            let boundaries = boundaries
+                .iter()
+                .map($T::try_from)
+                .collect::<VortexResult<Vec<_>>>()?;
+            Ok(primitive
+                .iter::<$T>()
+                .map(|v| v.map(|v| bucket(&boundaries, &v)))
+                .collect())
+        });
+    }
+
+    (0..array.len())
+        .map(|idx| {
+            let value = scalar_at(array, idx)?;
+            Ok((!value.is_null()).then(|| bucket(&boundaries, &value)))
+        })
+        .collect()
+}
+
+fn bucket<T: PartialOrd>(boundaries: &[T], value: &T) -> u64 {
+    boundaries
+        .search_sorted(value, SearchSortedSide::Right)
+        .to_index() as u64
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::array::{PrimitiveArray, VarBinArray};
+    use crate::compute::{bucket_counts, bucketize};
+    use crate::{ArrayDType, IntoArrayVariant};
+
+    #[test]
+    fn bucketize_primitive() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), Some(5), None, Some(10), Some(42)]);
+        let boundaries = [Scalar::from(5i32), Scalar::from(10i32)];
+
+        let buckets = bucketize(&array, &boundaries).unwrap();
+        assert!(buckets.dtype().is_nullable());
+        assert_eq!(
+            buckets
+                .into_primitive()
+                .unwrap()
+                .iter::<u64>()
+                .collect::<Vec<_>>(),
+            vec![Some(0), Some(1), None, Some(2), Some(2)]
+        );
+        assert_eq!(bucket_counts(&array, &boundaries).unwrap(), vec![1, 1, 2]);
+
+        // Boundaries are cast to the type of the array
+        assert_eq!(
+            bucket_counts(&array, &[Scalar::from(2i64)]).unwrap(),
+            vec![1, 3]
+        );
+        assert!(bucketize(&array, &[Scalar::from(10i32), Scalar::from(5i32)]).is_err());
+    }
+
+    #[test]
+    fn bucketize_strings() {
+        let array = VarBinArray::from(vec!["apple", "kiwi", "pear", "banana"]);
+        let boundaries = [Scalar::from("b"), Scalar::from("m")];
+        assert_eq!(
+            bucketize(&array, &boundaries)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[0, 1, 2, 1]
+        );
+        assert_eq!(bucket_counts(&array, &[]).unwrap(), vec![4]);
+    }
+}
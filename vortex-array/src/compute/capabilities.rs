@@ -0,0 +1,116 @@
+use std::fmt::{Display, Formatter};
+
+use crate::compute::ArrayCompute;
+
+/// Which compute functions an encoding implements natively, see [`Array::supported_computes`].
+///
+/// Functions without a native implementation fall back to canonicalizing the array first, or
+/// aren't supported at all. Comparisons against other arrays are left out, whether the encoding
+/// can compare natively depends on the other array.
+///
+/// [`Array::supported_computes`]: crate::Array::supported_computes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ComputeCapabilities {
+    pub cast: bool,
+    pub cumsum: bool,
+    pub compare_scalar: bool,
+    pub fill_forward: bool,
+    pub filter: bool,
+    pub rolling: bool,
+    pub scalar_at: bool,
+    pub binary_scalar: bool,
+    pub search_sorted: bool,
+    pub slice: bool,
+    pub take: bool,
+    pub and: bool,
+    pub or: bool,
+}
+
+impl ComputeCapabilities {
+    pub fn of<C: ArrayCompute + ?Sized>(compute: &C) -> Self {
+        Self {
+            cast: compute.cast().is_some(),
+            cumsum: compute.cumsum().is_some(),
+            compare_scalar: compute.compare_scalar().is_some(),
+            fill_forward: compute.fill_forward().is_some(),
+            filter: compute.filter().is_some(),
+            rolling: compute.rolling().is_some(),
+            scalar_at: compute.scalar_at().is_some(),
+            binary_scalar: compute.binary_scalar().is_some(),
+            search_sorted: compute.search_sorted().is_some(),
+            slice: compute.slice().is_some(),
+            take: compute.take().is_some(),
+            and: compute.and().is_some(),
+            or: compute.or().is_some(),
+        }
+    }
+
+    fn functions(&self) -> [(&'static str, bool); 13] {
+        [
+            ("cast", self.cast),
+            ("cumsum", self.cumsum),
+            ("compare_scalar", self.compare_scalar),
+            ("fill_forward", self.fill_forward),
+            ("filter", self.filter),
+            ("rolling", self.rolling),
+            ("scalar_at", self.scalar_at),
+            ("binary_scalar", self.binary_scalar),
+            ("search_sorted", self.search_sorted),
+            ("slice", self.slice),
+            ("take", self.take),
+            ("and", self.and),
+            ("or", self.or),
+        ]
+    }
+
+    /// Names of the functions the encoding implements natively
+    pub fn supported(&self) -> impl Iterator<Item = &'static str> {
+        self.functions()
+            .into_iter()
+            .filter_map(|(name, supported)| supported.then_some(name))
+    }
+
+    /// Names of the functions the encoding doesn't implement natively
+    pub fn unsupported(&self) -> impl Iterator<Item = &'static str> {
+        self.functions()
+            .into_iter()
+            .filter_map(|(name, supported)| (!supported).then_some(name))
+    }
+}
+
+impl Display for ComputeCapabilities {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "native: [{}], fallback: [{}]",
+            self.supported().collect::<Vec<_>>().join(", "),
+            self.unsupported().collect::<Vec<_>>().join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{PrimitiveArray, StructArray};
+    use crate::IntoArray;
+
+    #[test]
+    fn primitive_and_struct_capabilities() {
+        let primitive = PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        let capabilities = primitive.supported_computes();
+        assert!(capabilities.take);
+        assert!(capabilities.search_sorted);
+        assert!(!capabilities.and);
+
+        let st = StructArray::from_fields(&[("numbers", primitive)])
+            .unwrap()
+            .into_array();
+        let capabilities = st.supported_computes();
+        assert!(capabilities.take && capabilities.filter);
+        assert!(!capabilities.search_sorted);
+        assert_eq!(
+            capabilities.supported().collect::<Vec<_>>(),
+            vec!["filter", "scalar_at", "slice", "take"]
+        );
+    }
+}
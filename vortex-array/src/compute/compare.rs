@@ -1,14 +1,18 @@
 use core::fmt;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 
+use arrow_array::Scalar as ArrowScalar;
+use arrow_buffer::BooleanBuffer;
 use arrow_ord::cmp;
-use vortex_dtype::{DType, Nullability};
+use vortex_dtype::{match_each_float_ptype, DType, Nullability};
 use vortex_error::{vortex_bail, VortexResult};
-use vortex_scalar::Scalar;
+use vortex_scalar::{Scalar, ScalarRef};
 
-use crate::array::Constant;
+use crate::array::{BoolArray, Constant, ConstantArray};
 use crate::arrow::FromArrowArray;
-use crate::{Array, ArrayDType, ArrayDef, IntoCanonical};
+use crate::compute::FloatOrdering;
+use crate::{Array, ArrayDType, ArrayDef, IntoArray, IntoArrayVariant, IntoCanonical};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd)]
 pub enum Operator {
@@ -58,6 +62,19 @@ impl Operator {
         }
     }
 
+    /// Whether a left hand side ordered `ordering` against the right hand side satisfies the
+    /// operator
+    pub fn matches(self, ordering: Ordering) -> bool {
+        match self {
+            Operator::Eq => ordering.is_eq(),
+            Operator::NotEq => ordering.is_ne(),
+            Operator::Gt => ordering.is_gt(),
+            Operator::Gte => ordering.is_ge(),
+            Operator::Lt => ordering.is_lt(),
+            Operator::Lte => ordering.is_le(),
+        }
+    }
+
     pub fn to_fn<T: PartialEq + PartialOrd>(&self) -> fn(T, T) -> bool {
         match self {
             Operator::Eq => |l, r| l == r,
@@ -74,6 +91,11 @@ pub trait CompareFn {
     fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array>;
 }
 
+/// Comparison of every element of an array against a single value, see [`compare_scalar`].
+pub trait CompareScalarFn {
+    fn compare_scalar(&self, scalar: &Scalar, operator: Operator) -> VortexResult<Array>;
+}
+
 pub trait MaybeCompareFn {
     fn maybe_compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>>;
 }
@@ -123,7 +145,105 @@ pub fn compare(
     Ok(Array::from_arrow(&array, true))
 }
 
-pub fn scalar_cmp(lhs: &Scalar, rhs: &Scalar, operator: Operator) -> Scalar {
+/// Like [`compare`], but floats are in a total order with NaNs placed by `ordering`.
+///
+/// Floats are decoded and compared as canonical arrays. Arrays of other types are compared by
+/// [`compare`].
+pub fn compare_with_ordering(
+    left: impl AsRef<Array>,
+    right: impl AsRef<Array>,
+    operator: Operator,
+    ordering: FloatOrdering,
+) -> VortexResult<Array> {
+    let left = left.as_ref();
+    let right = right.as_ref();
+    if !left.dtype().is_float() {
+        return compare(left, right, operator);
+    }
+    if left.len() != right.len() {
+        vortex_bail!("Compare operations only support arrays of the same length");
+    }
+    if !left.dtype().eq_ignore_nullability(right.dtype()) {
+        vortex_bail!("Compare operations only support arrays of the same type");
+    }
+
+    let lhs = left.clone().into_primitive()?;
+    let rhs = right.clone().into_primitive()?;
+    let buffer = match_each_float_ptype!(lhs.ptype(), |$T| {
+        ordering.check(lhs.iter::<$T>().chain(rhs.iter::<$T>()).flatten())?;
+        let (lhs, rhs) = (lhs.maybe_null_slice::<$T>(), rhs.maybe_null_slice::<$T>());
+        BooleanBuffer::collect_bool(lhs.len(), |idx| {
+            ordering
+                .compare(lhs[idx], rhs[idx])
+                .is_some_and(|o| operator.matches(o))
+        })
+    });
+    let validity = lhs.validity().and(rhs.validity())?.into_nullable();
+    BoolArray::try_new(buffer, validity).map(IntoArray::into_array)
+}
+
+/// Compare every element of the array against `scalar`, the array on the left hand side.
+///
+/// Like [`compare`] against a [`ConstantArray`](crate::array::ConstantArray) of the scalar, but
+/// the scalar is never expanded to the length of the array, and encodings can compare against their
+/// values, e.g. a dictionary only compares its dictionary values.
+pub fn compare_scalar(
+    array: impl AsRef<Array>,
+    scalar: &Scalar,
+    operator: Operator,
+) -> VortexResult<Array> {
+    let array = array.as_ref();
+    if !array.dtype().eq_ignore_nullability(scalar.dtype()) {
+        vortex_bail!(MismatchedTypes: array.dtype(), scalar.dtype());
+    }
+
+    if scalar.is_null() {
+        return Ok(ConstantArray::new(
+            Scalar::null(DType::Bool(Nullability::Nullable)),
+            array.len(),
+        )
+        .into_array());
+    }
+
+    if let Some(result) = array.with_dyn(|a| {
+        a.compare_scalar()
+            .map(|f| f.compare_scalar(scalar, operator))
+    }) {
+        return result;
+    }
+
+    // Encodings without a scalar comparison may still compare against a constant array
+    let constant = ConstantArray::new(scalar.clone(), array.len()).into_array();
+    if let Some(result) = array.with_dyn(|a| a.compare(&constant, operator)) {
+        return result;
+    }
+
+    // Fallback to arrow, which compares against a single element array as a scalar
+    let lhs = array.clone().into_canonical()?.into_arrow()?;
+    let rhs = ArrowScalar::new(
+        ConstantArray::new(scalar.clone(), 1)
+            .into_canonical()?
+            .into_arrow()?,
+    );
+
+    let array = match operator {
+        Operator::Eq => cmp::eq(&lhs.as_ref(), &rhs)?,
+        Operator::NotEq => cmp::neq(&lhs.as_ref(), &rhs)?,
+        Operator::Gt => cmp::gt(&lhs.as_ref(), &rhs)?,
+        Operator::Gte => cmp::gt_eq(&lhs.as_ref(), &rhs)?,
+        Operator::Lt => cmp::lt(&lhs.as_ref(), &rhs)?,
+        Operator::Lte => cmp::lt_eq(&lhs.as_ref(), &rhs)?,
+    };
+
+    Ok(Array::from_arrow(&array, true))
+}
+
+pub fn scalar_cmp<'a, 'b>(
+    lhs: impl Into<ScalarRef<'a>>,
+    rhs: impl Into<ScalarRef<'b>>,
+    operator: Operator,
+) -> Scalar {
+    let (lhs, rhs) = (lhs.into(), rhs.into());
     if lhs.is_null() | rhs.is_null() {
         Scalar::null(DType::Bool(Nullability::Nullable))
     } else {
@@ -146,7 +266,7 @@ mod tests {
     use vortex_scalar::ScalarValue;
 
     use super::*;
-    use crate::array::{BoolArray, ConstantArray};
+    use crate::array::{BoolArray, ConstantArray, PrimitiveArray};
     use crate::validity::Validity;
     use crate::{IntoArray, IntoArrayVariant};
 
@@ -169,6 +289,35 @@ mod tests {
         filtered
     }
 
+    #[test]
+    fn compare_float_ordering() {
+        let lhs = PrimitiveArray::from(vec![f64::NAN, f64::NAN, -0.0, 1.0]);
+        let rhs = PrimitiveArray::from(vec![f64::NAN, 1.0, 0.0, 1.0]);
+        let matches = |operator, ordering| {
+            compare_with_ordering(&lhs, &rhs, operator, ordering)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            matches(Operator::Eq, FloatOrdering::NansLast),
+            vec![true, false, false, true]
+        );
+        assert_eq!(
+            matches(Operator::Gt, FloatOrdering::NansLast),
+            vec![false, true, false, false]
+        );
+        assert_eq!(
+            matches(Operator::Lt, FloatOrdering::NansFirst),
+            vec![false, true, true, false]
+        );
+        assert!(compare_with_ordering(&lhs, &rhs, Operator::Eq, FloatOrdering::Error).is_err());
+    }
+
     #[test]
     fn test_bool_basic_comparisons() {
         let arr = BoolArray::from_vec(
@@ -231,4 +380,34 @@ mod tests {
         assert_eq!(res.scalar_value(), &ScalarValue::Bool(false));
         assert_eq!(res.len(), 10);
     }
+
+    #[test]
+    fn compare_scalar_bool() {
+        let arr = BoolArray::from_vec(
+            vec![true, false, true, false],
+            Validity::Array(BoolArray::from(vec![true, true, false, true]).into_array()),
+        )
+        .into_array();
+
+        let matches = compare_scalar(&arr, &Scalar::from(true), Operator::Eq)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(to_int_indices(matches), [0u64]);
+
+        let matches = compare_scalar(&arr, &Scalar::from(true), Operator::Lt)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(to_int_indices(matches), [1u64, 3]);
+
+        let nulls = compare_scalar(
+            &arr,
+            &Scalar::null(DType::Bool(Nullability::Nullable)),
+            Operator::Eq,
+        )
+        .unwrap();
+        assert_eq!(nulls.len(), 4);
+        assert!(nulls.with_dyn(|a| a.logical_validity().all_invalid()));
+    }
 }
@@ -3,6 +3,8 @@ use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_bail, VortexResult};
 
 use crate::arrow::FromArrowArray;
+use crate::compute::take::empty_canonical;
+use crate::stats::ArrayStatistics;
 use crate::{Array, ArrayDType, IntoCanonical};
 
 pub trait FilterFn {
@@ -38,6 +40,10 @@ pub fn filter(array: impl AsRef<Array>, predicate: impl AsRef<Array>) -> VortexR
         );
     }
 
+    if predicate.statistics().compute_true_count() == Some(0) {
+        return empty_canonical(array);
+    }
+
     array.with_dyn(|a| {
         if let Some(filter_fn) = a.filter() {
             filter_fn.filter(predicate)
@@ -55,10 +61,10 @@ pub fn filter(array: impl AsRef<Array>, predicate: impl AsRef<Array>) -> VortexR
 
 #[cfg(test)]
 mod test {
-    use crate::array::{BoolArray, PrimitiveArray};
+    use crate::array::{BoolArray, Primitive, PrimitiveArray};
     use crate::compute::filter::filter;
     use crate::validity::Validity;
-    use crate::{IntoArray, IntoCanonical};
+    use crate::{ArrayDType, ArrayDef, IntoArray, IntoCanonical};
 
     #[test]
     fn test_filter() {
@@ -80,4 +86,15 @@ mod test {
             vec![0i32, 1i32, 2i32]
         );
     }
+
+    #[test]
+    fn filter_none() {
+        let items = PrimitiveArray::from_nullable_vec(vec![Some(0i32), None, Some(1i32)]);
+        let predicate = BoolArray::from_vec(vec![false; 3], Validity::NonNullable);
+
+        let filtered = filter(&items, &predicate).unwrap();
+        assert!(filtered.is_empty());
+        assert_eq!(filtered.dtype(), items.dtype());
+        assert!(filtered.is_encoding(Primitive::ID));
+    }
 }
@@ -0,0 +1,64 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use vortex_dtype::NativePType;
+use vortex_error::{vortex_bail, VortexResult};
+
+/// Placement of NaNs when ordering floats, by [`search_sorted_with_ordering`],
+/// [`compare_with_ordering`] and [`argsort`].
+///
+/// Floats are otherwise in their IEEE 754 total order, so `-0.0` is less than `+0.0`. NaNs are all
+/// equal to each other whatever their sign and payload. Min and max statistics of float arrays
+/// order NaNs last.
+///
+/// [`search_sorted_with_ordering`]: crate::compute::search_sorted_with_ordering
+/// [`compare_with_ordering`]: crate::compute::compare_with_ordering
+/// [`argsort`]: crate::compute::argsort
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FloatOrdering {
+    /// NaNs are greater than every other value
+    #[default]
+    NansLast,
+    /// NaNs are less than every other value
+    NansFirst,
+    /// Ordering a NaN is an error
+    Error,
+}
+
+impl Display for FloatOrdering {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NansLast => write!(f, "nans_last"),
+            Self::NansFirst => write!(f, "nans_first"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl FloatOrdering {
+    /// Ordering of `a` against `b`, `None` if either is NaN and NaNs are an error
+    pub fn compare<T: NativePType>(self, a: T, b: T) -> Option<Ordering> {
+        match (a.is_nan(), b.is_nan()) {
+            (false, false) => Some(a.compare(b)),
+            (true, true) => (self != Self::Error).then_some(Ordering::Equal),
+            (true, false) => self.nan_ordering(),
+            (false, true) => self.nan_ordering().map(Ordering::reverse),
+        }
+    }
+
+    /// Fail if NaNs are an error and any of the `values` is NaN
+    pub fn check<T: NativePType>(self, values: impl IntoIterator<Item = T>) -> VortexResult<()> {
+        if self == Self::Error && values.into_iter().any(T::is_nan) {
+            vortex_bail!(ComputeError: "Cannot order NaN values of {}", T::PTYPE)
+        }
+        Ok(())
+    }
+
+    fn nan_ordering(self) -> Option<Ordering> {
+        match self {
+            Self::NansLast => Some(Ordering::Greater),
+            Self::NansFirst => Some(Ordering::Less),
+            Self::Error => None,
+        }
+    }
+}
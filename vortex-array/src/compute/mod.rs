@@ -8,20 +8,33 @@
 //! from Arrow.
 
 pub use boolean::{and, or, AndFn, OrFn};
-pub use compare::{compare, scalar_cmp, CompareFn, MaybeCompareFn, Operator};
+pub use bucketize::{bucket_counts, bucketize};
+pub use capabilities::ComputeCapabilities;
+pub use compare::{
+    compare, compare_scalar, compare_with_ordering, scalar_cmp, CompareFn, CompareScalarFn,
+    MaybeCompareFn, Operator,
+};
 pub use filter::{filter, FilterFn};
+pub use float_ordering::FloatOrdering;
+pub use rank::{argsort, dense_rank, rank, SortOrder};
 pub use search_sorted::*;
+pub use selection::{indices_to_mask, mask_to_indices};
 pub use slice::{slice, SliceFn};
-pub use take::{take, TakeFn};
-use unary::{CastFn, FillForwardFn, ScalarAtFn, SubtractScalarFn};
+pub use take::{take, take_map, TakeFn};
+use unary::{BinaryScalarFn, CastFn, CumSumFn, FillForwardFn, RollingFn, ScalarAtFn};
 use vortex_error::VortexResult;
 
 use crate::Array;
 
 mod boolean;
+mod bucketize;
+mod capabilities;
 mod compare;
 mod filter;
+mod float_ordering;
+mod rank;
 mod search_sorted;
+mod selection;
 mod slice;
 mod take;
 
@@ -36,6 +49,13 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Running totals of the values of an array.
+    ///
+    /// See: [CumSumFn].
+    fn cumsum(&self) -> Option<&dyn CumSumFn> {
+        None
+    }
+
     /// Binary operator implementation for arrays against other arrays.
     ///
     ///See: [CompareFn].
@@ -43,6 +63,13 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Comparison of an array against a single value.
+    ///
+    /// See: [CompareScalarFn].
+    fn compare_scalar(&self) -> Option<&dyn CompareScalarFn> {
+        None
+    }
+
     /// Array function that returns new arrays a non-null value is repeated across runs of nulls.
     ///
     /// See: [FillForwardFn].
@@ -64,10 +91,17 @@ pub trait ArrayCompute {
         None
     }
 
-    /// Broadcast subtraction of scalar from Vortex array.
+    /// Aggregate values over a trailing window.
+    ///
+    /// See: [RollingFn].
+    fn rolling(&self) -> Option<&dyn RollingFn> {
+        None
+    }
+
+    /// Broadcast arithmetic between a Vortex array and a scalar.
     ///
-    /// See: [SubtractScalarFn].
-    fn subtract_scalar(&self) -> Option<&dyn SubtractScalarFn> {
+    /// See: [BinaryScalarFn].
+    fn binary_scalar(&self) -> Option<&dyn BinaryScalarFn> {
         None
     }
 
@@ -0,0 +1,205 @@
+use std::cmp::Ordering;
+
+use arrow_ord::ord::make_comparator;
+use arrow_ord::sort::{sort_to_indices, SortOptions};
+use vortex_dtype::{match_each_float_ptype, DType};
+use vortex_error::VortexResult;
+
+use crate::array::PrimitiveArray;
+use crate::compute::FloatOrdering;
+use crate::stats::ArrayStatistics;
+use crate::validity::Validity;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    /// Nulls sort after all values in ascending order and before them in descending order, as in
+    /// SQL
+    fn options(self) -> SortOptions {
+        let descending = self == SortOrder::Descending;
+        SortOptions {
+            descending,
+            nulls_first: descending,
+        }
+    }
+}
+
+/// Indices that sort the array by `order`, as a `u64` array.
+///
+/// The sort is stable. Nulls sort last in ascending order and first in descending order, as in
+/// [`rank`]. Floats are in a total order with NaNs placed by `float_ordering` in ascending order,
+/// and reversed along with the other values in descending order.
+pub fn argsort(
+    array: impl AsRef<Array>,
+    order: SortOrder,
+    float_ordering: FloatOrdering,
+) -> VortexResult<Array> {
+    let array = array.as_ref();
+    let indices = match array.dtype() {
+        DType::Primitive(ptype, _) if ptype.is_float() => {
+            let primitive = array.clone().into_primitive()?;
+            match_each_float_ptype!(ptype, |$T| {
+                let values = primitive.iter::<$T>().collect::<Vec<_>>();
+                float_ordering.check(values.iter().flatten().copied())?;
+                let mut indices = (0..values.len() as u64).collect::<Vec<_>>();
+                indices.sort_by(|a, b| {
+                    let ordering = match (values[*a as usize], values[*b as usize]) {
+                        (Some(a), Some(b)) => float_ordering.compare(a, b).unwrap_or(Ordering::Equal),
+                        (Some(_), None) => Ordering::Less,
+                        (None, Some(_)) => Ordering::Greater,
+                        (None, None) => Ordering::Equal,
+                    };
+                    match order {
+                        SortOrder::Ascending => ordering,
+                        SortOrder::Descending => ordering.reverse(),
+                    }
+                });
+                indices
+            })
+        }
+        _ => {
+            let arrow = array.clone().into_canonical()?.into_arrow()?;
+            let cmp = make_comparator(&arrow, &arrow, order.options())?;
+            let mut indices = (0..array.len() as u64).collect::<Vec<_>>();
+            indices.sort_by(|a, b| cmp(*a as usize, *b as usize));
+            indices
+        }
+    };
+    Ok(PrimitiveArray::from_vec(indices, Validity::NonNullable).into_array())
+}
+
+/// SQL `RANK` of every element of the array ordered by `order`, as a `u64` array.
+///
+/// Ranks start at 1, equal values get the same rank and leave a gap after them, e.g. `[1, 2, 2, 4]`.
+/// Nulls are equal to each other and ranked last in ascending order, first in descending order.
+///
+/// An argsort is skipped if `order` is ascending and the array has no nulls and is known to be
+/// sorted.
+pub fn rank(array: impl AsRef<Array>, order: SortOrder) -> VortexResult<Array> {
+    ranks(array.as_ref(), order, false)
+}
+
+/// SQL `DENSE_RANK` of every element of the array ordered by `order`, as a `u64` array.
+///
+/// Like [`rank`], except that there are no gaps after equal values, e.g. `[1, 2, 2, 3]`.
+pub fn dense_rank(array: impl AsRef<Array>, order: SortOrder) -> VortexResult<Array> {
+    ranks(array.as_ref(), order, true)
+}
+
+fn ranks(array: &Array, order: SortOrder, dense: bool) -> VortexResult<Array> {
+    let arrow = array.clone().into_canonical()?.into_arrow()?;
+    let options = order.options();
+
+    let presorted = order == SortOrder::Ascending
+        && array.statistics().compute_null_count() == Some(0)
+        && array.statistics().compute_is_sorted() == Some(true);
+    let sorted_indices = if presorted {
+        (0..array.len() as u32).collect::<Vec<_>>()
+    } else {
+        sort_to_indices(&arrow, Some(options), None)?
+            .values()
+            .to_vec()
+    };
+
+    let cmp = make_comparator(&arrow, &arrow, options)?;
+    let mut ranks = vec![0u64; array.len()];
+    let mut rank = 0u64;
+    for (pos, idx) in sorted_indices.iter().enumerate() {
+        let idx = *idx as usize;
+        let tied = pos > 0 && cmp(sorted_indices[pos - 1] as usize, idx).is_eq();
+        if !tied {
+            rank = if dense { rank + 1 } else { pos as u64 + 1 };
+        }
+        ranks[idx] = rank;
+    }
+
+    Ok(PrimitiveArray::from_vec(ranks, Validity::NonNullable).into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::PrimitiveArray;
+    use crate::compute::{argsort, dense_rank, rank, FloatOrdering, SortOrder};
+    use crate::IntoArrayVariant;
+
+    fn ranks(array: crate::Array) -> Vec<u64> {
+        array
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u64>()
+            .to_vec()
+    }
+
+    #[test]
+    fn rank_ties() {
+        let array = PrimitiveArray::from(vec![30i32, 10, 20, 10, 30]);
+        assert_eq!(
+            ranks(rank(&array, SortOrder::Ascending).unwrap()),
+            vec![4, 1, 3, 1, 4]
+        );
+        assert_eq!(
+            ranks(dense_rank(&array, SortOrder::Ascending).unwrap()),
+            vec![3, 1, 2, 1, 3]
+        );
+        assert_eq!(
+            ranks(rank(&array, SortOrder::Descending).unwrap()),
+            vec![1, 4, 3, 4, 1]
+        );
+    }
+
+    #[test]
+    fn rank_sorted_and_nulls() {
+        let sorted = PrimitiveArray::from(vec![1u8, 1, 2, 3, 3, 3, 4]);
+        assert_eq!(
+            ranks(rank(&sorted, SortOrder::Ascending).unwrap()),
+            vec![1, 1, 3, 4, 4, 4, 7]
+        );
+
+        let nullable = PrimitiveArray::from_nullable_vec(vec![None, Some(5i64), None, Some(1)]);
+        assert_eq!(
+            ranks(rank(&nullable, SortOrder::Ascending).unwrap()),
+            vec![3, 2, 3, 1]
+        );
+        assert_eq!(
+            ranks(dense_rank(&nullable, SortOrder::Descending).unwrap()),
+            vec![1, 2, 1, 3]
+        );
+    }
+
+    #[test]
+    fn argsort_floats() {
+        let array = PrimitiveArray::from_nullable_vec(vec![
+            Some(1.0f32),
+            None,
+            Some(f32::NAN),
+            Some(0.0),
+            Some(-0.0),
+        ]);
+        let sort = |order, ordering| ranks(argsort(&array, order, ordering).unwrap());
+        assert_eq!(
+            sort(SortOrder::Ascending, FloatOrdering::NansLast),
+            vec![4, 3, 0, 2, 1]
+        );
+        assert_eq!(
+            sort(SortOrder::Ascending, FloatOrdering::NansFirst),
+            vec![2, 4, 3, 0, 1]
+        );
+        assert_eq!(
+            sort(SortOrder::Descending, FloatOrdering::NansLast),
+            vec![1, 2, 0, 3, 4]
+        );
+        assert!(argsort(&array, SortOrder::Ascending, FloatOrdering::Error).is_err());
+
+        let ints = PrimitiveArray::from(vec![3u8, 1, 3, 2]);
+        assert_eq!(
+            ranks(argsort(&ints, SortOrder::Descending, FloatOrdering::Error).unwrap()),
+            vec![0, 2, 3, 1]
+        );
+    }
+}
@@ -1,13 +1,17 @@
+use std::cell::Cell;
 use std::cmp::Ordering;
 use std::cmp::Ordering::{Equal, Greater, Less};
 use std::fmt::{Debug, Display, Formatter};
 use std::hint;
+use std::marker::PhantomData;
 
 use itertools::Itertools;
-use vortex_error::{vortex_bail, VortexResult};
-use vortex_scalar::Scalar;
+use vortex_dtype::{match_each_float_ptype, match_each_integer_ptype, DType, NativePType};
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
 
 use crate::compute::unary::scalar_at;
+use crate::compute::FloatOrdering;
 use crate::{Array, ArrayDType};
 
 #[derive(Debug, Copy, Clone)]
@@ -137,7 +141,12 @@ pub fn search_sorted<T: Into<Scalar>>(
     target: T,
     side: SearchSortedSide,
 ) -> VortexResult<SearchResult> {
-    let scalar = target.into().cast(array.dtype())?;
+    let target = target.into();
+    if let Some(result) = search_out_of_range(array, &target) {
+        return Ok(result);
+    }
+
+    let scalar = target.cast(array.dtype())?;
     if scalar.is_null() {
         vortex_bail!("Search sorted with null value is not supported");
     }
@@ -158,6 +167,121 @@ pub fn search_sorted<T: Into<Scalar>>(
     })
 }
 
+/// Like [`search_sorted`], but floats are in a total order with NaNs placed by `ordering`, which
+/// must be the order the array is sorted in.
+///
+/// Float arrays are searched value by value with [`scalar_at`], with nulls sorted last. Arrays of
+/// other types are searched by [`search_sorted`]. With [`FloatOrdering::Error`], the search fails
+/// if it compares a NaN, NaNs it doesn't reach are ignored.
+pub fn search_sorted_with_ordering<T: Into<Scalar>>(
+    array: &Array,
+    target: T,
+    side: SearchSortedSide,
+    ordering: FloatOrdering,
+) -> VortexResult<SearchResult> {
+    let DType::Primitive(ptype, _) = array.dtype() else {
+        return search_sorted(array, target, side);
+    };
+    if !ptype.is_float() {
+        return search_sorted(array, target, side);
+    }
+
+    let target = target.into().cast(array.dtype())?;
+    if target.is_null() {
+        vortex_bail!("Search sorted with null value is not supported");
+    }
+    match_each_float_ptype!(ptype, |$T| {
+        let target = $T::try_from(&target)?;
+        let floats = FloatsNullsLast::<$T> {
+            array,
+            ordering,
+            unordered: Cell::new(false),
+            error: Cell::new(None),
+            ptype: PhantomData,
+        };
+        let result = floats.search_sorted(&target, side);
+        if let Some(err) = floats.error.take() {
+            return Err(err);
+        }
+        if floats.unordered.get() {
+            vortex_bail!(ComputeError: "Cannot order NaN values of {}", ptype);
+        }
+        Ok(result)
+    })
+}
+
+/// Floats of an array ordered by a [`FloatOrdering`], with nulls greater than every value
+struct FloatsNullsLast<'a, T> {
+    array: &'a Array,
+    ordering: FloatOrdering,
+    /// Set once a comparison had no ordering, i.e. compared a NaN that the ordering rejects
+    unordered: Cell<bool>,
+    /// First error reading a value, which the search can't return itself
+    error: Cell<Option<VortexError>>,
+    ptype: PhantomData<T>,
+}
+
+impl<T> IndexOrd<T> for FloatsNullsLast<'_, T>
+where
+    T: NativePType + for<'s> TryFrom<&'s Scalar, Error = VortexError>,
+{
+    fn index_cmp(&self, idx: usize, elem: &T) -> Option<Ordering> {
+        let value = scalar_at(self.array, idx).and_then(|scalar| {
+            if scalar.is_null() {
+                Ok(None)
+            } else {
+                T::try_from(&scalar).map(Some)
+            }
+        });
+        let value = match value {
+            Ok(Some(value)) => value,
+            Ok(None) => return Some(Greater),
+            Err(err) => {
+                let first = self.error.take();
+                self.error.set(first.or(Some(err)));
+                return None;
+            }
+        };
+        let ordering = self.ordering.compare(value, *elem);
+        if ordering.is_none() {
+            self.unordered.set(true);
+        }
+        ordering
+    }
+}
+
+impl<T> Len for FloatsNullsLast<'_, T> {
+    fn len(&self) -> usize {
+        self.array.len()
+    }
+}
+
+/// Integer targets outside of the range of the array's integer type sort before or after all of its
+/// values, e.g. searching `u8` indices for a row offset past 255.
+fn search_out_of_range(array: &Array, target: &Scalar) -> Option<SearchResult> {
+    let DType::Primitive(ptype, _) = array.dtype() else {
+        return None;
+    };
+    let target = PrimitiveScalar::try_from(target).ok()?;
+    if !ptype.is_int() || !target.ptype().is_int() {
+        return None;
+    }
+
+    let value = match_each_integer_ptype!(target.ptype(), |$T| {
+        i128::from(target.typed_value::<$T>()?)
+    });
+    let (min, max) = match_each_integer_ptype!(*ptype, |$T| {
+        (i128::from($T::MIN), i128::from($T::MAX))
+    });
+    if value > max {
+        Some(SearchResult::NotFound(array.len()))
+    } else if value < min {
+        Some(SearchResult::NotFound(0))
+    } else {
+        None
+    }
+}
+
 pub fn search_sorted_u64(
     array: &Array,
     target: u64,
@@ -404,7 +528,81 @@ impl<T> Len for [T] {
 
 #[cfg(test)]
 mod test {
+    use crate::array::PrimitiveArray;
     use crate::compute::search_sorted::{SearchResult, SearchSorted, SearchSortedSide};
+    use crate::compute::{search_sorted, search_sorted_with_ordering, FloatOrdering};
+
+    #[test]
+    fn target_outside_of_index_type() {
+        let unsigned = PrimitiveArray::from(vec![1u8, 5, 200]);
+        assert_eq!(
+            search_sorted(unsigned.as_ref(), 300usize, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(3)
+        );
+        assert_eq!(
+            search_sorted(unsigned.as_ref(), -1i64, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(0)
+        );
+
+        let signed = PrimitiveArray::from(vec![-100i8, 0, 100]);
+        assert_eq!(
+            search_sorted(signed.as_ref(), -200i32, SearchSortedSide::Right).unwrap(),
+            SearchResult::NotFound(0)
+        );
+        assert_eq!(
+            search_sorted(signed.as_ref(), 100i32, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(3)
+        );
+    }
+
+    #[test]
+    fn float_ordering() {
+        let nans_last = PrimitiveArray::from_nullable_vec(vec![
+            Some(-1.0f64),
+            Some(-0.0),
+            Some(0.0),
+            Some(f64::NAN),
+            None,
+        ]);
+        let search = |value: f64, side, ordering| {
+            search_sorted_with_ordering(nans_last.as_ref(), value, side, ordering)
+        };
+        assert_eq!(
+            search(0.0, SearchSortedSide::Left, FloatOrdering::NansLast).unwrap(),
+            SearchResult::Found(2)
+        );
+        assert_eq!(
+            search(-0.0, SearchSortedSide::Right, FloatOrdering::NansLast).unwrap(),
+            SearchResult::Found(2)
+        );
+        assert_eq!(
+            search(f64::NAN, SearchSortedSide::Left, FloatOrdering::NansLast).unwrap(),
+            SearchResult::Found(3)
+        );
+        assert_eq!(
+            search(1.0, SearchSortedSide::Left, FloatOrdering::NansLast).unwrap(),
+            SearchResult::NotFound(3)
+        );
+        // Only the NaNs the search compares are an error
+        assert_eq!(
+            search(-1.0, SearchSortedSide::Left, FloatOrdering::Error).unwrap(),
+            SearchResult::Found(0)
+        );
+        assert!(search(1.0, SearchSortedSide::Left, FloatOrdering::Error).is_err());
+        assert!(search(f64::NAN, SearchSortedSide::Left, FloatOrdering::Error).is_err());
+
+        let nans_first = PrimitiveArray::from(vec![f32::NAN, 1.0, 2.0]);
+        assert_eq!(
+            search_sorted_with_ordering(
+                nans_first.as_ref(),
+                f32::NAN,
+                SearchSortedSide::Right,
+                FloatOrdering::NansFirst
+            )
+            .unwrap(),
+            SearchResult::Found(1)
+        );
+    }
 
     #[test]
     fn left_side_equal() {
@@ -0,0 +1,79 @@
+use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
+use vortex_dtype::{DType, PType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::{BoolArray, PrimitiveArray};
+use crate::compute::unary::try_cast;
+use crate::validity::{ArrayValidity, LogicalValidity};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+/// Positions of the true values of a boolean mask, as a `u64` array. Null values are not selected.
+///
+/// The inverse of [`indices_to_mask`].
+pub fn mask_to_indices(mask: impl AsRef<Array>) -> VortexResult<Array> {
+    let mask = mask.as_ref();
+    if !matches!(mask.dtype(), DType::Bool(_)) {
+        vortex_bail!("Mask must be a boolean array, has type {}", mask.dtype())
+    }
+
+    let bools = mask.clone().into_bool()?;
+    let selected = match bools.logical_validity() {
+        LogicalValidity::AllValid(_) => bools,
+        LogicalValidity::AllInvalid(len) => BoolArray::from(BooleanBuffer::new_unset(len)),
+        LogicalValidity::Array(validity) => {
+            BoolArray::from(&bools.boolean_buffer() & &validity.into_bool()?.boolean_buffer())
+        }
+    };
+    Ok(PrimitiveArray::from(selected.set_indices()).into_array())
+}
+
+/// Non-nullable boolean mask of `length` values, which are true at `indices` and false elsewhere.
+///
+/// Indices can be of any integer type, in any order and repeated. The inverse of
+/// [`mask_to_indices`].
+pub fn indices_to_mask(indices: impl AsRef<Array>, length: usize) -> VortexResult<Array> {
+    let indices = indices.as_ref();
+    if !indices.dtype().is_int() {
+        vortex_bail!("Indices must be integers, have type {}", indices.dtype())
+    }
+
+    let indices = try_cast(indices, &PType::U64.into())?.into_primitive()?;
+    let mut mask = BooleanBufferBuilder::new(length);
+    mask.append_n(length, false);
+    for idx in indices.maybe_null_slice::<u64>() {
+        let idx = *idx as usize;
+        if idx >= length {
+            vortex_bail!(OutOfBounds: idx, 0, length)
+        }
+        mask.set_bit(idx, true);
+    }
+    Ok(BoolArray::from(mask.finish()).into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{BoolArray, PrimitiveArray};
+    use crate::compute::{indices_to_mask, mask_to_indices};
+    use crate::IntoArrayVariant;
+
+    #[test]
+    fn mask_indices_roundtrip() {
+        let mask = BoolArray::from_iter([Some(true), None, Some(false), Some(true), Some(true)]);
+        let indices = mask_to_indices(&mask).unwrap().into_primitive().unwrap();
+        assert_eq!(indices.maybe_null_slice::<u64>(), &[0, 3, 4]);
+
+        let roundtrip = indices_to_mask(&indices, 5).unwrap().into_bool().unwrap();
+        assert_eq!(
+            roundtrip.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, false, true, true]
+        );
+
+        let narrow = PrimitiveArray::from(vec![2u8, 0, 2]);
+        let mask = indices_to_mask(&narrow, 3).unwrap().into_bool().unwrap();
+        assert_eq!(
+            mask.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+        assert!(indices_to_mask(&narrow, 2).is_err());
+    }
+}
@@ -1,6 +1,8 @@
 use log::info;
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
+use crate::compute::slice;
+use crate::stats::{ArrayStatistics, Stat};
 use crate::{Array, ArrayDType as _, IntoCanonical as _};
 
 pub trait TakeFn {
@@ -18,7 +20,11 @@ pub fn take(array: impl AsRef<Array>, indices: impl AsRef<Array>) -> VortexResul
         );
     }
 
-    array.with_dyn(|a| {
+    if indices.is_empty() {
+        return empty_canonical(array);
+    }
+
+    let taken = array.with_dyn(|a| {
         if let Some(take) = a.take() {
             return take.take(indices);
         }
@@ -30,5 +36,94 @@ pub fn take(array: impl AsRef<Array>, indices: impl AsRef<Array>) -> VortexResul
                 .map(|t| t.take(indices))
                 .unwrap_or_else(|| Err(vortex_err!(NotImplemented: "take", array.encoding().id())))
         })
-    })
+    })?;
+
+    propagate_sortedness(array, indices, &taken);
+    Ok(taken)
+}
+
+/// Empty array in the canonical encoding of the dtype of `array`, decoding only an empty slice of it
+pub(crate) fn empty_canonical(array: &Array) -> VortexResult<Array> {
+    Ok(Array::from(slice(array, 0, 0)?.into_canonical()?))
+}
+
+/// Taking sorted indices from a sorted array gives a sorted array, and likewise for strictly
+/// sorted ones. Only sortedness already known for `array` is propagated, it isn't computed.
+fn propagate_sortedness(array: &Array, indices: &Array, taken: &Array) {
+    for stat in [Stat::IsSorted, Stat::IsStrictSorted] {
+        if array.statistics().get_as::<bool>(stat) == Some(true)
+            && indices.statistics().compute_as::<bool>(stat) == Some(true)
+        {
+            taken.statistics().set(stat, true.into());
+        }
+    }
+}
+
+/// Take the values at `indices` like [`take`], which keeps the encoding of arrays that take
+/// without decoding, e.g. a dictionary takes its codes and shares its values with the result.
+///
+/// If `materialize` is set, the result is decoded into its canonical encoding instead.
+pub fn take_map(
+    array: impl AsRef<Array>,
+    indices: impl AsRef<Array>,
+    materialize: bool,
+) -> VortexResult<Array> {
+    let taken = take(array, indices)?;
+    if materialize {
+        Ok(Array::from(taken.into_canonical()?))
+    } else {
+        Ok(taken)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::{ChunkedArray, Primitive, PrimitiveArray, VarBinArray, VarBinView};
+    use crate::compute::take;
+    use crate::stats::{ArrayStatistics, Stat};
+    use crate::{ArrayDType, ArrayDef, IntoArray};
+
+    #[test]
+    fn take_propagates_sortedness() {
+        let sorted = PrimitiveArray::from(vec![1i32, 2, 2, 5, 8]);
+        assert_eq!(sorted.statistics().compute_is_sorted(), Some(true));
+
+        let taken = take(&sorted, PrimitiveArray::from(vec![0u32, 2, 4])).unwrap();
+        assert_eq!(
+            taken.statistics().get_as::<bool>(Stat::IsSorted),
+            Some(true)
+        );
+        assert_eq!(
+            taken.statistics().get_as::<bool>(Stat::IsStrictSorted),
+            None
+        );
+
+        let unsorted_indices = take(&sorted, PrimitiveArray::from(vec![4u32, 0])).unwrap();
+        assert_eq!(
+            unsorted_indices.statistics().get_as::<bool>(Stat::IsSorted),
+            None
+        );
+    }
+
+    #[test]
+    fn take_nothing() {
+        let items = VarBinArray::from_iter(
+            [Some("a"), None, Some("c")],
+            DType::Utf8(Nullability::Nullable),
+        );
+        let taken = take(&items, PrimitiveArray::from(Vec::<u32>::new())).unwrap();
+        assert!(taken.is_empty());
+        assert_eq!(taken.dtype(), items.dtype());
+        assert!(taken.is_encoding(VarBinView::ID));
+
+        let chunked = ChunkedArray::from_iter([
+            PrimitiveArray::from(vec![1i32, 2]).into_array(),
+            PrimitiveArray::from(vec![3i32]).into_array(),
+        ]);
+        let taken = take(&chunked, PrimitiveArray::from(Vec::<u32>::new())).unwrap();
+        assert!(taken.is_empty());
+        assert!(taken.is_encoding(Primitive::ID));
+    }
 }
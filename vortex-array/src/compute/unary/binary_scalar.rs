@@ -0,0 +1,137 @@
+use std::fmt::{Display, Formatter};
+
+use vortex_dtype::DType;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::{Array, ArrayDType, IntoArrayVariant};
+
+/// What scalar arithmetic does with an integer result that doesn't fit the type of the array.
+///
+/// Float arithmetic follows IEEE 754 whatever the policy. Values under null positions don't
+/// overflow, their result is always wrapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Wrap around at the bounds of the type
+    Wrapping,
+    /// Fail the computation
+    Checked,
+    /// Clamp to the bounds of the type
+    Saturating,
+}
+
+/// Arithmetic operator applied by [`BinaryScalarFn`], with the array on the left hand side
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    /// Division, rounding towards zero for integers. Integer division by zero is an error.
+    Div,
+}
+
+impl Display for BinaryOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Add => write!(f, "+"),
+            Self::Sub => write!(f, "-"),
+            Self::Mul => write!(f, "*"),
+            Self::Div => write!(f, "/"),
+        }
+    }
+}
+
+/// Trait for applying an arithmetic operator between every element of an array and a scalar.
+///
+/// The scalar must have the same type as the array. Null elements stay null, a null scalar makes
+/// every element null.
+pub trait BinaryScalarFn {
+    fn binary_scalar(
+        &self,
+        op: BinaryOp,
+        operand: &Scalar,
+        overflow: OverflowPolicy,
+    ) -> VortexResult<Array>;
+}
+
+/// Subtraction of a scalar from every element of an array.
+#[deprecated(note = "use `BinaryScalarFn` with `BinaryOp::Sub`")]
+pub trait SubtractScalarFn {
+    fn subtract_scalar(
+        &self,
+        to_subtract: &Scalar,
+        overflow: OverflowPolicy,
+    ) -> VortexResult<Array>;
+}
+
+#[allow(deprecated)]
+impl<T: BinaryScalarFn + ?Sized> SubtractScalarFn for T {
+    fn subtract_scalar(
+        &self,
+        to_subtract: &Scalar,
+        overflow: OverflowPolicy,
+    ) -> VortexResult<Array> {
+        self.binary_scalar(BinaryOp::Sub, to_subtract, overflow)
+    }
+}
+
+pub fn binary_scalar(
+    array: impl AsRef<Array>,
+    op: BinaryOp,
+    operand: &Scalar,
+    overflow: OverflowPolicy,
+) -> VortexResult<Array> {
+    let array = array.as_ref();
+    if let Some(result) = array.with_dyn(|c| {
+        c.binary_scalar()
+            .map(|t| t.binary_scalar(op, operand, overflow))
+    }) {
+        return result;
+    }
+    // if arithmetic is not implemented for the given array type, but the array has a numeric
+    // DType, we can flatten the array and apply it to the flattened primitive array
+    match array.dtype() {
+        DType::Primitive(..) => {
+            // TODO(@jcasale): pass array instead of ref to get rid of clone?
+            // downside is that binary_scalar then consumes the array, which is not great
+            let flat = array.clone().into_primitive()?;
+            flat.binary_scalar(op, operand, overflow)
+        }
+        _ => Err(vortex_err!(
+            NotImplemented: "binary_scalar",
+            array.encoding().id()
+        )),
+    }
+}
+
+pub fn add_scalar(
+    array: impl AsRef<Array>,
+    to_add: &Scalar,
+    overflow: OverflowPolicy,
+) -> VortexResult<Array> {
+    binary_scalar(array, BinaryOp::Add, to_add, overflow)
+}
+
+pub fn subtract_scalar(
+    array: impl AsRef<Array>,
+    to_subtract: &Scalar,
+    overflow: OverflowPolicy,
+) -> VortexResult<Array> {
+    binary_scalar(array, BinaryOp::Sub, to_subtract, overflow)
+}
+
+pub fn mul_scalar(
+    array: impl AsRef<Array>,
+    to_multiply: &Scalar,
+    overflow: OverflowPolicy,
+) -> VortexResult<Array> {
+    binary_scalar(array, BinaryOp::Mul, to_multiply, overflow)
+}
+
+pub fn div_scalar(
+    array: impl AsRef<Array>,
+    divisor: &Scalar,
+    overflow: OverflowPolicy,
+) -> VortexResult<Array> {
+    binary_scalar(array, BinaryOp::Div, divisor, overflow)
+}
@@ -0,0 +1,92 @@
+use vortex_dtype::{match_each_native_ptype, DType, NativePType, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::array::PrimitiveArray;
+use crate::{Array, ArrayDType, IntoArrayVariant};
+
+/// Trait for computing the running totals of an array.
+///
+/// Integers are summed as `i64` or `u64` and floats as `f64`, overflowing the sum is an error.
+/// Nulls are skipped, they are null in the result and don't add to the running total.
+pub trait CumSumFn {
+    fn cumsum(&self) -> VortexResult<Array>;
+}
+
+pub fn cumsum(array: impl AsRef<Array>) -> VortexResult<Array> {
+    let array = array.as_ref();
+    if !matches!(array.dtype(), DType::Primitive(..)) {
+        vortex_bail!(
+            "Cumulative sum of non-primitive array of type {}",
+            array.dtype()
+        )
+    }
+
+    array.with_dyn(|a| {
+        a.cumsum().map(|f| f.cumsum()).unwrap_or_else(|| {
+            let primitive = array.clone().into_primitive()?;
+            CumSumFn::cumsum(&primitive)
+        })
+    })
+}
+
+/// Sum of the values seen so far by a cumulative sum, so that it can continue over chunks
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum RunningTotal {
+    Signed(i64),
+    Unsigned(u64),
+    Float(f64),
+}
+
+impl RunningTotal {
+    pub(crate) fn new(ptype: PType) -> Self {
+        if ptype.is_float() {
+            Self::Float(0.0)
+        } else if ptype.is_signed_int() {
+            Self::Signed(0)
+        } else {
+            Self::Unsigned(0)
+        }
+    }
+
+    pub(crate) fn ptype(&self) -> PType {
+        match self {
+            Self::Signed(_) => PType::I64,
+            Self::Unsigned(_) => PType::U64,
+            Self::Float(_) => PType::F64,
+        }
+    }
+}
+
+/// Running totals of the values of `array`, starting at `total`, which is updated to the sum of all
+/// the values
+pub(crate) fn running_total(
+    array: &PrimitiveArray,
+    total: &mut RunningTotal,
+) -> VortexResult<PrimitiveArray> {
+    match_each_native_ptype!(array.ptype(), |$T| {
+        match total {
+            RunningTotal::Signed(sum) => accumulate::<$T, i64>(array, sum, i64::checked_add),
+            RunningTotal::Unsigned(sum) => accumulate::<$T, u64>(array, sum, u64::checked_add),
+            RunningTotal::Float(sum) => accumulate::<$T, f64>(array, sum, |a, b| Some(a + b)),
+        }
+    })
+}
+
+fn accumulate<T: NativePType, A: NativePType>(
+    array: &PrimitiveArray,
+    total: &mut A,
+    add: impl Fn(A, A) -> Option<A>,
+) -> VortexResult<PrimitiveArray> {
+    let mut sums = Vec::with_capacity(array.len());
+    for value in array.iter::<T>() {
+        if let Some(value) = value {
+            let value = num_traits::cast::<T, A>(value)
+                .ok_or_else(|| vortex_err!("Cannot sum {value} as {}", A::PTYPE))?;
+            *total = add(*total, value).ok_or_else(
+                || vortex_err!(ComputeError: "Cumulative sum overflows {}", A::PTYPE),
+            )?;
+        }
+        sums.push(*total);
+    }
+    Ok(PrimitiveArray::from_vec(sums, array.validity()))
+}
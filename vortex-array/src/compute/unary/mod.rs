@@ -1,9 +1,21 @@
+#[allow(deprecated)]
+pub use binary_scalar::SubtractScalarFn;
+pub use binary_scalar::{
+    add_scalar, binary_scalar, div_scalar, mul_scalar, subtract_scalar, BinaryOp, BinaryScalarFn,
+    OverflowPolicy,
+};
 pub use cast::{try_cast, CastFn};
+pub use cumsum::{cumsum, CumSumFn};
+pub(crate) use cumsum::{running_total, RunningTotal};
 pub use fill_forward::{fill_forward, FillForwardFn};
-pub use scalar_at::{scalar_at, scalar_at_unchecked, ScalarAtFn};
-pub use scalar_subtract::{subtract_scalar, SubtractScalarFn};
+pub use rolling::{rolling, RollingAgg, RollingFn};
+pub use scalar_at::{
+    scalar_at, scalar_at_many, scalar_at_unchecked, scalar_at_wrapping, ScalarAtFn,
+};
 
+mod binary_scalar;
 mod cast;
+mod cumsum;
 mod fill_forward;
+mod rolling;
 mod scalar_at;
-mod scalar_subtract;
@@ -0,0 +1,61 @@
+use std::fmt::{Display, Formatter};
+
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::{Array, ArrayDType, IntoArrayVariant};
+
+/// Aggregate computed over the trailing window of each position by [`rolling`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollingAgg {
+    /// Summed like [`cumsum`](crate::compute::unary::cumsum), as `i64`, `u64` or `f64`
+    Sum,
+    /// Mean as `f64`
+    Mean,
+    /// Minimum, of the same type as the array
+    Min,
+    /// Maximum, of the same type as the array
+    Max,
+}
+
+impl Display for RollingAgg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sum => write!(f, "sum"),
+            Self::Mean => write!(f, "mean"),
+            Self::Min => write!(f, "min"),
+            Self::Max => write!(f, "max"),
+        }
+    }
+}
+
+/// Trait for aggregating the values of an array over a trailing window.
+///
+/// The result has the same length as the array and is nullable. Positions before the first full
+/// window are null. Nulls are skipped, a window of only nulls aggregates to null.
+pub trait RollingFn {
+    fn rolling(&self, window: usize, agg: RollingAgg) -> VortexResult<Array>;
+}
+
+/// Aggregate each value of `array` with the `window - 1` values before it.
+pub fn rolling(array: impl AsRef<Array>, window: usize, agg: RollingAgg) -> VortexResult<Array> {
+    let array = array.as_ref();
+    if !matches!(array.dtype(), DType::Primitive(..)) {
+        vortex_bail!(
+            "Rolling {agg} of non-primitive array of type {}",
+            array.dtype()
+        )
+    }
+    if window == 0 {
+        vortex_bail!("Rolling {agg} window must hold at least one value");
+    }
+
+    array.with_dyn(|a| {
+        a.rolling()
+            .map(|f| f.rolling(window, agg))
+            .unwrap_or_else(|| {
+                let primitive = array.clone().into_primitive()?;
+                RollingFn::rolling(&primitive, window, agg)
+            })
+    })
+}
@@ -7,6 +7,14 @@ pub trait ScalarAtFn {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar>;
 
     fn scalar_at_unchecked(&self, index: usize) -> Scalar;
+
+    /// The scalars at each of the in bounds and valid `indices`, in the same order.
+    ///
+    /// Encodings can override this to share work between the lookups, by default it calls
+    /// [`ScalarAtFn::scalar_at`] for each index.
+    fn scalar_at_many(&self, indices: &[usize]) -> VortexResult<Vec<Scalar>> {
+        indices.iter().map(|idx| self.scalar_at(*idx)).collect()
+    }
 }
 
 pub fn scalar_at(array: impl AsRef<Array>, index: usize) -> VortexResult<Scalar> {
@@ -26,6 +34,64 @@ pub fn scalar_at(array: impl AsRef<Array>, index: usize) -> VortexResult<Scalar>
     })
 }
 
+/// The scalars at each of `indices`, like calling [`scalar_at`] for each of them but dispatching to
+/// the encoding only once.
+pub fn scalar_at_many(array: impl AsRef<Array>, indices: &[usize]) -> VortexResult<Vec<Scalar>> {
+    let array = array.as_ref();
+    if let Some(idx) = indices.iter().find(|idx| **idx >= array.len()) {
+        vortex_bail!(OutOfBounds: *idx, 0, array.len());
+    }
+
+    array.with_dyn(|a| {
+        let f = a
+            .scalar_at()
+            .ok_or_else(|| vortex_err!(NotImplemented: "scalar_at", array.encoding().id()))?;
+
+        let valid = indices
+            .iter()
+            .map(|idx| a.is_valid(*idx))
+            .collect::<Vec<_>>();
+        if valid.iter().all(|v| *v) {
+            return f.scalar_at_many(indices);
+        }
+
+        let valid_indices = indices
+            .iter()
+            .zip(&valid)
+            .filter_map(|(idx, v)| v.then_some(*idx))
+            .collect::<Vec<_>>();
+        let mut valid_scalars = f.scalar_at_many(&valid_indices)?.into_iter();
+        valid
+            .iter()
+            .map(|v| {
+                if *v {
+                    valid_scalars
+                        .next()
+                        .ok_or_else(|| vortex_err!("Missing scalar for valid index"))
+                } else {
+                    Ok(Scalar::null(array.dtype().clone()))
+                }
+            })
+            .collect()
+    })
+}
+
+/// Like [`scalar_at`], except that negative indices count back from the end of the array, as in
+/// Python, so `-1` is the last element.
+pub fn scalar_at_wrapping(array: impl AsRef<Array>, index: i64) -> VortexResult<Scalar> {
+    let array = array.as_ref();
+    let len = array.len();
+    let wrapped = if index < 0 {
+        len.checked_sub(index.unsigned_abs() as usize)
+    } else {
+        Some(index as usize)
+    };
+    match wrapped {
+        Some(idx) => scalar_at(array, idx),
+        None => vortex_bail!("index {index} out of bounds from -{len} to {len}"),
+    }
+}
+
 /// Returns a [`Scalar`] value without checking for validity or array bounds. Might panic *OR* return an invalid value if used incorrectly.
 pub fn scalar_at_unchecked(array: impl AsRef<Array>, index: usize) -> Scalar {
     let array = array.as_ref();
@@ -33,3 +99,36 @@ pub fn scalar_at_unchecked(array: impl AsRef<Array>, index: usize) -> Scalar {
         .with_dyn(|a| a.scalar_at().map(|s| s.scalar_at_unchecked(index)))
         .unwrap_or_else(|| vortex_panic!(NotImplemented: "scalar_at", array.encoding().id()))
 }
+
+#[cfg(test)]
+mod test {
+    use crate::array::PrimitiveArray;
+    use crate::compute::unary::{scalar_at, scalar_at_many, scalar_at_wrapping};
+
+    #[test]
+    fn negative_indices() {
+        let array = PrimitiveArray::from(vec![10i32, 42, 999, 1992]);
+        assert_eq!(scalar_at_wrapping(&array, -1).unwrap(), 1992.into());
+        assert_eq!(scalar_at_wrapping(&array, -4).unwrap(), 10.into());
+        assert_eq!(
+            scalar_at_wrapping(&array, 1).unwrap(),
+            scalar_at(&array, 1).unwrap()
+        );
+        assert!(scalar_at_wrapping(&array, -5).is_err());
+        assert!(scalar_at_wrapping(&array, 4).is_err());
+    }
+
+    #[test]
+    fn many_indices() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i64), None, Some(3), Some(4)]);
+        let indices = [3, 1, 0, 3];
+        assert_eq!(
+            scalar_at_many(&array, &indices).unwrap(),
+            indices
+                .iter()
+                .map(|idx| scalar_at(&array, *idx).unwrap())
+                .collect::<Vec<_>>()
+        );
+        assert!(scalar_at_many(&array, &[0, 4]).is_err());
+    }
+}
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use vortex_error::{vortex_bail, VortexExpect, VortexResult};
+
 use crate::array::{
     BoolEncoding, ChunkedEncoding, ConstantEncoding, ExtensionEncoding, NullEncoding,
     PrimitiveEncoding, SparseEncoding, StructEncoding, VarBinEncoding, VarBinViewEncoding,
@@ -13,15 +15,52 @@ pub struct Context {
 }
 
 impl Context {
-    pub fn with_encoding(mut self, encoding: EncodingRef) -> Self {
-        self.encodings.insert(encoding.id().code(), encoding);
-        self
+    /// Register an encoding, see [`try_with_encoding`](Self::try_with_encoding).
+    ///
+    /// # Panics
+    ///
+    /// If the encoding's id is invalid or conflicts with an already registered encoding.
+    pub fn with_encoding(self, encoding: EncodingRef) -> Self {
+        self.try_with_encoding(encoding)
+            .vortex_expect("Failed to register encoding")
+    }
+
+    /// Register encodings, see [`try_with_encoding`](Self::try_with_encoding).
+    ///
+    /// # Panics
+    ///
+    /// If an encoding's id is invalid or conflicts with an already registered encoding.
+    pub fn with_encodings<E: IntoIterator<Item = EncodingRef>>(self, encodings: E) -> Self {
+        self.try_with_encodings(encodings)
+            .vortex_expect("Failed to register encodings")
     }
 
-    pub fn with_encodings<E: IntoIterator<Item = EncodingRef>>(mut self, encodings: E) -> Self {
-        self.encodings
-            .extend(encodings.into_iter().map(|e| (e.id().code(), e)));
-        self
+    /// Register an encoding, rejecting it if its id isn't [valid](crate::encoding::EncodingId::validate) or if
+    /// another encoding is registered with the same code or name.
+    ///
+    /// Registering the same encoding again is a no-op.
+    pub fn try_with_encoding(mut self, encoding: EncodingRef) -> VortexResult<Self> {
+        let id = encoding.id();
+        id.validate()?;
+        if let Some(existing) = self
+            .encodings
+            .values()
+            .map(|e| e.id())
+            .find(|e| (e.code() == id.code() || e.as_ref() == id.as_ref()) && *e != id)
+        {
+            vortex_bail!("Encoding {id} conflicts with already registered encoding {existing}");
+        }
+        self.encodings.insert(id.code(), encoding);
+        Ok(self)
+    }
+
+    pub fn try_with_encodings<E: IntoIterator<Item = EncodingRef>>(
+        self,
+        encodings: E,
+    ) -> VortexResult<Self> {
+        encodings
+            .into_iter()
+            .try_fold(self, |ctx, encoding| ctx.try_with_encoding(encoding))
     }
 
     pub fn encodings(&self) -> impl Iterator<Item = EncodingRef> + '_ {
@@ -54,3 +93,54 @@ impl Default for Context {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex_error::VortexResult;
+
+    use super::*;
+    use crate::encoding::{ArrayEncoding, EncodingId};
+    use crate::{Array, ArrayTrait, Canonical};
+
+    #[derive(Debug)]
+    struct TestEncoding(EncodingId);
+
+    impl ArrayEncoding for TestEncoding {
+        fn id(&self) -> EncodingId {
+            self.0
+        }
+
+        fn canonicalize(&self, _array: Array) -> VortexResult<Canonical> {
+            vortex_bail!("Not implemented")
+        }
+
+        fn with_dyn(
+            &self,
+            _array: &Array,
+            _f: &mut dyn for<'b> FnMut(&'b (dyn ArrayTrait + 'b)) -> VortexResult<()>,
+        ) -> VortexResult<()> {
+            vortex_bail!("Not implemented")
+        }
+    }
+
+    static CUSTOM: TestEncoding = TestEncoding(EncodingId::new("acme.custom", 0x8001));
+    static SAME_CODE: TestEncoding = TestEncoding(EncodingId::new("acme.other", 0x8001));
+    static SAME_NAME: TestEncoding = TestEncoding(EncodingId::new("acme.custom", 0x8002));
+    static SHADOWS_BUILTIN: TestEncoding =
+        TestEncoding(EncodingId::new("vortex.primitive", 0x8003));
+
+    #[test]
+    fn rejects_conflicting_encodings() {
+        let ctx = Context::default()
+            .try_with_encoding(&CUSTOM)
+            .unwrap()
+            // registering the same encoding again is fine
+            .try_with_encoding(&CUSTOM)
+            .unwrap();
+        assert!(ctx.lookup_encoding(0x8001).is_some());
+
+        assert!(ctx.clone().try_with_encoding(&SAME_CODE).is_err());
+        assert!(ctx.clone().try_with_encoding(&SAME_NAME).is_err());
+        assert!(ctx.try_with_encoding(&SHADOWS_BUILTIN).is_err());
+    }
+}
@@ -3,9 +3,9 @@
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 
-use vortex_error::{vortex_panic, VortexResult};
+use vortex_error::{vortex_bail, vortex_panic, VortexResult};
 
-use crate::canonical::{Canonical, IntoCanonical};
+use crate::canonical::{Canonical, CanonicalOptions, IntoCanonical};
 use crate::{Array, ArrayDef, ArrayTrait};
 
 pub mod opaque;
@@ -29,6 +29,52 @@ impl EncodingId {
     pub const fn code(&self) -> u16 {
         self.1
     }
+
+    /// Whether the code is in the range reserved for encodings bundled with Vortex
+    pub const fn is_builtin(&self) -> bool {
+        self.1 <= ids::MAX_BUILTIN
+    }
+
+    /// Check that the id is well formed and in the right namespace.
+    ///
+    /// Names are a namespace and a name separated by a dot, e.g. `vortex.alp`, made of lowercase
+    /// ASCII letters, digits and underscores. Built-in codes must use one of the
+    /// [built-in namespaces](ids::BUILTIN_NAMESPACES), all other codes must use their own vendor
+    /// namespace.
+    pub fn validate(&self) -> VortexResult<()> {
+        if self.1 == ids::RESERVED {
+            vortex_bail!("Encoding {self} uses the reserved code 0");
+        }
+
+        let Some((namespace, name)) = self.0.split_once('.') else {
+            vortex_bail!("Encoding {self} must be named <namespace>.<name>");
+        };
+        let well_formed = |s: &str| {
+            s.split('.').all(|part| {
+                !part.is_empty()
+                    && part
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            })
+        };
+        if !well_formed(namespace) || !well_formed(name) {
+            vortex_bail!(
+                "Encoding {self} must be named with lowercase ASCII letters, digits and underscores"
+            );
+        }
+
+        let builtin_namespace = ids::BUILTIN_NAMESPACES.contains(&namespace);
+        if self.is_builtin() && !builtin_namespace {
+            vortex_bail!(
+                "Encoding {self} uses a built-in code, but not a built-in namespace ({})",
+                ids::BUILTIN_NAMESPACES.join(", ")
+            );
+        }
+        if !self.is_builtin() && builtin_namespace {
+            vortex_bail!("Encoding {self} uses the reserved namespace {namespace}");
+        }
+        Ok(())
+    }
 }
 
 impl Display for EncodingId {
@@ -52,6 +98,15 @@ pub trait ArrayEncoding: 'static + Sync + Send + Debug {
     /// Flatten the given array.
     fn canonicalize(&self, array: Array) -> VortexResult<Canonical>;
 
+    /// Flatten the given array in the representation chosen by `options`.
+    fn canonicalize_with(
+        &self,
+        array: Array,
+        options: CanonicalOptions,
+    ) -> VortexResult<Canonical> {
+        self.canonicalize(array)?.with_options(options)
+    }
+
     /// Unwrap the provided array into an implementation of ArrayTrait
     fn with_dyn(
         &self,
@@ -81,6 +136,11 @@ pub trait ArrayEncodingExt {
         IntoCanonical::into_canonical(typed)
     }
 
+    fn into_canonical_with(array: Array, options: CanonicalOptions) -> VortexResult<Canonical> {
+        let typed = <<Self::D as ArrayDef>::Array as TryFrom<Array>>::try_from(array)?;
+        IntoCanonical::into_canonical_with(typed, options)
+    }
+
     fn with_dyn<R, F>(array: &Array, mut f: F) -> R
     where
         F: for<'b> FnMut(&'b (dyn ArrayTrait + 'b)) -> R,
@@ -107,6 +167,12 @@ pub mod ids {
     // reserved - 0x0000
     pub(crate) const RESERVED: u16 = 0;
 
+    /// Highest code reserved for encodings bundled with Vortex
+    pub const MAX_BUILTIN: u16 = 0x0400;
+
+    /// Namespaces of the names of encodings bundled with Vortex, reserved for them
+    pub const BUILTIN_NAMESPACES: [&str; 2] = ["vortex", "fastlanes"];
+
     // Vortex built-in encodings (1 - 15)
     // built-ins first
     pub const NULL: u16 = 1;
@@ -150,7 +216,7 @@ pub mod ids {
 mod tests {
     use std::collections::HashSet;
 
-    use super::ids;
+    use super::{ids, EncodingId};
 
     #[test]
     fn test_encoding_id() {
@@ -190,10 +256,39 @@ mod tests {
         let mut ids_set = HashSet::with_capacity(all_ids.len());
         ids_set.extend(all_ids);
         assert_eq!(ids_set.len(), all_ids.len()); // no duplicates
-        assert!(ids_set.iter().max().unwrap() <= &0x0400); // no ids are greater than 1024
+        assert!(ids_set.iter().max().unwrap() <= &ids::MAX_BUILTIN); // no ids are greater than 1024
         for (i, id) in all_ids.iter().enumerate() {
             // monotonic with no gaps
             assert_eq!(i as u16, *id, "id at index {} is not equal to index", i);
         }
     }
+
+    #[test]
+    fn validate_encoding_id() {
+        EncodingId::new("vortex.alp", ids::ALP).validate().unwrap();
+        EncodingId::new("fastlanes.for", ids::FL_FOR)
+            .validate()
+            .unwrap();
+        EncodingId::new("acme.delta_rle", 0x8001)
+            .validate()
+            .unwrap();
+
+        // reserved code
+        assert!(EncodingId::new("vortex.none", ids::RESERVED)
+            .validate()
+            .is_err());
+        // no namespace
+        assert!(EncodingId::new("delta_rle", 0x8001).validate().is_err());
+        assert!(EncodingId::new(".delta_rle", 0x8001).validate().is_err());
+        // malformed name
+        assert!(EncodingId::new("acme.Delta-RLE", 0x8001)
+            .validate()
+            .is_err());
+        // built-in code outside of a built-in namespace
+        assert!(EncodingId::new("acme.delta_rle", 42).validate().is_err());
+        // custom code in a built-in namespace
+        assert!(EncodingId::new("vortex.delta_rle", 0x8001)
+            .validate()
+            .is_err());
+    }
 }
@@ -18,9 +18,13 @@ use crate::{Array, ArrayTrait, Canonical};
 #[derive(Debug, Clone, Copy)]
 pub struct OpaqueEncoding(pub u16);
 
+impl OpaqueEncoding {
+    pub(crate) const ID_NAME: &'static str = "vortex.opaque";
+}
+
 impl ArrayEncoding for OpaqueEncoding {
     fn id(&self) -> EncodingId {
-        EncodingId::new("vortex.opaque", self.0)
+        EncodingId::new(Self::ID_NAME, self.0)
     }
 
     fn canonicalize(&self, _array: Array) -> VortexResult<Canonical> {
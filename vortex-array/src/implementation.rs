@@ -134,6 +134,15 @@ macro_rules! impl_encoding {
                     <Self as $crate::encoding::ArrayEncodingExt>::into_canonical(array)
                 }
 
+                #[inline]
+                fn canonicalize_with(
+                    &self,
+                    array: $crate::Array,
+                    options: $crate::CanonicalOptions,
+                ) -> vortex_error::VortexResult<$crate::Canonical> {
+                    <Self as $crate::encoding::ArrayEncodingExt>::into_canonical_with(array, options)
+                }
+
                 #[inline]
                 fn with_dyn(
                     &self,
@@ -23,15 +23,16 @@ pub use typed::*;
 pub use view::*;
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
-use vortex_error::{vortex_panic, VortexExpect, VortexResult};
+use vortex_error::{vortex_bail, vortex_panic, VortexExpect, VortexResult};
 
 use crate::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
-use crate::compute::ArrayCompute;
+use crate::compute::{ArrayCompute, ComputeCapabilities};
+use crate::encoding::opaque::OpaqueEncoding;
 use crate::encoding::{ArrayEncodingRef, EncodingId, EncodingRef};
 use crate::iter::{ArrayIterator, ArrayIteratorAdapter};
 use crate::stats::{ArrayStatistics, ArrayStatisticsCompute};
 use crate::stream::{ArrayStream, ArrayStreamAdapter};
-use crate::validity::ArrayValidity;
+use crate::validity::{ArrayValidity, Validity};
 use crate::variants::ArrayVariants;
 
 pub mod accessor;
@@ -46,9 +47,11 @@ pub mod elementwise;
 pub mod encoding;
 mod implementation;
 pub mod iter;
+pub mod memory;
 mod metadata;
 pub mod stats;
 pub mod stream;
+mod transform;
 mod tree;
 mod typed;
 pub mod validity;
@@ -63,6 +66,11 @@ pub mod flatbuffers {
 /// A central type for all Vortex arrays, which are known length sequences of typed and possibly compressed data.
 ///
 /// This is the main entrypoint for working with in-memory Vortex data, and dispatches work over the underlying encoding or memory representations.
+///
+/// Both representations are used through the same `Array`, so readers and writers take an `Array`
+/// whichever one it holds. Owned data becomes an array with [`IntoArray`], a view read from a
+/// message or file is available with [`Array::as_view`], and either can be copied into owned data
+/// with [`ToArrayData`] or [`ArrayView::to_data`].
 #[derive(Debug, Clone)]
 pub enum Array {
     /// Owned [`Array`] with serialized metadata, backed by heap-allocated memory.
@@ -95,6 +103,38 @@ impl Array {
         }
     }
 
+    /// Which compute functions the array's encoding implements natively, rather than by
+    /// canonicalizing the array first.
+    pub fn supported_computes(&self) -> ComputeCapabilities {
+        self.with_dyn(|a| ComputeCapabilities::of(a))
+    }
+
+    /// Check the invariants of the array and all of its descendants, e.g. that buffers hold as
+    /// many values as the array's length and that patch indices are in range.
+    ///
+    /// Readers can validate deserialized arrays before returning them, catching corrupt input before
+    /// it's read. Arrays of encodings unknown to the context can't be inspected and are skipped.
+    pub fn validate(&self) -> VortexResult<()> {
+        if self.encoding().id().as_ref() == OpaqueEncoding::ID_NAME {
+            return Ok(());
+        }
+
+        // Children first, encodings may read them to check their own invariants
+        self.with_dyn(|a| {
+            a.accept(&mut ValidateVisitor(self.len()))?;
+            a.validate()
+                .map_err(|err| err.with_context(format!("Invalid {} array", self.encoding().id())))
+        })
+    }
+
+    /// The flatbuffer view backing the array, or `None` if it is owned data
+    pub fn as_view(&self) -> Option<&ArrayView> {
+        match self {
+            Self::Data(_) => None,
+            Self::View(v) => Some(v),
+        }
+    }
+
     /// Total size of the array in bytes, including all children and buffers.
     pub fn nbytes(&self) -> usize {
         self.with_dyn(|a| a.nbytes())
@@ -127,6 +167,29 @@ impl Array {
         ArrayChildrenIterator::new(self.clone())
     }
 
+    /// Visit the named children and the buffers of the array, see [`ArrayVisitor`]
+    pub fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        self.with_dyn(|a| a.accept(visitor))
+    }
+
+    /// The child arrays with the names their encoding gives them, e.g. `"indices"` and `"values"`
+    /// of a sparse array or the quoted field names of a struct array
+    pub fn named_children(&self) -> VortexResult<Vec<(String, Array)>> {
+        let mut collector = NamedChildrenCollector::default();
+        self.accept(&mut collector)?;
+        Ok(collector.children)
+    }
+
+    /// Fold `f` over the array and all of its descendants, parents before their children
+    pub fn fold<T>(
+        &self,
+        init: T,
+        mut f: impl FnMut(T, &Array) -> VortexResult<T>,
+    ) -> VortexResult<T> {
+        self.depth_first_traversal()
+            .try_fold(init, |acc, array| f(acc, &array))
+    }
+
     /// Count the number of cumulative buffers encoded by self.
     pub fn cumulative_nbuffers(&self) -> usize {
         self.children()
@@ -186,6 +249,16 @@ impl Array {
         self.encoding().id() == id
     }
 
+    /// The array as the typed array of encoding `D`, if it has that encoding.
+    ///
+    /// Cheaper than `D::Array::try_from` when the encoding may not match, which builds an error.
+    pub fn as_typed<D: ArrayDef>(&self) -> Option<D::Array> {
+        if !self.is_encoding(D::ID) {
+            return None;
+        }
+        D::Array::try_from(self.clone()).ok()
+    }
+
     #[inline]
     pub fn with_dyn<R, F>(&self, mut f: F) -> R
     where
@@ -287,6 +360,12 @@ pub trait ArrayTrait:
             .vortex_expect("Failed to get nbytes from Array");
         visitor.0
     }
+
+    /// Check the invariants of the encoding, e.g. that its buffers hold as many values as its
+    /// length. Children are checked separately, see [`Array::validate`].
+    fn validate(&self) -> VortexResult<()> {
+        Ok(())
+    }
 }
 
 pub trait ArrayDType {
@@ -300,6 +379,18 @@ pub trait ArrayLen {
     fn is_empty(&self) -> bool;
 }
 
+#[derive(Default)]
+struct NamedChildrenCollector {
+    children: Vec<(String, Array)>,
+}
+
+impl ArrayVisitor for NamedChildrenCollector {
+    fn visit_child(&mut self, name: &str, array: &Array) -> VortexResult<()> {
+        self.children.push((name.to_string(), array.clone()));
+        Ok(())
+    }
+}
+
 struct NBytesVisitor(usize);
 
 impl ArrayVisitor for NBytesVisitor {
@@ -314,6 +405,29 @@ impl ArrayVisitor for NBytesVisitor {
     }
 }
 
+struct ValidateVisitor(usize);
+
+impl ArrayVisitor for ValidateVisitor {
+    fn visit_child(&mut self, _name: &str, array: &Array) -> VortexResult<()> {
+        array.validate()
+    }
+
+    fn visit_validity(&mut self, validity: &Validity) -> VortexResult<()> {
+        if let Some(v) = validity.as_array() {
+            if v.len() != self.0 {
+                vortex_bail!(
+                    "Validity has length {}, but its array has length {}",
+                    v.len(),
+                    self.0
+                );
+            }
+            self.visit_child("validity", v)
+        } else {
+            Ok(())
+        }
+    }
+}
+
 impl Display for Array {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let prefix = match self {
@@ -0,0 +1,201 @@
+//! Accounting of the memory held by decoded arrays against a shared budget.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::{Array, Canonical, IntoCanonical};
+
+/// Budget of bytes shared by everything that reserves memory from it, e.g. the streams of all the
+/// concurrent reads of a server.
+///
+/// Cloning the pool shares the budget. Memory is taken from the pool with a [`MemoryReservation`]
+/// and given back when the reservation shrinks or is dropped.
+#[derive(Debug, Clone)]
+pub struct MemoryPool {
+    limit: usize,
+    reserved: Arc<AtomicUsize>,
+}
+
+impl MemoryPool {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            reserved: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Bytes currently held by the reservations of the pool
+    pub fn reserved(&self) -> usize {
+        self.reserved.load(Ordering::Acquire)
+    }
+
+    /// An empty reservation against this pool
+    pub fn reservation(&self) -> MemoryReservation {
+        MemoryReservation {
+            pool: self.clone(),
+            size: 0,
+        }
+    }
+
+    fn try_reserve(&self, bytes: usize) -> VortexResult<()> {
+        let reserved =
+            self.reserved
+                .fetch_update(Ordering::AcqRel, Ordering::Acquire, |reserved| {
+                    reserved
+                        .checked_add(bytes)
+                        .filter(|total| *total <= self.limit)
+                });
+        if let Err(reserved) = reserved {
+            vortex_bail!(
+                "Memory budget of {} bytes exceeded, {} bytes reserved and {} more requested",
+                self.limit,
+                reserved,
+                bytes
+            );
+        }
+        Ok(())
+    }
+
+    fn release(&self, bytes: usize) {
+        self.reserved.fetch_sub(bytes, Ordering::AcqRel);
+    }
+}
+
+/// Bytes taken from a [`MemoryPool`], returned to it when the reservation is dropped.
+#[derive(Debug)]
+pub struct MemoryReservation {
+    pool: MemoryPool,
+    size: usize,
+}
+
+impl MemoryReservation {
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn pool(&self) -> &MemoryPool {
+        &self.pool
+    }
+
+    /// Reserve `bytes` more, failing without reserving anything if the pool doesn't have them
+    pub fn try_grow(&mut self, bytes: usize) -> VortexResult<()> {
+        self.pool.try_reserve(bytes)?;
+        self.size += bytes;
+        Ok(())
+    }
+
+    /// Give back `bytes` of the reservation, at most its size
+    pub fn shrink(&mut self, bytes: usize) {
+        let bytes = bytes.min(self.size);
+        self.pool.release(bytes);
+        self.size -= bytes;
+    }
+
+    /// Grow or shrink the reservation to `size` bytes
+    pub fn try_resize(&mut self, size: usize) -> VortexResult<()> {
+        if size > self.size {
+            self.try_grow(size - self.size)
+        } else {
+            self.shrink(self.size - size);
+            Ok(())
+        }
+    }
+
+    /// Give back the whole reservation
+    pub fn free(&mut self) {
+        self.shrink(self.size);
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+impl Array {
+    /// Canonicalize the array, charging the size of the canonical array to `reservation`.
+    ///
+    /// The size of the encoded array is reserved before decoding, so an array that doesn't fit the
+    /// pool isn't decoded. The reservation is then adjusted to the size of the canonical array,
+    /// failing if the pool can't hold it, in which case the decoded array is dropped.
+    pub fn into_canonical_reserved(
+        self,
+        reservation: &mut MemoryReservation,
+    ) -> VortexResult<Canonical> {
+        let estimate = self.nbytes();
+        reservation.try_grow(estimate)?;
+        let canonical = match self.into_canonical() {
+            Ok(canonical) => canonical,
+            Err(e) => {
+                reservation.shrink(estimate);
+                return Err(e);
+            }
+        };
+        let size = Array::from(canonical.clone()).nbytes();
+        if size > estimate {
+            if let Err(e) = reservation.try_grow(size - estimate) {
+                reservation.shrink(estimate);
+                return Err(e);
+            }
+        } else {
+            reservation.shrink(estimate - size);
+        }
+        Ok(canonical)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{ConstantArray, PrimitiveArray};
+    use crate::memory::MemoryPool;
+    use crate::IntoArray;
+
+    #[test]
+    fn reservations_share_budget() {
+        let pool = MemoryPool::new(100);
+        let mut first = pool.reservation();
+        let mut second = pool.reservation();
+        first.try_grow(60).unwrap();
+        assert!(second.try_grow(60).is_err());
+        assert_eq!(second.size(), 0);
+        second.try_grow(40).unwrap();
+        assert_eq!(pool.reserved(), 100);
+
+        first.try_resize(10).unwrap();
+        assert_eq!(pool.reserved(), 50);
+        drop(second);
+        assert_eq!(pool.reserved(), 10);
+        first.free();
+        assert_eq!(pool.reserved(), 0);
+    }
+
+    #[test]
+    fn canonicalize_reserved() {
+        let array = PrimitiveArray::from(vec![1u64; 16]).into_array();
+        let pool = MemoryPool::new(200);
+        let mut reservation = pool.reservation();
+        array
+            .clone()
+            .into_canonical_reserved(&mut reservation)
+            .unwrap();
+        assert_eq!(reservation.size(), 128);
+        assert!(array.into_canonical_reserved(&mut reservation).is_err());
+        assert_eq!(pool.reserved(), 128);
+    }
+
+    #[test]
+    fn canonicalize_reserved_larger_than_encoded() {
+        let array = ConstantArray::new(1u64, 100).into_array();
+        let pool = MemoryPool::new(200);
+        let mut reservation = pool.reservation();
+        assert!(array.into_canonical_reserved(&mut reservation).is_err());
+        assert_eq!(pool.reserved(), 0);
+    }
+}
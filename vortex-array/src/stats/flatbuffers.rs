@@ -1,6 +1,7 @@
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
 use itertools::Itertools;
 use vortex_flatbuffers::WriteFlatBuffer;
+use vortex_scalar::BinaryScalar;
 
 use crate::stats::{Stat, Statistics};
 
@@ -29,6 +30,15 @@ impl WriteFlatBuffer for &dyn Statistics {
             .get(Stat::Max)
             .map(|max| max.value().write_flatbuffer(fbb));
 
+        let histogram = self
+            .get(Stat::Histogram)
+            .map(|histogram| histogram.value().write_flatbuffer(fbb));
+
+        let approx_distinct = self
+            .get(Stat::ApproxDistinct)
+            .and_then(|sketch| BinaryScalar::try_from(&sketch).ok()?.value())
+            .map(|registers| fbb.create_vector(registers.as_slice()));
+
         let stat_args = &crate::flatbuffers::ArrayStatsArgs {
             min,
             max,
@@ -40,6 +50,8 @@ impl WriteFlatBuffer for &dyn Statistics {
             null_count: self.get_as_cast::<u64>(Stat::NullCount),
             bit_width_freq,
             trailing_zero_freq,
+            histogram,
+            approx_distinct,
         };
 
         crate::flatbuffers::ArrayStats::create(fbb, stat_args)
@@ -0,0 +1,54 @@
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// 64 bit FNV-1a hash, for sketches and filters that get persisted.
+///
+/// Unlike the hashers of the standard library its output doesn't depend on the process or the
+/// version of the library, so values hashed by different writers can be compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fnv1a {
+    pub fn new() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= *b as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn known_hashes() {
+        assert_eq!(Fnv1a::new().finish(), 0xcbf29ce484222325);
+
+        let mut hash = Fnv1a::new();
+        hash.write(b"a");
+        assert_eq!(hash.finish(), 0xaf63dc4c8601ec8c);
+
+        // Writing in pieces hashes the concatenation
+        let mut pieces = Fnv1a::new();
+        pieces.write(b"foo");
+        pieces.write(b"bar");
+        let mut whole = Fnv1a::new();
+        whole.write(b"foobar");
+        assert_eq!(pieces, whole);
+    }
+}
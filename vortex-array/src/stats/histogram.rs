@@ -0,0 +1,76 @@
+use std::cmp::Ordering;
+
+use vortex_dtype::NativePType;
+
+/// Number of buckets of a [`Stat::Histogram`](crate::stats::Stat::Histogram)
+pub const HISTOGRAM_BUCKETS: usize = 16;
+
+/// Boundaries of [`HISTOGRAM_BUCKETS`] buckets that each hold about the same number of `values`,
+/// starting with the minimum and ending with the maximum.
+pub(crate) fn equi_depth_histogram<T: NativePType>(mut values: Vec<T>) -> Option<Vec<T>> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable_by(|a, b| a.compare(*b));
+    let last = values.len() - 1;
+    Some(
+        (0..=HISTOGRAM_BUCKETS)
+            .map(|bucket| values[bucket * last / HISTOGRAM_BUCKETS])
+            .collect(),
+    )
+}
+
+/// Estimated fraction of the non-null values of an array that are less than `value`, given the
+/// bucket boundaries of its histogram.
+///
+/// Values are assumed to be spread evenly within each bucket.
+pub fn histogram_fraction_below<T: NativePType>(boundaries: &[T], value: T) -> Option<f64> {
+    let buckets = boundaries.len().checked_sub(1).filter(|b| *b > 0)?;
+    let idx = boundaries.partition_point(|b| b.compare(value) == Ordering::Less);
+    if idx == 0 {
+        return Some(0.0);
+    }
+    if idx > buckets {
+        return Some(1.0);
+    }
+
+    let lower = boundaries[idx - 1].to_f64()?;
+    let upper = boundaries[idx].to_f64()?;
+    let within = if upper > lower {
+        (value.to_f64()? - lower) / (upper - lower)
+    } else {
+        0.0
+    };
+    Some(((idx - 1) as f64 + within) / buckets as f64)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stats::histogram::{equi_depth_histogram, histogram_fraction_below};
+    use crate::stats::HISTOGRAM_BUCKETS;
+
+    #[test]
+    fn uniform() {
+        let boundaries = equi_depth_histogram((0..=1600u32).rev().collect()).unwrap();
+        assert_eq!(boundaries.len(), HISTOGRAM_BUCKETS + 1);
+        assert_eq!(boundaries[0], 0);
+        assert_eq!(boundaries[1], 100);
+        assert_eq!(boundaries[HISTOGRAM_BUCKETS], 1600);
+
+        assert_eq!(histogram_fraction_below(&boundaries, 0), Some(0.0));
+        assert_eq!(histogram_fraction_below(&boundaries, 400), Some(0.25));
+        assert_eq!(histogram_fraction_below(&boundaries, 450), Some(0.28125));
+        assert_eq!(histogram_fraction_below(&boundaries, 5000), Some(1.0));
+    }
+
+    #[test]
+    fn skewed() {
+        let mut values = vec![1.0f64; 90];
+        values.extend((0..10).map(|v| 100.0 + v as f64));
+        let boundaries = equi_depth_histogram(values).unwrap();
+        // Most of the buckets hold the repeated value
+        let below = histogram_fraction_below(&boundaries, 50.0).unwrap();
+        assert!(below > 0.8 && below < 1.0, "{below}");
+        assert!(equi_depth_histogram(Vec::<i8>::new()).is_none());
+    }
+}
@@ -0,0 +1,156 @@
+use vortex_buffer::Buffer;
+use vortex_dtype::{NativePType, Nullability};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::{BinaryScalar, Scalar};
+
+use crate::stats::Fnv1a;
+
+const PRECISION: u32 = 10;
+const NUM_REGISTERS: usize = 1 << PRECISION;
+
+/// HyperLogLog sketch of the distinct values of an array, the value of
+/// [`Stat::ApproxDistinct`](crate::stats::Stat::ApproxDistinct).
+///
+/// Uses 1024 one byte registers, for a standard error of about 3%. Values are hashed with FNV-1a
+/// ([`Fnv1a`]) so that sketches written by different processes can be merged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HyperLogLog {
+    pub fn new() -> Self {
+        Self {
+            registers: vec![0; NUM_REGISTERS],
+        }
+    }
+
+    pub fn insert_bytes(&mut self, bytes: &[u8]) {
+        let mut hash = Fnv1a::new();
+        hash.write(bytes);
+        self.insert_hash(mix(hash.finish()));
+    }
+
+    pub fn insert_native<T: NativePType>(&mut self, value: T) {
+        // Positive and negative zero compare equal and have to count as one value
+        let value = if value == T::zero() { T::zero() } else { value };
+        self.insert_bytes(value.to_le_bytes().as_ref());
+    }
+
+    fn insert_hash(&mut self, hash: u64) {
+        let idx = (hash >> (64 - PRECISION)) as usize;
+        let rank = ((hash << PRECISION) | (1 << (PRECISION - 1))).leading_zeros() as u8 + 1;
+        self.registers[idx] = self.registers[idx].max(rank);
+    }
+
+    /// Combine with the sketch of another array, as if all values had been inserted into one
+    pub fn merge(&mut self, other: &Self) {
+        for (own, other) in self.registers.iter_mut().zip(&other.registers) {
+            *own = (*own).max(*other);
+        }
+    }
+
+    /// Estimated number of distinct values inserted
+    pub fn estimate(&self) -> u64 {
+        let m = NUM_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self.registers.iter().map(|r| 2f64.powi(-(*r as i32))).sum();
+        let raw = alpha * m * m / sum;
+
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        // Linear counting is more accurate while many registers are still empty
+        let estimate = if raw <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            raw
+        };
+        estimate.round() as u64
+    }
+
+    pub fn to_scalar(&self) -> Scalar {
+        Scalar::binary(
+            Buffer::from(self.registers.clone()),
+            Nullability::NonNullable,
+        )
+    }
+}
+
+impl TryFrom<&Scalar> for HyperLogLog {
+    type Error = vortex_error::VortexError;
+
+    fn try_from(value: &Scalar) -> VortexResult<Self> {
+        let Some(registers) = BinaryScalar::try_from(value)?.value() else {
+            vortex_bail!("Null HyperLogLog sketch")
+        };
+        if registers.len() != NUM_REGISTERS {
+            vortex_bail!(InvalidSerde: "HyperLogLog sketch has {} registers instead of {NUM_REGISTERS}", registers.len())
+        }
+        Ok(Self {
+            registers: registers.as_slice().to_vec(),
+        })
+    }
+}
+
+/// Finalizer of MurmurHash3, FNV leaves the high bits poorly distributed for short inputs
+fn mix(mut hash: u64) -> u64 {
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ceb9fe1a85ec53);
+    hash ^ (hash >> 33)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stats::HyperLogLog;
+
+    fn assert_close(estimate: u64, expected: u64) {
+        let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+        assert!(error < 0.1, "estimated {estimate} for {expected}");
+    }
+
+    #[test]
+    fn estimate() {
+        let mut hll = HyperLogLog::new();
+        assert_eq!(hll.estimate(), 0);
+        for v in 0..100_000u64 {
+            hll.insert_native(v % 10);
+        }
+        assert_eq!(hll.estimate(), 10);
+
+        let mut hll = HyperLogLog::new();
+        for v in 0..100_000u32 {
+            hll.insert_native(v);
+        }
+        assert_close(hll.estimate(), 100_000);
+    }
+
+    #[test]
+    fn merge() {
+        let mut first = HyperLogLog::new();
+        let mut second = HyperLogLog::new();
+        for v in 0..30_000i64 {
+            first.insert_native(v);
+            second.insert_native(v + 20_000);
+        }
+        first.merge(&second);
+        assert_close(first.estimate(), 50_000);
+
+        let roundtrip = HyperLogLog::try_from(&first.to_scalar()).unwrap();
+        assert_eq!(roundtrip, first);
+    }
+
+    #[test]
+    fn signed_zero() {
+        let mut hll = HyperLogLog::new();
+        hll.insert_native(0.0f64);
+        hll.insert_native(-0.0f64);
+        assert_eq!(hll.estimate(), 1);
+    }
+}
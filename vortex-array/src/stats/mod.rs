@@ -4,6 +4,10 @@ use std::fmt::{Display, Formatter};
 use std::hash::Hash;
 
 use enum_iterator::Sequence;
+pub use fnv::Fnv1a;
+pub(crate) use histogram::equi_depth_histogram;
+pub use histogram::{histogram_fraction_below, HISTOGRAM_BUCKETS};
+pub use hll::HyperLogLog;
 use itertools::Itertools;
 pub use statsset::*;
 use vortex_dtype::Nullability::NonNullable;
@@ -14,6 +18,9 @@ use vortex_scalar::Scalar;
 use crate::Array;
 
 pub mod flatbuffers;
+mod fnv;
+mod histogram;
+mod hll;
 mod statsset;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
@@ -28,6 +35,11 @@ pub enum Stat {
     RunCount,
     TrueCount,
     NullCount,
+    /// Boundaries of buckets holding about the same number of non-null values, only computed when
+    /// requested, see [`HISTOGRAM_BUCKETS`]
+    Histogram,
+    /// [`HyperLogLog`] sketch of the distinct non-null values, only computed when requested
+    ApproxDistinct,
 }
 
 impl Display for Stat {
@@ -43,6 +55,8 @@ impl Display for Stat {
             Self::RunCount => write!(f, "run_count"),
             Self::TrueCount => write!(f, "true_count"),
             Self::NullCount => write!(f, "null_count"),
+            Self::Histogram => write!(f, "histogram"),
+            Self::ApproxDistinct => write!(f, "approx_distinct"),
         }
     }
 }
@@ -173,6 +187,18 @@ impl dyn Statistics + '_ {
     pub fn compute_trailing_zero_freq(&self) -> Option<Vec<usize>> {
         self.compute_as::<Vec<usize>>(Stat::TrailingZeroFreq)
     }
+
+    pub fn compute_histogram<U: for<'a> TryFrom<&'a Scalar, Error = VortexError>>(
+        &self,
+    ) -> Option<Vec<U>> {
+        self.compute_as::<Vec<U>>(Stat::Histogram)
+    }
+
+    /// Estimated number of distinct non-null values
+    pub fn compute_approx_distinct(&self) -> Option<u64> {
+        self.compute_as::<HyperLogLog>(Stat::ApproxDistinct)
+            .map(|hll| hll.estimate())
+    }
 }
 
 pub fn trailing_zeros(array: &Array) -> u8 {
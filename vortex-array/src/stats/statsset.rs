@@ -7,7 +7,7 @@ use vortex_dtype::DType;
 use vortex_error::{vortex_panic, VortexError, VortexExpect};
 use vortex_scalar::Scalar;
 
-use crate::stats::Stat;
+use crate::stats::{HyperLogLog, Stat};
 
 #[derive(Debug, Clone, Default)]
 pub struct StatsSet {
@@ -100,6 +100,8 @@ impl StatsSet {
                 Stat::RunCount => self.merge_run_count(other),
                 Stat::TrueCount => self.merge_true_count(other),
                 Stat::NullCount => self.merge_null_count(other),
+                Stat::Histogram => self.merge_histogram(),
+                Stat::ApproxDistinct => self.merge_approx_distinct(other),
             }
         }
 
@@ -194,6 +196,29 @@ impl StatsSet {
         }
     }
 
+    /// Histograms of two arrays can't be combined without the values
+    fn merge_histogram(&mut self) {
+        self.values.remove(&Stat::Histogram);
+    }
+
+    fn merge_approx_distinct(&mut self, other: &Self) {
+        if let Entry::Occupied(mut e) = self.values.entry(Stat::ApproxDistinct) {
+            let merged = other.get(Stat::ApproxDistinct).and_then(|other| {
+                let mut own = HyperLogLog::try_from(e.get()).ok()?;
+                own.merge(&HyperLogLog::try_from(other).ok()?);
+                Some(own)
+            });
+            match merged {
+                Some(hll) => {
+                    e.insert(hll.to_scalar());
+                }
+                None => {
+                    e.remove();
+                }
+            }
+        }
+    }
+
     fn merge_bit_width_freq(&mut self, other: &Self) {
         self.merge_freq_stat(other, Stat::BitWidthFreq)
     }
@@ -259,7 +284,23 @@ impl IntoIterator for StatsSet {
 mod test {
     use itertools::Itertools;
 
-    use crate::stats::{Stat, StatsSet};
+    use crate::stats::{HyperLogLog, Stat, StatsSet};
+
+    #[test]
+    fn merge_approx_distinct() {
+        let sketch = |values: std::ops::Range<u32>| {
+            let mut hll = HyperLogLog::new();
+            values.for_each(|v| hll.insert_native(v));
+            StatsSet::of(Stat::ApproxDistinct, hll.to_scalar())
+        };
+        let mut first = sketch(0..10);
+        first.merge(&sketch(5..20));
+        let merged = HyperLogLog::try_from(first.get(Stat::ApproxDistinct).unwrap()).unwrap();
+        assert_eq!(merged.estimate(), 20);
+
+        first.merge(&StatsSet::new());
+        assert_eq!(first.get(Stat::ApproxDistinct), None);
+    }
 
     #[test]
     fn merge_into_min() {
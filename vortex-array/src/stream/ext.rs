@@ -1,11 +1,11 @@
 use std::future::Future;
+use std::sync::Arc;
 
-use futures_util::TryStreamExt;
 use vortex_error::VortexResult;
 
 use crate::array::ChunkedArray;
 use crate::stream::take_rows::TakeRows;
-use crate::stream::{ArrayStream, ArrayStreamAdapter};
+use crate::stream::{ArrayStream, ArrayStreamAdapter, WorkerPool};
 use crate::Array;
 
 pub trait ArrayStreamExt: ArrayStream {
@@ -13,21 +13,29 @@ pub trait ArrayStreamExt: ArrayStream {
     where
         Self: Sized,
     {
-        async {
-            let dtype = self.dtype().clone();
-            self.try_collect()
-                .await
-                .and_then(|chunks| ChunkedArray::try_new(chunks, dtype))
-        }
+        ChunkedArray::try_from_stream(self)
     }
 
     fn take_rows(self, indices: Array) -> VortexResult<impl ArrayStream>
+    where
+        Self: Sized,
+    {
+        self.take_rows_on(indices, None)
+    }
+
+    /// Like [`take_rows`](Self::take_rows), but the rows of each batch are taken on `worker_pool`
+    /// if there is one.
+    fn take_rows_on(
+        self,
+        indices: Array,
+        worker_pool: Option<Arc<dyn WorkerPool>>,
+    ) -> VortexResult<impl ArrayStream>
     where
         Self: Sized,
     {
         Ok(ArrayStreamAdapter::new(
             self.dtype().clone(),
-            TakeRows::try_new(self, indices)?,
+            TakeRows::try_new(self, indices)?.with_worker_pool(worker_pool),
         ))
     }
 }
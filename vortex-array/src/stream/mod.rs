@@ -4,12 +4,14 @@ use futures_util::Stream;
 pub use take_rows::*;
 use vortex_dtype::DType;
 use vortex_error::VortexResult;
+pub use worker_pool::*;
 
 use crate::Array;
 
 mod adapter;
 mod ext;
 mod take_rows;
+mod worker_pool;
 
 /// A stream of array chunks along with a DType.
 ///
@@ -1,24 +1,30 @@
+use std::ops::Range;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::{Context, Poll};
 
-use futures_util::{ready, Stream};
+use futures_util::future::BoxFuture;
+use futures_util::{ready, FutureExt, Stream};
+use num_traits::ToPrimitive as _;
 use pin_project::pin_project;
 use vortex_dtype::match_each_integer_ptype;
-use vortex_error::{vortex_bail, VortexResult};
-use vortex_scalar::Scalar;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
-use crate::compute::unary::subtract_scalar;
-use crate::compute::{search_sorted, slice, take, SearchSortedSide};
+use crate::array::PrimitiveArray;
+use crate::compute::{search_sorted, take, SearchSortedSide};
 use crate::stats::{ArrayStatistics, Stat};
-use crate::stream::ArrayStream;
+use crate::stream::{ArrayStream, WorkerPool};
+use crate::validity::Validity;
 use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
 
 #[pin_project]
 pub struct TakeRows<R: ArrayStream> {
     #[pin]
     reader: R,
-    indices: Array,
+    indices: PrimitiveArray,
     row_offset: usize,
+    worker_pool: Option<Arc<dyn WorkerPool>>,
+    pending: Option<BoxFuture<'static, VortexResult<Array>>>,
 }
 
 impl<R: ArrayStream> TakeRows<R> {
@@ -54,10 +60,18 @@ impl<R: ArrayStream> TakeRows<R> {
 
         Ok(Self {
             reader,
-            indices,
+            indices: indices.into_primitive()?,
             row_offset: 0,
+            worker_pool: None,
+            pending: None,
         })
     }
+
+    /// Take the rows of each batch on `worker_pool` instead of the executor polling the stream.
+    pub fn with_worker_pool(mut self, worker_pool: Option<Arc<dyn WorkerPool>>) -> Self {
+        self.worker_pool = worker_pool;
+        self
+    }
 }
 
 impl<R: ArrayStream> Stream for TakeRows<R> {
@@ -70,11 +84,18 @@ impl<R: ArrayStream> Stream for TakeRows<R> {
             return Poll::Ready(None);
         }
 
+        if let Some(pending) = this.pending.as_mut() {
+            let taken = ready!(pending.poll_unpin(cx));
+            *this.pending = None;
+            return Poll::Ready(Some(taken));
+        }
+
         while let Some(batch) = ready!(this.reader.as_mut().poll_next(cx)?) {
             let curr_offset = *this.row_offset;
-            let left = search_sorted(this.indices, curr_offset, SearchSortedSide::Left)?.to_index();
+            let left = search_sorted(this.indices.as_ref(), curr_offset, SearchSortedSide::Left)?
+                .to_index();
             let right = search_sorted(
-                this.indices,
+                this.indices.as_ref(),
                 curr_offset + batch.len(),
                 SearchSortedSide::Left,
             )?
@@ -86,15 +107,122 @@ impl<R: ArrayStream> Stream for TakeRows<R> {
                 continue;
             }
 
-            // TODO(ngates): this is probably too heavy to run on the event loop. We should spawn
-            //  onto a worker pool.
-            let indices_for_batch = slice(this.indices, left, right)?.into_primitive()?;
-            let shifted_arr = match_each_integer_ptype!(indices_for_batch.ptype(), |$T| {
-                subtract_scalar(&indices_for_batch.into_array(), &Scalar::from(curr_offset as $T))?
-            });
-            return Poll::Ready(take(&batch, &shifted_arr).map(Some).transpose());
+            let Some(worker_pool) = this.worker_pool.as_ref() else {
+                return Poll::Ready(
+                    take_range(&batch, this.indices, left..right, curr_offset)
+                        .map(Some)
+                        .transpose(),
+                );
+            };
+
+            let indices = this.indices.clone();
+            let mut pending = worker_pool.spawn_blocking(Box::new(move || {
+                take_range(&batch, &indices, left..right, curr_offset)
+            }));
+            return match pending.poll_unpin(cx) {
+                Poll::Ready(taken) => Poll::Ready(Some(taken)),
+                Poll::Pending => {
+                    *this.pending = Some(pending);
+                    Poll::Pending
+                }
+            };
         }
 
         Poll::Ready(None)
     }
 }
+
+/// Take the rows of `batch` at `indices[range]`, which are row numbers of a stream in which the
+/// batch starts at row `offset`.
+///
+/// Equivalent to slicing the indices, subtracting `offset` and taking, but the batch relative
+/// indices are computed in a single pass, allocating one array instead of three.
+pub fn take_range(
+    batch: &Array,
+    indices: &PrimitiveArray,
+    range: Range<usize>,
+    offset: usize,
+) -> VortexResult<Array> {
+    if range.end > indices.len() {
+        vortex_bail!(OutOfBounds: range.end, 0, indices.len())
+    }
+
+    let shifted = match_each_integer_ptype!(indices.ptype(), |$T| {
+        // An offset that doesn't fit the index type is past every index
+        let typed_offset = <$T>::try_from(offset).ok();
+        PrimitiveArray::from_vec(
+            indices.maybe_null_slice::<$T>()[range]
+                .iter()
+                .map(|i| {
+                    typed_offset.and_then(|o| i.checked_sub(o)).ok_or_else(|| {
+                        vortex_err!(
+                            OutOfBounds: i.to_usize().unwrap_or_default(),
+                            offset,
+                            offset + batch.len()
+                        )
+                    })
+                })
+                .collect::<VortexResult<Vec<_>>>()?,
+            Validity::NonNullable,
+        )
+    });
+    take(batch, shifted.into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use futures_util::{FutureExt, TryStreamExt};
+
+    use crate::array::{ChunkedArray, PrimitiveArray};
+    use crate::stream::{take_range, ArrayStreamExt};
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn take_from_range() {
+        let batch = PrimitiveArray::from((100i32..110).collect::<Vec<_>>());
+        let indices = PrimitiveArray::from(vec![3u16, 52, 55, 59, 61]);
+
+        let taken = take_range(batch.as_ref(), &indices, 1..4, 50)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(taken.maybe_null_slice::<i32>(), &[102, 105, 109]);
+        assert!(take_range(batch.as_ref(), &indices, 1..6, 50).is_err());
+        // Indices before the batch offset are out of bounds rather than wrapping around
+        assert!(take_range(batch.as_ref(), &indices, 0..2, 50).is_err());
+    }
+
+    #[test]
+    fn take_rows_with_narrow_indices() {
+        let chunks = (0..3)
+            .map(|c| {
+                PrimitiveArray::from((c * 200..(c + 1) * 200).collect::<Vec<i32>>()).into_array()
+            })
+            .collect::<Vec<_>>();
+        let chunked = ChunkedArray::from_iter(chunks);
+
+        for indices in [
+            PrimitiveArray::from(vec![3u8, 199, 255]).into_array(),
+            PrimitiveArray::from(vec![3i16, 199, 255]).into_array(),
+        ] {
+            let taken = chunked
+                .array_stream()
+                .take_rows(indices)
+                .unwrap()
+                .try_collect::<Vec<_>>()
+                .now_or_never()
+                .unwrap()
+                .unwrap();
+            let values = taken
+                .into_iter()
+                .flat_map(|a| {
+                    a.into_primitive()
+                        .unwrap()
+                        .maybe_null_slice::<i32>()
+                        .to_vec()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(values, vec![3, 199, 255]);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use futures_util::future::BoxFuture;
+use vortex_error::VortexResult;
+
+use crate::Array;
+
+/// CPU heavy work producing an array, to run on a [`WorkerPool`]
+pub type BlockingWork = Box<dyn FnOnce() -> VortexResult<Array> + Send>;
+
+/// Thread pool for work too heavy to run on the async executor polling a stream, e.g. tokio's
+/// blocking thread pool.
+pub trait WorkerPool: Send + Sync {
+    /// Start running `work` on the pool, returning a future resolving to its result.
+    fn spawn_blocking(&self, work: BlockingWork) -> BoxFuture<'static, VortexResult<Array>>;
+}
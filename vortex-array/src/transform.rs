@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::stats::ArrayStatistics;
+use crate::{Array, ArrayDType, ArrayData, ToArrayData};
+
+impl Array {
+    /// Rewrite the tree of encodings of the array bottom-up.
+    ///
+    /// `f` is called on every array after its children have been rewritten, and replaces it if it
+    /// returns a new array, which must have the same dtype and length. Parents of replaced arrays
+    /// are rebuilt with the same encoding and metadata around their new children, so that passes
+    /// like decoding every dictionary don't need code for each encoding that may contain one.
+    pub fn transform(&self, mut f: impl FnMut(&Array) -> Option<Array>) -> VortexResult<Array> {
+        Ok(transform_array(self, &mut f)?.unwrap_or_else(|| self.clone()))
+    }
+}
+
+/// The rewritten array, or `None` if neither it nor any of its descendants were replaced
+fn transform_array(
+    array: &Array,
+    f: &mut dyn FnMut(&Array) -> Option<Array>,
+) -> VortexResult<Option<Array>> {
+    let children = array.children();
+    let mut new_children = Vec::with_capacity(children.len());
+    let mut changed = false;
+    for child in &children {
+        match transform_array(child, f)? {
+            Some(new_child) => {
+                changed = true;
+                new_children.push(new_child);
+            }
+            None => new_children.push(child.clone()),
+        }
+    }
+
+    let rebuilt = changed
+        .then(|| {
+            let data = array.to_array_data();
+            ArrayData::try_new(
+                data.encoding(),
+                data.dtype().clone(),
+                data.len(),
+                data.metadata().clone(),
+                data.buffer().cloned(),
+                Arc::from(new_children),
+                array.statistics().to_set(),
+            )
+            .map(Array::from)
+        })
+        .transpose()?;
+
+    let current = rebuilt.as_ref().unwrap_or(array);
+    match f(current) {
+        Some(replacement) => {
+            if replacement.dtype() != current.dtype() || replacement.len() != current.len() {
+                vortex_bail!(
+                    "Replacement of {} array of type {} and length {} has type {} and length {}",
+                    current.encoding().id(),
+                    current.dtype(),
+                    current.len(),
+                    replacement.dtype(),
+                    replacement.len()
+                )
+            }
+            Ok(Some(replacement))
+        }
+        None => Ok(rebuilt),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{ChunkedArray, Primitive, PrimitiveArray, Sparse, SparseArray, StructArray};
+    use crate::variants::StructArrayTrait;
+    use crate::{Array, ArrayDef, CanonicalKind, IntoArray, IntoArrayVariant};
+
+    fn sparse(value: i32) -> Array {
+        SparseArray::try_new(
+            PrimitiveArray::from(vec![1u64]).into_array(),
+            PrimitiveArray::from(vec![value]).into_array(),
+            2,
+            0i32.into(),
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    #[test]
+    fn decode_nested_sparse() {
+        let chunked = ChunkedArray::from_iter([sparse(1), sparse(2)]).into_array();
+        let st = StructArray::from_fields(&[("a", chunked)])
+            .unwrap()
+            .into_array();
+
+        let mut visited = 0;
+        let decoded = st
+            .transform(|array| {
+                visited += 1;
+                array
+                    .is_encoding(Sparse::ID)
+                    .then(|| array.canonicalize_to(CanonicalKind::Primitive).unwrap())
+            })
+            .unwrap();
+        assert!(visited > 3);
+
+        let field = decoded.into_struct().unwrap().field(0).unwrap();
+        let chunks = ChunkedArray::try_from(field).unwrap();
+        assert!(chunks.chunks().all(|c| c.is_encoding(Primitive::ID)));
+        assert_eq!(
+            chunks.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[0, 1, 0, 2]
+        );
+
+        assert!(st
+            .transform(|array| array
+                .is_encoding(Sparse::ID)
+                .then(|| PrimitiveArray::from(vec![1i32]).into_array()))
+            .is_err());
+    }
+}
@@ -218,6 +218,14 @@ impl Validity {
             _ => self,
         }
     }
+
+    /// Convert into a non-nullable variant, failing if any of the `length` values is null
+    pub fn into_non_nullable(self, length: usize) -> VortexResult<Validity> {
+        if !self.to_logical(length).all_valid() {
+            vortex_bail!("Cannot make validity non-nullable, it contains nulls");
+        }
+        Ok(Self::NonNullable)
+    }
 }
 
 impl PartialEq for Validity {
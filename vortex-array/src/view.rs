@@ -13,7 +13,7 @@ use crate::array::visitor::ArrayVisitor;
 use crate::encoding::opaque::OpaqueEncoding;
 use crate::encoding::EncodingRef;
 use crate::stats::{Stat, Statistics, StatsSet};
-use crate::{flatbuffers as fb, Array, Context, IntoArray, ToArray};
+use crate::{flatbuffers as fb, Array, ArrayData, Context, IntoArray, ToArray, ToArrayData};
 
 /// Zero-copy view over flatbuffer-encoded array data, created without eager serialization.
 #[derive(Clone)]
@@ -150,7 +150,7 @@ impl ArrayView {
     pub fn children(&self) -> Vec<Array> {
         let mut collector = ChildrenCollector::default();
         Array::View(self.clone())
-            .with_dyn(|a| a.accept(&mut collector))
+            .accept(&mut collector)
             .vortex_expect("Failed to get children");
         collector.children
     }
@@ -166,6 +166,11 @@ impl ArrayView {
             .map(|idx| &self.buffers[idx as usize])
     }
 
+    /// Copy the array into owned data, deserializing the metadata of the whole tree of arrays.
+    pub fn to_data(&self) -> ArrayData {
+        self.to_array().to_array_data()
+    }
+
     pub fn statistics(&self) -> &dyn Statistics {
         self
     }
@@ -227,6 +232,25 @@ impl Statistics for ArrayView {
                 .trailing_zero_freq()
                 .map(|v| v.iter().collect_vec())
                 .map(|v| v.into()),
+            Stat::Histogram => {
+                let histogram = self.flatbuffer().stats()?.histogram();
+                histogram
+                    .and_then(|v| ScalarValue::try_from(v).ok())
+                    .map(|v| {
+                        Scalar::new(
+                            DType::List(
+                                Arc::new(self.dtype.with_nullability(Nullability::NonNullable)),
+                                Nullability::NonNullable,
+                            ),
+                            v,
+                        )
+                    })
+            }
+            Stat::ApproxDistinct => self
+                .flatbuffer()
+                .stats()?
+                .approx_distinct()
+                .map(|v| Scalar::binary(Buffer::from(v.bytes()), Nullability::NonNullable)),
         }
     }
 
@@ -0,0 +1,82 @@
+use core::ops::{Deref, DerefMut};
+
+use bytes::{Buf, BytesMut};
+
+use crate::Buffer;
+
+/// Alignment, in bytes, that Vortex lays out buffers at so that they can be read with SIMD kernels.
+pub const ALIGNMENT: usize = 64;
+
+/// A zero initialised, mutable byte buffer whose start address is [`ALIGNMENT`] aligned.
+///
+/// Reading serialized arrays into an aligned buffer keeps the buffers of the decoded arrays
+/// aligned, as they're written at aligned offsets.
+#[derive(Debug)]
+pub struct AlignedBuffer(BytesMut);
+
+impl AlignedBuffer {
+    /// Create an aligned buffer of `len` zero bytes.
+    pub fn zeroed(len: usize) -> Self {
+        Self::zeroed_at(len, 0)
+    }
+
+    /// Create a buffer of `len` zero bytes that starts `offset` bytes past an aligned address.
+    ///
+    /// This is used when reading from a position in a file, so that data at aligned positions of
+    /// the file ends up at aligned addresses.
+    pub fn zeroed_at(len: usize, offset: u64) -> Self {
+        let mut bytes = BytesMut::zeroed(len + ALIGNMENT - 1);
+        let skip = (bytes.as_ptr().align_offset(ALIGNMENT) + (offset % ALIGNMENT as u64) as usize)
+            % ALIGNMENT;
+        bytes.advance(skip);
+        bytes.truncate(len);
+        Self(bytes)
+    }
+
+    /// Unwrap into the underlying [`BytesMut`], e.g. to read into it.
+    pub fn into_inner(self) -> BytesMut {
+        self.0
+    }
+
+    /// Convert into an immutable [`Buffer`] without copying.
+    pub fn freeze(self) -> Buffer {
+        Buffer::from(self.0.freeze())
+    }
+}
+
+impl Deref for AlignedBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AlignedBuffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AlignedBuffer, ALIGNMENT};
+
+    #[test]
+    fn aligned() {
+        for len in [0, 1, 63, 64, 1000] {
+            let buffer = AlignedBuffer::zeroed(len);
+            assert_eq!(buffer.len(), len);
+            assert!(buffer.iter().all(|b| *b == 0));
+            assert!(buffer.freeze().is_aligned());
+        }
+    }
+
+    #[test]
+    fn aligned_at_offset() {
+        let buffer = AlignedBuffer::zeroed_at(100, 70);
+        assert_eq!(buffer.as_ptr() as usize % ALIGNMENT, 6);
+        // Position 58 of the buffer is at offset 128 of the file
+        assert_eq!(buffer[58..].as_ptr() as usize % ALIGNMENT, 0);
+    }
+}
@@ -7,14 +7,17 @@
 //! # Alignment
 //! See: `<https://github.com/spiraldb/vortex/issues/115>`
 //!
-//! We do not currently enforce any alignment guarantees on the buffer.
+//! We do not enforce any alignment guarantees on the buffer. Buffers read into an
+//! [`AlignedBuffer`] start at [`ALIGNMENT`], which can be checked with [`Buffer::is_aligned`].
 
 use core::cmp::Ordering;
 use core::ops::{Deref, Range};
 
+pub use aligned::*;
 use arrow_buffer::{ArrowNativeType, Buffer as ArrowBuffer, MutableBuffer as ArrowMutableBuffer};
 pub use string::*;
 
+mod aligned;
 mod flexbuffers;
 pub mod io_buf;
 mod string;
@@ -78,6 +81,13 @@ impl Buffer {
         }
     }
 
+    /// Whether the buffer starts at an address that is a multiple of [`ALIGNMENT`].
+    ///
+    /// Empty buffers are always considered aligned.
+    pub fn is_aligned(&self) -> bool {
+        self.is_empty() || self.as_ptr().align_offset(ALIGNMENT) == 0
+    }
+
     #[allow(clippy::same_name_method)]
     /// Access the buffer as an immutable byte slice.
     pub fn as_slice(&self) -> &[u8] {
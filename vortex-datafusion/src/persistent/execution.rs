@@ -2,6 +2,7 @@ use std::fmt;
 use std::sync::Arc;
 
 use datafusion::datasource::physical_plan::{FileScanConfig, FileStream};
+use datafusion::physical_optimizer::pruning::PruningPredicate;
 use datafusion_common::{project_schema, Result as DFResult};
 use datafusion_execution::{SendableRecordBatchStream, TaskContext};
 use datafusion_physical_expr::{EquivalenceProperties, Partitioning, PhysicalExpr};
@@ -18,6 +19,7 @@ pub struct VortexExec {
     file_scan_config: FileScanConfig,
     metrics: ExecutionPlanMetricsSet,
     predicate: Option<Arc<dyn PhysicalExpr>>,
+    pruning_predicate: Option<Arc<PruningPredicate>>,
     plan_properties: PlanProperties,
     ctx: Arc<Context>,
 }
@@ -37,10 +39,20 @@ impl VortexExec {
             ExecutionMode::Bounded,
         );
 
+        // Predicates DataFusion can't evaluate over chunk statistics just don't prune chunks
+        let pruning_predicate = predicate
+            .as_ref()
+            .and_then(|p| {
+                PruningPredicate::try_new(p.clone(), file_scan_config.file_schema.clone()).ok()
+            })
+            .filter(|p| !p.always_true())
+            .map(Arc::new);
+
         Ok(Self {
             file_scan_config,
             metrics,
             predicate,
+            pruning_predicate,
             plan_properties,
             ctx,
         })
@@ -101,6 +113,7 @@ impl ExecutionPlan for VortexExec {
             batch_size: None,
             predicate: self.predicate.clone(),
             arrow_schema,
+            pruning_predicate: self.pruning_predicate.clone(),
         };
         let stream = FileStream::new(&self.file_scan_config, partition, opener, &self.metrics)?;
 
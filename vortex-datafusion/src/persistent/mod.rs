@@ -2,3 +2,4 @@ pub mod config;
 pub mod execution;
 pub mod opener;
 pub mod provider;
+pub mod pruning;
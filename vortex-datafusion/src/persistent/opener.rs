@@ -3,17 +3,23 @@ use std::sync::Arc;
 use arrow_array::RecordBatch;
 use arrow_schema::SchemaRef;
 use datafusion::datasource::physical_plan::{FileMeta, FileOpenFuture, FileOpener};
+use datafusion::physical_optimizer::pruning::PruningPredicate;
 use datafusion_common::Result as DFResult;
+use datafusion_physical_expr::utils::collect_columns;
 use datafusion_physical_expr::PhysicalExpr;
 use futures::{FutureExt as _, StreamExt, TryStreamExt};
+use log::warn;
 use object_store::ObjectStore;
 use vortex::Context;
 use vortex_expr::datafusion::convert_expr_to_vortex;
 use vortex_serde::io::ObjectStoreReadAt;
 use vortex_serde::layouts::{
-    LayoutContext, LayoutDeserializer, LayoutReaderBuilder, Projection, RowFilter,
+    LayoutContext, LayoutDescriptorReader, LayoutDeserializer, LayoutReaderBuilder, Projection,
+    RowFilter,
 };
 
+use crate::persistent::pruning::ChunkStatistics;
+
 pub struct VortexFileOpener {
     pub ctx: Arc<Context>,
     pub object_store: Arc<dyn ObjectStore>,
@@ -21,6 +27,8 @@ pub struct VortexFileOpener {
     pub projection: Option<Vec<usize>>,
     pub predicate: Option<Arc<dyn PhysicalExpr>>,
     pub arrow_schema: SchemaRef,
+    /// Predicate over the chunk statistics of the file, chunks it rules out aren't read
+    pub pruning_predicate: Option<Arc<PruningPredicate>>,
 }
 
 impl FileOpener for VortexFileOpener {
@@ -28,10 +36,9 @@ impl FileOpener for VortexFileOpener {
         let read_at =
             ObjectStoreReadAt::new(self.object_store.clone(), file_meta.location().clone());
 
-        let mut builder = LayoutReaderBuilder::new(
-            read_at,
-            LayoutDeserializer::new(self.ctx.clone(), Arc::new(LayoutContext::default())),
-        );
+        let layout_serde =
+            LayoutDeserializer::new(self.ctx.clone(), Arc::new(LayoutContext::default()));
+        let mut builder = LayoutReaderBuilder::new(read_at.clone(), layout_serde.clone());
 
         if let Some(batch_size) = self.batch_size {
             builder = builder.with_batch_size(batch_size);
@@ -52,7 +59,37 @@ impl FileOpener for VortexFileOpener {
             builder = builder.with_projection(Projection::new(projection));
         }
 
-        Ok(async {
+        let pruning_predicate = self.pruning_predicate.clone();
+        let arrow_schema = self.arrow_schema.clone();
+        let file_size = file_meta.object_meta.size as u64;
+
+        Ok(async move {
+            if let Some(pruning_predicate) = pruning_predicate {
+                let footer = LayoutDescriptorReader::new(layout_serde)
+                    .read_footer(&read_at, file_size)
+                    .await?;
+                let columns = collect_columns(pruning_predicate.orig_expr())
+                    .into_iter()
+                    .map(|c| c.name().to_string())
+                    .collect();
+                let stats =
+                    ChunkStatistics::try_new(&read_at, &footer, &arrow_schema, &columns).await?;
+                match stats.map(|stats| pruning_predicate.prune(&stats)) {
+                    Some(Ok(keep)) => {
+                        builder = builder.with_pruned_chunks(
+                            keep.iter()
+                                .enumerate()
+                                .filter(|(_, keep)| !**keep)
+                                .map(|(chunk_idx, _)| chunk_idx),
+                        )
+                    }
+                    // Pruning is an optimization, the row filter still applies to every chunk
+                    Some(Err(e)) => warn!("Failed to prune chunks: {e}"),
+                    None => {}
+                }
+                builder = builder.with_footer(footer);
+            }
+
             Ok(Box::pin(
                 builder
                     .build()
@@ -65,3 +102,81 @@ impl FileOpener for VortexFileOpener {
         .boxed())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_schema::{DataType, Field, Schema};
+    use datafusion::datasource::physical_plan::FileOpener;
+    use datafusion::physical_optimizer::pruning::PruningPredicate;
+    use datafusion_expr::Operator;
+    use datafusion_physical_expr::expressions::{binary, col, lit};
+    use futures::TryStreamExt;
+    use object_store::memory::InMemory;
+    use object_store::path::Path;
+    use object_store::ObjectStore;
+    use vortex::array::{ChunkedArray, PrimitiveArray, StructArray};
+    use vortex::{Context, IntoArray};
+    use vortex_serde::layouts::LayoutWriter;
+
+    use crate::persistent::opener::VortexFileOpener;
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn open_unaligned_chunks() {
+        let a = ChunkedArray::from_iter([
+            PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+            PrimitiveArray::from(vec![4i32, 5, 6]).into_array(),
+        ])
+        .into_array();
+        let b = ChunkedArray::from_iter([
+            PrimitiveArray::from(vec![1i32, 2]).into_array(),
+            PrimitiveArray::from(vec![3i32, 4]).into_array(),
+            PrimitiveArray::from(vec![5i32, 6]).into_array(),
+        ])
+        .into_array();
+        let st = StructArray::from_fields(&[("a", a), ("b", b)]).unwrap();
+        let written = LayoutWriter::new(Vec::new())
+            .with_pruning_stats(&["a", "b"])
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+
+        let object_store = Arc::new(InMemory::new());
+        let path = Path::from("unaligned.vortex");
+        object_store.put(&path, written.into()).await.unwrap();
+        let object_meta = object_store.head(&path).await.unwrap();
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let predicate =
+            binary(col("a", &schema).unwrap(), Operator::Gt, lit(4i32), &schema).unwrap();
+        let opener = VortexFileOpener {
+            ctx: Arc::new(Context::default()),
+            object_store,
+            batch_size: None,
+            projection: None,
+            predicate: None,
+            arrow_schema: schema.clone(),
+            pruning_predicate: Some(Arc::new(
+                PruningPredicate::try_new(predicate, schema).unwrap(),
+            )),
+        };
+
+        let batches = opener
+            .open(object_meta.into())
+            .unwrap()
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 6);
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::{HashMap, HashSet};
+
+use arrow_array::{ArrayRef, BooleanArray};
+use arrow_schema::{DataType, SchemaRef};
+use datafusion::arrow::compute::cast;
+use datafusion::physical_optimizer::pruning::PruningStatistics;
+use datafusion_common::{Column, ScalarValue};
+use log::warn;
+use vortex::array::StructArray;
+use vortex::variants::StructArrayTrait;
+use vortex::IntoCanonical;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_serde::io::VortexReadAt;
+use vortex_serde::layouts::LayoutDescriptor;
+
+/// Min, max and null count of every chunk of a Vortex file, read from the metadata tables of its
+/// columns, for DataFusion's [`PruningPredicate`] to skip chunks with.
+///
+/// [`PruningPredicate`]: datafusion::physical_optimizer::pruning::PruningPredicate
+pub struct ChunkStatistics {
+    num_chunks: usize,
+    columns: HashMap<String, ColumnChunkStatistics>,
+}
+
+#[derive(Default)]
+struct ColumnChunkStatistics {
+    min: Option<ArrayRef>,
+    max: Option<ArrayRef>,
+    null_count: Option<ArrayRef>,
+}
+
+impl ChunkStatistics {
+    /// Read the statistics of the named columns, converted to the types of the columns in `schema`.
+    ///
+    /// Chunks are pruned by their index in every column, so there are no statistics if the columns
+    /// are chunked differently.
+    pub async fn try_new<R: VortexReadAt>(
+        read: &R,
+        footer: &LayoutDescriptor,
+        schema: &SchemaRef,
+        columns: &HashSet<String>,
+    ) -> VortexResult<Option<Self>> {
+        let DType::Struct(st, _) = footer.dtype()? else {
+            vortex_bail!("Expected a struct dtype, found {}", footer.dtype()?)
+        };
+
+        let mut num_chunks = None;
+        let mut chunk_stats = HashMap::new();
+        for name in columns {
+            let (Some(column_idx), Ok(field)) = (st.find_name(name), schema.field_with_name(name))
+            else {
+                continue;
+            };
+            let Some(table) = footer.chunk_metadata(read, column_idx).await? else {
+                continue;
+            };
+            if *num_chunks.get_or_insert(table.len()) != table.len() {
+                warn!("Columns have a different number of chunks, chunks will not be pruned");
+                return Ok(None);
+            }
+
+            chunk_stats.insert(
+                name.clone(),
+                ColumnChunkStatistics {
+                    min: stats_field(&table, "min", field.data_type())?,
+                    max: stats_field(&table, "max", field.data_type())?,
+                    null_count: stats_field(&table, "null_count", &DataType::UInt64)?,
+                },
+            );
+        }
+
+        Ok(Some(Self {
+            num_chunks: num_chunks.unwrap_or_default(),
+            columns: chunk_stats,
+        }))
+    }
+}
+
+fn stats_field(
+    table: &StructArray,
+    name: &str,
+    data_type: &DataType,
+) -> VortexResult<Option<ArrayRef>> {
+    table
+        .field_by_name(name)
+        .map(|field| {
+            let arrow = field.into_canonical()?.into_arrow()?;
+            Ok(cast(&arrow, data_type)?)
+        })
+        .transpose()
+}
+
+impl PruningStatistics for ChunkStatistics {
+    fn min_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.columns.get(&column.name)?.min.clone()
+    }
+
+    fn max_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.columns.get(&column.name)?.max.clone()
+    }
+
+    fn num_containers(&self) -> usize {
+        self.num_chunks
+    }
+
+    fn null_counts(&self, column: &Column) -> Option<ArrayRef> {
+        self.columns.get(&column.name)?.null_count.clone()
+    }
+
+    fn row_counts(&self, _column: &Column) -> Option<ArrayRef> {
+        None
+    }
+
+    fn contained(&self, _column: &Column, _values: &HashSet<ScalarValue>) -> Option<BooleanArray> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    use arrow_schema::{DataType, Field, Schema};
+    use datafusion::physical_optimizer::pruning::PruningPredicate;
+    use datafusion_expr::Operator;
+    use datafusion_physical_expr::expressions::{binary, col, lit};
+    use vortex::array::{ChunkedArray, PrimitiveArray, StructArray};
+    use vortex::IntoArray;
+    use vortex_serde::layouts::{LayoutDescriptorReader, LayoutDeserializer, LayoutWriter};
+
+    use crate::persistent::pruning::ChunkStatistics;
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn prune_chunks() {
+        let numbers = ChunkedArray::from_iter([
+            PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+            PrimitiveArray::from(vec![10i32, 11, 12]).into_array(),
+            PrimitiveArray::from(vec![20i32, 21, 22]).into_array(),
+        ])
+        .into_array();
+        let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+        let written = LayoutWriter::new(Vec::new())
+            .with_pruning_stats(&["numbers"])
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+        let footer = LayoutDescriptorReader::new(LayoutDeserializer::default())
+            .read_footer(&written, written.len() as u64)
+            .await
+            .unwrap();
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "numbers",
+            DataType::Int32,
+            false,
+        )]));
+        let stats = ChunkStatistics::try_new(
+            &written,
+            &footer,
+            &schema,
+            &HashSet::from(["numbers".to_string()]),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        let predicate = binary(
+            col("numbers", &schema).unwrap(),
+            Operator::Gt,
+            lit(11i32),
+            &schema,
+        )
+        .unwrap();
+        let pruning_predicate = PruningPredicate::try_new(predicate, schema).unwrap();
+        assert_eq!(
+            pruning_predicate.prune(&stats).unwrap(),
+            vec![false, true, true]
+        );
+    }
+}
@@ -3,6 +3,7 @@
 pub use dtype::*;
 pub use extension::*;
 pub use half;
+pub use mismatch::*;
 pub use nullability::*;
 pub use ptype::*;
 
@@ -11,6 +12,7 @@ mod arbitrary;
 mod dtype;
 mod extension;
 pub mod field;
+mod mismatch;
 mod nullability;
 mod ptype;
 mod serde;
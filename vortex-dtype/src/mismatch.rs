@@ -0,0 +1,255 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::field::{Field, FieldPath};
+use crate::{DType, ExtDType, FieldNames, Nullability, PType};
+
+/// Where and how a dtype differs from the one it was expected to match, see
+/// [`DType::is_compatible_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DTypeMismatch {
+    /// Path of the nested field that differs, empty if the types differ at the top level.
+    ///
+    /// Fields of structs are named, the element type of a list appears as index 0.
+    pub path: FieldPath,
+    pub kind: DTypeMismatchKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DTypeMismatchKind {
+    /// The types are of different kinds, e.g. a struct and a primitive
+    Type {
+        expected: DType,
+        actual: DType,
+    },
+    Nullability {
+        expected: Nullability,
+        actual: Nullability,
+    },
+    PType {
+        expected: PType,
+        actual: PType,
+    },
+    /// Structs with different fields or fields in a different order
+    FieldNames {
+        expected: FieldNames,
+        actual: FieldNames,
+    },
+    Extension {
+        expected: ExtDType,
+        actual: ExtDType,
+    },
+}
+
+impl DType {
+    /// Check that `other` is the same type as `self`, reporting the first difference otherwise.
+    ///
+    /// Nested types are compared field by field, so that the mismatch points to the innermost
+    /// field that differs.
+    pub fn is_compatible_with(&self, other: &DType) -> Result<(), DTypeMismatch> {
+        let mut path = Vec::new();
+        compare(self, other, &mut path).map_err(|kind| DTypeMismatch {
+            path: path.into(),
+            kind,
+        })
+    }
+}
+
+fn compare(
+    expected: &DType,
+    actual: &DType,
+    path: &mut Vec<Field>,
+) -> Result<(), DTypeMismatchKind> {
+    match (expected, actual) {
+        (DType::Null, DType::Null) => {}
+        (DType::Bool(_), DType::Bool(_))
+        | (DType::Utf8(_), DType::Utf8(_))
+        | (DType::Binary(_), DType::Binary(_)) => {}
+        (DType::Primitive(e, _), DType::Primitive(a, _)) => {
+            if e != a {
+                return Err(DTypeMismatchKind::PType {
+                    expected: *e,
+                    actual: *a,
+                });
+            }
+        }
+        (DType::Extension(e, _), DType::Extension(a, _)) => {
+            if e != a {
+                return Err(DTypeMismatchKind::Extension {
+                    expected: e.clone(),
+                    actual: a.clone(),
+                });
+            }
+        }
+        (DType::Struct(e, _), DType::Struct(a, _)) => {
+            if e.names() != a.names() {
+                return Err(DTypeMismatchKind::FieldNames {
+                    expected: e.names().clone(),
+                    actual: a.names().clone(),
+                });
+            }
+            for ((name, e), a) in e
+                .names()
+                .iter()
+                .zip(e.dtypes().iter())
+                .zip(a.dtypes().iter())
+            {
+                path.push(Field::Name(name.to_string()));
+                compare(e, a, path)?;
+                path.pop();
+            }
+        }
+        (DType::List(e, _), DType::List(a, _)) => {
+            path.push(Field::Index(0));
+            compare(e, a, path)?;
+            path.pop();
+        }
+        _ => {
+            return Err(DTypeMismatchKind::Type {
+                expected: expected.clone(),
+                actual: actual.clone(),
+            })
+        }
+    }
+
+    if expected.nullability() != actual.nullability() {
+        return Err(DTypeMismatchKind::Nullability {
+            expected: expected.nullability(),
+            actual: actual.nullability(),
+        });
+    }
+    Ok(())
+}
+
+impl Display for DTypeMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.path.path().is_empty() {
+            write!(f, "field {}: ", self.path)?;
+        }
+        Display::fmt(&self.kind, f)
+    }
+}
+
+impl Display for DTypeMismatchKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Type { expected, actual } => write!(f, "expected {expected} but got {actual}"),
+            Self::Nullability { expected, actual } => write!(
+                f,
+                "expected {} but got {}",
+                describe_nullability(*expected),
+                describe_nullability(*actual)
+            ),
+            Self::PType { expected, actual } => {
+                write!(f, "expected primitive {expected} but got {actual}")
+            }
+            Self::FieldNames { expected, actual } => write!(
+                f,
+                "expected fields [{}] but got [{}]",
+                expected.join(", "),
+                actual.join(", ")
+            ),
+            Self::Extension { expected, actual } => write!(
+                f,
+                "expected extension {} but got {}",
+                DType::Extension(expected.clone(), Nullability::NonNullable),
+                DType::Extension(actual.clone(), Nullability::NonNullable)
+            ),
+        }
+    }
+}
+
+fn describe_nullability(nullability: Nullability) -> &'static str {
+    match nullability {
+        Nullability::NonNullable => "non-nullable",
+        Nullability::Nullable => "nullable",
+    }
+}
+
+impl Error for DTypeMismatch {}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::field::{Field, FieldPath};
+    use crate::{DType, DTypeMismatch, DTypeMismatchKind, Nullability, PType, StructDType};
+
+    fn st(fields: &[(&str, DType)]) -> DType {
+        DType::Struct(
+            StructDType::new(
+                fields.iter().map(|(n, _)| (*n).into()).collect(),
+                fields.iter().map(|(_, d)| d.clone()).collect(),
+            ),
+            Nullability::NonNullable,
+        )
+    }
+
+    #[test]
+    fn identical() {
+        let dtype = st(&[
+            ("a", DType::Primitive(PType::I32, Nullability::Nullable)),
+            (
+                "b",
+                DType::List(
+                    Arc::new(DType::Utf8(Nullability::NonNullable)),
+                    Nullability::Nullable,
+                ),
+            ),
+        ]);
+        assert_eq!(dtype.is_compatible_with(&dtype.clone()), Ok(()));
+    }
+
+    #[test]
+    fn nested_nullability() {
+        let inner = |n| st(&[("x", DType::Bool(n))]);
+        let expected = st(&[("a", inner(Nullability::NonNullable))]);
+        let actual = st(&[("a", inner(Nullability::Nullable))]);
+
+        let mismatch = expected.is_compatible_with(&actual).unwrap_err();
+        assert_eq!(
+            mismatch,
+            DTypeMismatch {
+                path: FieldPath::from(vec![Field::from("a"), Field::from("x")]),
+                kind: DTypeMismatchKind::Nullability {
+                    expected: Nullability::NonNullable,
+                    actual: Nullability::Nullable,
+                },
+            }
+        );
+        assert_eq!(
+            mismatch.to_string(),
+            "field $a.$x: expected non-nullable but got nullable"
+        );
+    }
+
+    #[test]
+    fn list_element_ptype() {
+        let list = |p| {
+            DType::List(
+                Arc::new(DType::Primitive(p, Nullability::NonNullable)),
+                Nullability::NonNullable,
+            )
+        };
+        let mismatch = list(PType::I32)
+            .is_compatible_with(&list(PType::I64))
+            .unwrap_err();
+        assert_eq!(mismatch.path, FieldPath::from(Field::Index(0)));
+        assert_eq!(
+            mismatch.to_string(),
+            "field [0]: expected primitive i32 but got i64"
+        );
+    }
+
+    #[test]
+    fn field_names_and_kinds() {
+        let a = DType::Primitive(PType::U8, Nullability::NonNullable);
+        let mismatch = st(&[("a", a.clone())])
+            .is_compatible_with(&st(&[("b", a.clone())]))
+            .unwrap_err();
+        assert_eq!(mismatch.to_string(), "expected fields [a] but got [b]");
+
+        let mismatch = st(&[("a", a.clone())]).is_compatible_with(&a).unwrap_err();
+        assert!(matches!(mismatch.kind, DTypeMismatchKind::Type { .. }));
+    }
+}
@@ -2,12 +2,12 @@ use std::any::Any;
 use std::collections::HashSet;
 use std::sync::Arc;
 
-use vortex::compute::{and, compare, or, Operator as ArrayOperator};
+use vortex::compute::{and, compare, compare_scalar, or, Operator as ArrayOperator};
 use vortex::Array;
 use vortex_dtype::field::Field;
 use vortex_error::VortexResult;
 
-use crate::{unbox_any, Operator, VortexExpr};
+use crate::{unbox_any, Literal, Operator, VortexExpr};
 
 #[derive(Debug, Clone)]
 pub struct BinaryExpr {
@@ -32,6 +32,18 @@ impl BinaryExpr {
     pub fn op(&self) -> Operator {
         self.operator
     }
+
+    fn compare_operator(&self) -> Option<ArrayOperator> {
+        match self.operator {
+            Operator::Eq => Some(ArrayOperator::Eq),
+            Operator::NotEq => Some(ArrayOperator::NotEq),
+            Operator::Lt => Some(ArrayOperator::Lt),
+            Operator::Lte => Some(ArrayOperator::Lte),
+            Operator::Gt => Some(ArrayOperator::Gt),
+            Operator::Gte => Some(ArrayOperator::Gte),
+            Operator::And | Operator::Or => None,
+        }
+    }
 }
 
 impl VortexExpr for BinaryExpr {
@@ -40,6 +52,16 @@ impl VortexExpr for BinaryExpr {
     }
 
     fn evaluate(&self, batch: &Array) -> VortexResult<Array> {
+        if let Some(operator) = self.compare_operator() {
+            // Compare against a literal without expanding it to the length of the batch
+            if let Some(literal) = self.rhs.as_any().downcast_ref::<Literal>() {
+                return compare_scalar(self.lhs.evaluate(batch)?, literal.value(), operator);
+            }
+            if let Some(literal) = self.lhs.as_any().downcast_ref::<Literal>() {
+                return compare_scalar(self.rhs.evaluate(batch)?, literal.value(), operator.swap());
+            }
+        }
+
         let lhs = self.lhs.evaluate(batch)?;
         let rhs = self.rhs.evaluate(batch)?;
 
@@ -300,6 +300,8 @@ impl<'a> ArrayStats<'a> {
   pub const VT_NULL_COUNT: flatbuffers::VOffsetT = 18;
   pub const VT_BIT_WIDTH_FREQ: flatbuffers::VOffsetT = 20;
   pub const VT_TRAILING_ZERO_FREQ: flatbuffers::VOffsetT = 22;
+  pub const VT_HISTOGRAM: flatbuffers::VOffsetT = 24;
+  pub const VT_APPROX_DISTINCT: flatbuffers::VOffsetT = 26;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -314,6 +316,8 @@ impl<'a> ArrayStats<'a> {
     if let Some(x) = args.null_count { builder.add_null_count(x); }
     if let Some(x) = args.true_count { builder.add_true_count(x); }
     if let Some(x) = args.run_count { builder.add_run_count(x); }
+    if let Some(x) = args.approx_distinct { builder.add_approx_distinct(x); }
+    if let Some(x) = args.histogram { builder.add_histogram(x); }
     if let Some(x) = args.trailing_zero_freq { builder.add_trailing_zero_freq(x); }
     if let Some(x) = args.bit_width_freq { builder.add_bit_width_freq(x); }
     if let Some(x) = args.max { builder.add_max(x); }
@@ -395,6 +399,20 @@ impl<'a> ArrayStats<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u64>>>(ArrayStats::VT_TRAILING_ZERO_FREQ, None)}
   }
+  #[inline]
+  pub fn histogram(&self) -> Option<ScalarValue<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<ScalarValue>>(ArrayStats::VT_HISTOGRAM, None)}
+  }
+  #[inline]
+  pub fn approx_distinct(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ArrayStats::VT_APPROX_DISTINCT, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for ArrayStats<'_> {
@@ -414,6 +432,8 @@ impl flatbuffers::Verifiable for ArrayStats<'_> {
      .visit_field::<u64>("null_count", Self::VT_NULL_COUNT, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u64>>>("bit_width_freq", Self::VT_BIT_WIDTH_FREQ, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u64>>>("trailing_zero_freq", Self::VT_TRAILING_ZERO_FREQ, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<ScalarValue>>("histogram", Self::VT_HISTOGRAM, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("approx_distinct", Self::VT_APPROX_DISTINCT, false)?
      .finish();
     Ok(())
   }
@@ -429,6 +449,8 @@ pub struct ArrayStatsArgs<'a> {
     pub null_count: Option<u64>,
     pub bit_width_freq: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u64>>>,
     pub trailing_zero_freq: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u64>>>,
+    pub histogram: Option<flatbuffers::WIPOffset<ScalarValue<'a>>>,
+    pub approx_distinct: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
 }
 impl<'a> Default for ArrayStatsArgs<'a> {
   #[inline]
@@ -444,6 +466,8 @@ impl<'a> Default for ArrayStatsArgs<'a> {
       null_count: None,
       bit_width_freq: None,
       trailing_zero_freq: None,
+      histogram: None,
+      approx_distinct: None,
     }
   }
 }
@@ -494,6 +518,14 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ArrayStatsBuilder<'a, 'b, A> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ArrayStats::VT_TRAILING_ZERO_FREQ, trailing_zero_freq);
   }
   #[inline]
+  pub fn add_histogram(&mut self, histogram: flatbuffers::WIPOffset<ScalarValue<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<ScalarValue>>(ArrayStats::VT_HISTOGRAM, histogram);
+  }
+  #[inline]
+  pub fn add_approx_distinct(&mut self, approx_distinct: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ArrayStats::VT_APPROX_DISTINCT, approx_distinct);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ArrayStatsBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     ArrayStatsBuilder {
@@ -521,6 +553,8 @@ impl core::fmt::Debug for ArrayStats<'_> {
       ds.field("null_count", &self.null_count());
       ds.field("bit_width_freq", &self.bit_width_freq());
       ds.field("trailing_zero_freq", &self.trailing_zero_freq());
+      ds.field("histogram", &self.histogram());
+      ds.field("approx_distinct", &self.approx_distinct());
       ds.finish()
   }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use vortex::array::PrimitiveArray;
+use vortex::array::Primitive;
 use vortex::encoding::EncodingRef;
 use vortex::{Array, ArrayDef, IntoArray};
 use vortex_alp::{
@@ -27,7 +27,7 @@ impl EncodingCompressor for ALPCompressor {
 
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         // Only support primitive arrays
-        let parray = PrimitiveArray::try_from(array).ok()?;
+        let parray = array.as_typed::<Primitive>()?;
 
         // Only supports f32 and f64
         if !matches!(parray.ptype(), PType::F32 | PType::F64) {
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use vortex::array::PrimitiveArray;
+use vortex::array::{Primitive, PrimitiveArray};
 use vortex::encoding::EncodingRef;
 use vortex::stats::ArrayStatistics;
 use vortex::{Array, IntoArray};
@@ -54,7 +54,7 @@ impl EncodingCompressor for BitPackedCompressor {
 
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         // Only support primitive arrays
-        let parray = PrimitiveArray::try_from(array).ok()?;
+        let parray = array.as_typed::<Primitive>()?;
 
         // Only supports unsigned ints
         if !parray.ptype().is_unsigned_int() {
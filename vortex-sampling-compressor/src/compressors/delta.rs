@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use vortex::array::PrimitiveArray;
+use vortex::array::{Primitive, PrimitiveArray};
 use vortex::encoding::EncodingRef;
 use vortex::{Array, ArrayDef, IntoArray};
 use vortex_error::VortexResult;
@@ -23,7 +23,7 @@ impl EncodingCompressor for DeltaCompressor {
 
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         // Only support primitive arrays
-        let parray = PrimitiveArray::try_from(array).ok()?;
+        let parray = array.as_typed::<Primitive>()?;
 
         // Only supports ints
         if !parray.ptype().is_unsigned_int() {
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use vortex::array::PrimitiveArray;
+use vortex::array::Primitive;
 use vortex::encoding::EncodingRef;
 use vortex::stats::{trailing_zeros, ArrayStatistics};
 use vortex::validity::ArrayValidity;
@@ -26,7 +26,7 @@ impl EncodingCompressor for FoRCompressor {
 
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         // Only support primitive arrays
-        let parray = PrimitiveArray::try_from(array).ok()?;
+        let parray = array.as_typed::<Primitive>()?;
 
         // Only supports integers
         if !parray.ptype().is_int() {
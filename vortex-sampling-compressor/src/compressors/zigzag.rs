@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use vortex::array::PrimitiveArray;
+use vortex::array::{Primitive, PrimitiveArray};
 use vortex::encoding::EncodingRef;
 use vortex::stats::{ArrayStatistics, Stat};
 use vortex::{Array, ArrayDef, IntoArray};
@@ -24,7 +24,7 @@ impl EncodingCompressor for ZigZagCompressor {
 
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         // Only support primitive arrays
-        let parray = PrimitiveArray::try_from(array).ok()?;
+        let parray = array.as_typed::<Primitive>()?;
 
         // Only supports signed integers
         if !parray.ptype().is_signed_int() {
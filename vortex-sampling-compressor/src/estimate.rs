@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use vortex::array::ChunkedArray;
+use vortex::compute::slice;
+use vortex::{Array, ArrayDType, IntoCanonical};
+use vortex_dtype::FieldName;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::sampling::stratified_slices;
+use crate::{CompressConfig, SamplingCompressor, DEFAULT_COMPRESSORS};
+
+/// Estimate the number of bytes `array` would occupy once compressed, without compressing all of it.
+///
+/// Compression is run over a stratified sample of the array, using the sample size and count from
+/// `config`, and the compressed size of the sample is extrapolated to the full length. Struct arrays
+/// are estimated column by column, see [`estimate_column_sizes`].
+pub fn estimate_compressed_size(array: &Array, config: &CompressConfig) -> VortexResult<usize> {
+    if array.dtype().is_struct() {
+        Ok(estimate_column_sizes(array, config)?
+            .into_iter()
+            .map(|(_, nbytes)| nbytes)
+            .sum())
+    } else {
+        estimate_array(array, config)
+    }
+}
+
+/// Estimate the compressed size of every column of a struct array.
+///
+/// Returns the estimated number of bytes for each field, in field order.
+pub fn estimate_column_sizes(
+    array: &Array,
+    config: &CompressConfig,
+) -> VortexResult<Vec<(FieldName, usize)>> {
+    let Some(struct_dtype) = array.dtype().as_struct() else {
+        vortex_bail!("Expected a struct array, found {}", array.dtype());
+    };
+
+    array.with_dyn(|a| {
+        let struct_array = a.as_struct_array().ok_or_else(|| {
+            vortex_err!(
+                "{} does not implement struct variant",
+                array.encoding().id()
+            )
+        })?;
+        struct_dtype
+            .names()
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                let field = struct_array
+                    .field(idx)
+                    .ok_or_else(|| vortex_err!("Missing field {} of struct array", name))?;
+                Ok((name.clone(), estimate_array(&field, config)?))
+            })
+            .collect()
+    })
+}
+
+fn estimate_array(array: &Array, config: &CompressConfig) -> VortexResult<usize> {
+    if array.is_empty() {
+        return Ok(0);
+    }
+
+    let compressor =
+        SamplingCompressor::new_with_options(HashSet::from(*DEFAULT_COMPRESSORS), config.clone());
+
+    if array.len() <= config.sample_size as usize * config.sample_count as usize {
+        return Ok(compressor.compress(array, None)?.nbytes());
+    }
+
+    let mut rng = StdRng::seed_from_u64(config.rng_seed);
+    let sample: Array = ChunkedArray::try_new(
+        stratified_slices(
+            array.len(),
+            config.sample_size,
+            config.sample_count,
+            &mut rng,
+        )
+        .into_iter()
+        .map(|(start, stop)| slice(array, start, stop))
+        .collect::<VortexResult<Vec<Array>>>()?,
+        array.dtype().clone(),
+    )?
+    .into_canonical()?
+    .into();
+
+    let compressed = compressor.compress(&sample, None)?.nbytes();
+    Ok((compressed as f64 * array.len() as f64 / sample.len() as f64).ceil() as usize)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{PrimitiveArray, StructArray};
+    use vortex::validity::Validity;
+    use vortex::IntoArray;
+
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn estimate_struct_columns() {
+        let len = 65536;
+        let ints =
+            PrimitiveArray::from((0..len).map(|i| i as i64).collect::<Vec<_>>()).into_array();
+        let constant = PrimitiveArray::from(vec![7u32; len]).into_array();
+        let array = StructArray::try_new(
+            ["ints".into(), "constant".into()].into(),
+            vec![ints.clone(), constant.clone()],
+            len,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let config = CompressConfig::default();
+        let columns = estimate_column_sizes(&array, &config).unwrap();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0].0.as_ref(), "ints");
+        assert!(columns[0].1 < ints.nbytes());
+        assert_eq!(columns[1].0.as_ref(), "constant");
+        assert!(columns[1].1 < constant.nbytes() / 100);
+
+        let total = estimate_compressed_size(&array, &config).unwrap();
+        assert_eq!(total, columns[0].1 + columns[1].1);
+        assert!(estimate_column_sizes(&ints, &config).is_err());
+    }
+}
@@ -13,14 +13,14 @@ use rand::SeedableRng;
 use vortex::array::{ChunkedArray, Constant};
 use vortex::compress::{check_dtype_unchanged, check_validity_unchanged, CompressionStrategy};
 use vortex::compute::slice;
-use vortex::encoding::EncodingRef;
+use vortex::encoding::{EncodingId, EncodingRef};
 use vortex::validity::Validity;
 use vortex::{Array, ArrayDType, ArrayDef, Context, IntoCanonical};
 use vortex_alp::{ALPEncoding, ALPRDEncoding};
 use vortex_bytebool::ByteBoolEncoding;
 use vortex_datetime_parts::DateTimePartsEncoding;
 use vortex_dict::DictEncoding;
-use vortex_error::{VortexExpect as _, VortexResult};
+use vortex_error::{vortex_err, VortexExpect as _, VortexResult};
 use vortex_fastlanes::{BitPackedEncoding, DeltaEncoding, FoREncoding};
 use vortex_fsst::FSSTEncoding;
 use vortex_roaring::{RoaringBoolEncoding, RoaringIntEncoding};
@@ -43,8 +43,13 @@ use crate::sampling::stratified_slices;
 pub mod arbitrary;
 pub mod compressors;
 mod constants;
+mod estimate;
+mod report;
 mod sampling;
 
+pub use estimate::{estimate_column_sizes, estimate_compressed_size};
+pub use report::{ColumnReport, CompressReport};
+
 lazy_static! {
     pub static ref DEFAULT_COMPRESSORS: [CompressorRef<'static>; 9] = [
         &ALPCompressor as CompressorRef,
@@ -157,6 +162,24 @@ impl CompressionStrategy for SamplingCompressor<'_> {
         Self::compress(self, array, None).map(compressors::CompressedArray::into_array)
     }
 
+    fn compress_with_encoding(&self, array: &Array, encoding: EncodingId) -> VortexResult<Array> {
+        let compressor = self
+            .compressors
+            .iter()
+            .find(|c| c.id() == encoding.as_ref())
+            .ok_or_else(|| vortex_err!("No compressor for the {encoding} encoding"))?;
+        let compressor = compressor.can_compress(array).ok_or_else(|| {
+            vortex_err!(
+                "{encoding} can't compress {} array of type {}",
+                array.encoding().id(),
+                array.dtype()
+            )
+        })?;
+        compressor
+            .compress(array, None, self.for_compressor(compressor))
+            .map(compressors::CompressedArray::into_array)
+    }
+
     fn used_encodings(&self) -> HashSet<EncodingRef> {
         self.compressors
             .iter()
@@ -332,6 +355,11 @@ impl<'a> SamplingCompressor<'a> {
             candidates.retain(|&compression| compression.id() != array.encoding().id().as_ref());
         }
 
+        // Candidates are scored on the sample and the best scoring one wins, ties go to the first
+        // candidate. Order them by id rather than by the iteration order of the compressor set, so
+        // the same array always compresses the same way.
+        candidates.sort_by_key(|compression| compression.id());
+
         if array.len() <= (self.options.sample_size as usize * self.options.sample_count as usize) {
             // We're either already within a sample, or we're operating over a sufficiently small array.
             return find_best_compression(candidates, array, self);
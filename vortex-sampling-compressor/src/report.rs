@@ -0,0 +1,181 @@
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+use vortex::array::ChunkedArray;
+use vortex::encoding::EncodingId;
+use vortex::{Array, ArrayDType};
+use vortex_dtype::FieldName;
+use vortex_error::{vortex_err, VortexResult};
+
+use crate::SamplingCompressor;
+
+/// What the compressor chose for each column of an array, see
+/// [`SamplingCompressor::compress_with_report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressReport {
+    pub columns: Vec<ColumnReport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnReport {
+    /// Name of the struct field, empty if the compressed array isn't a struct
+    pub name: FieldName,
+    /// Top level encodings of the compressed column, one per distinct encoding of its chunks
+    pub encodings: Vec<EncodingId>,
+    pub uncompressed_bytes: usize,
+    pub compressed_bytes: usize,
+}
+
+impl CompressReport {
+    pub fn uncompressed_bytes(&self) -> usize {
+        self.columns.iter().map(|c| c.uncompressed_bytes).sum()
+    }
+
+    pub fn compressed_bytes(&self) -> usize {
+        self.columns.iter().map(|c| c.compressed_bytes).sum()
+    }
+
+    pub fn column(&self, name: &str) -> Option<&ColumnReport> {
+        self.columns.iter().find(|c| c.name.as_ref() == name)
+    }
+}
+
+impl ColumnReport {
+    /// Uncompressed over compressed size of the column
+    pub fn ratio(&self) -> f64 {
+        if self.compressed_bytes == 0 {
+            return 1.0;
+        }
+        self.uncompressed_bytes as f64 / self.compressed_bytes as f64
+    }
+}
+
+impl Display for CompressReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for column in &self.columns {
+            writeln!(f, "{column}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ColumnReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: [{}] {} -> {} bytes ({:.2}x)",
+            self.name,
+            self.encodings.iter().join(", "),
+            self.uncompressed_bytes,
+            self.compressed_bytes,
+            self.ratio()
+        )
+    }
+}
+
+impl SamplingCompressor<'_> {
+    /// Compress the array, reporting the encoding chosen for and the size of each of its columns.
+    pub fn compress_with_report(&self, array: &Array) -> VortexResult<(Array, CompressReport)> {
+        let compressed = self.compress(array, None)?.into_array();
+
+        let columns = if array.dtype().is_struct() {
+            columns(array)?
+                .into_iter()
+                .zip_eq(columns(&compressed)?)
+                .map(|((name, original), (_, compressed))| {
+                    column_report(name, &original, &compressed)
+                })
+                .collect()
+        } else {
+            vec![column_report(FieldName::from(""), array, &compressed)]
+        };
+
+        Ok((compressed, CompressReport { columns }))
+    }
+}
+
+fn columns(array: &Array) -> VortexResult<Vec<(FieldName, Array)>> {
+    let names = array
+        .dtype()
+        .as_struct()
+        .ok_or_else(|| vortex_err!("Expected a struct array, found {}", array.dtype()))?
+        .names()
+        .clone();
+    array.with_dyn(|a| {
+        let struct_array = a.as_struct_array().ok_or_else(|| {
+            vortex_err!(
+                "{} does not implement struct variant",
+                array.encoding().id()
+            )
+        })?;
+        names
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                struct_array
+                    .field(idx)
+                    .map(|field| (name.clone(), field))
+                    .ok_or_else(|| vortex_err!("Missing field {} of struct array", name))
+            })
+            .collect()
+    })
+}
+
+fn column_report(name: FieldName, original: &Array, compressed: &Array) -> ColumnReport {
+    let encodings = match ChunkedArray::try_from(compressed) {
+        Ok(chunked) => chunked
+            .chunks()
+            .map(|c| c.encoding().id())
+            .unique()
+            .collect(),
+        Err(_) => vec![compressed.encoding().id()],
+    };
+    ColumnReport {
+        name,
+        encodings,
+        uncompressed_bytes: original.nbytes(),
+        compressed_bytes: compressed.nbytes(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{PrimitiveArray, StructArray};
+    use vortex::validity::Validity;
+    use vortex::{ArrayDef, IntoArray};
+    use vortex_fastlanes::FoR;
+
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn report_struct_columns() {
+        let len = 4096;
+        let array = StructArray::try_new(
+            ["ints".into(), "floats".into()].into(),
+            vec![
+                PrimitiveArray::from((0..len).map(|i| i as i64 + 1000).collect::<Vec<_>>())
+                    .into_array(),
+                PrimitiveArray::from(vec![1.5f64; len]).into_array(),
+            ],
+            len,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let (compressed, report) = SamplingCompressor::default()
+            .compress_with_report(&array)
+            .unwrap();
+        assert_eq!(report.columns.len(), 2);
+        assert_eq!(report.compressed_bytes(), compressed.nbytes());
+
+        let ints = report.column("ints").unwrap();
+        assert_eq!(ints.encodings, vec![FoR::ID]);
+        assert_eq!(ints.uncompressed_bytes, len * 8);
+        assert!(ints.ratio() > 1.0);
+
+        let floats = report.column("floats").unwrap();
+        assert!(floats.compressed_bytes < floats.uncompressed_bytes);
+    }
+}
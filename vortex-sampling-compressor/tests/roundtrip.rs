@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::fmt::Debug;
+
+    use proptest::prelude::*;
+    use vortex::array::PrimitiveArray;
+    use vortex::validity::Validity;
+    use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant, ToArray};
+    use vortex_dtype::NativePType;
+    use vortex_sampling_compressor::compressors::alp::ALPCompressor;
+    use vortex_sampling_compressor::compressors::dict::DictCompressor;
+    use vortex_sampling_compressor::compressors::roaring_int::RoaringIntCompressor;
+    use vortex_sampling_compressor::compressors::CompressorRef;
+    use vortex_sampling_compressor::{CompressConfig, SamplingCompressor};
+
+    /// Values with or without nulls, the array is non-nullable when `nullable` is false
+    fn values<T: Debug>(
+        value: impl Strategy<Value = T>,
+    ) -> impl Strategy<Value = (Vec<Option<T>>, bool)> {
+        (
+            prop::collection::vec(prop::option::weighted(0.8, value), 0..1024),
+            any::<bool>(),
+        )
+    }
+
+    /// Mostly a few distinct values, runs of them or anything in the domain of the type
+    fn distribution<T: Arbitrary + Copy + Debug>(
+        few: impl Fn(u8) -> T + Clone + 'static,
+    ) -> impl Strategy<Value = (Vec<Option<T>>, bool)> {
+        prop_oneof![
+            values((0u8..8).prop_map(few.clone())),
+            values(any::<T>()),
+            values((0u8..4, 1usize..64).prop_map(move |(v, run)| (few(v), run))).prop_map(
+                |(runs, nullable)| {
+                    let values = runs
+                        .into_iter()
+                        .flat_map(|r| {
+                            let (value, len) = r.map_or((None, 1), |(v, len)| (Some(v), len));
+                            std::iter::repeat(value).take(len)
+                        })
+                        .collect();
+                    (values, nullable)
+                }
+            ),
+        ]
+    }
+
+    fn primitive_array<T: NativePType>(values: Vec<Option<T>>, nullable: bool) -> PrimitiveArray {
+        if nullable {
+            PrimitiveArray::from_nullable_vec(values)
+        } else {
+            PrimitiveArray::from_vec(
+                values.into_iter().map(Option::unwrap_or_default).collect(),
+                Validity::NonNullable,
+            )
+        }
+    }
+
+    fn compressors() -> [SamplingCompressor<'static>; 4] {
+        let only = |c: CompressorRef<'static>| {
+            SamplingCompressor::new_with_options(HashSet::from([c]), CompressConfig::default())
+        };
+        [
+            SamplingCompressor::default(),
+            only(&ALPCompressor),
+            only(&DictCompressor),
+            only(&RoaringIntCompressor),
+        ]
+    }
+
+    fn assert_roundtrip<T: NativePType>(
+        values: Vec<Option<T>>,
+        nullable: bool,
+    ) -> Result<(), TestCaseError> {
+        let array = primitive_array(values, nullable).into_array();
+        let expected = array.clone().into_primitive().unwrap();
+
+        for compressor in compressors() {
+            let compressed = compressor.compress(&array, None).unwrap().into_array();
+            prop_assert_eq!(compressed.dtype(), array.dtype());
+            prop_assert_eq!(compressed.len(), array.len());
+
+            let decompressed = compressed.clone().into_primitive().unwrap();
+            for i in 0..array.len() {
+                let valid = is_valid(&compressed, i);
+                prop_assert_eq!(valid, is_valid(&array, i), "validity at {}", i);
+                prop_assert_eq!(is_valid(&decompressed.to_array(), i), valid);
+                if valid {
+                    let (actual, expected) = (
+                        decompressed.maybe_null_slice::<T>()[i],
+                        expected.maybe_null_slice::<T>()[i],
+                    );
+                    prop_assert!(
+                        actual.is_eq(expected),
+                        "value at {} was {} instead of {} in {}",
+                        i,
+                        actual,
+                        expected,
+                        compressed.tree_display()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_valid(array: &Array, index: usize) -> bool {
+        array.with_dyn(|a| a.is_valid(index))
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        #[cfg_attr(miri, ignore)] // roaring bit maps uses an unsupported FFI
+        fn roundtrip_u8((values, nullable) in distribution(|v| v)) {
+            assert_roundtrip::<u8>(values, nullable)?;
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn roundtrip_u32((values, nullable) in distribution(|v| 1000 + v as u32)) {
+            assert_roundtrip::<u32>(values, nullable)?;
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn roundtrip_i32((values, nullable) in distribution(|v| v as i32 - 4)) {
+            assert_roundtrip::<i32>(values, nullable)?;
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn roundtrip_i64((values, nullable) in distribution(|v| i64::MAX - v as i64)) {
+            assert_roundtrip::<i64>(values, nullable)?;
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn roundtrip_f32((values, nullable) in distribution(|v| v as f32 / 8.0)) {
+            assert_roundtrip::<f32>(values, nullable)?;
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn roundtrip_f64((values, nullable) in distribution(|v| 1.5 + v as f64 / 100.0)) {
+            assert_roundtrip::<f64>(values, nullable)?;
+        }
+    }
+}
@@ -186,6 +186,32 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn compression_is_deterministic() {
+        let to_compress = StructArray::try_new(
+            vec!["prim_col".into(), "varbin_col".into()].into(),
+            vec![make_primitive_column(4096), make_string_column(4096)],
+            4096,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        // Every compressor has its own compressor set, with its own iteration order
+        let trees = (0..8)
+            .map(|_| {
+                SamplingCompressor::default()
+                    .compress(&to_compress, None)
+                    .unwrap()
+                    .into_array()
+                    .tree_display()
+                    .to_string()
+            })
+            .collect::<HashSet<_>>();
+        assert_eq!(trees.len(), 1);
+    }
+
     fn make_primitive_column(count: usize) -> Array {
         PrimitiveArray::from_vec(
             (0..count).map(|i| i as i64).collect::<Vec<i64>>(),
@@ -6,6 +6,7 @@ use vortex_dtype::DType;
 
 use crate::binary::BinaryScalar;
 use crate::extension::ExtScalar;
+use crate::list::ListScalar;
 use crate::struct_::StructScalar;
 use crate::utf8::Utf8Scalar;
 use crate::{PValue, Scalar, ScalarValue};
@@ -58,7 +59,15 @@ impl Display for Scalar {
                     write!(f, "}}")
                 }
             }
-            DType::List(..) => todo!(),
+            DType::List(..) => {
+                let v = ListScalar::try_from(self).map_err(|_| std::fmt::Error)?;
+
+                if self.value.is_null() {
+                    write!(f, "null")
+                } else {
+                    write!(f, "[{}]", v.elements().format(","))
+                }
+            }
             DType::Extension(dtype, _) if is_temporal_ext_type(dtype.id()) => {
                 let metadata = TemporalMetadata::try_from(dtype).map_err(|_| std::fmt::Error)?;
                 match ExtScalar::try_from(self)
@@ -79,7 +88,8 @@ impl Display for Scalar {
                     _ => Err(std::fmt::Error),
                 }
             }
-            DType::Extension(..) => todo!(),
+            // Without knowing the meaning of the extension type, show its storage value
+            DType::Extension(..) => Display::fmt(&self.value, f),
         }
     }
 }
@@ -91,7 +101,7 @@ mod tests {
     use vortex_buffer::Buffer;
     use vortex_datetime_dtype::{TemporalMetadata, TimeUnit, DATE_ID, TIMESTAMP_ID, TIME_ID};
     use vortex_dtype::Nullability::{NonNullable, Nullable};
-    use vortex_dtype::{DType, ExtDType, ExtMetadata, PType, StructDType};
+    use vortex_dtype::{DType, ExtDType, ExtID, ExtMetadata, PType, StructDType};
 
     use crate::{PValue, Scalar, ScalarValue};
 
@@ -231,6 +241,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_list() {
+        let element = DType::Primitive(PType::I32, Nullable);
+        assert_eq!(
+            format!(
+                "{}",
+                Scalar::list(
+                    element.clone(),
+                    vec![
+                        ScalarValue::Primitive(PValue::I32(1)),
+                        ScalarValue::Null,
+                        ScalarValue::Primitive(PValue::I32(3))
+                    ]
+                )
+            ),
+            "[1_i32,null,3_i32]"
+        );
+        assert_eq!(format!("{}", Scalar::list(element.clone(), vec![])), "[]");
+        assert_eq!(
+            format!("{}", Scalar::null(DType::List(Arc::new(element), Nullable))),
+            "null"
+        );
+    }
+
+    #[test]
+    fn display_list_in_struct() {
+        let dtype = DType::Struct(
+            StructDType::new(
+                Arc::new([Arc::from("xs")]),
+                vec![DType::List(Arc::new(DType::Utf8(NonNullable)), NonNullable)],
+            ),
+            NonNullable,
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Scalar::r#struct(
+                    dtype,
+                    vec![ScalarValue::List(Arc::new([
+                        ScalarValue::BufferString("a".to_string().into()),
+                        ScalarValue::BufferString("b".to_string().into())
+                    ]))]
+                )
+            ),
+            "{xs:[a,b]}"
+        );
+    }
+
+    #[test]
+    fn display_other_extension() {
+        let dtype = DType::Extension(ExtDType::new(ExtID::from("uuid"), None), Nullable);
+        assert_eq!(
+            format!(
+                "{}",
+                Scalar::new(dtype.clone(), ScalarValue::Primitive(PValue::U64(7)))
+            ),
+            "7_u64"
+        );
+        assert_eq!(format!("{}", Scalar::null(dtype)), "null");
+    }
+
     #[test]
     fn display_time() {
         fn dtype() -> DType {
@@ -14,6 +14,7 @@ mod extension;
 mod list;
 mod primitive;
 mod pvalue;
+mod scalar_ref;
 mod scalar_type;
 #[cfg(feature = "serde")]
 mod serde;
@@ -27,6 +28,7 @@ pub use extension::*;
 pub use list::*;
 pub use primitive::*;
 pub use pvalue::*;
+pub use scalar_ref::*;
 pub use struct_::*;
 pub use utf8::*;
 pub use value::*;
@@ -114,11 +116,7 @@ impl PartialEq for Scalar {
 
 impl PartialOrd for Scalar {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.dtype().eq_ignore_nullability(other.dtype()) {
-            self.value.partial_cmp(&other.value)
-        } else {
-            None
-        }
+        self.as_scalar_ref().partial_cmp(&other.as_scalar_ref())
     }
 }
 
@@ -0,0 +1,112 @@
+use std::cmp::Ordering;
+
+use vortex_dtype::DType;
+
+use crate::value::ScalarValue;
+use crate::Scalar;
+
+/// A borrowed [`Scalar`], whose dtype and value are owned by someone else.
+///
+/// Lets callers hand out a scalar, e.g. a constant or a statistic, without cloning it. Use
+/// [`ScalarRef::to_owned`] to get a [`Scalar`] back.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScalarRef<'a> {
+    dtype: &'a DType,
+    value: &'a ScalarValue,
+}
+
+impl<'a> ScalarRef<'a> {
+    pub fn new(dtype: &'a DType, value: &'a ScalarValue) -> Self {
+        Self { dtype, value }
+    }
+
+    #[inline]
+    pub fn dtype(&self) -> &'a DType {
+        self.dtype
+    }
+
+    #[inline]
+    pub fn value(&self) -> &'a ScalarValue {
+        self.value
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.value.is_null()
+    }
+
+    pub fn to_owned(&self) -> Scalar {
+        Scalar::new(self.dtype.clone(), self.value.clone())
+    }
+}
+
+impl Scalar {
+    pub fn as_scalar_ref(&self) -> ScalarRef<'_> {
+        ScalarRef::new(&self.dtype, &self.value)
+    }
+}
+
+impl<'a> From<&'a Scalar> for ScalarRef<'a> {
+    fn from(value: &'a Scalar) -> Self {
+        value.as_scalar_ref()
+    }
+}
+
+impl From<ScalarRef<'_>> for Scalar {
+    fn from(value: ScalarRef<'_>) -> Self {
+        value.to_owned()
+    }
+}
+
+impl PartialOrd for ScalarRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.dtype.eq_ignore_nullability(other.dtype) {
+            self.value.partial_cmp(other.value)
+        } else {
+            None
+        }
+    }
+}
+
+impl PartialEq<Scalar> for ScalarRef<'_> {
+    fn eq(&self, other: &Scalar) -> bool {
+        self.dtype == other.dtype() && self.value == other.value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::Nullability;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let scalar = Scalar::primitive(42i32, Nullability::Nullable);
+        let borrowed = ScalarRef::from(&scalar);
+        assert_eq!(borrowed.dtype(), scalar.dtype());
+        assert_eq!(borrowed, scalar);
+        assert_eq!(Scalar::from(borrowed), scalar);
+        assert!(!borrowed.is_null());
+
+        let null = Scalar::null(scalar.dtype().clone());
+        assert!(null.as_scalar_ref().is_null());
+        assert_ne!(null.as_scalar_ref(), scalar);
+    }
+
+    #[test]
+    fn ordering_matches_scalar() {
+        let one = Scalar::primitive(1i32, Nullability::NonNullable);
+        let two = Scalar::primitive(2i32, Nullability::Nullable);
+        assert!(one.as_scalar_ref() < two.as_scalar_ref());
+        assert_eq!(
+            one.as_scalar_ref().partial_cmp(&two.as_scalar_ref()),
+            one.partial_cmp(&two)
+        );
+
+        let other_type = Scalar::primitive(1i64, Nullability::NonNullable);
+        assert_eq!(
+            one.as_scalar_ref().partial_cmp(&other_type.as_scalar_ref()),
+            None
+        );
+    }
+}
@@ -2,6 +2,7 @@ use std::fmt::{Display, Write};
 use std::sync::Arc;
 
 use half::f16;
+use itertools::Itertools;
 use vortex_buffer::{Buffer, BufferString};
 use vortex_dtype::DType;
 use vortex_error::{vortex_err, VortexResult};
@@ -63,7 +64,7 @@ impl Display for ScalarValue {
                     write!(f, "{}", bufstr.as_str())
                 }
             }
-            ScalarValue::List(_) => todo!(),
+            ScalarValue::List(values) => write!(f, "[{}]", values.iter().format(",")),
             ScalarValue::Null => write!(f, "null"),
         }
     }
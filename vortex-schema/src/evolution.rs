@@ -0,0 +1,216 @@
+//! Comparing and combining the schemas of files written at different times.
+
+use vortex_dtype::{DType, FieldName, Nullability, StructDType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::Schema;
+
+/// Columns that differ between two schemas, see [`Schema::diff`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDiff {
+    /// Columns only in the other schema
+    pub added: Vec<FieldName>,
+    /// Columns only in this schema
+    pub removed: Vec<FieldName>,
+    /// Columns in both schemas with different types
+    pub changed: Vec<ChangedColumn>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangedColumn {
+    pub name: FieldName,
+    pub from: DType,
+    pub to: DType,
+}
+
+impl SchemaDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl Schema {
+    /// Union of the columns of both schemas, e.g. to read files written with different schemas
+    /// as one.
+    ///
+    /// Columns are ordered as in this schema followed by the ones only in `other`. Columns that
+    /// are missing from either schema, or nullable in either, are nullable in the result. Nested
+    /// structs are merged the same way, any other difference in type is an error.
+    pub fn merge(&self, other: &Schema) -> VortexResult<Schema> {
+        let (DType::Struct(..), DType::Struct(..)) = (&self.0, &other.0) else {
+            vortex_bail!(
+                "Can only merge struct schemas, got {} and {}",
+                self.0,
+                other.0
+            )
+        };
+        merge_dtypes(&self.0, &other.0).map(Schema)
+    }
+
+    /// Columns added, removed or changed in `other` compared to this schema
+    pub fn diff(&self, other: &Schema) -> VortexResult<SchemaDiff> {
+        let (DType::Struct(from, _), DType::Struct(to, _)) = (&self.0, &other.0) else {
+            vortex_bail!(
+                "Can only diff struct schemas, got {} and {}",
+                self.0,
+                other.0
+            )
+        };
+
+        let mut diff = SchemaDiff::default();
+        for (name, dtype) in from.names().iter().zip(from.dtypes().iter()) {
+            match to.find_name(name) {
+                None => diff.removed.push(name.clone()),
+                Some(idx) if &to.dtypes()[idx] != dtype => diff.changed.push(ChangedColumn {
+                    name: name.clone(),
+                    from: dtype.clone(),
+                    to: to.dtypes()[idx].clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+        diff.added = to
+            .names()
+            .iter()
+            .filter(|name| from.find_name(name).is_none())
+            .cloned()
+            .collect();
+        Ok(diff)
+    }
+}
+
+fn merge_dtypes(a: &DType, b: &DType) -> VortexResult<DType> {
+    let nullability = if a.is_nullable() || b.is_nullable() {
+        Nullability::Nullable
+    } else {
+        Nullability::NonNullable
+    };
+
+    let (DType::Struct(a_st, _), DType::Struct(b_st, _)) = (a, b) else {
+        if let Err(mismatch) = a.as_nullable().is_compatible_with(&b.as_nullable()) {
+            vortex_bail!(MismatchedTypes: a, format!("{b} ({mismatch})"))
+        }
+        return Ok(a.with_nullability(nullability));
+    };
+
+    let mut names = Vec::new();
+    let mut dtypes = Vec::new();
+    for (name, dtype) in a_st.names().iter().zip(a_st.dtypes().iter()) {
+        let merged = match b_st.find_name(name) {
+            Some(idx) => merge_dtypes(dtype, &b_st.dtypes()[idx])
+                .map_err(|e| e.with_context(format!("Conflicting types for column {name}")))?,
+            None => dtype.as_nullable(),
+        };
+        names.push(name.clone());
+        dtypes.push(merged);
+    }
+    for (name, dtype) in b_st.names().iter().zip(b_st.dtypes().iter()) {
+        if a_st.find_name(name).is_none() {
+            names.push(name.clone());
+            dtypes.push(dtype.as_nullable());
+        }
+    }
+
+    Ok(DType::Struct(
+        StructDType::new(names.into(), dtypes),
+        nullability,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType, StructDType};
+
+    use crate::evolution::ChangedColumn;
+    use crate::Schema;
+
+    fn schema(fields: &[(&str, DType)]) -> Schema {
+        Schema::new(DType::Struct(
+            StructDType::new(
+                fields.iter().map(|(n, _)| (*n).into()).collect(),
+                fields.iter().map(|(_, d)| d.clone()).collect(),
+            ),
+            Nullability::NonNullable,
+        ))
+    }
+
+    fn int(ptype: PType, nullability: Nullability) -> DType {
+        DType::Primitive(ptype, nullability)
+    }
+
+    #[test]
+    fn merge_widens_nullability() {
+        let old = schema(&[
+            ("id", int(PType::U64, Nullability::NonNullable)),
+            ("age", int(PType::I32, Nullability::NonNullable)),
+        ]);
+        let new = schema(&[
+            ("id", int(PType::U64, Nullability::NonNullable)),
+            ("age", int(PType::I32, Nullability::Nullable)),
+            ("name", DType::Utf8(Nullability::NonNullable)),
+        ]);
+
+        let merged = old.merge(&new).unwrap();
+        assert_eq!(
+            merged.dtype(),
+            schema(&[
+                ("id", int(PType::U64, Nullability::NonNullable)),
+                ("age", int(PType::I32, Nullability::Nullable)),
+                ("name", DType::Utf8(Nullability::Nullable)),
+            ])
+            .dtype()
+        );
+    }
+
+    #[test]
+    fn merge_nested_and_conflicts() {
+        let nested = |fields: &[(&str, DType)]| schema(fields).dtype().clone();
+        let narrow = schema(&[(
+            "s",
+            nested(&[("x", int(PType::I8, Nullability::NonNullable))]),
+        )]);
+        let other = schema(&[("s", nested(&[("y", DType::Bool(Nullability::NonNullable))]))]);
+        assert_eq!(
+            narrow.merge(&other).unwrap().dtype(),
+            schema(&[(
+                "s",
+                nested(&[
+                    ("x", int(PType::I8, Nullability::Nullable)),
+                    ("y", DType::Bool(Nullability::Nullable)),
+                ])
+            )])
+            .dtype()
+        );
+
+        let conflict = schema(&[("s", int(PType::I8, Nullability::NonNullable))]);
+        let err = narrow.merge(&conflict).unwrap_err().to_string();
+        assert!(err.contains("Conflicting types for column s"), "{err}");
+    }
+
+    #[test]
+    fn diff() {
+        let old = schema(&[
+            ("id", int(PType::U64, Nullability::NonNullable)),
+            ("age", int(PType::I32, Nullability::NonNullable)),
+            ("gone", DType::Bool(Nullability::NonNullable)),
+        ]);
+        let new = schema(&[
+            ("id", int(PType::U64, Nullability::NonNullable)),
+            ("age", int(PType::I64, Nullability::NonNullable)),
+            ("name", DType::Utf8(Nullability::Nullable)),
+        ]);
+
+        let diff = old.diff(&new).unwrap();
+        assert_eq!(diff.added, vec!["name".into()]);
+        assert_eq!(diff.removed, vec!["gone".into()]);
+        assert_eq!(
+            diff.changed,
+            vec![ChangedColumn {
+                name: "age".into(),
+                from: int(PType::I32, Nullability::NonNullable),
+                to: int(PType::I64, Nullability::NonNullable),
+            }]
+        );
+        assert!(old.diff(&old).unwrap().is_empty());
+    }
+}
@@ -2,8 +2,10 @@ use vortex_dtype::field::Field;
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
+pub use self::evolution::{ChangedColumn, SchemaDiff};
 use self::projection::Projection;
 
+mod evolution;
 pub mod projection;
 
 #[derive(Clone, Debug)]
@@ -14,6 +16,29 @@ impl Schema {
         Self(schema_dtype)
     }
 
+    /// Select the named columns, in the given order.
+    ///
+    /// Returns the projected schema together with the index based [`Projection`] to read the
+    /// same columns with, so names only need to be resolved once.
+    pub fn project_names(&self, names: &[&str]) -> VortexResult<(Self, Projection)> {
+        let DType::Struct(s, _) = &self.0 else {
+            vortex_bail!("Can't project non struct types")
+        };
+        let indices = names
+            .iter()
+            .map(|name| {
+                s.find_name(name).ok_or_else(|| {
+                    vortex_err!(
+                        "Unknown column {name}, expected one of {}",
+                        s.names().join(", ")
+                    )
+                })
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+        let projection = Projection::new(&indices);
+        Ok((self.project(projection.clone())?, projection))
+    }
+
     pub fn project(&self, projection: Projection) -> VortexResult<Self> {
         match projection {
             Projection::All => Ok(self.clone()),
@@ -51,3 +76,51 @@ impl From<Schema> for DType {
         value.0
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::field::Field;
+    use vortex_dtype::{DType, Nullability, PType, StructDType};
+
+    use crate::projection::Projection;
+    use crate::Schema;
+
+    #[test]
+    fn project_names() {
+        let schema = Schema::new(DType::Struct(
+            StructDType::new(
+                ["a", "b", "c"].into_iter().map(Into::into).collect(),
+                vec![
+                    DType::Bool(Nullability::NonNullable),
+                    DType::Primitive(PType::I32, Nullability::Nullable),
+                    DType::Utf8(Nullability::NonNullable),
+                ],
+            ),
+            Nullability::NonNullable,
+        ));
+
+        let (projected, projection) = schema.project_names(&["c", "a"]).unwrap();
+        assert!(
+            matches!(projection, Projection::Flat(fields) if fields == [Field::Index(2), Field::Index(0)])
+        );
+        assert_eq!(
+            projected.dtype(),
+            &DType::Struct(
+                StructDType::new(
+                    ["c", "a"].into_iter().map(Into::into).collect(),
+                    vec![
+                        DType::Utf8(Nullability::NonNullable),
+                        DType::Bool(Nullability::NonNullable),
+                    ],
+                ),
+                Nullability::NonNullable,
+            )
+        );
+
+        let err = schema.project_names(&["d"]).unwrap_err().to_string();
+        assert!(
+            err.contains("Unknown column d, expected one of a, b, c"),
+            "{err}"
+        );
+    }
+}
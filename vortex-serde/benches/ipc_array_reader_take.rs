@@ -36,9 +36,14 @@ fn ipc_array_reader_take(c: &mut Criterion) {
         )
         .into_array();
 
-        let buffer = block_on(async { StreamArrayWriter::new(vec![]).write_array(array).await })
-            .unwrap()
-            .into_inner();
+        let buffer = block_on(async {
+            StreamArrayWriter::new(vec![])
+                .write_array(array)
+                .await?
+                .finish()
+                .await
+        })
+        .unwrap();
 
         let indices = indices.clone().into_array();
 
@@ -63,10 +63,14 @@ fn ipc_take(c: &mut Criterion) {
         let compressed = compressor.compress(&uncompressed).unwrap();
 
         // Try running take over an ArrayView.
-        let buffer =
-            block_on(async { StreamArrayWriter::new(vec![]).write_array(compressed).await })
-                .unwrap()
-                .into_inner();
+        let buffer = block_on(async {
+            StreamArrayWriter::new(vec![])
+                .write_array(compressed)
+                .await?
+                .finish()
+                .await
+        })
+        .unwrap();
 
         let ctx_ref = &Arc::new(ctx);
         let ro_buffer = buffer.as_slice();
@@ -1,14 +1,16 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
+use bytes::BytesMut;
 use vortex::compute::unary::scalar_at;
-use vortex::stream::ArrayStream;
+use vortex::stream::{ArrayStream, WorkerPool};
 use vortex::{Array, Context};
 use vortex_dtype::DType;
-use vortex_error::{vortex_bail, VortexExpect as _, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexExpect as _, VortexResult};
 
 use crate::io::VortexReadAt;
 use crate::stream_reader::StreamArrayReader;
+use crate::MessageReader;
 
 mod take_rows;
 
@@ -21,6 +23,7 @@ pub struct ChunkedArrayReader<R: VortexReadAt> {
     // One row per chunk + 1 row for the end of the last chunk.
     byte_offsets: Array,
     row_offsets: Array,
+    worker_pool: Option<Arc<dyn WorkerPool>>,
 }
 
 impl<R: VortexReadAt> ChunkedArrayReader<R> {
@@ -38,11 +41,21 @@ impl<R: VortexReadAt> ChunkedArrayReader<R> {
             dtype,
             byte_offsets,
             row_offsets,
+            worker_pool: None,
         })
     }
 
+    /// Take rows out of the decoded chunks on `worker_pool`, keeping the executor polling the reads
+    /// responsive during large takes.
+    pub fn with_worker_pool(mut self, worker_pool: Arc<dyn WorkerPool>) -> Self {
+        self.worker_pool = Some(worker_pool);
+        self
+    }
+
+    /// Number of chunks that can be read, one less than the number of byte offsets which also
+    /// include the end of the last chunk
     pub fn nchunks(&self) -> usize {
-        self.byte_offsets.len()
+        self.byte_offsets.len().saturating_sub(1)
     }
 
     fn validate(byte_offsets: &Array, row_offsets: &Array) -> VortexResult<()> {
@@ -52,6 +65,29 @@ impl<R: VortexReadAt> ChunkedArrayReader<R> {
         Ok(())
     }
 
+    /// Read a single chunk, seeking directly to its byte range instead of replaying the stream.
+    pub async fn read_chunk(&self, chunk_idx: usize) -> VortexResult<Array> {
+        if chunk_idx >= self.nchunks() {
+            vortex_bail!(OutOfBounds: chunk_idx, 0, self.nchunks());
+        }
+        let begin = u64::try_from(&scalar_at(&self.byte_offsets, chunk_idx)?)?;
+        let end = u64::try_from(&scalar_at(&self.byte_offsets, chunk_idx + 1)?)?;
+        if begin > end {
+            vortex_bail!(InvalidSerde: "Chunk {} ends at byte {} before it begins at byte {}", chunk_idx, end, begin);
+        }
+
+        let buffer = self
+            .read
+            .read_at_into(begin, BytesMut::zeroed((end - begin) as usize))
+            .await?;
+
+        MessageReader::try_new(buffer)
+            .await?
+            .maybe_read_chunk(self.context.clone(), (*self.dtype).clone())
+            .await?
+            .ok_or_else(|| vortex_err!(InvalidSerde: "Chunk {} is not a batch message", chunk_idx))
+    }
+
     pub async fn array_stream(&mut self) -> impl ArrayStream + '_ {
         let mut cursor = Cursor::new(&self.read);
         let byte_offset = scalar_at(&self.byte_offsets, 0)
@@ -62,7 +98,79 @@ impl<R: VortexReadAt> ChunkedArrayReader<R> {
         StreamArrayReader::try_new(cursor, self.context.clone())
             .await
             .vortex_expect("Failed to create stream array reader")
+            .allow_missing_end_of_stream()
             .with_dtype(self.dtype.clone())
             .into_array_stream()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use futures_executor::block_on;
+    use itertools::Itertools;
+    use vortex::array::{ChunkedArray, PrimitiveArray};
+    use vortex::{Context, IntoArray, IntoArrayVariant};
+    use vortex_buffer::Buffer;
+    use vortex_dtype::PType;
+
+    use crate::chunked_reader::ChunkedArrayReader;
+    use crate::stream_writer::StreamArrayWriter;
+    use crate::MessageReader;
+
+    #[test]
+    fn read_chunk() {
+        let chunked = ChunkedArray::try_new(
+            (0..4)
+                .map(|i| PrimitiveArray::from((i * 100..(i + 1) * 100).collect_vec()).into_array())
+                .collect(),
+            PType::I32.into(),
+        )
+        .unwrap();
+        let writer = block_on(async {
+            StreamArrayWriter::new(vec![])
+                .write_array(chunked.into_array())
+                .await
+        })
+        .unwrap();
+        let chunks = writer.array_layouts()[0].chunks.clone();
+        let buffer = Buffer::from(writer.into_inner());
+        let dtype = block_on(async {
+            MessageReader::try_new(Cursor::new(buffer.clone()))
+                .await
+                .unwrap()
+                .read_dtype()
+                .await
+        })
+        .unwrap();
+
+        let reader = ChunkedArrayReader::try_new(
+            buffer.clone(),
+            Arc::new(Context::default()),
+            Arc::new(dtype.clone()),
+            PrimitiveArray::from(chunks.byte_offsets.clone()).into_array(),
+            PrimitiveArray::from(chunks.row_offsets).into_array(),
+        )
+        .unwrap();
+        assert_eq!(reader.nchunks(), 4);
+
+        let chunk = block_on(reader.read_chunk(2))
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(chunk.maybe_null_slice::<i32>(), (200..300).collect_vec());
+        assert!(block_on(reader.read_chunk(4)).is_err());
+
+        let corrupt = ChunkedArrayReader::try_new(
+            buffer,
+            Arc::new(Context::default()),
+            Arc::new(dtype),
+            PrimitiveArray::from(vec![chunks.byte_offsets[1], chunks.byte_offsets[0]]).into_array(),
+            PrimitiveArray::from(vec![0u64, 100]).into_array(),
+        )
+        .unwrap();
+        assert!(block_on(corrupt.read_chunk(0)).is_err());
+    }
+}
@@ -5,7 +5,7 @@ use bytes::BytesMut;
 use futures_util::{stream, StreamExt, TryStreamExt};
 use itertools::Itertools;
 use vortex::array::{ChunkedArray, PrimitiveArray};
-use vortex::compute::unary::{subtract_scalar, try_cast};
+use vortex::compute::unary::{subtract_scalar, try_cast, OverflowPolicy};
 use vortex::compute::{search_sorted, slice, take, SearchSortedSide};
 use vortex::stats::ArrayStatistics;
 use vortex::stream::{ArrayStream, ArrayStreamExt};
@@ -19,6 +19,10 @@ use crate::io::VortexReadAt;
 use crate::stream_reader::StreamArrayReader;
 
 impl<R: VortexReadAt> ChunkedArrayReader<R> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(indices = indices.len()))
+    )]
     pub async fn take_rows(&mut self, indices: &Array) -> VortexResult<Array> {
         // Figure out if the row indices are sorted / unique. If not, we need to sort them.
         if indices
@@ -131,22 +135,29 @@ impl<R: VortexReadAt> ChunkedArrayReader<R> {
             search_sorted(indices, row_range.end, SearchSortedSide::Right)?.to_index();
         let relative_indices = slice(indices, indices_start, indices_stop)?;
         let row_start_scalar = Scalar::from(row_range.start).cast(relative_indices.dtype())?;
-        let relative_indices = subtract_scalar(&relative_indices, &row_start_scalar)?;
+        let relative_indices = subtract_scalar(
+            &relative_indices,
+            &row_start_scalar,
+            OverflowPolicy::Checked,
+        )?;
 
         // Set up an array reader to read this range of chunks.
-        let mut buffer = BytesMut::with_capacity(range_byte_len);
-        unsafe { buffer.set_len(range_byte_len) }
+        let buffer = BytesMut::zeroed(range_byte_len);
         // TODO(ngates): instead of reading the whole range into a buffer, we should stream
         //  the byte range (e.g. if its coming from an HTTP endpoint) and wrap that with an
         //  MesssageReader.
         let buffer = self.read.read_at_into(byte_range.start, buffer).await?;
 
+        // Only the chunks in the range are read, without the end of the stream
         let reader = StreamArrayReader::try_new(buffer, self.context.clone())
             .await?
+            .allow_missing_end_of_stream()
             .with_dtype(self.dtype.clone());
 
         // Take the indices from the stream.
-        reader.into_array_stream().take_rows(relative_indices)
+        reader
+            .into_array_stream()
+            .take_rows_on(relative_indices, self.worker_pool.clone())
     }
 }
 
@@ -233,7 +244,6 @@ mod test {
     }
 
     #[test]
-    #[cfg_attr(miri, ignore)]
     fn test_take_rows() -> VortexResult<()> {
         let writer = chunked_array()?;
 
@@ -267,4 +277,39 @@ mod test {
         assert_eq!(result.maybe_null_slice::<i32>(), &[0, 10, 999]);
         Ok(())
     }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)] // Spawning onto tokio's blocking pool is too slow on miri
+    async fn test_take_rows_on_worker_pool() -> VortexResult<()> {
+        let writer = chunked_array()?;
+
+        let array_layout = writer.array_layouts()[0].clone();
+        let byte_offsets = PrimitiveArray::from(array_layout.chunks.byte_offsets.clone());
+        let row_offsets = PrimitiveArray::from(array_layout.chunks.row_offsets.clone());
+
+        let buffer = Buffer::from(writer.into_inner());
+        let dtype = Arc::new(
+            MessageReader::try_new(Cursor::new(buffer.clone()))
+                .await?
+                .read_dtype()
+                .await?,
+        );
+
+        let mut reader = ChunkedArrayReader::try_new(
+            buffer,
+            Arc::new(Context::default()),
+            dtype,
+            byte_offsets.into_array(),
+            row_offsets.into_array(),
+        )?
+        .with_worker_pool(Arc::new(crate::io::TokioWorkerPool::current()));
+
+        let result = reader
+            .take_rows(&PrimitiveArray::from(vec![1u64, 2_500, 9_998]).into_array())
+            .await?
+            .into_primitive()?;
+        assert_eq!(result.maybe_null_slice::<i32>(), &[1, 500, 998]);
+        Ok(())
+    }
 }
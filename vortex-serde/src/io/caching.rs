@@ -0,0 +1,260 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use bytes::{Bytes, BytesMut};
+
+use crate::io::VortexReadAt;
+
+/// An adapter that keeps the bytes of previously read ranges in memory.
+///
+/// Ranges are cached by their exact offset and length, up to `capacity` bytes in total, evicting
+/// the least recently used range first. Share one instance, e.g. through an `Arc`, between streams
+/// reading the same file to serve the footer and hot columns from memory.
+pub struct CachingReadAt<R> {
+    read: R,
+    capacity: usize,
+    cache: Mutex<LruCache>,
+    size: OnceLock<u64>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<R: VortexReadAt> CachingReadAt<R> {
+    pub fn new(read: R, capacity: usize) -> Self {
+        Self {
+            read,
+            capacity,
+            cache: Mutex::new(LruCache::default()),
+            size: OnceLock::new(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Number of reads served from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Number of reads forwarded to the underlying reader
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Total size of the cached byte ranges
+    pub fn cached_bytes(&self) -> usize {
+        self.cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .size
+    }
+}
+
+impl<R: VortexReadAt> VortexReadAt for CachingReadAt<R> {
+    async fn read_at_into(&self, pos: u64, mut buffer: BytesMut) -> io::Result<BytesMut> {
+        let key = (pos, buffer.len());
+        let cached = self
+            .cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key);
+        if let Some(bytes) = cached {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            buffer.copy_from_slice(&bytes);
+            return Ok(buffer);
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let buffer = self.read.read_at_into(pos, buffer).await?;
+        self.cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, Bytes::copy_from_slice(&buffer), self.capacity);
+        Ok(buffer)
+    }
+
+    fn performance_hint(&self) -> usize {
+        self.read.performance_hint()
+    }
+
+    /// Cached ranges are always returned without copying them. Other ranges are only read, and
+    /// cached, if the underlying reader supports shared reads.
+    fn read_at_shared(&self, pos: u64, len: usize) -> Option<io::Result<Bytes>> {
+        let key = (pos, len);
+        let cached = self
+            .cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key);
+        if let Some(bytes) = cached {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Some(Ok(bytes));
+        }
+
+        let bytes = match self.read.read_at_shared(pos, len)? {
+            Ok(bytes) => bytes,
+            Err(e) => return Some(Err(e)),
+        };
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        self.cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, bytes.clone(), self.capacity);
+        Some(Ok(bytes))
+    }
+
+    async fn size(&self) -> u64 {
+        if let Some(size) = self.size.get() {
+            return *size;
+        }
+        let size = self.read.size().await;
+        *self.size.get_or_init(|| size)
+    }
+}
+
+type RangeKey = (u64, usize);
+
+#[derive(Default)]
+struct LruCache {
+    entries: HashMap<RangeKey, (Bytes, u64)>,
+    // Keys of cached entries ordered by last access
+    recency: BTreeMap<u64, RangeKey>,
+    tick: u64,
+    size: usize,
+}
+
+impl LruCache {
+    fn get(&mut self, key: &RangeKey) -> Option<Bytes> {
+        let (bytes, last_access) = self.entries.get_mut(key)?;
+        self.recency.remove(last_access);
+        self.tick += 1;
+        *last_access = self.tick;
+        self.recency.insert(self.tick, *key);
+        Some(bytes.clone())
+    }
+
+    fn insert(&mut self, key: RangeKey, bytes: Bytes, capacity: usize) {
+        if bytes.len() > capacity || self.entries.contains_key(&key) {
+            return;
+        }
+
+        while self.size + bytes.len() > capacity {
+            let Some((_, evicted)) = self.recency.pop_first() else {
+                break;
+            };
+            if let Some((evicted, _)) = self.entries.remove(&evicted) {
+                self.size -= evicted.len();
+            }
+        }
+
+        self.tick += 1;
+        self.size += bytes.len();
+        self.recency.insert(self.tick, key);
+        self.entries.insert(key, (bytes, self.tick));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::sync::Arc;
+
+    use bytes::{Bytes, BytesMut};
+    use vortex::array::{PrimitiveArray, StructArray};
+    use vortex::IntoArray;
+
+    use crate::io::caching::CachingReadAt;
+    use crate::io::VortexReadAt;
+    use crate::layouts::{LayoutDeserializer, LayoutReaderBuilder, LayoutWriter};
+
+    /// In memory reader supporting shared reads, like a memory mapped file
+    struct SharedBytes(Bytes);
+
+    impl VortexReadAt for SharedBytes {
+        async fn read_at_into(&self, pos: u64, buffer: BytesMut) -> io::Result<BytesMut> {
+            self.0.as_ref().read_at_into(pos, buffer).await
+        }
+
+        fn read_at_shared(&self, pos: u64, len: usize) -> Option<io::Result<Bytes>> {
+            Some(Ok(self.0.slice(pos as usize..pos as usize + len)))
+        }
+
+        async fn size(&self) -> u64 {
+            self.0.len() as u64
+        }
+    }
+
+    async fn read<R: VortexReadAt>(reader: &CachingReadAt<R>, pos: u64, len: usize) -> Vec<u8> {
+        reader
+            .read_at_into(pos, BytesMut::zeroed(len))
+            .await
+            .unwrap()
+            .to_vec()
+    }
+
+    #[tokio::test]
+    async fn evicts_least_recently_used() {
+        let reader = CachingReadAt::new((0u8..100).collect::<Vec<_>>(), 20);
+        assert_eq!(read(&reader, 0, 10).await, (0..10).collect::<Vec<_>>());
+        read(&reader, 10, 10).await;
+        // Touch the first range so that the second one is evicted
+        read(&reader, 0, 10).await;
+        read(&reader, 50, 10).await;
+        assert_eq!(reader.cached_bytes(), 20);
+        assert_eq!((reader.hits(), reader.misses()), (1, 3));
+
+        assert_eq!(read(&reader, 0, 10).await, (0..10).collect::<Vec<_>>());
+        read(&reader, 10, 10).await;
+        assert_eq!((reader.hits(), reader.misses()), (2, 4));
+    }
+
+    #[tokio::test]
+    async fn shared_reads_use_cache() {
+        let reader = CachingReadAt::new((0u8..100).collect::<Vec<_>>(), 20);
+        // The underlying reader doesn't support shared reads, so only cached ranges are returned
+        assert!(reader.read_at_shared(0, 10).is_none());
+        assert_eq!((reader.hits(), reader.misses()), (0, 0));
+
+        read(&reader, 0, 10).await;
+        let shared = reader.read_at_shared(0, 10).unwrap().unwrap();
+        assert_eq!(shared.to_vec(), (0..10).collect::<Vec<_>>());
+        assert_eq!((reader.hits(), reader.misses()), (1, 1));
+
+        let reader = CachingReadAt::new(SharedBytes(Bytes::from_iter(0u8..100)), 20);
+        reader.read_at_shared(10, 10).unwrap().unwrap();
+        assert_eq!(read(&reader, 10, 10).await, (10..20).collect::<Vec<_>>());
+        assert_eq!((reader.hits(), reader.misses()), (1, 1));
+        assert_eq!(reader.cached_bytes(), 10);
+    }
+
+    #[tokio::test]
+    async fn repeated_streams_hit_cache() {
+        let st = StructArray::from_fields(&[(
+            "numbers",
+            PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        )])
+        .unwrap();
+        let written = LayoutWriter::new(Vec::new())
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+        let reader = Arc::new(CachingReadAt::new(written, 1 << 20));
+
+        for _ in 0..2 {
+            LayoutReaderBuilder::new(reader.clone(), LayoutDeserializer::default())
+                .build()
+                .await
+                .unwrap()
+                .read_all()
+                .await
+                .unwrap();
+        }
+        assert!(reader.misses() > 0);
+        assert_eq!(reader.hits(), reader.misses());
+    }
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "mmap")]
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use bytes::{Bytes, BytesMut};
+use memmap2::Mmap;
+use vortex_error::vortex_err;
+
+use crate::io::VortexReadAt;
+
+/// Reads a local file through a memory mapping.
+///
+/// Arrays read from it point directly into the mapped pages instead of copies of them, the
+/// mapping is kept alive for as long as any of them is.
+#[derive(Debug, Clone)]
+pub struct MmapReadAt {
+    bytes: Bytes,
+}
+
+impl MmapReadAt {
+    /// Map the file at `path`.
+    ///
+    /// # Safety
+    ///
+    /// See [`MmapReadAt::map`].
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        unsafe { Self::map(&File::open(path)?) }
+    }
+
+    /// Map the contents of `file`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while it's mapped, by this or any other
+    /// process, as the arrays read from it would change underneath or point to unmapped memory.
+    pub unsafe fn map(file: &File) -> io::Result<Self> {
+        let mmap = unsafe { Mmap::map(file)? };
+        Ok(Self {
+            bytes: Bytes::from_owner(mmap),
+        })
+    }
+
+    fn range(&self, pos: u64, len: usize) -> io::Result<Bytes> {
+        let start = usize::try_from(pos).unwrap_or(usize::MAX);
+        if start.saturating_add(len) > self.bytes.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                vortex_err!(
+                    "Reading {len} bytes at {pos} past the end of a {} byte file",
+                    self.bytes.len()
+                ),
+            ));
+        }
+        Ok(self.bytes.slice(start..start + len))
+    }
+}
+
+impl VortexReadAt for MmapReadAt {
+    async fn read_at_into(&self, pos: u64, mut buffer: BytesMut) -> io::Result<BytesMut> {
+        let bytes = self.range(pos, buffer.len())?;
+        buffer.copy_from_slice(&bytes);
+        Ok(buffer)
+    }
+
+    fn read_at_shared(&self, pos: u64, len: usize) -> Option<io::Result<Bytes>> {
+        Some(self.range(pos, len))
+    }
+
+    async fn size(&self) -> u64 {
+        self.bytes.len() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use vortex::array::{PrimitiveArray, StructArray};
+    use vortex::variants::StructArrayTrait;
+    use vortex::{IntoArray, IntoArrayVariant};
+
+    use crate::io::MmapReadAt;
+    use crate::layouts::{LayoutDeserializer, LayoutReaderBuilder, LayoutWriter};
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)] // Miri doesn't support mmap
+    async fn read_without_copying() {
+        let st = StructArray::from_fields(&[(
+            "numbers",
+            PrimitiveArray::from((0u64..1000).collect::<Vec<_>>()).into_array(),
+        )])
+        .unwrap();
+        let written = LayoutWriter::new(Vec::new())
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(&written).unwrap();
+
+        let reader = unsafe { MmapReadAt::map(&file) }.unwrap();
+        let mapped = reader.bytes.as_ptr_range();
+        let numbers = LayoutReaderBuilder::new(reader, LayoutDeserializer::default())
+            .build()
+            .await
+            .unwrap()
+            .read_all()
+            .await
+            .unwrap()
+            .into_struct()
+            .unwrap()
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+
+        assert_eq!(
+            numbers.maybe_null_slice::<u64>(),
+            (0u64..1000).collect::<Vec<_>>()
+        );
+        assert!(mapped.contains(&numbers.buffer().as_ptr()));
+        assert!(numbers.is_aligned());
+    }
+}
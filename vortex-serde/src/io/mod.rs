@@ -1,5 +1,7 @@
 #[cfg(feature = "futures")]
 pub use futures::*;
+#[cfg(feature = "mmap")]
+pub use mmap::*;
 #[cfg(feature = "monoio")]
 pub use monoio::*;
 #[cfg(feature = "object_store")]
@@ -9,7 +11,9 @@ pub use read::*;
 pub use tokio::*;
 pub use write::*;
 
+pub mod caching;
 mod futures;
+mod mmap;
 mod monoio;
 mod object_store;
 pub mod offset;
@@ -69,11 +69,27 @@ impl ObjectStoreReadAt {
 
 impl VortexReadAt for ObjectStoreReadAt {
     async fn read_at_into(&self, pos: u64, mut buffer: BytesMut) -> io::Result<BytesMut> {
+        if buffer.is_empty() {
+            return Ok(buffer);
+        }
+
         let start_range = pos as usize;
         let bytes = self
             .object_store
             .get_range(&self.location, start_range..(start_range + buffer.len()))
             .await?;
+        // Stores may return fewer bytes than requested if the range extends past the end
+        if bytes.len() != buffer.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!(
+                    "Expected {} bytes at offset {pos} of {}, got {}",
+                    buffer.len(),
+                    self.location,
+                    bytes.len()
+                ),
+            ));
+        }
         buffer.as_mut().copy_from_slice(bytes.as_ref());
         Ok(buffer)
     }
@@ -132,3 +148,71 @@ impl VortexWrite for ObjectStoreWriter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use bytes::BytesMut;
+    use object_store::memory::InMemory;
+    use object_store::path::Path;
+    use object_store::ObjectStore;
+    use vortex::array::{PrimitiveArray, StructArray};
+    use vortex::variants::StructArrayTrait;
+    use vortex::{IntoArray, IntoArrayVariant};
+
+    use crate::io::{ObjectStoreReadAt, VortexReadAt};
+    use crate::layouts::{LayoutDeserializer, LayoutReaderBuilder, LayoutWriter};
+
+    #[tokio::test]
+    async fn read_layout_from_object_store() {
+        let st = StructArray::from_fields(&[(
+            "numbers",
+            PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        )])
+        .unwrap();
+        let written = LayoutWriter::new(Vec::new())
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let location = Path::from("test.vortex");
+        store.put(&location, written.into()).await.unwrap();
+
+        let reader = ObjectStoreReadAt::new(store, location);
+        let array = LayoutReaderBuilder::new(reader, LayoutDeserializer::default())
+            .build()
+            .await
+            .unwrap()
+            .read_all()
+            .await
+            .unwrap();
+        let numbers = array
+            .into_struct()
+            .unwrap()
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(numbers.maybe_null_slice::<u32>(), &[1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn short_read() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let location = Path::from("short");
+        store.put(&location, vec![0u8; 4].into()).await.unwrap();
+
+        let reader = ObjectStoreReadAt::new(store, location);
+        assert_eq!(reader.size().await, 4);
+        let err = reader
+            .read_at_into(0, BytesMut::zeroed(8))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
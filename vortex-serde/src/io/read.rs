@@ -3,7 +3,7 @@ use std::io;
 use std::io::Cursor;
 use std::sync::Arc;
 
-use bytes::BytesMut;
+use bytes::{Bytes, BytesMut};
 use vortex_buffer::Buffer;
 use vortex_error::vortex_err;
 
@@ -25,6 +25,12 @@ pub trait VortexReadAt: Send + Sync {
         0
     }
 
+    /// Bytes at `pos` that share memory with the reader instead of being copied, for readers that
+    /// already hold the file in memory. Returns `None` when the reader doesn't support it.
+    fn read_at_shared(&self, _pos: u64, _len: usize) -> Option<io::Result<Bytes>> {
+        None
+    }
+
     /// Size of the underlying file in bytes
     fn size(&self) -> impl Future<Output = u64>;
 }
@@ -42,6 +48,10 @@ impl<T: VortexReadAt> VortexReadAt for Arc<T> {
         T::performance_hint(self)
     }
 
+    fn read_at_shared(&self, pos: u64, len: usize) -> Option<io::Result<Bytes>> {
+        T::read_at_shared(self, pos, len)
+    }
+
     async fn size(&self) -> u64 {
         T::size(self).await
     }
@@ -81,6 +91,10 @@ impl<R: ?Sized + VortexReadAt> VortexReadAt for &R {
         R::performance_hint(*self)
     }
 
+    fn read_at_shared(&self, pos: u64, len: usize) -> Option<io::Result<Bytes>> {
+        R::read_at_shared(*self, pos, len)
+    }
+
     async fn size(&self) -> u64 {
         R::size(*self).await
     }
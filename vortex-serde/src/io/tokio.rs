@@ -5,11 +5,15 @@ use std::io;
 use std::os::unix::prelude::FileExt;
 
 use bytes::BytesMut;
+use futures_util::future::BoxFuture;
+use futures_util::FutureExt;
 use tokio::fs::File;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
-use tokio::runtime::Runtime;
+use tokio::runtime::{Handle, Runtime};
+use vortex::stream::{BlockingWork, WorkerPool};
+use vortex::Array;
 use vortex_buffer::io_buf::IoBuf;
-use vortex_error::{VortexError, VortexUnwrap as _};
+use vortex_error::{vortex_err, VortexError, VortexResult, VortexUnwrap as _};
 
 use crate::io::{VortexRead, VortexReadAt, VortexWrite};
 use crate::layouts::AsyncRuntime;
@@ -81,3 +85,31 @@ impl AsyncRuntime for Runtime {
         self.block_on(fut)
     }
 }
+
+/// Worker pool running work on the blocking thread pool of a tokio runtime.
+#[derive(Debug, Clone)]
+pub struct TokioWorkerPool(Handle);
+
+impl TokioWorkerPool {
+    pub fn new(handle: Handle) -> Self {
+        Self(handle)
+    }
+
+    /// Pool of the runtime of the current task.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime.
+    pub fn current() -> Self {
+        Self(Handle::current())
+    }
+}
+
+impl WorkerPool for TokioWorkerPool {
+    fn spawn_blocking(&self, work: BlockingWork) -> BoxFuture<'static, VortexResult<Array>> {
+        self.0
+            .spawn_blocking(work)
+            .map(|joined| joined.map_err(|err| vortex_err!("Worker pool task failed: {err}"))?)
+            .boxed()
+    }
+}
@@ -0,0 +1,234 @@
+use bytes::Bytes;
+use vortex::accessor::ArrayAccessor;
+use vortex::array::PrimitiveArray;
+use vortex::compute::unary::scalar_at;
+use vortex::stats::Fnv1a;
+use vortex::{Array, ArrayDType, Canonical, IntoCanonical};
+use vortex_dtype::{match_each_native_ptype, DType};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::{PValue, ScalarValue};
+
+const BITS_PER_VALUE: usize = 10;
+// Optimal number of hash functions for 10 bits per value, ~1% false positive rate
+const NUM_HASHES: u8 = 7;
+
+// Prefixes of the hashed bytes of binary and string values
+const BINARY_TAG: u8 = 2;
+const UTF8_TAG: u8 = 3;
+
+/// Bloom filter over the values of a single chunk, stored in the metadata of its flat layout.
+///
+/// Values are hashed with FNV-1a so that the serialized filter doesn't depend on the version of
+/// the hashing library or the process that wrote it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BloomFilter {
+    num_hashes: u8,
+    words: Vec<u64>,
+}
+
+impl BloomFilter {
+    pub fn with_capacity(num_values: usize) -> Self {
+        let num_words = (num_values * BITS_PER_VALUE).div_ceil(64).max(1);
+        Self {
+            num_hashes: NUM_HASHES,
+            words: vec![0; num_words],
+        }
+    }
+
+    /// Build a filter containing all non null values of the array
+    pub fn try_from_array(array: &Array) -> VortexResult<Self> {
+        let mut bloom = Self::with_capacity(array.len());
+        match array.clone().into_canonical()? {
+            Canonical::Bool(b) => {
+                let validity = b.validity();
+                for (i, v) in b.boolean_buffer().iter().enumerate() {
+                    if validity.is_valid(i) {
+                        bloom.insert(&ScalarValue::Bool(v));
+                    }
+                }
+            }
+            Canonical::Primitive(p) => bloom.insert_primitives(&p),
+            Canonical::VarBinView(v) => {
+                let tag = if matches!(v.dtype(), DType::Utf8(_)) {
+                    UTF8_TAG
+                } else {
+                    BINARY_TAG
+                };
+                v.with_iterator(|iter| {
+                    for bytes in iter.flatten() {
+                        bloom.insert_hash(hash_bytes(tag, bytes));
+                    }
+                })?;
+            }
+            // Structs and extension types are hashed by their scalar values
+            _ => {
+                for i in 0..array.len() {
+                    let scalar = scalar_at(array, i)?;
+                    if scalar.is_valid() {
+                        bloom.insert(scalar.value());
+                    }
+                }
+            }
+        }
+        Ok(bloom)
+    }
+
+    fn insert_primitives(&mut self, array: &PrimitiveArray) {
+        let validity = array.validity();
+        match_each_native_ptype!(array.ptype(), |$T| {
+            for (i, v) in array.maybe_null_slice::<$T>().iter().enumerate() {
+                if validity.is_valid(i) {
+                    self.insert(&ScalarValue::Primitive(PValue::from(*v)));
+                }
+            }
+        })
+    }
+
+    pub fn insert(&mut self, value: &ScalarValue) {
+        self.insert_hash(hash_value(value));
+    }
+
+    fn insert_hash(&mut self, hash: u64) {
+        for bit in self.bits(hash) {
+            self.words[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Returns false if the value is definitely not in the filter
+    pub fn might_contain(&self, value: &ScalarValue) -> bool {
+        self.bits(hash_value(value))
+            .all(|bit| self.words[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    fn bits(&self, hash: u64) -> impl Iterator<Item = usize> {
+        // Kirsch-Mitzenmacher double hashing, derive all hash functions from two halves of one hash
+        let (h1, h2) = (hash & 0xffff_ffff, (hash >> 32) | 1);
+        let num_bits = (self.words.len() * 64) as u64;
+        (0..self.num_hashes as u64)
+            .map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % num_bits) as usize)
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        let mut bytes = Vec::with_capacity(1 + self.words.len() * 8);
+        bytes.push(self.num_hashes);
+        for word in &self.words {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        Bytes::from(bytes)
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> VortexResult<Self> {
+        let Some((&num_hashes, words)) = bytes.split_first() else {
+            vortex_bail!(InvalidSerde: "Empty bloom filter")
+        };
+        if words.is_empty() || words.len() % 8 != 0 {
+            vortex_bail!(InvalidSerde: "Bloom filter of {} bytes is not a whole number of words", words.len())
+        }
+
+        Ok(Self {
+            num_hashes,
+            words: words
+                .chunks_exact(8)
+                .map(|w| {
+                    let mut word = [0u8; 8];
+                    word.copy_from_slice(w);
+                    u64::from_le_bytes(word)
+                })
+                .collect(),
+        })
+    }
+}
+
+fn hash_value(value: &ScalarValue) -> u64 {
+    let mut hash = Fnv1a::new();
+    hash_into(value, &mut hash);
+    hash.finish()
+}
+
+fn hash_into(value: &ScalarValue, hash: &mut Fnv1a) {
+    match value {
+        ScalarValue::Bool(b) => hash.write(&[0, *b as u8]),
+        ScalarValue::Primitive(p) => {
+            hash.write(&[1]);
+            match p {
+                PValue::U8(v) => hash.write(&v.to_le_bytes()),
+                PValue::U16(v) => hash.write(&v.to_le_bytes()),
+                PValue::U32(v) => hash.write(&v.to_le_bytes()),
+                PValue::U64(v) => hash.write(&v.to_le_bytes()),
+                PValue::I8(v) => hash.write(&v.to_le_bytes()),
+                PValue::I16(v) => hash.write(&v.to_le_bytes()),
+                PValue::I32(v) => hash.write(&v.to_le_bytes()),
+                PValue::I64(v) => hash.write(&v.to_le_bytes()),
+                // Positive and negative zero compare equal and have to hash the same
+                PValue::F16(v) => {
+                    let bits = if v.to_f32() == 0.0 { 0 } else { v.to_bits() };
+                    hash.write(&bits.to_le_bytes())
+                }
+                PValue::F32(v) => {
+                    let bits = if *v == 0.0 { 0 } else { v.to_bits() };
+                    hash.write(&bits.to_le_bytes())
+                }
+                PValue::F64(v) => {
+                    let bits = if *v == 0.0 { 0 } else { v.to_bits() };
+                    hash.write(&bits.to_le_bytes())
+                }
+            }
+        }
+        ScalarValue::Buffer(b) => {
+            hash.write(&[BINARY_TAG]);
+            hash.write(b.as_slice())
+        }
+        ScalarValue::BufferString(s) => {
+            hash.write(&[UTF8_TAG]);
+            hash.write(s.as_str().as_bytes())
+        }
+        ScalarValue::List(elems) => {
+            hash.write(&[4]);
+            hash.write(&(elems.len() as u64).to_le_bytes());
+            for e in elems.iter() {
+                hash_into(e, hash);
+            }
+        }
+        ScalarValue::Null => hash.write(&[5]),
+    }
+}
+
+fn hash_bytes(tag: u8, bytes: &[u8]) -> u64 {
+    let mut hash = Fnv1a::new();
+    hash.write(&[tag]);
+    hash.write(bytes);
+    hash.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::VarBinViewArray;
+    use vortex::IntoArray;
+    use vortex_scalar::Scalar;
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let bloom =
+            BloomFilter::try_from_array(&PrimitiveArray::from(vec![1i32, 5, 9]).into_array())
+                .unwrap();
+        let bloom = BloomFilter::try_from_bytes(&bloom.to_bytes()).unwrap();
+        for v in [1i32, 5, 9] {
+            assert!(bloom.might_contain(Scalar::from(v).value()));
+        }
+        assert!(!(100..200).all(|v: i32| bloom.might_contain(Scalar::from(v).value())));
+    }
+
+    #[test]
+    fn strings() {
+        let strings =
+            VarBinViewArray::from_iter_nullable_str([Some("a"), None, Some("a long string value")]);
+        let bloom = BloomFilter::try_from_array(&strings.into_array()).unwrap();
+        assert!(bloom.might_contain(Scalar::from("a").value()));
+        assert!(bloom.might_contain(Scalar::from("a long string value").value()));
+        assert!(
+            !(0..100).all(|v| bloom.might_contain(Scalar::from(v.to_string().as_str()).value()))
+        );
+    }
+}
@@ -1,6 +1,7 @@
 mod read;
 mod write;
 
+mod bloom;
 mod pruning;
 #[cfg(test)]
 mod tests;
@@ -14,6 +15,7 @@ pub const FLAT_LAYOUT_ID: LayoutId = LayoutId(1);
 pub const CHUNKED_LAYOUT_ID: LayoutId = LayoutId(2);
 pub const COLUMN_LAYOUT_ID: LayoutId = LayoutId(3);
 pub const INLINE_SCHEMA_LAYOUT_ID: LayoutId = LayoutId(4);
+pub const DICT_LAYOUT_ID: LayoutId = LayoutId(5);
 
 pub use read::*;
 pub use write::*;
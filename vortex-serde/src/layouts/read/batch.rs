@@ -1,17 +1,16 @@
 use std::mem;
 use std::sync::Arc;
 
-use vortex::array::StructArray;
-use vortex::{Array, IntoArray};
 use vortex_error::{vortex_err, VortexResult};
 
+use crate::layouts::read::lazy::LazyArray;
 use crate::layouts::read::{LayoutReader, ReadResult};
 
 #[derive(Debug)]
 pub struct BatchReader {
     names: Arc<[Arc<str>]>,
     children: Vec<Box<dyn LayoutReader>>,
-    arrays: Vec<Option<Array>>,
+    arrays: Vec<Option<LazyArray>>,
 }
 
 impl BatchReader {
@@ -24,6 +23,10 @@ impl BatchReader {
         }
     }
 
+    pub(crate) fn children(&self) -> &[Box<dyn LayoutReader>] {
+        &self.children
+    }
+
     pub(crate) fn read(&mut self) -> VortexResult<Option<ReadResult>> {
         let mut messages = Vec::new();
         for (i, child_array) in self
@@ -37,7 +40,8 @@ impl BatchReader {
                     ReadResult::ReadMore(message) => {
                         messages.extend(message);
                     }
-                    ReadResult::Batch(a) => *child_array = Some(a),
+                    ReadResult::Batch(a) => *child_array = Some(a.into()),
+                    ReadResult::Lazy(a) => *child_array = Some(a),
                 },
                 None => {
                     debug_assert!(
@@ -55,10 +59,9 @@ impl BatchReader {
                 .enumerate()
                 .map(|(i, a)| a.ok_or_else(|| vortex_err!("Missing child array at index {}", i)))
                 .collect::<VortexResult<Vec<_>>>()?;
-            Ok(Some(ReadResult::Batch(
-                StructArray::from_fields(&self.names.iter().zip(child_arrays).collect::<Vec<_>>())?
-                    .into_array(),
-            )))
+            Ok(Some(
+                LazyArray::struct_(self.names.clone(), child_arrays)?.into_read_result(),
+            ))
         } else {
             Ok(Some(ReadResult::ReadMore(messages)))
         }
@@ -0,0 +1,90 @@
+use bytes::{Buf, BytesMut};
+use vortex_buffer::{AlignedBuffer, ALIGNMENT};
+
+/// Arena the buffers for reads of a stream are carved from.
+///
+/// Buffers share a single allocation of `capacity` bytes. Once all the arrays decoded from earlier
+/// reads have been dropped the allocation is reset and reused instead of being freed, otherwise a
+/// new one is started. Reads larger than the arena get a buffer of their own.
+///
+/// Like [`AlignedBuffer`], buffers are placed so that aligned positions of the file they're read
+/// from are at aligned addresses.
+#[derive(Debug)]
+pub(crate) struct BufferPool {
+    arena: BytesMut,
+    capacity: usize,
+    allocations: u64,
+}
+
+impl BufferPool {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            arena: BytesMut::new(),
+            capacity,
+            allocations: 0,
+        }
+    }
+
+    /// Zeroed buffer of `len` bytes for a read at `offset`
+    pub fn get(&mut self, len: usize, offset: u64) -> BytesMut {
+        let padded_len = len + ALIGNMENT - 1;
+        if padded_len > self.capacity {
+            return AlignedBuffer::zeroed_at(len, offset).into_inner();
+        }
+
+        if !self.arena.try_reclaim(padded_len) {
+            self.arena = BytesMut::with_capacity(self.capacity);
+            self.allocations += 1;
+        }
+        let skip = (self.arena.as_ptr().align_offset(ALIGNMENT)
+            + (offset % ALIGNMENT as u64) as usize)
+            % ALIGNMENT;
+        self.arena.resize(skip + len, 0);
+        self.arena.advance(skip);
+        self.arena.split_to(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_buffer::ALIGNMENT;
+
+    use crate::layouts::read::buffer_pool::BufferPool;
+
+    #[test]
+    fn reuses_released_buffers() {
+        let mut pool = BufferPool::new(256);
+        let first = pool.get(64, 0).freeze();
+        let second = pool.get(64, 0).freeze();
+        assert_eq!(pool.allocations, 1);
+
+        // The rest of the arena is too small and can't be reclaimed while a buffer is referenced
+        drop(first);
+        let third = pool.get(128, 0);
+        assert_eq!(pool.allocations, 2);
+
+        drop((second, third));
+        let mut fourth = pool.get(128, 0);
+        assert_eq!(pool.allocations, 2);
+        assert!(fourth.iter().all(|b| *b == 0));
+        fourth[0] = 1;
+        drop(fourth);
+
+        assert_eq!(pool.get(8, 0).as_ref(), &[0; 8]);
+        assert_eq!(pool.get(512, 0).len(), 512);
+        assert_eq!(pool.allocations, 2);
+    }
+
+    #[test]
+    fn aligned_to_offset() {
+        let mut pool = BufferPool::new(1024);
+        for (len, offset) in [(10, 0), (7, 3), (100, 64), (30, 130)] {
+            let buffer = pool.get(len, offset);
+            assert_eq!(buffer.len(), len);
+            assert_eq!(
+                buffer.as_ptr() as usize % ALIGNMENT,
+                offset as usize % ALIGNMENT
+            );
+        }
+    }
+}
@@ -1,47 +1,65 @@
 use std::collections::VecDeque;
 
-use vortex::array::ChunkedArray;
-use vortex::compute::slice;
-use vortex::{Array, ArrayDType, IntoArray};
 use vortex_error::VortexResult;
 
-use crate::layouts::read::{LayoutReader, ReadResult};
+use crate::layouts::read::lazy::LazyArray;
+use crate::layouts::read::{ChunkPosition, LayoutReader, ReadResult};
 
+/// Reads the chunks of a chunked layout in batches of `batch_size` rows
 #[derive(Debug)]
 pub struct BufferedReader {
-    layouts: VecDeque<Box<dyn LayoutReader>>,
-    arrays: VecDeque<Array>,
+    /// Chunks left to read, with their index
+    layouts: VecDeque<(usize, Box<dyn LayoutReader>)>,
+    /// Arrays read ahead of the current batch, with the index of the chunk they're from
+    arrays: VecDeque<(usize, LazyArray)>,
     batch_size: usize,
+    /// Position of the next row returned
+    position: ChunkPosition,
+    /// Rows at the start of `position`'s chunk that are read but not returned
+    rows_to_skip: usize,
 }
 
 impl BufferedReader {
-    pub fn new(layouts: VecDeque<Box<dyn LayoutReader>>, batch_size: usize) -> Self {
+    /// Reader of the given chunks, skipping the rows before `start`
+    pub fn new(
+        layouts: VecDeque<(usize, Box<dyn LayoutReader>)>,
+        batch_size: usize,
+        start: ChunkPosition,
+    ) -> Self {
         Self {
             layouts,
             arrays: Default::default(),
             batch_size,
+            position: start,
+            rows_to_skip: start.rows_read,
         }
     }
 
+    pub fn position(&self) -> ChunkPosition {
+        self.position
+    }
+
     fn is_empty(&self) -> bool {
         self.layouts.is_empty() && self.arrays.is_empty()
     }
 
     fn buffered_row_count(&self) -> usize {
-        self.arrays.iter().map(Array::len).sum()
+        self.arrays.iter().map(|(_, a)| a.len()).sum()
     }
 
     fn buffer(&mut self) -> VortexResult<Option<ReadResult>> {
         while self.buffered_row_count() < self.batch_size {
-            if let Some(mut layout) = self.layouts.pop_front() {
+            if let Some((chunk_idx, mut layout)) = self.layouts.pop_front() {
                 if let Some(rr) = layout.read_next()? {
-                    self.layouts.push_front(layout);
-                    match rr {
+                    self.layouts.push_front((chunk_idx, layout));
+                    let array = match rr {
                         read_more @ ReadResult::ReadMore(..) => {
                             return Ok(Some(read_more));
                         }
-                        ReadResult::Batch(a) => self.arrays.push_back(a),
-                    }
+                        ReadResult::Batch(a) => a.into(),
+                        ReadResult::Lazy(a) => a,
+                    };
+                    self.push_array(chunk_idx, array)?;
                 } else {
                     continue;
                 }
@@ -52,6 +70,18 @@ impl BufferedReader {
         Ok(None)
     }
 
+    fn push_array(&mut self, chunk_idx: usize, mut array: LazyArray) -> VortexResult<()> {
+        if chunk_idx == self.position.chunk_idx && self.rows_to_skip > 0 {
+            let skipped = self.rows_to_skip.min(array.len());
+            self.rows_to_skip -= skipped;
+            array = array.slice(skipped, array.len())?;
+        }
+        if !array.is_empty() {
+            self.arrays.push_back((chunk_idx, array));
+        }
+        Ok(())
+    }
+
     pub fn read(&mut self) -> VortexResult<Option<ReadResult>> {
         if self.is_empty() {
             return Ok(None);
@@ -60,7 +90,7 @@ impl BufferedReader {
         if let Some(rr) = self.buffer()? {
             match rr {
                 read_more @ ReadResult::ReadMore(..) => return Ok(Some(read_more)),
-                ReadResult::Batch(_) => {
+                ReadResult::Batch(_) | ReadResult::Lazy(_) => {
                     unreachable!("Batches should be handled inside the buffer call")
                 }
             }
@@ -77,28 +107,33 @@ impl BufferedReader {
         while rows_to_read != 0 {
             match self.arrays.pop_front() {
                 None => break,
-                Some(array) => {
-                    if array.len() > rows_to_read {
-                        let taken = slice(&array, 0, rows_to_read)?;
-                        let leftover = slice(&array, rows_to_read, array.len())?;
-                        self.arrays.push_front(leftover);
-                        rows_to_read -= taken.len();
-                        result.push(taken);
+                Some((chunk_idx, array)) => {
+                    let taken = if array.len() > rows_to_read {
+                        let taken = array.slice(0, rows_to_read)?;
+                        let leftover = array.slice(rows_to_read, array.len())?;
+                        self.arrays.push_front((chunk_idx, leftover));
+                        taken
                     } else {
-                        rows_to_read -= array.len();
-                        result.push(array);
+                        array
+                    };
+                    if chunk_idx != self.position.chunk_idx {
+                        self.position = ChunkPosition {
+                            chunk_idx,
+                            rows_read: 0,
+                        };
                     }
+                    self.position.rows_read += taken.len();
+                    rows_to_read -= taken.len();
+                    result.push(taken);
                 }
             }
         }
 
         match result.len() {
-            0 | 1 => Ok(result.pop().map(ReadResult::Batch)),
+            0 | 1 => Ok(result.pop().map(LazyArray::into_read_result)),
             _ => {
                 let dtype = result[0].dtype().clone();
-                Ok(Some(ReadResult::Batch(
-                    ChunkedArray::try_new(result, dtype)?.into_array(),
-                )))
+                Ok(Some(LazyArray::chunked(result, dtype)?.into_read_result()))
             }
         }
     }
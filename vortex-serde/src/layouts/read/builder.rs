@@ -1,25 +1,50 @@
+use std::collections::HashSet;
+use std::mem;
 use std::sync::{Arc, RwLock};
 
-use vortex::{Array, ArrayDType};
-use vortex_error::VortexResult;
+use futures_util::{stream, StreamExt, TryStreamExt};
+use vortex::memory::MemoryPool;
+use vortex::{Array, ArrayDType, Context};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexError, VortexResult};
 use vortex_schema::projection::Projection;
 
 use crate::io::VortexReadAt;
 use crate::layouts::read::cache::{LayoutMessageCache, LazyDeserializedDType, RelativeLayoutCache};
 use crate::layouts::read::context::LayoutDeserializer;
-use crate::layouts::read::filtering::RowFilter;
-use crate::layouts::read::footer::LayoutDescriptorReader;
-use crate::layouts::read::stream::LayoutBatchStream;
+use crate::layouts::read::filtering::{
+    compared_column, prunes_chunks, rules_out, FilterMode, RowFilter,
+};
+use crate::layouts::read::footer::{LayoutDescriptor, LayoutDescriptorReader};
+use crate::layouts::read::position::StreamPosition;
+use crate::layouts::read::recordbatchreader::VortexRecordBatchStream;
+use crate::layouts::read::recovery::{ErrorPolicy, SkippedChunkCallback};
+use crate::layouts::read::stream::{
+    BatchWithIndex, IndexedBatchStream, LayoutBatchStream, LazyBatchStream,
+};
 use crate::layouts::read::{Scan, DEFAULT_BATCH_SIZE};
 
 pub struct LayoutReaderBuilder<R> {
     reader: R,
     layout_serde: LayoutDeserializer,
+    footer: Option<LayoutDescriptor>,
     projection: Option<Projection>,
     size: Option<u64>,
     indices: Option<Array>,
     row_filter: Option<RowFilter>,
+    filter_mode: FilterMode,
     batch_size: Option<usize>,
+    error_policy: ErrorPolicy,
+    on_skipped_chunk: Option<SkippedChunkCallback>,
+    coalesce_gap: u64,
+    buffer_pool_capacity: Option<usize>,
+    memory_pool: Option<MemoryPool>,
+    lazy: bool,
+    validate: bool,
+    pruned_chunks: HashSet<usize>,
+    selected_chunk: Option<usize>,
+    resume_from: Option<StreamPosition>,
+    ordered: bool,
 }
 
 impl<R: VortexReadAt> LayoutReaderBuilder<R> {
@@ -27,14 +52,39 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         Self {
             reader,
             layout_serde,
+            footer: None,
             projection: None,
             row_filter: None,
+            filter_mode: FilterMode::default(),
             size: None,
             indices: None,
             batch_size: None,
+            error_policy: ErrorPolicy::default(),
+            on_skipped_chunk: None,
+            coalesce_gap: 0,
+            buffer_pool_capacity: None,
+            memory_pool: None,
+            lazy: false,
+            validate: false,
+            pruned_chunks: HashSet::new(),
+            selected_chunk: None,
+            resume_from: None,
+            ordered: true,
         }
     }
 
+    /// Decode arrays using the given context, e.g. one that registers additional encodings
+    pub fn with_context(mut self, ctx: Arc<Context>) -> Self {
+        self.layout_serde = self.layout_serde.with_context(ctx);
+        self
+    }
+
+    /// Use a footer that has already been read instead of reading it again from the file
+    pub fn with_footer(mut self, footer: LayoutDescriptor) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
     pub fn with_size(mut self, size: u64) -> Self {
         self.size = Some(size);
         self
@@ -60,15 +110,117 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         self
     }
 
+    /// Return batches with all their rows and the rows matching the row filter as a separate
+    /// selection instead of only the matching rows, see [`FilterMode`].
+    ///
+    /// The selection is only available on the batches of [`Self::build_lazy`], the other streams
+    /// can't be built with [`FilterMode::Mask`]. As every row is returned, no chunks are pruned.
+    pub fn with_filter_mode(mut self, filter_mode: FilterMode) -> Self {
+        self.filter_mode = filter_mode;
+        self
+    }
+
     pub fn with_batch_size(mut self, batch_size: usize) -> Self {
         self.batch_size = Some(batch_size);
         self
     }
 
-    pub async fn build(self) -> VortexResult<LayoutBatchStream<R>> {
-        let footer = LayoutDescriptorReader::new(self.layout_serde.clone())
-            .read_footer(&self.reader, self.size().await as u64)
-            .await?;
+    pub fn with_error_policy(mut self, error_policy: ErrorPolicy) -> Self {
+        self.error_policy = error_policy;
+        self
+    }
+
+    /// Register a callback notified of every chunk skipped under [`ErrorPolicy::SkipChunk`]
+    pub fn with_skipped_chunk_callback(mut self, callback: SkippedChunkCallback) -> Self {
+        self.on_skipped_chunk = Some(callback);
+        self
+    }
+
+    /// Merge byte ranges at most `gap` bytes apart into a single read, trading over-reading the
+    /// bytes in between for fewer requests. Adjacent and overlapping ranges are always merged.
+    pub fn with_coalesce_gap(mut self, gap: u64) -> Self {
+        self.coalesce_gap = gap;
+        self
+    }
+
+    /// Allocate read buffers from an arena of `capacity` bytes that is reused once the arrays
+    /// decoded from previous batches are dropped, reducing allocations during long scans.
+    pub fn with_buffer_pool(mut self, capacity: usize) -> Self {
+        self.buffer_pool_capacity = Some(capacity);
+        self
+    }
+
+    /// Charge the buffers read by the stream against `pool` before reading them, failing the
+    /// stream once the budget is exhausted.
+    ///
+    /// A buffer stays charged until the last batch decoded from it is dropped, so the pool bounds
+    /// the memory of the batches being read as well as of those kept by the caller.
+    pub fn with_memory_pool(mut self, pool: MemoryPool) -> Self {
+        self.memory_pool = Some(pool);
+        self
+    }
+
+    /// Check the invariants of every array read from the file, failing the stream on corrupt
+    /// input instead of returning arrays that may panic when accessed, see [`Array::validate`].
+    ///
+    /// Some checks decode parts of the arrays, so validation is off by default.
+    pub fn with_validation(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Skip the chunks with the given indices, e.g. because their statistics rule out rows that
+    /// match a filter the caller applies. Skipped chunks are counted as pruned.
+    ///
+    /// Ignored with [`FilterMode::Mask`], which returns every row of the file.
+    pub fn with_pruned_chunks(mut self, chunks: impl IntoIterator<Item = usize>) -> Self {
+        self.pruned_chunks.extend(chunks);
+        self
+    }
+
+    /// Continue reading from the position of an earlier stream over the same file, see
+    /// [`LayoutBatchStream::position`]. The stream has to be built with the same projection and
+    /// row filter as the earlier one, and without indices.
+    pub fn resume_from(mut self, position: StreamPosition) -> Self {
+        self.resume_from = Some(position);
+        self
+    }
+
+    /// Whether [`Self::build_indexed`] yields the batches in file order, the default, or as soon as
+    /// the reads of their chunks complete.
+    ///
+    /// The other streams always read the file in order and can't be built with `false`.
+    pub fn with_ordered(mut self, ordered: bool) -> Self {
+        self.ordered = ordered;
+        self
+    }
+
+    pub async fn build(mut self) -> VortexResult<LayoutBatchStream<R>> {
+        if !self.ordered {
+            vortex_bail!("Only indexed streams can return batches out of order");
+        }
+        let (footer, footer_bytes_read) = match self.footer.take() {
+            Some(mut footer) => {
+                footer.layout_serde = self.layout_serde.clone();
+                (footer, 0)
+            }
+            None => {
+                let footer = LayoutDescriptorReader::new(self.layout_serde.clone())
+                    .read_footer(&self.reader, self.size().await as u64)
+                    .await?;
+                let footer_bytes_read = footer.initial_read.len() as u64;
+                (footer, footer_bytes_read)
+            }
+        };
+        self.prune_by_stats(&footer).await?;
+        self.build_with_footer(footer, footer_bytes_read)
+    }
+
+    fn build_with_footer(
+        mut self,
+        footer: LayoutDescriptor,
+        footer_bytes_read: u64,
+    ) -> VortexResult<LayoutBatchStream<R>> {
         let batch_size = self.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
         // TODO(robert): Propagate projection immediately instead of delegating to layouts, needs more restructuring
         let footer_dtype = Arc::new(LazyDeserializedDType::from_bytes(
@@ -83,16 +235,59 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
             .map(|f| f.references().into_iter().cloned().collect::<Vec<_>>())
             .map(Projection::from);
 
+        let column_names = match footer.dtype()? {
+            DType::Struct(s, _) => Some(s.names().clone()),
+            _ => None,
+        };
+
+        if self.filter_mode == FilterMode::Mask && !self.lazy {
+            vortex_bail!("Only lazy streams return the selection of FilterMode::Mask");
+        }
+
+        let mut pruned_chunks = mem::take(&mut self.pruned_chunks);
+        match (&self.row_filter, self.filter_mode) {
+            (Some(filter), FilterMode::Compact) => {
+                pruned_chunks.extend(bloom_pruned_chunks(&footer, filter, &footer.dtype()?)?);
+            }
+            (None, FilterMode::Compact) => {}
+            // Every row is returned with the mask, so no chunk can be skipped
+            (_, FilterMode::Mask) => pruned_chunks.clear(),
+        }
+        let num_pruned_chunks = pruned_chunks.len() as u64;
+        let pruned_chunks = (!pruned_chunks.is_empty()).then(|| Arc::new(pruned_chunks));
+
         let projected_dtype = match read_projection {
             Projection::All => footer.dtype()?,
             Projection::Flat(ref projection) => footer.projected_dtype(projection)?,
         };
 
+        if self.resume_from.is_some() && self.indices.is_some() {
+            vortex_bail!("Can't resume a scan of row indices");
+        }
+        let current_offset = self
+            .resume_from
+            .as_ref()
+            .map(|p| p.current_offset)
+            .unwrap_or_default();
+        let resume_from = self.resume_from.take().map(Arc::new);
+
         let scan = Scan {
             filter: self.row_filter.clone(),
+            filter_mode: self.filter_mode,
             batch_size,
             projection: read_projection,
             indices: self.indices,
+            error_policy: self.error_policy,
+            on_skipped_chunk: self.on_skipped_chunk.clone(),
+            pruned_chunks: pruned_chunks.clone(),
+            selected_chunk: self.selected_chunk,
+            coalesce_gap: self.coalesce_gap,
+            buffer_pool_capacity: self.buffer_pool_capacity,
+            memory_pool: self.memory_pool.clone(),
+            lazy: self.lazy,
+            validate: self.validate,
+            resume_from: resume_from.clone(),
+            chunk_start: None,
         };
 
         let message_cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
@@ -107,23 +302,177 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
                 footer.layout(
                     Scan {
                         filter: self.row_filter,
+                        filter_mode: self.filter_mode,
                         batch_size,
                         projection,
                         indices: None,
+                        error_policy: self.error_policy,
+                        on_skipped_chunk: self.on_skipped_chunk,
+                        pruned_chunks,
+                        selected_chunk: self.selected_chunk,
+                        coalesce_gap: self.coalesce_gap,
+                        buffer_pool_capacity: self.buffer_pool_capacity,
+                        memory_pool: self.memory_pool,
+                        lazy: false,
+                        validate: self.validate,
+                        resume_from,
+                        chunk_start: None,
                     },
                     RelativeLayoutCache::new(message_cache.clone(), footer_dtype),
                 )
             })
             .transpose()?;
 
-        Ok(LayoutBatchStream::new(
+        let mut stream = LayoutBatchStream::new(
             self.reader,
             data_reader,
             filter_reader,
             message_cache,
             projected_dtype,
+            column_names,
             scan,
-        ))
+        );
+        stream.record_bytes_read(footer_bytes_read);
+        stream.record_chunks_pruned(num_pruned_chunks);
+        stream.set_current_offset(current_offset);
+        Ok(stream)
+    }
+
+    /// Build a stream of batches whose columns are only decoded when they're accessed, see
+    /// [`LazyBatch`](crate::layouts::LazyBatch).
+    ///
+    /// Errors decoding a column are returned when accessing it, regardless of the error policy.
+    pub async fn build_lazy(mut self) -> VortexResult<LazyBatchStream<R>> {
+        self.lazy = true;
+        Ok(LazyBatchStream::new(self.build().await?))
+    }
+
+    /// Build a stream of Arrow record batches, see [`Self::build`]
+    pub async fn build_arrow(self) -> VortexResult<VortexRecordBatchStream<R>> {
+        VortexRecordBatchStream::new(self.build().await?)
+    }
+
+    /// Build a stream reading up to `concurrency` chunks of the file at a time, each with its own
+    /// [`LayoutBatchStream`], and tagging their batches with the chunk index.
+    ///
+    /// Batches of a chunk are always returned in order. Unless [`Self::with_ordered`] is `false`,
+    /// the chunks are too, otherwise a chunk's batches are returned as soon as all of them are read.
+    /// Files whose columns aren't chunked the same way are read as a single chunk, as are
+    /// files without chunked columns. Reading row indices or resuming a stream is not supported.
+    pub async fn build_indexed(mut self, concurrency: usize) -> VortexResult<IndexedBatchStream>
+    where
+        R: Clone + Unpin + Send + 'static,
+    {
+        if self.indices.is_some() || self.resume_from.is_some() {
+            vortex_bail!("Can't read chunks of row indices or of a resumed scan concurrently");
+        }
+        if concurrency == 0 {
+            vortex_bail!("Concurrency of an indexed stream must be at least 1");
+        }
+
+        let mut footer = match self.footer.take() {
+            Some(footer) => footer,
+            None => {
+                LayoutDescriptorReader::new(self.layout_serde.clone())
+                    .read_footer(&self.reader, self.size().await)
+                    .await?
+            }
+        };
+        footer.layout_serde = self.layout_serde.clone();
+        self.prune_by_stats(&footer).await?;
+
+        let chunk_builders = match footer.num_chunks()? {
+            Some(num_chunks) => (0..num_chunks)
+                .filter(|chunk_idx| !self.pruned_chunks.contains(chunk_idx))
+                .map(|chunk_idx| {
+                    let mut builder = self.clone_builder();
+                    builder.selected_chunk = Some(chunk_idx);
+                    (chunk_idx, builder, footer.clone())
+                })
+                .collect::<Vec<_>>(),
+            None => vec![(0, self.clone_builder(), footer)],
+        };
+
+        let chunks = stream::iter(chunk_builders).map(|(chunk_idx, builder, footer)| async move {
+            let batches = builder
+                .build_with_footer(footer, 0)?
+                .try_collect::<Vec<_>>()
+                .await?;
+            Ok::<_, VortexError>(stream::iter(
+                batches
+                    .into_iter()
+                    .map(move |batch| Ok(BatchWithIndex { chunk_idx, batch })),
+            ))
+        });
+
+        Ok(if self.ordered {
+            chunks.buffered(concurrency).try_flatten().boxed()
+        } else {
+            chunks.buffer_unordered(concurrency).try_flatten().boxed()
+        })
+    }
+
+    /// Add the chunks whose minimum and maximum rule out a comparison of the filter to the pruned
+    /// chunks, reading the metadata tables of the compared columns
+    async fn prune_by_stats(&mut self, footer: &LayoutDescriptor) -> VortexResult<()> {
+        let Some(filter) = self
+            .row_filter
+            .as_ref()
+            .filter(|_| self.filter_mode == FilterMode::Compact)
+        else {
+            return Ok(());
+        };
+
+        let dtype = footer.dtype()?;
+        for (field, op, value) in filter.comparisons() {
+            let Some(column_idx) =
+                compared_column(&dtype, field, value).filter(|_| prunes_chunks(op))
+            else {
+                continue;
+            };
+            if let Some(min_max) = footer.chunk_min_max(&self.reader, column_idx).await? {
+                self.pruned_chunks.extend(
+                    min_max
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, bounds)| {
+                            bounds
+                                .as_ref()
+                                .is_some_and(|(min, max)| rules_out(op, value, min, max))
+                        })
+                        .map(|(chunk_idx, _)| chunk_idx),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn clone_builder(&self) -> Self
+    where
+        R: Clone,
+    {
+        Self {
+            reader: self.reader.clone(),
+            layout_serde: self.layout_serde.clone(),
+            footer: None,
+            projection: self.projection.clone(),
+            size: self.size,
+            indices: None,
+            row_filter: self.row_filter.clone(),
+            filter_mode: self.filter_mode,
+            batch_size: self.batch_size,
+            error_policy: self.error_policy,
+            on_skipped_chunk: self.on_skipped_chunk.clone(),
+            coalesce_gap: self.coalesce_gap,
+            buffer_pool_capacity: self.buffer_pool_capacity,
+            memory_pool: self.memory_pool.clone(),
+            lazy: self.lazy,
+            validate: self.validate,
+            pruned_chunks: self.pruned_chunks.clone(),
+            selected_chunk: None,
+            resume_from: None,
+            ordered: self.ordered,
+        }
     }
 
     async fn size(&self) -> u64 {
@@ -133,3 +482,29 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         }
     }
 }
+
+/// Indices of chunks whose bloom filters rule out a value an equality conjunct of the filter requires
+fn bloom_pruned_chunks(
+    footer: &LayoutDescriptor,
+    filter: &RowFilter,
+    dtype: &DType,
+) -> VortexResult<HashSet<usize>> {
+    let mut pruned = HashSet::new();
+    for (field, value) in filter.equalities() {
+        let Some(column_idx) = compared_column(dtype, field, value) else {
+            continue;
+        };
+
+        if let Some(bloom_filters) = footer.bloom_filters(column_idx)? {
+            pruned.extend(
+                bloom_filters
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, bloom)| !bloom.might_contain(value.value()))
+                    .map(|(chunk_idx, _)| chunk_idx),
+            );
+        }
+    }
+
+    Ok(pruned)
+}
@@ -8,7 +8,9 @@ use vortex_error::{vortex_err, VortexResult};
 use vortex_flatbuffers::footer as fb;
 
 use crate::layouts::read::cache::RelativeLayoutCache;
-use crate::layouts::read::layouts::{ChunkedLayoutSpec, ColumnLayoutSpec, FlatLayoutSpec};
+use crate::layouts::read::layouts::{
+    ChunkedLayoutSpec, ColumnLayoutSpec, DictLayoutSpec, FlatLayoutSpec,
+};
 use crate::layouts::read::{LayoutReader, Scan};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
@@ -30,7 +32,7 @@ pub trait LayoutSpec: Debug + Send + Sync {
         scan: Scan,
         layout_reader: LayoutDeserializer,
         message_cache: RelativeLayoutCache,
-    ) -> Box<dyn LayoutReader>;
+    ) -> VortexResult<Box<dyn LayoutReader>>;
 }
 
 pub type LayoutSpecRef = &'static dyn LayoutSpec;
@@ -57,6 +59,7 @@ impl Default for LayoutContext {
                 &ColumnLayoutSpec as LayoutSpecRef,
                 &ChunkedLayoutSpec,
                 &FlatLayoutSpec,
+                &DictLayoutSpec,
             ]
             .into_iter()
             .map(|l| (l.id(), l))
@@ -65,15 +68,26 @@ impl Default for LayoutContext {
     }
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct LayoutDeserializer {
     ctx: Arc<Context>,
     layout_ctx: Arc<LayoutContext>,
+    data_end: u64,
+}
+
+impl Default for LayoutDeserializer {
+    fn default() -> Self {
+        Self::new(Arc::default(), Arc::default())
+    }
 }
 
 impl LayoutDeserializer {
     pub fn new(ctx: Arc<Context>, layout_ctx: Arc<LayoutContext>) -> Self {
-        Self { ctx, layout_ctx }
+        Self {
+            ctx,
+            layout_ctx,
+            data_end: u64::MAX,
+        }
     }
 
     pub fn read_layout(
@@ -88,14 +102,29 @@ impl LayoutDeserializer {
             fb::Layout::init_from_table(tab)
         };
         let layout_id = LayoutId(fb_layout.encoding());
-        Ok(self
+        let layout_spec = self
             .layout_ctx
             .lookup_layout(&layout_id)
-            .ok_or_else(|| vortex_err!("Unknown layout definition {layout_id}"))?
-            .layout(fb_bytes, fb_loc, scan, self.clone(), message_cache))
+            .ok_or_else(|| vortex_err!("Unknown layout definition {layout_id}"))?;
+        layout_spec.layout(fb_bytes, fb_loc, scan, self.clone(), message_cache)
+    }
+
+    /// Replace the encoding context used to decode arrays, keeping the layout definitions
+    pub fn with_context(self, ctx: Arc<Context>) -> Self {
+        Self { ctx, ..self }
     }
 
     pub(crate) fn ctx(&self) -> Arc<Context> {
         self.ctx.clone()
     }
+
+    /// Limit the buffers of the layouts to the first `data_end` bytes of the file
+    pub(crate) fn with_data_end(self, data_end: u64) -> Self {
+        Self { data_end, ..self }
+    }
+
+    /// Offset that the buffers of the layouts have to end before
+    pub(crate) fn data_end(&self) -> u64 {
+        self.data_end
+    }
 }
@@ -2,15 +2,55 @@ use std::collections::HashSet;
 use std::fmt::Debug;
 use std::sync::Arc;
 
-use vortex::array::ConstantArray;
-use vortex::compute::and;
+use vortex::array::{Bool, ConstantArray, Extension, Null, Primitive, Struct, VarBin, VarBinView};
+use vortex::compute::{and, slice, Operator as ArrayOperator};
 use vortex::stats::ArrayStatistics;
-use vortex::{Array, IntoArray, IntoArrayVariant};
+use vortex::{Array, ArrayDef, IntoArray, IntoArrayVariant};
 use vortex_dtype::field::Field;
+use vortex_dtype::DType;
 use vortex_error::{VortexExpect, VortexResult};
-use vortex_expr::{split_conjunction, VortexExpr};
+use vortex_expr::{split_conjunction, BinaryExpr, Column, Literal, Operator, VortexExpr};
+use vortex_scalar::Scalar;
 
-use crate::layouts::null_as_false;
+use crate::io::VortexReadAt;
+use crate::layouts::{null_as_false, LayoutDescriptor};
+
+/// How the reader applies a [`RowFilter`] to the batches it returns
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FilterMode {
+    /// Only the rows matching the filter are returned, batches without any are skipped
+    #[default]
+    Compact,
+    /// Batches are returned with all their rows, and the rows matching the filter as a separate
+    /// selection, see [`LazyBatch::selection`](crate::layouts::LazyBatch::selection). Keeps row
+    /// positions for consumers that need them, e.g. window functions.
+    Mask,
+}
+
+/// How each predicate of a [`RowFilter`] is executed against a file, see [`RowFilter::explain`]
+#[derive(Debug, Clone)]
+pub struct FilterPlan {
+    pub predicates: Vec<PredicatePlan>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PredicatePlan {
+    pub predicate: Arc<dyn VortexExpr>,
+    /// Strategies in the order they are applied, the last one evaluates the predicate on the rows
+    pub strategies: Vec<PredicateStrategy>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredicateStrategy {
+    /// Chunks whose minimum and maximum rule out any match are skipped without being read
+    ChunkPruning,
+    /// Chunks whose bloom filter rules out the compared value are skipped without being read
+    BloomFilter,
+    /// Compared against a literal by the encoding of the column, e.g. on the codes of a dictionary
+    EncodedCompare,
+    /// Evaluated on every row of the decoded batches
+    FullScan,
+}
 
 #[derive(Debug, Clone)]
 pub struct RowFilter {
@@ -24,6 +64,10 @@ impl RowFilter {
     }
 
     /// Evaluate the underlying filter against a target array, returning a boolean mask
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(rows = target.len()))
+    )]
     pub fn evaluate(&self, target: &Array) -> VortexResult<Array> {
         let mut filter_iter = self.conjunction.iter();
         let mut mask = filter_iter
@@ -51,4 +95,158 @@ impl RowFilter {
 
         set
     }
+
+    /// Returns the column, operator and value of every conjunct comparing a column with a literal
+    pub(crate) fn comparisons(&self) -> impl Iterator<Item = (&Field, Operator, &Scalar)> {
+        self.conjunction.iter().filter_map(column_literal_compare)
+    }
+
+    /// Returns the column and value of every conjunct comparing a column for equality with a literal
+    pub(crate) fn equalities(&self) -> impl Iterator<Item = (&Field, &Scalar)> {
+        self.comparisons()
+            .filter_map(|(field, op, value)| (op == Operator::Eq).then_some((field, value)))
+    }
+
+    /// Describe how every predicate of the filter is applied when reading the file of `footer`,
+    /// to check whether a filter benefits from pushdown before running it.
+    ///
+    /// Reads the chunk metadata tables and the first chunk of every column compared with a
+    /// literal, whose encoding decides whether the comparison runs on the encoded values. Chunks
+    /// are only pruned with [`FilterMode::Compact`].
+    pub async fn explain<R: VortexReadAt>(
+        &self,
+        read: &R,
+        footer: &LayoutDescriptor,
+    ) -> VortexResult<FilterPlan> {
+        let dtype = footer.dtype()?;
+        let mut predicates = Vec::with_capacity(self.conjunction.len());
+        for expr in self.conjunction.iter() {
+            let mut strategies = Vec::new();
+            match column_literal_compare(expr) {
+                Some((field, op, value)) => {
+                    let column_idx = compared_column(&dtype, field, value);
+                    if let Some(column_idx) = column_idx {
+                        if prunes_chunks(op)
+                            && footer.chunk_min_max(read, column_idx).await?.is_some()
+                        {
+                            strategies.push(PredicateStrategy::ChunkPruning);
+                        }
+                        if op == Operator::Eq && footer.bloom_filters(column_idx)?.is_some() {
+                            strategies.push(PredicateStrategy::BloomFilter);
+                        }
+                    }
+                    let encoded = match column_idx {
+                        Some(column_idx) => footer
+                            .first_chunk(read, column_idx)
+                            .await?
+                            .map(|chunk| compares_encoded(&chunk, op, value))
+                            .transpose()?
+                            .unwrap_or(false),
+                        None => false,
+                    };
+                    strategies.push(if encoded {
+                        PredicateStrategy::EncodedCompare
+                    } else {
+                        PredicateStrategy::FullScan
+                    });
+                }
+                None => strategies.push(PredicateStrategy::FullScan),
+            }
+            predicates.push(PredicatePlan {
+                predicate: expr.clone(),
+                strategies,
+            });
+        }
+        Ok(FilterPlan { predicates })
+    }
+}
+
+/// Column, operator and value of a conjunct comparing a column with a literal, the operator with
+/// the column on the left hand side
+fn column_literal_compare(expr: &Arc<dyn VortexExpr>) -> Option<(&Field, Operator, &Scalar)> {
+    let bexp = expr.as_any().downcast_ref::<BinaryExpr>()?;
+    if matches!(bexp.op(), Operator::And | Operator::Or) {
+        return None;
+    }
+    let lhs = bexp.lhs().as_any();
+    let rhs = bexp.rhs().as_any();
+    match (lhs.downcast_ref::<Column>(), rhs.downcast_ref::<Literal>()) {
+        (Some(col), Some(lit)) => Some((col.field(), bexp.op(), lit.value())),
+        _ => rhs
+            .downcast_ref::<Column>()
+            .zip(lhs.downcast_ref::<Literal>())
+            .map(|(col, lit)| (col.field(), bexp.op().swap(), lit.value())),
+    }
+}
+
+/// Index of the top level column compared with `value`, if its chunk statistics and bloom
+/// filters can be used to prune chunks
+pub(crate) fn compared_column(dtype: &DType, field: &Field, value: &Scalar) -> Option<usize> {
+    let DType::Struct(s, _) = dtype else {
+        return None;
+    };
+    let column_idx = match field {
+        Field::Name(name) => s.find_name(name),
+        Field::Index(idx) => Some(*idx),
+    }?;
+    // Statistics and filters only hold values of the column type, other literals may still compare
+    let comparable = s
+        .dtypes()
+        .get(column_idx)
+        .is_some_and(|dtype| dtype.eq_ignore_nullability(value.dtype()));
+    (comparable && !value.is_null()).then_some(column_idx)
+}
+
+/// Whether chunks can be pruned by their minimum and maximum for comparisons with `op`
+pub(crate) fn prunes_chunks(op: Operator) -> bool {
+    matches!(
+        op,
+        Operator::Eq | Operator::Lt | Operator::Lte | Operator::Gt | Operator::Gte
+    )
+}
+
+/// Whether no value of a chunk between `min` and `max` can satisfy `column <op> value`
+pub(crate) fn rules_out(op: Operator, value: &Scalar, min: &Scalar, max: &Scalar) -> bool {
+    match op {
+        Operator::Eq => value < min || value > max,
+        Operator::Lt => min >= value,
+        Operator::Lte => min > value,
+        Operator::Gt => max <= value,
+        Operator::Gte => max < value,
+        _ => false,
+    }
+}
+
+/// Whether the encoding of `array` compares it with a literal without decoding it. Canonical
+/// arrays, and the encodings without a comparison of their own, compare every decoded row.
+fn compares_encoded(array: &Array, op: Operator, value: &Scalar) -> VortexResult<bool> {
+    if [
+        Null::ID,
+        Bool::ID,
+        Primitive::ID,
+        Struct::ID,
+        VarBin::ID,
+        VarBinView::ID,
+        Extension::ID,
+    ]
+    .into_iter()
+    .any(|id| array.is_encoding(id))
+    {
+        return Ok(false);
+    }
+    let op = match op {
+        Operator::Eq => ArrayOperator::Eq,
+        Operator::NotEq => ArrayOperator::NotEq,
+        Operator::Gt => ArrayOperator::Gt,
+        Operator::Gte => ArrayOperator::Gte,
+        Operator::Lt => ArrayOperator::Lt,
+        Operator::Lte => ArrayOperator::Lte,
+        Operator::And | Operator::Or => return Ok(false),
+    };
+    if array.is_empty() {
+        return Ok(false);
+    }
+    let probe = slice(array, 0, 1)?;
+    let literal = ConstantArray::new(value.clone(), probe.len()).into_array();
+    Ok(probe.with_dyn(|a| a.compare(&literal, op)).is_some())
 }
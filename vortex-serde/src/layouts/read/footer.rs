@@ -1,17 +1,30 @@
 use bytes::{Bytes, BytesMut};
 use flatbuffers::root;
+use vortex::array::StructArray;
+use vortex::compute::unary::scalar_at;
+use vortex::stats::{ArrayStatistics, Stat, StatsSet};
+use vortex::variants::StructArrayTrait;
+use vortex::{Array, ArrayDType, IntoArrayVariant, IntoCanonical};
 use vortex_dtype::field::Field;
 use vortex_dtype::flatbuffers::deserialize_and_project;
-use vortex_dtype::DType;
+use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 use vortex_flatbuffers::{footer, message as fb};
+use vortex_scalar::Scalar;
+use vortex_schema::projection::Projection;
 
 use crate::io::VortexReadAt;
+use crate::layouts::bloom::BloomFilter;
 use crate::layouts::read::cache::RelativeLayoutCache;
 use crate::layouts::read::context::LayoutDeserializer;
+use crate::layouts::read::layouts::{array_from_bytes, buffer_range};
 use crate::layouts::read::{LayoutReader, Scan, INITIAL_READ_SIZE};
-use crate::layouts::{EOF_SIZE, FOOTER_POSTSCRIPT_SIZE, MAGIC_BYTES, VERSION};
-use crate::FLATBUFFER_SIZE_LENGTH;
+use crate::layouts::{
+    CHUNKED_LAYOUT_ID, COLUMN_LAYOUT_ID, DICT_LAYOUT_ID, EOF_SIZE, FLAT_LAYOUT_ID,
+    FOOTER_POSTSCRIPT_SIZE, INLINE_SCHEMA_LAYOUT_ID, MAGIC_BYTES, VERSION,
+};
+use crate::stream_writer::ByteRange;
+use crate::{MessageReader, FLATBUFFER_SIZE_LENGTH};
 
 /// Wrapper around serialized file footer. Provides handle on file schema and
 /// layout metadata to read the contents.
@@ -48,7 +61,7 @@ use crate::FLATBUFFER_SIZE_LENGTH;
 /// │    Magic bytes (4 bytes)   │
 /// └────────────────────────────┘
 ///
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LayoutDescriptor {
     pub(crate) schema_offset: u64,
     pub(crate) footer_offset: u64,
@@ -66,16 +79,19 @@ impl LayoutDescriptor {
         (self.schema_offset - self.initial_read_offset) as usize
     }
 
+    fn footer_bytes(&self) -> Bytes {
+        let start_offset = self.initial_read_layout_offset();
+        let end_offset = self.initial_read.len() - FOOTER_POSTSCRIPT_SIZE - EOF_SIZE;
+        self.initial_read
+            .slice(start_offset + FLATBUFFER_SIZE_LENGTH..end_offset)
+    }
+
     pub fn layout(
         &self,
         scan: Scan,
         message_cache: RelativeLayoutCache,
     ) -> VortexResult<Box<dyn LayoutReader>> {
-        let start_offset = self.initial_read_layout_offset();
-        let end_offset = self.initial_read.len() - FOOTER_POSTSCRIPT_SIZE - EOF_SIZE;
-        let footer_bytes = self
-            .initial_read
-            .slice(start_offset + FLATBUFFER_SIZE_LENGTH..end_offset);
+        let footer_bytes = self.footer_bytes();
         let fb_footer = root::<footer::Footer>(&footer_bytes)?;
 
         let fb_layout = fb_footer
@@ -86,6 +102,289 @@ impl LayoutDescriptor {
             .read_layout(footer_bytes, loc, scan, message_cache)
     }
 
+    /// Bloom filters of every chunk of the given top level column, if the file was written with
+    /// bloom filters for it.
+    pub(crate) fn bloom_filters(
+        &self,
+        column_idx: usize,
+    ) -> VortexResult<Option<Vec<BloomFilter>>> {
+        let footer_bytes = self.footer_bytes();
+        let Some(column) = chunked_column(&footer_bytes, column_idx)? else {
+            return Ok(None);
+        };
+
+        column
+            .children()
+            .ok_or_else(|| vortex_err!("Missing children"))?
+            .iter()
+            .skip(if has_metadata(column) { 1 } else { 0 })
+            .map(|chunk| {
+                chunk
+                    .metadata()
+                    .filter(|_| chunk.encoding() == FLAT_LAYOUT_ID.0)
+                    .map(|m| BloomFilter::try_from_bytes(m.bytes()))
+                    .transpose()
+            })
+            .collect::<VortexResult<Option<Vec<_>>>>()
+    }
+
+    /// Number of chunks every top level column is split into, if the file is laid out in chunked
+    /// columns that all have the same number of chunks.
+    pub fn num_chunks(&self) -> VortexResult<Option<usize>> {
+        let footer_bytes = self.footer_bytes();
+        let num_columns = root::<footer::Footer>(&footer_bytes)?
+            .layout()
+            .filter(|l| l.encoding() == COLUMN_LAYOUT_ID.0)
+            .and_then(|l| l.children())
+            .map(|c| c.len())
+            .unwrap_or_default();
+
+        let mut num_chunks = None;
+        for column_idx in 0..num_columns {
+            let Some(column) = chunked_column(&footer_bytes, column_idx)? else {
+                return Ok(None);
+            };
+            let column_chunks = column.children().map(|c| c.len()).unwrap_or_default()
+                - usize::from(has_metadata(column));
+            if num_chunks.is_some_and(|n| n != column_chunks) {
+                return Ok(None);
+            }
+            num_chunks = Some(column_chunks);
+        }
+
+        Ok(num_chunks)
+    }
+
+    /// Metadata table of the chunks of the given top level column, with a row per chunk.
+    ///
+    /// The table has a `row_offset` field with the first row of each chunk and, if the writer
+    /// computed them for every chunk, `min`, `max`, `null_count` and `histogram` fields.
+    pub async fn chunk_metadata<R: VortexReadAt>(
+        &self,
+        read: &R,
+        column_idx: usize,
+    ) -> VortexResult<Option<StructArray>> {
+        let Some(range) = self.chunk_metadata_range(column_idx)? else {
+            return Ok(None);
+        };
+
+        let buf = read
+            .read_at_into(range.begin, BytesMut::zeroed(range.len()))
+            .await?;
+        let mut msgs = MessageReader::try_new(buf).await?;
+        let dtype = msgs.read_dtype().await?;
+        let table = msgs
+            .maybe_read_chunk(self.layout_serde.ctx(), dtype)
+            .await?
+            .ok_or_else(|| vortex_err!(InvalidSerde: "Missing chunk metadata table"))?;
+        StructArray::try_from(table).map(Some)
+    }
+
+    /// Statistics of the given top level column, combined from its metadata table: the least of the
+    /// chunk minimums, the greatest of the chunk maximums and the sum of the chunk null counts.
+    ///
+    /// Only the metadata table of this column is read, stats the table doesn't have are missing.
+    pub async fn column_stats<R: VortexReadAt>(
+        &self,
+        read: &R,
+        column_idx: usize,
+    ) -> VortexResult<Option<StatsSet>> {
+        let Some(table) = self.chunk_metadata(read, column_idx).await? else {
+            return Ok(None);
+        };
+
+        let mut stats = StatsSet::new();
+        for (name, stat) in [("min", Stat::Min), ("max", Stat::Max)] {
+            let Some(values) = table.field_by_name(name) else {
+                continue;
+            };
+            if let Some(value) = Array::from(values.into_canonical()?)
+                .statistics()
+                .compute(stat)
+                .filter(|value| !value.is_null())
+            {
+                stats.set(stat, value);
+            }
+        }
+        if let Some(null_counts) = table.field_by_name("null_count") {
+            let null_count: u64 = null_counts
+                .into_primitive()?
+                .maybe_null_slice::<u64>()
+                .iter()
+                .sum();
+            stats.set(Stat::NullCount, null_count.into());
+        }
+        Ok(Some(stats))
+    }
+
+    /// [`Stat::Histogram`] of every chunk of the given top level column, read from its metadata
+    /// table. Chunks without a histogram, e.g. because all their values are null, have `None`.
+    pub async fn chunk_histograms<R: VortexReadAt>(
+        &self,
+        read: &R,
+        column_idx: usize,
+    ) -> VortexResult<Option<Vec<Option<Scalar>>>> {
+        let Some(histogram) = self
+            .chunk_metadata(read, column_idx)
+            .await?
+            .and_then(|table| table.field_by_name("histogram"))
+        else {
+            return Ok(None);
+        };
+        let boundaries = histogram
+            .into_struct()?
+            .children()
+            .map(|boundary| boundary.into_canonical().map(Array::from))
+            .collect::<VortexResult<Vec<_>>>()?;
+        let Some(dtype) = boundaries
+            .first()
+            .map(|b| b.dtype().with_nullability(Nullability::NonNullable))
+        else {
+            return Ok(None);
+        };
+
+        let num_chunks = boundaries[0].len();
+        (0..num_chunks)
+            .map(|chunk| {
+                let values = boundaries
+                    .iter()
+                    .map(|boundary| scalar_at(boundary, chunk))
+                    .collect::<VortexResult<Vec<_>>>()?;
+                Ok((!values.iter().any(Scalar::is_null)).then(|| {
+                    Scalar::list(
+                        dtype.clone(),
+                        values.into_iter().map(Scalar::into_value).collect(),
+                    )
+                }))
+            })
+            .collect::<VortexResult<Vec<_>>>()
+            .map(Some)
+    }
+
+    /// Minimum and maximum of every chunk of the given top level column, read from its metadata
+    /// table. Chunks without either of them, e.g. because all their values are null, have `None`.
+    pub(crate) async fn chunk_min_max<R: VortexReadAt>(
+        &self,
+        read: &R,
+        column_idx: usize,
+    ) -> VortexResult<Option<Vec<Option<(Scalar, Scalar)>>>> {
+        let Some(table) = self.chunk_metadata(read, column_idx).await? else {
+            return Ok(None);
+        };
+        let (Some(min), Some(max)) = (table.field_by_name("min"), table.field_by_name("max"))
+        else {
+            return Ok(None);
+        };
+
+        (0..table.len())
+            .map(|chunk| {
+                let min = scalar_at(&min, chunk)?;
+                let max = scalar_at(&max, chunk)?;
+                Ok((!min.is_null() && !max.is_null()).then_some((min, max)))
+            })
+            .collect::<VortexResult<Vec<_>>>()
+            .map(Some)
+    }
+
+    /// First data chunk of the given top level column, decoded without validation
+    pub(crate) async fn first_chunk<R: VortexReadAt>(
+        &self,
+        read: &R,
+        column_idx: usize,
+    ) -> VortexResult<Option<Array>> {
+        let footer_bytes = self.footer_bytes();
+        // Chunks of a column with a shared dictionary only hold its codes
+        if column_layout(&footer_bytes, column_idx)?
+            .is_some_and(|column| column.encoding() == DICT_LAYOUT_ID.0)
+        {
+            return Ok(None);
+        }
+        let Some(column) = chunked_column(&footer_bytes, column_idx)? else {
+            return Ok(None);
+        };
+        let Some(chunk) = column
+            .children()
+            .and_then(|c| c.iter().nth(usize::from(has_metadata(column))))
+            .filter(|chunk| chunk.encoding() == FLAT_LAYOUT_ID.0)
+        else {
+            return Ok(None);
+        };
+        let DType::Struct(st, _) = self.dtype()? else {
+            return Ok(None);
+        };
+        let Some(dtype) = st.dtypes().get(column_idx).cloned() else {
+            return Ok(None);
+        };
+
+        let range = buffer_range(chunk, self.layout_serde.data_end())?;
+        let buf = read
+            .read_at_into(range.begin, BytesMut::zeroed(range.len()))
+            .await?;
+        array_from_bytes(buf.freeze(), self.layout_serde.ctx(), dtype, false).map(Some)
+    }
+
+    /// [`column_stats`](Self::column_stats) of every column of the projection, in its order.
+    ///
+    /// The metadata tables of the columns outside of the projection are never read.
+    pub async fn projected_column_stats<R: VortexReadAt>(
+        &self,
+        read: &R,
+        projection: &Projection,
+    ) -> VortexResult<Vec<Option<StatsSet>>> {
+        let DType::Struct(st, _) = self.dtype()? else {
+            vortex_bail!("Expected a struct dtype, found {}", self.dtype()?)
+        };
+        let column_idxs = match projection {
+            Projection::All => (0..st.names().len()).collect(),
+            Projection::Flat(fields) => fields
+                .iter()
+                .map(|field| match field {
+                    Field::Index(idx) => Ok(*idx),
+                    Field::Name(name) => st
+                        .find_name(name)
+                        .ok_or_else(|| vortex_err!("Projected column {name} doesn't exist")),
+                })
+                .collect::<VortexResult<Vec<_>>>()?,
+        };
+
+        let mut stats = Vec::with_capacity(column_idxs.len());
+        for column_idx in column_idxs {
+            stats.push(self.column_stats(read, column_idx).await?);
+        }
+        Ok(stats)
+    }
+
+    /// Byte range of the dtype and the array of a column's metadata table, which are adjacent
+    fn chunk_metadata_range(&self, column_idx: usize) -> VortexResult<Option<ByteRange>> {
+        let footer_bytes = self.footer_bytes();
+        let Some(column) = chunked_column(&footer_bytes, column_idx)? else {
+            return Ok(None);
+        };
+        if !has_metadata(column) {
+            return Ok(None);
+        }
+
+        let metadata = column
+            .children()
+            .and_then(|c| c.iter().next())
+            .ok_or_else(|| vortex_err!("Missing children"))?;
+        if metadata.encoding() != INLINE_SCHEMA_LAYOUT_ID.0 {
+            return Ok(None);
+        }
+        let dtype_range = buffer_range(metadata, self.layout_serde.data_end())?;
+        let array_range = buffer_range(
+            metadata
+                .children()
+                .and_then(|c| c.iter().next())
+                .ok_or_else(|| vortex_err!("Missing children"))?,
+            self.layout_serde.data_end(),
+        )?;
+        if dtype_range.end != array_range.begin {
+            vortex_bail!(InvalidSerde: "Chunk metadata dtype and array aren't adjacent")
+        }
+        Ok(Some(ByteRange::new(dtype_range.begin, array_range.end)))
+    }
+
     pub fn dtype_bytes(&self) -> VortexResult<Bytes> {
         let start_offset = self.initial_read_schema_offset();
         let end_offset = self.initial_read_layout_offset();
@@ -128,6 +427,42 @@ impl LayoutDescriptor {
     }
 }
 
+/// Layout of the given top level column, if the file is laid out in columns
+fn column_layout(footer_bytes: &[u8], column_idx: usize) -> VortexResult<Option<footer::Layout>> {
+    let fb_layout = root::<footer::Footer>(footer_bytes)?
+        .layout()
+        .ok_or_else(|| vortex_err!("Footer must contain a layout"))?;
+    if fb_layout.encoding() != COLUMN_LAYOUT_ID.0 {
+        return Ok(None);
+    }
+
+    Ok(fb_layout
+        .children()
+        .and_then(|c| (column_idx < c.len()).then(|| c.get(column_idx))))
+}
+
+/// Chunked layout of the given top level column, if the file is laid out in chunked columns.
+///
+/// The chunks of a column with a shared dictionary are its codes, their metadata table and bloom
+/// filters still describe the values of the column.
+fn chunked_column(footer_bytes: &[u8], column_idx: usize) -> VortexResult<Option<footer::Layout>> {
+    Ok(column_layout(footer_bytes, column_idx)?
+        .and_then(|column| match column.encoding() {
+            id if id == DICT_LAYOUT_ID.0 => {
+                column.children().filter(|c| c.len() == 2).map(|c| c.get(1))
+            }
+            _ => Some(column),
+        })
+        .filter(|column| column.encoding() == CHUNKED_LAYOUT_ID.0))
+}
+
+fn has_metadata(column: footer::Layout) -> bool {
+    column
+        .metadata()
+        .and_then(|b| b.bytes().first().copied())
+        .is_some_and(|has_metadata| has_metadata != 0)
+}
+
 pub struct LayoutDescriptorReader {
     layout_serde: LayoutDeserializer,
 }
@@ -137,22 +472,25 @@ impl LayoutDescriptorReader {
         Self { layout_serde }
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(file_size = file_size))
+    )]
     pub async fn read_footer<R: VortexReadAt>(
         &self,
         read: &R,
         file_size: u64,
     ) -> VortexResult<LayoutDescriptor> {
-        if file_size < EOF_SIZE as u64 {
+        if file_size < (FOOTER_POSTSCRIPT_SIZE + EOF_SIZE) as u64 {
             vortex_bail!(
                 "Malformed vortex file, size {} must be at least {}",
                 file_size,
-                EOF_SIZE,
+                FOOTER_POSTSCRIPT_SIZE + EOF_SIZE,
             )
         }
 
         let read_size = INITIAL_READ_SIZE.min(file_size as usize);
-        let mut buf = BytesMut::with_capacity(read_size);
-        unsafe { buf.set_len(read_size) }
+        let mut buf = BytesMut::zeroed(read_size);
 
         let read_offset = file_size - read_size as u64;
         buf = read.read_at_into(read_offset, buf).await?;
@@ -176,13 +514,27 @@ impl LayoutDescriptorReader {
         }
 
         let ps = root::<footer::Postscript>(&buf[eof_loc - FOOTER_POSTSCRIPT_SIZE..eof_loc])?;
+        let schema_offset = ps.schema_offset();
+        let footer_offset = ps.footer_offset();
+        // Schema and footer have to be contained in the initial read, in order, each prefixed by
+        // its flatbuffer length
+        let footer_end = file_size - (FOOTER_POSTSCRIPT_SIZE + EOF_SIZE) as u64;
+        if schema_offset < read_offset
+            || schema_offset.saturating_add(FLATBUFFER_SIZE_LENGTH as u64) > footer_offset
+            || footer_offset.saturating_add(FLATBUFFER_SIZE_LENGTH as u64) > footer_end
+        {
+            vortex_bail!(
+                InvalidSerde: "Malformed file, schema offset {schema_offset} and footer offset {footer_offset} must be ordered within {read_offset}..{footer_end}"
+            )
+        }
 
         Ok(LayoutDescriptor {
-            schema_offset: ps.schema_offset(),
-            footer_offset: ps.footer_offset(),
+            schema_offset,
+            footer_offset,
             initial_read: buf.freeze(),
             initial_read_offset: read_offset,
-            layout_serde: self.layout_serde.clone(),
+            // Array data is written before the schema
+            layout_serde: self.layout_serde.clone().with_data_end(schema_offset),
         })
     }
 }
@@ -7,7 +7,8 @@ use vortex_flatbuffers::footer;
 use crate::layouts::read::buffered::BufferedReader;
 use crate::layouts::read::cache::RelativeLayoutCache;
 use crate::layouts::{
-    LayoutDeserializer, LayoutId, LayoutReader, LayoutSpec, ReadResult, Scan, CHUNKED_LAYOUT_ID,
+    ChunkPosition, LayoutDeserializer, LayoutId, LayoutReader, LayoutSpec, ReadResult, Scan,
+    CHUNKED_LAYOUT_ID,
 };
 
 #[derive(Debug)]
@@ -25,14 +26,14 @@ impl LayoutSpec for ChunkedLayoutSpec {
         scan: Scan,
         layout_serde: LayoutDeserializer,
         message_cache: RelativeLayoutCache,
-    ) -> Box<dyn LayoutReader> {
-        Box::new(ChunkedLayout::new(
+    ) -> VortexResult<Box<dyn LayoutReader>> {
+        Ok(Box::new(ChunkedLayout::new(
             fb_bytes,
             fb_loc,
             scan,
             layout_serde,
             message_cache,
-        ))
+        )))
     }
 }
 
@@ -78,8 +79,8 @@ impl ChunkedLayout {
     fn has_metadata(&self) -> bool {
         self.flatbuffer()
             .metadata()
-            .map(|b| b.bytes()[0] != 0)
-            .unwrap_or(false)
+            .and_then(|b| b.bytes().first().copied())
+            .is_some_and(|has_metadata| has_metadata != 0)
     }
 }
 
@@ -88,6 +89,12 @@ impl LayoutReader for ChunkedLayout {
         if let Some(cr) = &mut self.reader {
             cr.read()
         } else {
+            let start = self.scan.chunk_start.unwrap_or_default();
+            // Pruned and selected chunks are indices of the file's chunks, they don't apply to
+            // chunked layouts nested in a chunk
+            let mut chunk_scan = self.scan.clone();
+            chunk_scan.pruned_chunks = None;
+            chunk_scan.selected_chunk = None;
             let children = self
                 .flatbuffer()
                 .children()
@@ -96,18 +103,37 @@ impl LayoutReader for ChunkedLayout {
                 .enumerate()
                 // Skip over the metadata table of this layout
                 .skip(if self.has_metadata() { 1 } else { 0 })
-                .map(|(i, c)| {
-                    self.layout_builder.read_layout(
-                        self.fb_bytes.clone(),
-                        c._tab.loc(),
-                        self.scan.clone(),
-                        self.message_cache
-                            .relative(i as u16, self.message_cache.dtype().clone()),
-                    )
+                .map(|(i, c)| (i, if self.has_metadata() { i - 1 } else { i }, c))
+                .filter(|(_, chunk_idx, _)| {
+                    *chunk_idx >= start.chunk_idx
+                        && self.scan.selected_chunk.map_or(true, |c| c == *chunk_idx)
+                        && !self
+                            .scan
+                            .pruned_chunks
+                            .as_ref()
+                            .is_some_and(|pruned| pruned.contains(chunk_idx))
+                })
+                .map(|(i, chunk_idx, c)| {
+                    self.layout_builder
+                        .read_layout(
+                            self.fb_bytes.clone(),
+                            c._tab.loc(),
+                            chunk_scan.clone(),
+                            self.message_cache
+                                .relative(i as u16, self.message_cache.dtype().clone()),
+                        )
+                        .map(|layout| (chunk_idx, layout))
                 })
                 .collect::<VortexResult<VecDeque<_>>>()?;
-            self.reader = Some(BufferedReader::new(children, self.scan.batch_size));
+            self.reader = Some(BufferedReader::new(children, self.scan.batch_size, start));
             self.read_next()
         }
     }
+
+    fn chunk_position(&self) -> Option<ChunkPosition> {
+        Some(match &self.reader {
+            Some(reader) => reader.position(),
+            None => self.scan.chunk_start.unwrap_or_default(),
+        })
+    }
 }
@@ -2,7 +2,6 @@ use std::sync::Arc;
 
 use bytes::Bytes;
 use flatbuffers::{ForwardsUOffset, Vector};
-use itertools::Itertools;
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 use vortex_flatbuffers::footer as fb;
@@ -11,7 +10,7 @@ use vortex_schema::projection::Projection;
 use crate::layouts::read::batch::BatchReader;
 use crate::layouts::read::cache::{LazyDeserializedDType, RelativeLayoutCache};
 use crate::layouts::read::context::{LayoutDeserializer, LayoutId, LayoutSpec};
-use crate::layouts::read::{LayoutReader, ReadResult, Scan};
+use crate::layouts::read::{ChunkPosition, LayoutReader, ReadResult, Scan};
 use crate::layouts::COLUMN_LAYOUT_ID;
 
 #[derive(Debug)]
@@ -29,14 +28,14 @@ impl LayoutSpec for ColumnLayoutSpec {
         scan: Scan,
         layout_builder: LayoutDeserializer,
         message_cache: RelativeLayoutCache,
-    ) -> Box<dyn LayoutReader> {
-        Box::new(ColumnLayout::new(
+    ) -> VortexResult<Box<dyn LayoutReader>> {
+        Ok(Box::new(ColumnLayout::new(
             fb_bytes,
             fb_loc,
             scan,
             layout_builder,
             message_cache,
-        ))
+        )))
     }
 }
 
@@ -51,6 +50,8 @@ pub struct ColumnLayout {
     layout_builder: LayoutDeserializer,
     message_cache: RelativeLayoutCache,
     reader: Option<BatchReader>,
+    /// Index of the column every child reads
+    child_indices: Vec<usize>,
 }
 
 impl ColumnLayout {
@@ -68,6 +69,7 @@ impl ColumnLayout {
             layout_builder,
             message_cache,
             reader: None,
+            child_indices: Vec::new(),
         }
     }
 
@@ -84,11 +86,21 @@ impl ColumnLayout {
         children: Vector<ForwardsUOffset<fb::Layout>>,
         dtype: DType,
     ) -> VortexResult<Box<dyn LayoutReader>> {
+        if idx >= children.len() {
+            vortex_bail!(InvalidSerde: "Column {idx} out of bounds for {} columns", children.len())
+        }
         let layout = children.get(idx);
 
         // TODO: Figure out complex nested schema projections
         let mut child_scan = self.scan.clone();
         child_scan.projection = Projection::All;
+        if let Some(position) = &self.scan.resume_from {
+            child_scan.chunk_start = Some(position.column(idx).ok_or_else(|| {
+                vortex_err!(
+                    "Position has no chunk of column {idx}, a scan has to be resumed with the same projection and filter"
+                )
+            })?);
+        }
 
         self.layout_builder.read_layout(
             self.fb_bytes.clone(),
@@ -124,21 +136,48 @@ impl LayoutReader for ColumnLayout {
                 .children()
                 .ok_or_else(|| vortex_err!("Missing children"))?;
 
-            let child_layouts = match &self.scan.projection {
-                Projection::All => (0..fb_children.len())
-                    .zip_eq(s.dtypes().iter())
-                    .map(|(index, dtype)| self.read_child(index, fb_children, dtype.clone()))
-                    .collect::<VortexResult<Vec<_>>>()?,
+            let child_indices = match &self.scan.projection {
+                Projection::All => {
+                    if fb_children.len() != s.dtypes().len() {
+                        vortex_bail!(
+                            InvalidSerde: "Layout has {} columns but dtype has {} fields",
+                            fb_children.len(),
+                            s.dtypes().len()
+                        )
+                    }
+                    (0..fb_children.len()).collect::<Vec<_>>()
+                }
                 Projection::Flat(proj) => proj
                     .iter()
                     .map(|f| result_lazy_dtype.resolve_field(f))
-                    .zip(s.dtypes().iter().cloned())
-                    .map(|(child_idx, dtype)| self.read_child(child_idx?, fb_children, dtype))
                     .collect::<VortexResult<Vec<_>>>()?,
             };
+            let child_layouts = child_indices
+                .iter()
+                .zip(s.dtypes().iter())
+                .map(|(idx, dtype)| self.read_child(*idx, fb_children, dtype.clone()))
+                .collect::<VortexResult<Vec<_>>>()?;
 
             self.reader = Some(BatchReader::new(s.names().clone(), child_layouts));
+            self.child_indices = child_indices;
             self.read_next()
         }
     }
+
+    fn column_positions(&self) -> Vec<(usize, ChunkPosition)> {
+        match &self.reader {
+            Some(reader) => self
+                .child_indices
+                .iter()
+                .zip(reader.children())
+                .filter_map(|(idx, child)| child.chunk_position().map(|p| (*idx, p)))
+                .collect(),
+            None => self
+                .scan
+                .resume_from
+                .as_ref()
+                .map(|position| position.columns.clone())
+                .unwrap_or_default(),
+        }
+    }
 }
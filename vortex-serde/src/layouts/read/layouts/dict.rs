@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use vortex::Array;
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::{vortex_err, VortexResult};
+use vortex_flatbuffers::footer;
+
+use crate::layouts::read::cache::{LazyDeserializedDType, RelativeLayoutCache};
+use crate::layouts::read::layouts::{array_from_bytes, buffer_range};
+use crate::layouts::read::lazy::LazyArray;
+use crate::layouts::{
+    ChunkPosition, LayoutDeserializer, LayoutId, LayoutReader, LayoutSpec, ReadResult, Scan,
+    DICT_LAYOUT_ID,
+};
+
+/// Type of the codes of a dictionary layout
+const CODES_DTYPE: DType = DType::Primitive(PType::U32, Nullability::NonNullable);
+
+#[derive(Debug)]
+pub struct DictLayoutSpec;
+
+impl LayoutSpec for DictLayoutSpec {
+    fn id(&self) -> LayoutId {
+        DICT_LAYOUT_ID
+    }
+
+    fn layout(
+        &self,
+        fb_bytes: Bytes,
+        fb_loc: usize,
+        scan: Scan,
+        layout_serde: LayoutDeserializer,
+        message_cache: RelativeLayoutCache,
+    ) -> VortexResult<Box<dyn LayoutReader>> {
+        Ok(Box::new(DictLayout::new(
+            fb_bytes,
+            fb_loc,
+            scan,
+            layout_serde,
+            message_cache,
+        )))
+    }
+}
+
+/// In memory representation of a dictionary layout.
+///
+/// First child is the flat layout of the values shared by all codes, the second the layout of the
+/// codes. Every array read from the codes is returned as a `DictArray` of the same values.
+#[derive(Debug)]
+pub struct DictLayout {
+    fb_bytes: Bytes,
+    fb_loc: usize,
+    scan: Scan,
+    layout_builder: LayoutDeserializer,
+    message_cache: RelativeLayoutCache,
+    values: Option<Array>,
+    codes: Option<Box<dyn LayoutReader>>,
+}
+
+impl DictLayout {
+    pub fn new(
+        fb_bytes: Bytes,
+        fb_loc: usize,
+        scan: Scan,
+        layout_builder: LayoutDeserializer,
+        message_cache: RelativeLayoutCache,
+    ) -> Self {
+        Self {
+            fb_bytes,
+            fb_loc,
+            scan,
+            layout_builder,
+            message_cache,
+            values: None,
+            codes: None,
+        }
+    }
+
+    fn flatbuffer(&self) -> footer::Layout {
+        unsafe {
+            let tab = flatbuffers::Table::new(&self.fb_bytes, self.fb_loc);
+            footer::Layout::init_from_table(tab)
+        }
+    }
+
+    fn child(&self, idx: usize) -> VortexResult<footer::Layout> {
+        self.flatbuffer()
+            .children()
+            .filter(|c| c.len() == 2)
+            .map(|c| c.get(idx))
+            .ok_or_else(|| vortex_err!(InvalidSerde: "Dictionary layout needs values and codes"))
+    }
+}
+
+impl LayoutReader for DictLayout {
+    fn read_next(&mut self) -> VortexResult<Option<ReadResult>> {
+        let Some(values) = self.values.clone() else {
+            return match self.message_cache.remove(&[0]) {
+                Some(buf) => {
+                    self.values = Some(array_from_bytes(
+                        buf,
+                        self.layout_builder.ctx(),
+                        self.message_cache.dtype().value()?.clone(),
+                        self.scan.validate,
+                    )?);
+                    self.read_next()
+                }
+                None => Ok(Some(ReadResult::ReadMore(vec![(
+                    self.message_cache.absolute_id(&[0]),
+                    buffer_range(self.child(0)?, self.layout_builder.data_end())?,
+                )]))),
+            };
+        };
+
+        if self.codes.is_none() {
+            self.codes = Some(
+                self.layout_builder.read_layout(
+                    self.fb_bytes.clone(),
+                    self.child(1)?._tab.loc(),
+                    self.scan.clone(),
+                    self.message_cache
+                        .relative(1, Arc::new(LazyDeserializedDType::from_dtype(CODES_DTYPE))),
+                )?,
+            );
+        }
+        let codes = self
+            .codes
+            .as_mut()
+            .ok_or_else(|| vortex_err!("Missing codes"))?;
+        Ok(match codes.read_next()? {
+            Some(ReadResult::Batch(codes)) => {
+                Some(LazyArray::dict(codes.into(), values)?.into_read_result())
+            }
+            Some(ReadResult::Lazy(codes)) => {
+                Some(LazyArray::dict(codes, values)?.into_read_result())
+            }
+            read_more @ (Some(ReadResult::ReadMore(_)) | None) => read_more,
+        })
+    }
+
+    fn chunk_position(&self) -> Option<ChunkPosition> {
+        match &self.codes {
+            Some(codes) => codes.chunk_position(),
+            None => Some(self.scan.chunk_start.unwrap_or_default()),
+        }
+    }
+}
@@ -2,14 +2,16 @@ use std::cmp::min;
 use std::sync::Arc;
 
 use bytes::Bytes;
-use vortex::compute::slice;
 use vortex::{Array, Context};
-use vortex_error::{vortex_err, VortexResult, VortexUnwrap};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
 use vortex_flatbuffers::footer;
 
 use crate::layouts::read::cache::RelativeLayoutCache;
+use crate::layouts::read::layouts::buffer_range;
+use crate::layouts::read::lazy::LazyArray;
 use crate::layouts::{
-    LayoutDeserializer, LayoutId, LayoutReader, LayoutSpec, Message, ReadResult, Scan,
+    ErrorPolicy, LayoutDeserializer, LayoutId, LayoutReader, LayoutSpec, Message, ReadResult, Scan,
     FLAT_LAYOUT_ID,
 };
 use crate::message_reader::ArrayBufferReader;
@@ -30,23 +32,18 @@ impl LayoutSpec for FlatLayoutSpec {
         scan: Scan,
         layout_serde: LayoutDeserializer,
         message_cache: RelativeLayoutCache,
-    ) -> Box<dyn LayoutReader> {
+    ) -> VortexResult<Box<dyn LayoutReader>> {
         let fb_layout = unsafe {
             let tab = flatbuffers::Table::new(&fb_bytes, fb_loc);
             footer::Layout::init_from_table(tab)
         };
-        let buf = fb_layout
-            .buffers()
-            .ok_or_else(|| vortex_err!("No buffers"))
-            .vortex_unwrap()
-            .get(0);
 
-        Box::new(FlatLayout::new(
-            ByteRange::new(buf.begin(), buf.end()),
+        Ok(Box::new(FlatLayout::new(
+            buffer_range(fb_layout, layout_serde.data_end())?,
             scan,
             layout_serde.ctx(),
             message_cache,
-        ))
+        )))
     }
 }
 
@@ -57,7 +54,7 @@ pub struct FlatLayout {
     ctx: Arc<Context>,
     cache: RelativeLayoutCache,
     done: bool,
-    cached_array: Option<Array>,
+    cached_array: Option<LazyArray>,
 }
 
 impl FlatLayout {
@@ -81,16 +78,49 @@ impl FlatLayout {
         (self.cache.absolute_id(&[]), self.range)
     }
 
-    fn array_from_bytes(&self, mut buf: Bytes) -> VortexResult<Array> {
-        let mut array_reader = ArrayBufferReader::new();
-        let mut read_buf = Bytes::new();
-        while let Some(u) = array_reader.read(read_buf)? {
-            read_buf = buf.split_to(u);
+    fn decode(&self, buf: Bytes) -> VortexResult<LazyArray> {
+        let dtype = self.cache.dtype().value()?.clone();
+        if self.scan.lazy {
+            let len = ArrayBufferReader::array_len(&buf)?;
+            Ok(LazyArray::deferred(
+                self.range,
+                buf,
+                self.ctx.clone(),
+                dtype,
+                len,
+                self.scan.validate,
+            ))
+        } else {
+            array_from_bytes(buf, self.ctx.clone(), dtype, self.scan.validate).map(LazyArray::from)
         }
-        array_reader.into_array(self.ctx.clone(), self.cache.dtype().value()?.clone())
     }
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", name = "decode", skip_all, fields(bytes = buf.len()))
+)]
+pub(crate) fn array_from_bytes(
+    mut buf: Bytes,
+    ctx: Arc<Context>,
+    dtype: DType,
+    validate: bool,
+) -> VortexResult<Array> {
+    let mut array_reader = ArrayBufferReader::new();
+    let mut read_buf = Bytes::new();
+    while let Some(u) = array_reader.read(read_buf)? {
+        if u > buf.len() {
+            vortex_bail!(InvalidSerde: "Message needs {u} more bytes but only {} remain", buf.len())
+        }
+        read_buf = buf.split_to(u);
+    }
+    let array = array_reader.into_array(ctx, dtype)?;
+    if validate {
+        array.validate()?;
+    }
+    Ok(array)
+}
+
 impl LayoutReader for FlatLayout {
     fn read_next(&mut self) -> VortexResult<Option<ReadResult>> {
         if self.done {
@@ -100,17 +130,33 @@ impl LayoutReader for FlatLayout {
         if let Some(array) = self.cached_array.take() {
             let array = if array.len() > self.scan.batch_size {
                 let rows_to_read = min(self.scan.batch_size, array.len());
-                let taken = slice(&array, 0, rows_to_read)?;
-                let leftover = slice(&array, rows_to_read, array.len())?;
+                let taken = array.slice(0, rows_to_read)?;
+                let leftover = array.slice(rows_to_read, array.len())?;
                 self.cached_array = Some(leftover);
                 taken
             } else {
                 self.done = true;
                 array
             };
-            Ok(Some(ReadResult::Batch(array)))
-        } else if let Some(buf) = self.cache.get(&[]) {
-            self.cached_array = Some(self.array_from_bytes(buf)?);
+            Ok(Some(array.into_read_result()))
+        } else if let Some(buf) = self.cache.remove(&[]) {
+            match self.decode(buf.clone()) {
+                Ok(array) => self.cached_array = Some(array),
+                Err(err) => match self.scan.error_policy {
+                    ErrorPolicy::Fail => return Err(err),
+                    ErrorPolicy::SkipChunk => {
+                        // The stream drops the rows of the chunk from every column, which needs
+                        // to know how many there are
+                        let Ok(len) = ArrayBufferReader::array_len(&buf) else {
+                            return Err(err);
+                        };
+                        let err =
+                            err.with_context(format!("Failed decoding message at {}", self.range));
+                        let dtype = self.cache.dtype().value()?.clone();
+                        self.cached_array = Some(LazyArray::skipped(err, dtype, len));
+                    }
+                },
+            }
             self.read_next()
         } else {
             Ok(Some(ReadResult::ReadMore(vec![self.own_message()])))
@@ -7,11 +7,11 @@ use vortex_error::{vortex_err, VortexResult};
 use vortex_flatbuffers::{footer, message};
 
 use crate::layouts::read::cache::{LazyDeserializedDType, RelativeLayoutCache};
+use crate::layouts::read::layouts::buffer_range;
 use crate::layouts::{
     LayoutDeserializer, LayoutId, LayoutReader, LayoutSpec, Message, ReadResult, Scan,
     INLINE_SCHEMA_LAYOUT_ID,
 };
-use crate::stream_writer::ByteRange;
 
 #[derive(Debug)]
 pub struct InlineDTypeLayoutSpec;
@@ -28,14 +28,14 @@ impl LayoutSpec for InlineDTypeLayoutSpec {
         scan: Scan,
         layout_reader: LayoutDeserializer,
         message_cache: RelativeLayoutCache,
-    ) -> Box<dyn LayoutReader> {
-        Box::new(InlineDTypeLayout::new(
+    ) -> VortexResult<Box<dyn LayoutReader>> {
+        Ok(Box::new(InlineDTypeLayout::new(
             fb_bytes,
             fb_loc,
             scan,
             layout_reader,
             message_cache,
-        ))
+        )))
     }
 }
 
@@ -95,14 +95,9 @@ impl InlineDTypeLayout {
                 .map_err(|e| vortex_err!(InvalidSerde: "Failed to parse DType: {e}"))?,
             ))
         } else {
-            let dtype_buf = self
-                .flatbuffer()
-                .buffers()
-                .ok_or_else(|| vortex_err!("No buffers"))?
-                .get(0);
             Ok(DTypeReadResult::ReadMore(vec![(
                 self.message_cache.absolute_id(&[0]),
-                ByteRange::new(dtype_buf.begin(), dtype_buf.end()),
+                buffer_range(self.flatbuffer(), self.layout_builder.data_end())?,
             )]))
         }
     }
@@ -119,8 +114,8 @@ impl LayoutReader for InlineDTypeLayout {
                     let layout = self
                         .flatbuffer()
                         .children()
-                        .ok_or_else(|| vortex_err!("No children"))?
-                        .get(0);
+                        .and_then(|c| c.iter().next())
+                        .ok_or_else(|| vortex_err!("No children"))?;
 
                     self.child_layout = Some(
                         self.layout_builder.read_layout(
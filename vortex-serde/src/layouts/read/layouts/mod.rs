@@ -1,8 +1,75 @@
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_flatbuffers::footer;
+
+use crate::stream_writer::ByteRange;
+
 mod chunked;
 mod column;
+mod dict;
 mod flat;
 mod inline_dtype;
 
 pub use chunked::ChunkedLayoutSpec;
 pub use column::ColumnLayoutSpec;
+pub use dict::DictLayoutSpec;
+pub(crate) use flat::array_from_bytes;
 pub use flat::FlatLayoutSpec;
+
+/// Largest buffer a layout can reference, larger ones are rejected before allocating for them
+pub(crate) const MAX_BUFFER_LENGTH: u64 = 4 << 30;
+
+/// Byte range of the first buffer of the layout, validated so that malformed files return an error
+/// instead of allocating for it. Buffers have to end before `data_end`, the end of the data of the
+/// file.
+pub(crate) fn buffer_range(fb_layout: footer::Layout, data_end: u64) -> VortexResult<ByteRange> {
+    let buffer = fb_layout
+        .buffers()
+        .and_then(|b| b.iter().next())
+        .ok_or_else(|| vortex_err!(InvalidSerde: "No buffers"))?;
+    if buffer.end() <= buffer.begin() {
+        vortex_bail!(
+            InvalidSerde: "Buffer end {} must be after its beginning {}",
+            buffer.end(),
+            buffer.begin()
+        )
+    }
+    if buffer.end() > data_end {
+        vortex_bail!(
+            InvalidSerde: "Buffer end {} is past the end of the data {data_end}",
+            buffer.end()
+        )
+    }
+    if buffer.end() - buffer.begin() > MAX_BUFFER_LENGTH {
+        vortex_bail!(
+            InvalidSerde: "Buffer of {} bytes exceeds the maximum of {MAX_BUFFER_LENGTH}",
+            buffer.end() - buffer.begin()
+        )
+    }
+    Ok(ByteRange::new(buffer.begin(), buffer.end()))
+}
+
+#[cfg(test)]
+mod test {
+    use flatbuffers::{root, FlatBufferBuilder};
+    use vortex_flatbuffers::WriteFlatBuffer;
+
+    use super::*;
+    use crate::layouts::write::layouts::Layout;
+
+    fn check_flat(begin: u64, end: u64, data_end: u64) -> VortexResult<ByteRange> {
+        let mut fbb = FlatBufferBuilder::new();
+        let layout = Layout::flat(ByteRange::new(begin, end)).write_flatbuffer(&mut fbb);
+        fbb.finish_minimal(layout);
+        buffer_range(root::<footer::Layout>(fbb.finished_data())?, data_end)
+    }
+
+    #[test]
+    fn buffer_range_bounds() {
+        assert_eq!(check_flat(10, 20, 100).unwrap(), ByteRange::new(10, 20));
+        assert_eq!(check_flat(90, 100, 100).unwrap(), ByteRange::new(90, 100));
+        // Past the end of the data
+        assert!(check_flat(90, 101, 100).is_err());
+        // Too large to allocate even if the file claims to be that large
+        assert!(check_flat(0, MAX_BUFFER_LENGTH + 1, u64::MAX).is_err());
+    }
+}
@@ -0,0 +1,466 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use once_cell::sync::OnceCell;
+use vortex::array::{ChunkedArray, StructArray};
+use vortex::compute::{filter, slice};
+use vortex::{Array, ArrayDType, Context, IntoArray};
+use vortex_dict::DictArray;
+use vortex_dtype::{DType, FieldNames, Nullability, StructDType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::layouts::read::layouts::array_from_bytes;
+use crate::layouts::read::recovery::{kept_rows, merge_skipped, SkippedRows};
+use crate::layouts::read::ReadResult;
+use crate::stream_writer::ByteRange;
+
+/// Array read by a layout that may not have been decoded yet.
+///
+/// Flat layouts of a lazy scan keep the bytes of their message and only decode them the first time
+/// the array is accessed. Slicing and combining lazy arrays is deferred until then as well.
+#[derive(Debug, Clone)]
+pub struct LazyArray(Inner);
+
+#[derive(Debug, Clone)]
+enum Inner {
+    Decoded(Array),
+    Deferred {
+        message: Arc<DeferredMessage>,
+        start: usize,
+        end: usize,
+    },
+    Chunked(Vec<LazyArray>, DType),
+    Struct(Vec<LazyArray>, DType, usize),
+    /// Codes into values shared with the other arrays of a dictionary layout
+    Dict(Box<LazyArray>, Array),
+    /// Rows of a chunk that failed to decode under
+    /// [`ErrorPolicy::SkipChunk`](crate::layouts::ErrorPolicy::SkipChunk)
+    Skipped {
+        error: Arc<VortexError>,
+        dtype: DType,
+        len: usize,
+    },
+}
+
+/// Serialized array decoded on first access, shared by all slices of it
+#[derive(Debug)]
+struct DeferredMessage {
+    range: ByteRange,
+    bytes: Bytes,
+    ctx: Arc<Context>,
+    dtype: DType,
+    len: usize,
+    validate: bool,
+    array: OnceCell<Array>,
+}
+
+impl DeferredMessage {
+    fn array(&self) -> VortexResult<&Array> {
+        self.array.get_or_try_init(|| {
+            array_from_bytes(
+                self.bytes.clone(),
+                self.ctx.clone(),
+                self.dtype.clone(),
+                self.validate,
+            )
+            .map_err(|e| e.with_context(format!("Failed decoding message at {}", self.range)))
+        })
+    }
+}
+
+impl LazyArray {
+    /// Array of `len` rows serialized in `bytes`, read from `range` of the file
+    pub(crate) fn deferred(
+        range: ByteRange,
+        bytes: Bytes,
+        ctx: Arc<Context>,
+        dtype: DType,
+        len: usize,
+        validate: bool,
+    ) -> Self {
+        Self(Inner::Deferred {
+            message: Arc::new(DeferredMessage {
+                range,
+                bytes,
+                ctx,
+                dtype,
+                len,
+                validate,
+                array: OnceCell::new(),
+            }),
+            start: 0,
+            end: len,
+        })
+    }
+
+    /// Placeholder for the `len` rows of a chunk that failed to decode with `error`
+    pub(crate) fn skipped(error: VortexError, dtype: DType, len: usize) -> Self {
+        Self(Inner::Skipped {
+            error: Arc::new(error),
+            dtype,
+            len,
+        })
+    }
+
+    /// Dictionary array of `codes` into `values`, decoded right away if the codes already are
+    pub(crate) fn dict(codes: LazyArray, values: Array) -> VortexResult<Self> {
+        match codes.0 {
+            Inner::Decoded(codes) => Ok(DictArray::try_new(codes, values)?.into_array().into()),
+            _ => Ok(Self(Inner::Dict(Box::new(codes), values))),
+        }
+    }
+
+    /// Concatenation of `chunks`, decoded right away if all of them already are
+    pub(crate) fn chunked(mut chunks: Vec<LazyArray>, dtype: DType) -> VortexResult<Self> {
+        if chunks.len() == 1 {
+            return Ok(chunks.remove(0));
+        }
+        if chunks.iter().all(|c| matches!(c.0, Inner::Decoded(_))) {
+            let chunks = chunks
+                .into_iter()
+                .map(|c| c.decode())
+                .collect::<VortexResult<_>>()?;
+            return Ok(ChunkedArray::try_new(chunks, dtype)?.into_array().into());
+        }
+        Ok(Self(Inner::Chunked(chunks, dtype)))
+    }
+
+    /// Struct of the given fields, decoded right away if all of them already are
+    pub(crate) fn struct_(names: FieldNames, fields: Vec<LazyArray>) -> VortexResult<Self> {
+        if fields.iter().all(|f| matches!(f.0, Inner::Decoded(_))) {
+            let fields = names
+                .iter()
+                .zip(fields)
+                .map(|(name, f)| Ok((name.clone(), f.decode()?)))
+                .collect::<VortexResult<Vec<_>>>()?;
+            return Ok(StructArray::from_fields(&fields)?.into_array().into());
+        }
+
+        let len = fields
+            .first()
+            .map(LazyArray::len)
+            .ok_or_else(|| vortex_err!("Struct needs at least one field"))?;
+        let dtype = DType::Struct(
+            StructDType::new(names, fields.iter().map(|f| f.dtype().clone()).collect()),
+            Nullability::NonNullable,
+        );
+        Ok(Self(Inner::Struct(fields, dtype, len)))
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            Inner::Decoded(a) => a.len(),
+            Inner::Deferred { start, end, .. } => end - start,
+            Inner::Chunked(chunks, _) => chunks.iter().map(LazyArray::len).sum(),
+            Inner::Struct(_, _, len) | Inner::Skipped { len, .. } => *len,
+            Inner::Dict(codes, _) => codes.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn dtype(&self) -> &DType {
+        match &self.0 {
+            Inner::Decoded(a) => a.dtype(),
+            Inner::Deferred { message, .. } => &message.dtype,
+            Inner::Chunked(_, dtype)
+            | Inner::Struct(_, dtype, _)
+            | Inner::Skipped { dtype, .. } => dtype,
+            Inner::Dict(_, values) => values.dtype(),
+        }
+    }
+
+    /// Whether all the messages this array is read from have been decoded
+    pub fn is_decoded(&self) -> bool {
+        match &self.0 {
+            Inner::Decoded(_) | Inner::Skipped { .. } => true,
+            Inner::Deferred { message, .. } => message.array.get().is_some(),
+            Inner::Chunked(arrays, _) | Inner::Struct(arrays, ..) => {
+                arrays.iter().all(LazyArray::is_decoded)
+            }
+            Inner::Dict(codes, _) => codes.is_decoded(),
+        }
+    }
+
+    pub fn slice(&self, start: usize, stop: usize) -> VortexResult<Self> {
+        if start > stop || stop > self.len() {
+            vortex_bail!(OutOfBounds: stop, start, self.len())
+        }
+
+        Ok(Self(match &self.0 {
+            Inner::Decoded(a) => Inner::Decoded(slice(a, start, stop)?),
+            Inner::Deferred {
+                message,
+                start: offset,
+                ..
+            } => Inner::Deferred {
+                message: message.clone(),
+                start: offset + start,
+                end: offset + stop,
+            },
+            Inner::Chunked(chunks, dtype) => {
+                let mut sliced = Vec::new();
+                let mut chunk_start = 0;
+                for chunk in chunks {
+                    let chunk_end = chunk_start + chunk.len();
+                    if chunk_end > start && chunk_start < stop {
+                        sliced.push(chunk.slice(
+                            start.saturating_sub(chunk_start),
+                            stop.min(chunk_end) - chunk_start,
+                        )?);
+                    }
+                    chunk_start = chunk_end;
+                }
+                Inner::Chunked(sliced, dtype.clone())
+            }
+            Inner::Struct(fields, dtype, _) => Inner::Struct(
+                fields
+                    .iter()
+                    .map(|f| f.slice(start, stop))
+                    .collect::<VortexResult<_>>()?,
+                dtype.clone(),
+                stop - start,
+            ),
+            Inner::Skipped { error, dtype, .. } => Inner::Skipped {
+                error: error.clone(),
+                dtype: dtype.clone(),
+                len: stop - start,
+            },
+            Inner::Dict(codes, values) => {
+                Inner::Dict(Box::new(codes.slice(start, stop)?), values.clone())
+            }
+        }))
+    }
+
+    /// Rows of the array that were skipped in any of its fields, sorted and merged
+    pub(crate) fn skipped_rows(&self) -> SkippedRows {
+        match &self.0 {
+            Inner::Decoded(_) | Inner::Deferred { .. } => Vec::new(),
+            Inner::Skipped { error, len, .. } => vec![(0..*len, error.clone())],
+            Inner::Dict(codes, _) => codes.skipped_rows(),
+            Inner::Chunked(chunks, _) => {
+                let mut skipped = Vec::new();
+                let mut chunk_start = 0;
+                for chunk in chunks {
+                    skipped.extend(chunk.skipped_rows().into_iter().map(|(range, err)| {
+                        (chunk_start + range.start..chunk_start + range.end, err)
+                    }));
+                    chunk_start += chunk.len();
+                }
+                merge_skipped(skipped)
+            }
+            Inner::Struct(fields, ..) => {
+                merge_skipped(fields.iter().flat_map(LazyArray::skipped_rows).collect())
+            }
+        }
+    }
+
+    /// The array without the merged `skipped` rows, which must leave at least one row
+    pub(crate) fn without_rows(&self, skipped: &SkippedRows) -> VortexResult<Self> {
+        let kept = kept_rows(self.len(), skipped)
+            .into_iter()
+            .map(|range| self.slice(range.start, range.end))
+            .collect::<VortexResult<Vec<_>>>()?;
+        if kept.is_empty() {
+            vortex_bail!("Can't skip every row of an array")
+        }
+        Self::chunked(kept, self.dtype().clone())
+    }
+
+    /// The field at `idx` of a struct array, without decoding any other field
+    pub fn field(&self, idx: usize) -> VortexResult<Self> {
+        let DType::Struct(st, _) = self.dtype() else {
+            vortex_bail!("Array of type {} has no fields", self.dtype())
+        };
+        if idx >= st.names().len() {
+            vortex_bail!(OutOfBounds: idx, 0, st.names().len())
+        }
+
+        match &self.0 {
+            Inner::Struct(fields, ..) => Ok(fields[idx].clone()),
+            Inner::Chunked(chunks, _) => Ok(Self(Inner::Chunked(
+                chunks
+                    .iter()
+                    .map(|c| c.field(idx))
+                    .collect::<VortexResult<_>>()?,
+                st.dtypes()[idx].clone(),
+            ))),
+            Inner::Skipped { error, len, .. } => Ok(Self(Inner::Skipped {
+                error: error.clone(),
+                dtype: st.dtypes()[idx].clone(),
+                len: *len,
+            })),
+            Inner::Decoded(_) | Inner::Deferred { .. } | Inner::Dict(..) => self
+                .decode()?
+                .with_dyn(|a| a.as_struct_array().and_then(|s| s.field(idx)))
+                .map(LazyArray::from)
+                .ok_or_else(|| vortex_err!("Missing field {idx} of struct array")),
+        }
+    }
+
+    /// Decode the array, reusing the result of any earlier decode of the same messages
+    pub fn decode(&self) -> VortexResult<Array> {
+        match &self.0 {
+            Inner::Decoded(a) => Ok(a.clone()),
+            Inner::Deferred {
+                message,
+                start,
+                end,
+            } => {
+                let array = message.array()?;
+                if *start == 0 && *end == message.len {
+                    Ok(array.clone())
+                } else {
+                    slice(array, *start, *end)
+                }
+            }
+            Inner::Chunked(chunks, dtype) => ChunkedArray::try_new(
+                chunks
+                    .iter()
+                    .map(LazyArray::decode)
+                    .collect::<VortexResult<_>>()?,
+                dtype.clone(),
+            )
+            .map(IntoArray::into_array),
+            Inner::Struct(fields, DType::Struct(st, _), _) => {
+                let fields = st
+                    .names()
+                    .iter()
+                    .zip(fields)
+                    .map(|(name, f)| Ok((name.clone(), f.decode()?)))
+                    .collect::<VortexResult<Vec<_>>>()?;
+                StructArray::from_fields(&fields).map(IntoArray::into_array)
+            }
+            Inner::Struct(_, dtype, _) => vortex_bail!("Struct array with non struct type {dtype}"),
+            Inner::Skipped { error, .. } => vortex_bail!("Rows were skipped: {error}"),
+            Inner::Dict(codes, values) => {
+                DictArray::try_new(codes.decode()?, values.clone()).map(IntoArray::into_array)
+            }
+        }
+    }
+
+    pub(crate) fn into_read_result(self) -> ReadResult {
+        match self.0 {
+            Inner::Decoded(a) => ReadResult::Batch(a),
+            _ => ReadResult::Lazy(self),
+        }
+    }
+}
+
+impl From<Array> for LazyArray {
+    fn from(array: Array) -> Self {
+        Self(Inner::Decoded(array))
+    }
+}
+
+/// Batch of a lazy scan whose columns are decoded, and filtered, the first time they're accessed.
+///
+/// A consumer that only looks at some of the columns never pays for decoding the others.
+#[derive(Debug)]
+pub struct LazyBatch {
+    array: LazyArray,
+    mask: Option<Array>,
+    selection: Option<Array>,
+    len: usize,
+    columns: Vec<OnceCell<Array>>,
+}
+
+impl LazyBatch {
+    pub(crate) fn new(array: LazyArray, mask: Option<Array>, len: usize) -> Self {
+        let num_columns = match array.dtype() {
+            DType::Struct(st, _) => st.names().len(),
+            _ => 0,
+        };
+        Self {
+            array,
+            mask,
+            selection: None,
+            len,
+            columns: (0..num_columns).map(|_| OnceCell::new()).collect(),
+        }
+    }
+
+    pub(crate) fn with_selection(mut self, selection: Option<Array>) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Rows of the batch that match the row filter, if the scan doesn't apply it, see
+    /// [`FilterMode::Mask`](crate::layouts::FilterMode::Mask)
+    pub fn selection(&self) -> Option<&Array> {
+        self.selection.as_ref()
+    }
+
+    /// Number of rows in the batch, after applying the row filter
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn dtype(&self) -> &DType {
+        self.array.dtype()
+    }
+
+    /// Names of the columns of a batch of struct type
+    pub fn names(&self) -> Option<&FieldNames> {
+        match self.array.dtype() {
+            DType::Struct(st, _) => Some(st.names()),
+            _ => None,
+        }
+    }
+
+    /// The column at `idx`, decoded on the first call
+    pub fn column(&self, idx: usize) -> VortexResult<Array> {
+        let cell = self
+            .columns
+            .get(idx)
+            .ok_or_else(|| vortex_err!(OutOfBounds: idx, 0, self.columns.len()))?;
+        cell.get_or_try_init(|| self.apply_mask(self.array.field(idx)?.decode()?))
+            .cloned()
+    }
+
+    pub fn column_by_name(&self, name: &str) -> VortexResult<Array> {
+        let idx = self
+            .names()
+            .and_then(|names| names.iter().position(|n| n.as_ref() == name))
+            .ok_or_else(|| vortex_err!("Batch has no column named {name}"))?;
+        self.column(idx)
+    }
+
+    /// Whether the messages the column at `idx` is read from have been decoded
+    pub fn is_decoded(&self, idx: usize) -> bool {
+        match &self.array.0 {
+            Inner::Struct(fields, ..) => fields.get(idx).is_some_and(LazyArray::is_decoded),
+            _ => self.array.is_decoded(),
+        }
+    }
+
+    /// Decode the whole batch
+    pub fn into_array(self) -> VortexResult<Array> {
+        let Some(names) = self.names().cloned() else {
+            return self.apply_mask(self.array.decode()?);
+        };
+        let fields = names
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| Ok((name.clone(), self.column(idx)?)))
+            .collect::<VortexResult<Vec<_>>>()?;
+        StructArray::from_fields(&fields).map(IntoArray::into_array)
+    }
+
+    fn apply_mask(&self, array: Array) -> VortexResult<Array> {
+        match &self.mask {
+            Some(mask) => {
+                #[cfg(feature = "tracing")]
+                let _span = tracing::debug_span!("filter", rows = array.len()).entered();
+                filter(array, mask.clone())
+            }
+            None => Ok(array),
+        }
+    }
+}
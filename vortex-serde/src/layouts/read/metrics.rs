@@ -0,0 +1,13 @@
+/// Counters accumulated by a [`LayoutBatchStream`](super::LayoutBatchStream) while it is polled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReaderMetrics {
+    /// Bytes read from the underlying storage, including the initial footer read
+    pub bytes_read: u64,
+    /// Chunks skipped using bloom filters and batches dropped without materializing because the
+    /// row filter didn't select any row
+    pub chunks_pruned: u64,
+    /// Rows removed by the row filter
+    pub rows_filtered: u64,
+    /// Rows returned to the caller
+    pub rows_returned: u64,
+}
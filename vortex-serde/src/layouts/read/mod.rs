@@ -1,12 +1,16 @@
+use std::collections::HashSet;
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use arrow_buffer::BooleanBuffer;
 use vortex::array::BoolArray;
+use vortex::memory::MemoryPool;
 use vortex::validity::Validity;
 use vortex::{Array, IntoArray as _, IntoArrayVariant as _};
 use vortex_error::VortexResult;
 
 mod batch;
+mod buffer_pool;
 mod buffered;
 mod builder;
 mod cache;
@@ -14,16 +18,26 @@ mod context;
 mod filtering;
 mod footer;
 mod layouts;
+mod lazy;
+mod metrics;
+mod position;
 mod recordbatchreader;
+mod recovery;
 mod stream;
 
 pub use builder::LayoutReaderBuilder;
 pub use cache::LayoutMessageCache;
 pub use context::*;
-pub use filtering::RowFilter;
-pub use footer::LayoutDescriptorReader;
-pub use recordbatchreader::{AsyncRuntime, VortexRecordBatchReader};
-pub use stream::LayoutBatchStream;
+pub use filtering::{FilterMode, FilterPlan, PredicatePlan, PredicateStrategy, RowFilter};
+pub use footer::{LayoutDescriptor, LayoutDescriptorReader};
+pub use lazy::{LazyArray, LazyBatch};
+pub use metrics::ReaderMetrics;
+pub use position::{ChunkPosition, StreamPosition};
+pub use recordbatchreader::{
+    AsyncRuntime, BlockingRuntime, VortexRecordBatchReader, VortexRecordBatchStream,
+};
+pub use recovery::{ErrorPolicy, SkippedChunkCallback};
+pub use stream::{BatchWithIndex, IndexedBatchStream, LayoutBatchStream, LazyBatchStream};
 pub use vortex_schema::projection::Projection;
 pub use vortex_schema::Schema;
 
@@ -39,7 +53,28 @@ pub struct Scan {
     indices: Option<Array>,
     projection: Projection,
     filter: Option<RowFilter>,
+    filter_mode: FilterMode,
     batch_size: usize,
+    error_policy: ErrorPolicy,
+    on_skipped_chunk: Option<SkippedChunkCallback>,
+    /// Indices of chunks that can't contain rows matching the filter and aren't read at all
+    pruned_chunks: Option<Arc<HashSet<usize>>>,
+    /// Only chunk of chunked layouts to read, if the scan covers a single chunk
+    selected_chunk: Option<usize>,
+    /// Requested byte ranges at most this many bytes apart are fetched with a single read
+    coalesce_gap: u64,
+    /// Size of the arena read buffers are allocated from, if they're pooled across reads
+    buffer_pool_capacity: Option<usize>,
+    /// Budget the read buffers and decoded batches of the stream are charged against
+    memory_pool: Option<MemoryPool>,
+    /// Defer decoding the arrays of flat layouts until they're accessed
+    lazy: bool,
+    /// Check the invariants of every decoded array, see [`Array::validate`]
+    validate: bool,
+    /// Position of an earlier scan to continue from
+    resume_from: Option<Arc<StreamPosition>>,
+    /// Chunk of a chunked layout to start reading from, set for the columns of a resumed scan
+    chunk_start: Option<ChunkPosition>,
 }
 
 /// Unique identifier for a message within a layout
@@ -51,6 +86,8 @@ pub type Message = (MessageId, ByteRange);
 pub enum ReadResult {
     ReadMore(Vec<Message>),
     Batch(Array),
+    /// Batch of a lazy scan that still has parts to decode
+    Lazy(LazyArray),
 }
 
 pub trait LayoutReader: Debug + Send {
@@ -63,6 +100,16 @@ pub trait LayoutReader: Debug + Send {
     /// The layout is finished reading when it returns None
     fn read_next(&mut self) -> VortexResult<Option<ReadResult>>;
 
+    /// Position of the next row a chunked layout reads, see [`StreamPosition`]
+    fn chunk_position(&self) -> Option<ChunkPosition> {
+        None
+    }
+
+    /// Chunk positions of the columns a column layout reads, keyed by column index
+    fn column_positions(&self) -> Vec<(usize, ChunkPosition)> {
+        Vec::new()
+    }
+
     // TODO(robert): Support stats pruning via planning. Requires propagating all the metadata
     //  to top level and then pushing down the result of it
     // Try to use metadata of the layout to perform pruning given the passed `Scan` object.
@@ -0,0 +1,30 @@
+/// Position of a stream between two of its batches, to continue reading from later.
+///
+/// See [`LayoutBatchStream::position`](crate::layouts::LayoutBatchStream::position) and
+/// [`LayoutReaderBuilder::resume_from`](crate::layouts::LayoutReaderBuilder::resume_from). A stream
+/// can only be resumed with the same projection and row filter it was read with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamPosition {
+    /// Chunk position of every top level column read, keyed by the index of the column in the file
+    pub columns: Vec<(usize, ChunkPosition)>,
+    /// Rows decoded before the position, including the ones removed by the row filter
+    pub current_offset: u64,
+}
+
+impl StreamPosition {
+    pub fn column(&self, column_idx: usize) -> Option<ChunkPosition> {
+        self.columns
+            .iter()
+            .find(|(idx, _)| *idx == column_idx)
+            .map(|(_, position)| *position)
+    }
+}
+
+/// Position within the chunks of a column
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChunkPosition {
+    /// Index of the chunk the next row is read from
+    pub chunk_idx: usize,
+    /// Rows of that chunk that were already read
+    pub rows_read: usize,
+}
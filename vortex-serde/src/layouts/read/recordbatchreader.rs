@@ -1,9 +1,11 @@
 use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::{Context, Poll};
 
 use arrow_array::{RecordBatch, RecordBatchReader};
 use arrow_schema::{ArrowError, SchemaRef};
-use futures::StreamExt;
+use futures::{Stream, StreamExt};
 use vortex::arrow::infer_schema;
 use vortex::Array;
 use vortex_error::{VortexError, VortexResult};
@@ -15,16 +17,32 @@ fn vortex_to_arrow_error(error: VortexError) -> ArrowError {
     ArrowError::ExternalError(Box::new(error))
 }
 
-fn vortex_to_arrow(result: VortexResult<Array>) -> Result<RecordBatch, ArrowError> {
-    result
-        .and_then(RecordBatch::try_from)
-        .map_err(vortex_to_arrow_error)
+/// Convert a batch to a record batch that shares the given schema instead of allocating its own
+fn vortex_to_record_batch(array: Array, schema: &SchemaRef) -> VortexResult<RecordBatch> {
+    let batch = RecordBatch::try_from(array)?;
+    Ok(RecordBatch::try_new(
+        schema.clone(),
+        batch.columns().to_vec(),
+    )?)
 }
 
 pub trait AsyncRuntime {
     fn block_on<F: Future>(&self, fut: F) -> F::Output;
 }
 
+/// Runs futures to completion on the calling thread, for reading without an async runtime.
+///
+/// Only suitable for inputs whose reads don't depend on a runtime's reactor, e.g. in-memory
+/// buffers, use a tokio `Runtime` to read tokio files.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BlockingRuntime;
+
+impl AsyncRuntime for BlockingRuntime {
+    fn block_on<F: Future>(&self, fut: F) -> F::Output {
+        futures_executor::block_on(fut)
+    }
+}
+
 pub struct VortexRecordBatchReader<'a, R, AR> {
     stream: LayoutBatchStream<R>,
     arrow_schema: SchemaRef,
@@ -49,6 +67,17 @@ where
     }
 }
 
+impl<R: VortexReadAt + Unpin + 'static> LayoutBatchStream<R> {
+    /// Read the batches synchronously as an Arrow [`RecordBatchReader`], blocking on `runtime`
+    /// for every batch.
+    pub fn into_record_batch_reader<AR: AsyncRuntime>(
+        self,
+        runtime: &AR,
+    ) -> VortexResult<VortexRecordBatchReader<'_, R, AR>> {
+        VortexRecordBatchReader::new(self, runtime)
+    }
+}
+
 impl<R, AR> Iterator for VortexRecordBatchReader<'_, R, AR>
 where
     R: VortexReadAt + Unpin + 'static,
@@ -58,7 +87,11 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         let maybe_result = self.runtime.block_on(self.stream.next());
-        maybe_result.map(vortex_to_arrow)
+        maybe_result.map(|result| {
+            result
+                .and_then(|array| vortex_to_record_batch(array, &self.arrow_schema))
+                .map_err(vortex_to_arrow_error)
+        })
     }
 }
 
@@ -75,3 +108,33 @@ where
         self.next().transpose()
     }
 }
+
+/// Asynchronous stream of Arrow record batches, all sharing the schema returned by [`Self::schema`]
+pub struct VortexRecordBatchStream<R> {
+    stream: LayoutBatchStream<R>,
+    arrow_schema: SchemaRef,
+}
+
+impl<R: VortexReadAt> VortexRecordBatchStream<R> {
+    pub fn new(stream: LayoutBatchStream<R>) -> VortexResult<Self> {
+        let arrow_schema = Arc::new(infer_schema(stream.schema().dtype())?);
+        Ok(Self {
+            stream,
+            arrow_schema,
+        })
+    }
+
+    pub fn schema(&self) -> SchemaRef {
+        self.arrow_schema.clone()
+    }
+}
+
+impl<R: VortexReadAt + Unpin + Send + 'static> Stream for VortexRecordBatchStream<R> {
+    type Item = VortexResult<RecordBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.poll_next_unpin(cx).map(|batch| {
+            batch.map(|batch| batch.and_then(|a| vortex_to_record_batch(a, &self.arrow_schema)))
+        })
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::Range;
+use std::sync::Arc;
+
+use vortex_error::VortexError;
+
+/// How a layout reader reacts to a chunk that fails to decode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Abort the stream with the decoding error.
+    #[default]
+    Fail,
+    /// Log the error, skip the rows of the offending chunk and continue with the next one.
+    ///
+    /// The same rows are dropped from every projected column, so the remaining columns stay
+    /// aligned even if only one of them failed to decode. A chunk whose row count can't be read
+    /// from its header can't be skipped and still fails the stream.
+    SkipChunk,
+}
+
+/// Callback invoked once for every range of file rows skipped under [`ErrorPolicy::SkipChunk`],
+/// with the error of the first chunk that failed to decode in it.
+#[derive(Clone)]
+pub struct SkippedChunkCallback(Arc<SkippedChunkFn>);
+
+type SkippedChunkFn = dyn Fn(Range<u64>, &VortexError) + Send + Sync;
+
+impl SkippedChunkCallback {
+    pub fn new<F: Fn(Range<u64>, &VortexError) + Send + Sync + 'static>(f: F) -> Self {
+        Self(Arc::new(f))
+    }
+
+    pub fn call(&self, rows: Range<u64>, err: &VortexError) {
+        (self.0)(rows, err)
+    }
+}
+
+impl Debug for SkippedChunkCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SkippedChunkCallback")
+    }
+}
+
+/// Rows of an array that were skipped, together with the error that caused it
+pub(crate) type SkippedRows = Vec<(Range<usize>, Arc<VortexError>)>;
+
+/// Sort the skipped ranges and merge the ones that overlap or touch, keeping the first error
+pub(crate) fn merge_skipped(mut skipped: SkippedRows) -> SkippedRows {
+    skipped.sort_by_key(|(range, _)| range.start);
+    let mut merged: SkippedRows = Vec::with_capacity(skipped.len());
+    for (range, err) in skipped {
+        match merged.last_mut() {
+            Some((last, _)) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push((range, err)),
+        }
+    }
+    merged
+}
+
+/// Ranges of `0..len` not covered by the merged `skipped` ranges
+pub(crate) fn kept_rows(len: usize, skipped: &SkippedRows) -> Vec<Range<usize>> {
+    let mut kept = Vec::with_capacity(skipped.len() + 1);
+    let mut start = 0;
+    for (range, _) in skipped {
+        if range.start > start {
+            kept.push(start..range.start);
+        }
+        start = range.end;
+    }
+    if start < len {
+        kept.push(start..len);
+    }
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_error::vortex_err;
+
+    use super::*;
+
+    #[test]
+    fn merge_and_keep() {
+        let err = Arc::new(vortex_err!("corrupt"));
+        let skipped = merge_skipped(vec![
+            (6..8, err.clone()),
+            (0..2, err.clone()),
+            (1..3, err.clone()),
+            (8..9, err),
+        ]);
+        assert_eq!(
+            skipped.iter().map(|(r, _)| r.clone()).collect::<Vec<_>>(),
+            vec![0..3, 6..9]
+        );
+        assert_eq!(kept_rows(10, &skipped), vec![3..6, 9..10]);
+        assert_eq!(kept_rows(9, &skipped), vec![3..6]);
+    }
+}
@@ -1,3 +1,5 @@
+use std::io;
+use std::ops::Range;
 use std::pin::Pin;
 use std::sync::{Arc, RwLock};
 use std::task::{ready, Context, Poll};
@@ -5,18 +7,30 @@ use std::task::{ready, Context, Poll};
 use bytes::{Bytes, BytesMut};
 use futures::Stream;
 use futures_util::future::BoxFuture;
-use futures_util::{stream, FutureExt, StreamExt, TryStreamExt};
-use vortex::array::ChunkedArray;
-use vortex::compute::filter;
+use futures_util::stream::BoxStream;
+use futures_util::{future, stream, FutureExt, StreamExt, TryStreamExt};
+use log::warn;
+use vortex::array::{BoolArray, ChunkedArray, StructArray};
+use vortex::memory::MemoryReservation;
 use vortex::stats::ArrayStatistics;
-use vortex::Array;
-use vortex_dtype::DType;
+use vortex::stream::ArrayStream;
+use vortex::validity::Validity;
+use vortex::variants::StructArrayTrait;
+use vortex::{Array, Canonical, IntoArray, IntoArrayVariant, IntoCanonical};
+use vortex_buffer::AlignedBuffer;
+use vortex_dtype::{DType, FieldName, FieldNames};
 use vortex_error::{vortex_err, vortex_panic, VortexError, VortexExpect, VortexResult};
 use vortex_schema::Schema;
 
 use crate::io::VortexReadAt;
+use crate::layouts::read::buffer_pool::BufferPool;
 use crate::layouts::read::cache::LayoutMessageCache;
-use crate::layouts::read::{LayoutReader, MessageId, ReadResult, Scan};
+use crate::layouts::read::filtering::FilterMode;
+use crate::layouts::read::lazy::{LazyArray, LazyBatch};
+use crate::layouts::read::metrics::ReaderMetrics;
+use crate::layouts::read::position::StreamPosition;
+use crate::layouts::read::recovery::{kept_rows, merge_skipped, SkippedRows};
+use crate::layouts::read::{LayoutPartId, LayoutReader, Message, MessageId, ReadResult, Scan};
 use crate::stream_writer::ByteRange;
 
 pub struct LayoutBatchStream<R> {
@@ -27,7 +41,13 @@ pub struct LayoutBatchStream<R> {
     messages_cache: Arc<RwLock<LayoutMessageCache>>,
     state: StreamingState<R>,
     dtype: DType,
+    column_names: Option<FieldNames>,
     cached_mask: Option<Array>,
+    metrics: ReaderMetrics,
+    buffer_pool: Option<BufferPool>,
+    current_offset: u64,
+    filter_skipped: SkippedRows,
+    pending_skip: Option<(Range<u64>, Arc<VortexError>)>,
 }
 
 impl<R: VortexReadAt> LayoutBatchStream<R> {
@@ -37,6 +57,7 @@ impl<R: VortexReadAt> LayoutBatchStream<R> {
         filter_reader: Option<Box<dyn LayoutReader>>,
         messages_cache: Arc<RwLock<LayoutMessageCache>>,
         dtype: DType,
+        column_names: Option<FieldNames>,
         scan: Scan,
     ) -> Self {
         let state = if filter_reader.is_some() {
@@ -45,6 +66,7 @@ impl<R: VortexReadAt> LayoutBatchStream<R> {
             StreamingState::Init
         };
 
+        let buffer_pool = scan.buffer_pool_capacity.map(BufferPool::new);
         LayoutBatchStream {
             input: Some(input),
             layout_reader,
@@ -52,8 +74,14 @@ impl<R: VortexReadAt> LayoutBatchStream<R> {
             scan,
             messages_cache,
             dtype,
+            column_names,
             state,
             cached_mask: None,
+            metrics: ReaderMetrics::default(),
+            buffer_pool,
+            current_offset: 0,
+            filter_skipped: Vec::new(),
+            pending_skip: None,
         }
     }
 
@@ -61,7 +89,74 @@ impl<R: VortexReadAt> LayoutBatchStream<R> {
         Schema::new(self.dtype.clone())
     }
 
-    fn store_messages(&self, messages: Vec<(MessageId, Bytes)>) {
+    /// Counters accumulated while reading, complete once the stream has been exhausted
+    pub fn metrics(&self) -> ReaderMetrics {
+        self.metrics
+    }
+
+    /// Position after the last returned batch, to continue reading from later with
+    /// [`LayoutReaderBuilder::resume_from`](crate::layouts::LayoutReaderBuilder::resume_from)
+    pub fn position(&self) -> StreamPosition {
+        let mut columns = self.layout_reader.column_positions();
+        if let Some(filter_reader) = &self.filter_reader {
+            for (idx, position) in filter_reader.column_positions() {
+                if !columns.iter().any(|(c, _)| *c == idx) {
+                    columns.push((idx, position));
+                }
+            }
+        }
+        columns.sort_by_key(|(idx, _)| *idx);
+        StreamPosition {
+            columns,
+            current_offset: self.current_offset,
+        }
+    }
+
+    pub(crate) fn set_current_offset(&mut self, offset: u64) {
+        self.current_offset = offset;
+    }
+
+    pub(crate) fn record_bytes_read(&mut self, bytes: u64) {
+        self.metrics.bytes_read += bytes;
+    }
+
+    pub(crate) fn record_chunks_pruned(&mut self, chunks: u64) {
+        self.metrics.chunks_pruned += chunks;
+    }
+
+    /// Record the rows of the batch starting at file row `offset` that were skipped, reporting
+    /// every contiguous range of file rows once it can't grow any further
+    fn record_skipped(&mut self, offset: u64, len: usize, skipped: &SkippedRows) {
+        for (range, err) in skipped {
+            let rows = offset + range.start as u64..offset + range.end as u64;
+            match &mut self.pending_skip {
+                Some((pending, _)) if pending.end == rows.start => pending.end = rows.end,
+                _ => {
+                    self.report_skipped();
+                    self.pending_skip = Some((rows, err.clone()));
+                }
+            }
+        }
+        if self
+            .pending_skip
+            .as_ref()
+            .is_some_and(|(pending, _)| pending.end < offset + len as u64)
+        {
+            self.report_skipped();
+        }
+    }
+
+    fn report_skipped(&mut self) {
+        if let Some((rows, err)) = self.pending_skip.take() {
+            warn!("Skipped rows {rows:?} that failed to decode: {err}");
+            if let Some(callback) = &self.scan.on_skipped_chunk {
+                callback.call(rows, &err);
+            }
+        }
+    }
+
+    fn store_messages(&mut self, messages: Vec<(MessageId, Bytes)>, bytes_read: u64) {
+        self.metrics.bytes_read += bytes_read;
         let mut write_cache_guard = self
             .messages_cache
             .write()
@@ -72,7 +167,7 @@ impl<R: VortexReadAt> LayoutBatchStream<R> {
     }
 }
 
-type StreamStateFuture<R> = BoxFuture<'static, VortexResult<(R, Vec<(MessageId, Bytes)>)>>;
+type StreamStateFuture<R> = BoxFuture<'static, VortexResult<(R, Vec<(MessageId, Bytes)>, u64)>>;
 
 #[derive(Default)]
 enum StreamingState<R> {
@@ -81,7 +176,7 @@ enum StreamingState<R> {
     FilterInit,
     Reading(StreamStateFuture<R>),
     FilterReading(StreamStateFuture<R>),
-    Decoding(Array),
+    Decoding(LazyArray),
     Error,
 }
 
@@ -89,21 +184,27 @@ impl<R: VortexReadAt + Unpin + Send + 'static> Stream for LayoutBatchStream<R> {
     type Item = VortexResult<Array>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_batch(cx)
+            .map(|batch| batch.map(|batch| batch?.into_array()))
+    }
+}
+
+impl<R: VortexReadAt + Unpin + Send + 'static> LayoutBatchStream<R> {
+    fn poll_batch(&mut self, cx: &mut Context<'_>) -> Poll<Option<VortexResult<LazyBatch>>> {
         loop {
             match &mut self.state {
                 StreamingState::Init => {
                     if let Some(read) = self.layout_reader.read_next()? {
                         match read {
                             ReadResult::ReadMore(messages) => {
-                                let reader = self.input.take().ok_or_else(|| {
-                                    vortex_err!("Invalid state transition - reader dropped")
-                                })?;
-                                let read_future = read_ranges(reader, messages).boxed();
+                                let read_future = self.read_messages(messages)?;
                                 self.state = StreamingState::Reading(read_future);
                             }
-                            ReadResult::Batch(a) => self.state = StreamingState::Decoding(a),
+                            ReadResult::Batch(a) => self.state = StreamingState::Decoding(a.into()),
+                            ReadResult::Lazy(a) => self.state = StreamingState::Decoding(a),
                         }
                     } else {
+                        self.report_skipped();
                         return Poll::Ready(None);
                     }
                 }
@@ -116,50 +217,93 @@ impl<R: VortexReadAt + Unpin + Send + 'static> Stream for LayoutBatchStream<R> {
                     {
                         match read {
                             ReadResult::ReadMore(messages) => {
-                                let reader = self.input.take().ok_or_else(|| {
-                                    vortex_err!("Invalid state transition - reader dropped")
-                                })?;
-                                let read_future = read_ranges(reader, messages).boxed();
+                                let read_future = self.read_messages(messages)?;
                                 self.state = StreamingState::FilterReading(read_future);
                             }
-                            ReadResult::Batch(a) => {
-                                let mask = self
+                            batch @ (ReadResult::Batch(_) | ReadResult::Lazy(_)) => {
+                                let a = match batch {
+                                    ReadResult::Lazy(a) => a,
+                                    ReadResult::Batch(a) => a.into(),
+                                    ReadResult::ReadMore(_) => unreachable!(),
+                                };
+                                let filter = self
                                     .scan
                                     .filter
                                     .as_ref()
-                                    .vortex_expect("Cant filter without filter")
-                                    .evaluate(&a)?;
+                                    .vortex_expect("Cant filter without filter");
+                                // Skipped rows never match, the filter only sees the rows it can
+                                let skipped = a.skipped_rows();
+                                let mask = if skipped.is_empty() {
+                                    filter.evaluate(&a.decode()?)?
+                                } else if kept_rows(a.len(), &skipped).is_empty() {
+                                    BoolArray::from(vec![false; a.len()]).into_array()
+                                } else {
+                                    let mask =
+                                        filter.evaluate(&a.without_rows(&skipped)?.decode()?)?;
+                                    pad_mask(mask, a.len(), &skipped)?
+                                };
                                 self.cached_mask = Some(mask);
+                                self.filter_skipped = skipped;
                                 self.state = StreamingState::Init;
                             }
                         }
                     } else {
+                        self.report_skipped();
                         return Poll::Ready(None);
                     }
                 }
                 StreamingState::Decoding(arr) => {
-                    let mut batch = arr.clone();
-
-                    if let Some(mask) = self.cached_mask.take() {
-                        if mask.statistics().compute_true_count().unwrap_or_default() == 0 {
-                            self.state = StreamingState::Init;
-                            continue;
-                        }
-
-                        batch = filter(batch, mask)?;
-                    }
-
                     let goto_state = if self.filter_reader.is_some() {
                         StreamingState::FilterInit
                     } else {
                         StreamingState::Init
                     };
+
+                    let mut batch = arr.clone();
+                    let mut mask = self.cached_mask.take();
+                    let mut rows = batch.len();
+                    let mut skipped = batch.skipped_rows();
+                    skipped.append(&mut self.filter_skipped);
+                    let skipped = merge_skipped(skipped);
+                    let offset = self.current_offset;
+                    self.record_skipped(offset, rows, &skipped);
+                    self.current_offset += rows as u64;
+                    if !skipped.is_empty() {
+                        // The skipped rows are dropped from every column to keep them aligned
+                        if kept_rows(rows, &skipped).is_empty() {
+                            self.state = goto_state;
+                            continue;
+                        }
+                        batch = batch.without_rows(&skipped)?;
+                        mask = mask.map(|m| drop_mask_rows(m, &skipped)).transpose()?;
+                        rows = batch.len();
+                    }
+                    if let Some(mask) = &mask {
+                        // Masked rows are still returned, only compacting removes them
+                        if self.scan.filter_mode == FilterMode::Compact {
+                            let true_count =
+                                mask.statistics().compute_true_count().unwrap_or_default();
+                            self.metrics.rows_filtered += (rows - true_count) as u64;
+                            if true_count == 0 {
+                                self.metrics.chunks_pruned += 1;
+                                self.state = goto_state;
+                                continue;
+                            }
+                            rows = true_count;
+                        }
+                    }
+
                     self.state = goto_state;
+                    self.metrics.rows_returned += rows as u64;
+                    let batch = match self.scan.filter_mode {
+                        FilterMode::Compact => LazyBatch::new(batch, mask, rows),
+                        FilterMode::Mask => LazyBatch::new(batch, None, rows).with_selection(mask),
+                    };
                     return Poll::Ready(Some(Ok(batch)));
                 }
                 StreamingState::Reading(f) => match ready!(f.poll_unpin(cx)) {
-                    Ok((input, messages)) => {
-                        self.store_messages(messages);
+                    Ok((input, messages, bytes_read)) => {
+                        self.store_messages(messages, bytes_read);
                         self.input = Some(input);
 
                         self.state = StreamingState::Init
@@ -170,8 +314,8 @@ impl<R: VortexReadAt + Unpin + Send + 'static> Stream for LayoutBatchStream<R> {
                     }
                 },
                 StreamingState::FilterReading(f) => match ready!(f.poll_unpin(cx)) {
-                    Ok((input, messages)) => {
-                        self.store_messages(messages);
+                    Ok((input, messages, bytes_read)) => {
+                        self.store_messages(messages, bytes_read);
                         self.input = Some(input);
 
                         self.state = StreamingState::FilterInit
@@ -187,41 +331,316 @@ impl<R: VortexReadAt + Unpin + Send + 'static> Stream for LayoutBatchStream<R> {
     }
 }
 
+impl<R: VortexReadAt + Unpin + Send + 'static> ArrayStream for LayoutBatchStream<R> {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+}
+
 impl<R: VortexReadAt + Unpin + Send + 'static> LayoutBatchStream<R> {
-    pub async fn read_all(self) -> VortexResult<Array> {
-        let dtype = self.schema().clone().into();
-        let vecs: Vec<Array> = self.try_collect().await?;
-        if vecs.len() == 1 {
-            vecs.into_iter().next().ok_or_else(|| {
-                vortex_panic!(
-                    "Should be impossible: vecs.len() == 1 but couldn't get first element"
-                )
+    fn read_messages(&mut self, messages: Vec<Message>) -> VortexResult<StreamStateFuture<R>> {
+        let reader = self
+            .input
+            .take()
+            .ok_or_else(|| vortex_err!("Invalid state transition - reader dropped"))?;
+        let coalesced = coalesce_ranges(messages, self.scan.coalesce_gap);
+
+        if let Some(messages) = read_shared(&reader, &coalesced, self.column_names.as_deref()) {
+            let bytes_read = coalesced.iter().map(|(range, _)| range.len() as u64).sum();
+            return Ok(future::ready(messages.map(|m| (reader, m, bytes_read))).boxed());
+        }
+
+        // Every range is charged before any is read, the charge is released once nothing references
+        // the bytes read into it anymore
+        let reservations = coalesced
+            .iter()
+            .map(|(range, _)| {
+                self.scan
+                    .memory_pool
+                    .as_ref()
+                    .map(|pool| {
+                        let mut reservation = pool.reservation();
+                        reservation.try_grow(range.len())?;
+                        Ok(reservation)
+                    })
+                    .transpose()
             })
+            .collect::<VortexResult<Vec<_>>>()?;
+        let reads = coalesced
+            .into_iter()
+            .zip(reservations)
+            .map(|((range, messages), reservation)| {
+                let buf = match self.buffer_pool.as_mut() {
+                    Some(pool) => pool.get(range.len(), range.begin),
+                    None => AlignedBuffer::zeroed_at(range.len(), range.begin).into_inner(),
+                };
+                (range, messages, buf, reservation)
+            })
+            .collect();
+        Ok(read_ranges(reader, reads, self.column_names.clone()).boxed())
+    }
+
+    pub async fn read_all(self) -> VortexResult<Array> {
+        let chunked = ChunkedArray::try_from_stream(self).await?;
+        if chunked.nchunks() == 1 {
+            chunked.chunk(0)
         } else {
-            ChunkedArray::try_new(vecs, dtype).map(|e| e.into())
+            Ok(chunked.into_array())
+        }
+    }
+
+    /// Read every batch into a single struct array whose columns are all canonical, for callers
+    /// that want the whole file decoded.
+    pub async fn collect_canonical(self) -> VortexResult<StructArray> {
+        let array = self.read_all().await?;
+        canonicalize_struct(array.into_struct()?)
+    }
+}
+
+/// Spread the mask of the rows left after dropping the `skipped` ones over all `len` rows, the
+/// skipped rows never match
+fn pad_mask(mask: Array, len: usize, skipped: &SkippedRows) -> VortexResult<Array> {
+    let mask = mask.into_bool()?.boolean_buffer();
+    let mut padded = vec![false; len];
+    let mut bits = mask.iter();
+    for range in kept_rows(len, skipped) {
+        for (row, bit) in padded[range].iter_mut().zip(&mut bits) {
+            *row = bit;
         }
     }
+    Ok(BoolArray::from(padded).into_array())
+}
+
+/// The mask without the rows of the `skipped` ranges
+fn drop_mask_rows(mask: Array, skipped: &SkippedRows) -> VortexResult<Array> {
+    let mask = mask.into_bool()?.boolean_buffer();
+    let kept = kept_rows(mask.len(), skipped)
+        .into_iter()
+        .flatten()
+        .map(|row| mask.value(row))
+        .collect::<Vec<_>>();
+    Ok(BoolArray::from(kept).into_array())
+}
+
+/// Canonicalize the fields of a struct array, recursing into nested structs
+fn canonicalize_struct(array: StructArray) -> VortexResult<StructArray> {
+    let fields = array
+        .children()
+        .map(|field| match field.into_canonical()? {
+            Canonical::Struct(st) => canonicalize_struct(st).map(IntoArray::into_array),
+            canonical => Ok(canonical.into()),
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+    let validity = match array.validity() {
+        Validity::Array(validity) => Validity::Array(validity.into_bool()?.into_array()),
+        validity => validity,
+    };
+    StructArray::try_new(array.names().clone(), fields, array.len(), validity)
+}
+
+/// Batch of a [`LayoutReaderBuilder::build_indexed`] stream with the index of the chunk it was
+/// read from.
+///
+/// [`LayoutReaderBuilder::build_indexed`]: crate::layouts::LayoutReaderBuilder::build_indexed
+#[derive(Debug, Clone)]
+pub struct BatchWithIndex {
+    /// Index of the chunk of the file the batch was read from
+    pub chunk_idx: usize,
+    pub batch: Array,
+}
+
+/// Stream of batches tagged with their chunk index, see
+/// [`LayoutReaderBuilder::build_indexed`](crate::layouts::LayoutReaderBuilder::build_indexed)
+pub type IndexedBatchStream = BoxStream<'static, VortexResult<BatchWithIndex>>;
+
+/// Stream of [`LazyBatch`]es, whose columns are decoded when they're first accessed
+pub struct LazyBatchStream<R>(LayoutBatchStream<R>);
+
+impl<R> LazyBatchStream<R> {
+    pub(crate) fn new(stream: LayoutBatchStream<R>) -> Self {
+        Self(stream)
+    }
+
+    pub fn schema(&self) -> Schema {
+        Schema::new(self.0.dtype.clone())
+    }
+
+    /// Counters accumulated while reading, complete once the stream has been exhausted
+    pub fn metrics(&self) -> ReaderMetrics {
+        self.0.metrics
+    }
+}
+
+impl<R: VortexReadAt> LazyBatchStream<R> {
+    /// Position after the last returned batch, see [`LayoutBatchStream::position`]
+    pub fn position(&self) -> StreamPosition {
+        self.0.position()
+    }
 }
 
+impl<R: VortexReadAt + Unpin + Send + 'static> Stream for LazyBatchStream<R> {
+    type Item = VortexResult<LazyBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.poll_batch(cx)
+    }
+}
+
+/// Read each coalesced range into its buffer and split it into the messages it covers
 async fn read_ranges<R: VortexReadAt>(
     reader: R,
-    ranges: Vec<(MessageId, ByteRange)>,
-) -> VortexResult<(R, Vec<(MessageId, Bytes)>)> {
-    stream::iter(ranges.into_iter())
-        .map(|(id, range)| {
-            let mut buf = BytesMut::with_capacity(range.len());
-            unsafe { buf.set_len(range.len()) }
-
+    reads: Vec<(ByteRange, Vec<Message>, BytesMut, Option<MemoryReservation>)>,
+    column_names: Option<FieldNames>,
+) -> VortexResult<(R, Vec<(MessageId, Bytes)>, u64)> {
+    let bytes_read = reads.iter().map(|(range, ..)| range.len() as u64).sum();
+    let reads: Vec<Vec<(MessageId, Bytes)>> = stream::iter(reads)
+        .map(|(range, messages, buf, reservation)| {
             let read_ft = reader.read_at_into(range.begin, buf);
+            #[cfg(feature = "tracing")]
+            let read_ft = tracing::Instrument::instrument(
+                read_ft,
+                tracing::debug_span!("read_at_into", offset = range.begin, bytes = range.len()),
+            );
 
-            read_ft.map(|result| {
+            let column_names = column_names.clone();
+            read_ft.map(move |result| {
                 result
-                    .map(|res| (id, res.freeze()))
-                    .map_err(VortexError::from)
+                    .map(|res| {
+                        let buf = match reservation {
+                            Some(reservation) => Bytes::from_owner(ReservedBytes {
+                                bytes: res.freeze(),
+                                _reservation: reservation,
+                            }),
+                            None => res.freeze(),
+                        };
+                        split_messages(range, &messages, buf)
+                    })
+                    .map_err(|e| read_error(e, range, &messages, column_names.as_deref()))
             })
         })
         .buffered(10)
         .try_collect()
-        .await
-        .map(|b| (reader, b))
+        .await?;
+
+    Ok((reader, reads.into_iter().flatten().collect(), bytes_read))
+}
+
+/// Bytes read into memory charged to a [`MemoryPool`](vortex::memory::MemoryPool), which is given
+/// back when the last slice of them is dropped
+struct ReservedBytes {
+    bytes: Bytes,
+    _reservation: MemoryReservation,
+}
+
+impl AsRef<[u8]> for ReservedBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// Slice the messages of the coalesced ranges from memory shared with the reader, without copying.
+///
+/// Returns `None` if the reader doesn't hold the file in memory.
+fn read_shared<R: VortexReadAt>(
+    reader: &R,
+    reads: &[(ByteRange, Vec<Message>)],
+    column_names: Option<&[FieldName]>,
+) -> Option<VortexResult<Vec<(MessageId, Bytes)>>> {
+    let mut shared = Vec::new();
+    for (range, messages) in reads {
+        match reader.read_at_shared(range.begin, range.len())? {
+            Ok(buf) => shared.extend(split_messages(*range, messages, buf)),
+            Err(e) => return Some(Err(read_error(e, *range, messages, column_names))),
+        }
+    }
+    Some(Ok(shared))
+}
+
+/// Split the bytes of a coalesced range into the messages it covers
+fn split_messages(range: ByteRange, messages: &[Message], buf: Bytes) -> Vec<(MessageId, Bytes)> {
+    messages
+        .iter()
+        .map(|(id, r)| {
+            let start = (r.begin - range.begin) as usize;
+            (id.clone(), buf.slice(start..start + r.len()))
+        })
+        .collect()
+}
+
+fn read_error(
+    err: io::Error,
+    range: ByteRange,
+    messages: &[Message],
+    column_names: Option<&[FieldName]>,
+) -> VortexError {
+    VortexError::from(err).with_context(format!(
+        "Failed reading {} bytes {}..{}",
+        describe_message(&messages[0].0, column_names),
+        range.begin,
+        range.end
+    ))
+}
+
+/// Group messages whose byte ranges are at most `gap` bytes apart, returning the range covering
+/// each group together with its messages.
+fn coalesce_ranges(mut ranges: Vec<Message>, gap: u64) -> Vec<(ByteRange, Vec<Message>)> {
+    ranges.sort_by_key(|(_, range)| range.begin);
+
+    let mut coalesced: Vec<(ByteRange, Vec<Message>)> = Vec::new();
+    for (id, range) in ranges {
+        match coalesced.last_mut() {
+            Some((merged, messages)) if range.begin <= merged.end.saturating_add(gap) => {
+                merged.end = merged.end.max(range.end);
+                messages.push((id, range));
+            }
+            _ => coalesced.push((range, vec![(id, range)])),
+        }
+    }
+    coalesced
+}
+
+/// Human readable name of the data a message belongs to, used to annotate read errors.
+///
+/// The first part of a message id is the index of the top level column the message belongs to.
+fn describe_message(id: &[LayoutPartId], column_names: Option<&[FieldName]>) -> String {
+    match id
+        .first()
+        .and_then(|col| column_names.and_then(|names| names.get(*col as usize)))
+    {
+        Some(name) => format!("column '{name}'"),
+        None => format!("message {id:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coalesce_within_gap() {
+        let ranges = vec![
+            (vec![1], ByteRange::new(20, 30)),
+            (vec![0], ByteRange::new(0, 10)),
+            (vec![2], ByteRange::new(10, 15)),
+            (vec![3], ByteRange::new(25, 40)),
+            (vec![4], ByteRange::new(100, 110)),
+        ];
+
+        let coalesced = coalesce_ranges(ranges.clone(), 0)
+            .into_iter()
+            .map(|(range, msgs)| (range, msgs.into_iter().map(|(id, _)| id[0]).collect()))
+            .collect::<Vec<(ByteRange, Vec<u16>)>>();
+        assert_eq!(
+            coalesced,
+            vec![
+                (ByteRange::new(0, 15), vec![0, 2]),
+                (ByteRange::new(20, 40), vec![1, 3]),
+                (ByteRange::new(100, 110), vec![4]),
+            ]
+        );
+
+        let coalesced = coalesce_ranges(ranges, 5);
+        assert_eq!(coalesced.len(), 2);
+        assert_eq!(coalesced[0].0, ByteRange::new(0, 40));
+    }
 }
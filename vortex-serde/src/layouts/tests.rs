@@ -1,23 +1,40 @@
 #![allow(clippy::panic)]
 
-use std::iter;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::{io, iter};
 
-use futures::StreamExt;
+use arrow_array::RecordBatchReader;
+use bytes::BytesMut;
+use futures::executor::block_on;
+use futures::{StreamExt, TryStreamExt};
 use vortex::accessor::ArrayAccessor;
-use vortex::array::{ChunkedArray, PrimitiveArray, StructArray, VarBinArray};
+use vortex::array::{
+    ChunkedArray, Primitive, PrimitiveArray, StructArray, VarBinArray, VarBinView,
+};
+use vortex::memory::MemoryPool;
+use vortex::stats::{histogram_fraction_below, ArrayStatistics, Stat, HISTOGRAM_BUCKETS};
 use vortex::validity::Validity;
 use vortex::variants::StructArrayTrait;
-use vortex::{ArrayDType, IntoArray, IntoArrayVariant};
+use vortex::{ArrayDType, ArrayDef, Context, IntoArray, IntoArrayVariant};
+use vortex_alp::{alp_encode, ALPArray, ALPEncoding, ALP};
+use vortex_dict::DictArray;
 use vortex_dtype::field::Field;
 use vortex_dtype::{DType, Nullability, PType, StructDType};
 use vortex_expr::{BinaryExpr, Column, Literal, Operator};
+use vortex_fastlanes::FoR;
+use vortex_sampling_compressor::{SamplingCompressor, ALL_COMPRESSORS_CONTEXT};
 
-use crate::layouts::write::LayoutWriter;
-use crate::layouts::{LayoutDeserializer, LayoutReaderBuilder, Projection, RowFilter};
+use crate::io::caching::CachingReadAt;
+use crate::io::VortexReadAt;
+use crate::layouts::write::{LayoutStrategy, LayoutWriter};
+use crate::layouts::{
+    BlockingRuntime, ChunkPosition, ErrorPolicy, FilterMode, LayoutDescriptorReader,
+    LayoutDeserializer, LayoutReaderBuilder, PredicateStrategy, Projection, RowFilter,
+    SkippedChunkCallback,
+};
 
 #[tokio::test]
-#[cfg_attr(miri, ignore)]
 async fn test_read_simple() {
     let strings = ChunkedArray::from_iter([
         VarBinArray::from(vec!["ab", "foo", "bar", "baz"]).into_array(),
@@ -56,7 +73,48 @@ async fn test_read_simple() {
 }
 
 #[tokio::test]
-#[cfg_attr(miri, ignore)]
+async fn collect_canonical() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+    ])
+    .into_array();
+    let strings = ChunkedArray::from_iter([
+        VarBinArray::from(vec!["ab", "foo"]).into_array(),
+        VarBinArray::from(vec!["bar", "baz", "a", "b", "c", "d"]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers), ("strings", strings)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let st = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_batch_size(3)
+        .build()
+        .await
+        .unwrap()
+        .collect_canonical()
+        .await
+        .unwrap();
+    assert_eq!(st.len(), 8);
+    assert!(st.field(0).unwrap().is_encoding(Primitive::ID));
+    assert!(st.field(1).unwrap().is_encoding(VarBinView::ID));
+    assert_eq!(
+        st.field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>(),
+        &[1, 2, 3, 4, 5, 6, 7, 8]
+    );
+}
+
+#[tokio::test]
 async fn test_read_projection() {
     let strings_expected = ["ab", "foo", "bar", "baz", "ab", "foo", "bar", "baz"];
     let strings = ChunkedArray::from_iter([
@@ -202,7 +260,6 @@ async fn test_read_projection() {
 }
 
 #[tokio::test]
-#[cfg_attr(miri, ignore)]
 async fn unequal_batches() {
     let strings = ChunkedArray::from_iter([
         VarBinArray::from(vec!["ab", "foo", "bar", "bob"]).into_array(),
@@ -249,7 +306,6 @@ async fn unequal_batches() {
 }
 
 #[tokio::test]
-#[cfg_attr(miri, ignore)]
 async fn write_chunked() {
     let strings = VarBinArray::from(vec!["ab", "foo", "bar", "baz"]).into_array();
     let string_dtype = strings.dtype().clone();
@@ -292,7 +348,6 @@ async fn write_chunked() {
 }
 
 #[tokio::test]
-#[cfg_attr(miri, ignore)]
 async fn filter_string() {
     let names_orig = VarBinArray::from_iter(
         vec![Some("Joseph"), None, Some("Angela"), Some("Mikhail"), None],
@@ -352,7 +407,6 @@ async fn filter_string() {
 }
 
 #[tokio::test]
-#[cfg_attr(miri, ignore)]
 async fn filter_or() {
     let names = VarBinArray::from_iter(
         vec![Some("Joseph"), None, Some("Angela"), Some("Mikhail"), None],
@@ -425,7 +479,6 @@ async fn filter_or() {
 }
 
 #[tokio::test]
-#[cfg_attr(miri, ignore)]
 async fn filter_and() {
     let names = VarBinArray::from_iter(
         vec![Some("Joseph"), None, Some("Angela"), Some("Mikhail"), None],
@@ -487,3 +540,1647 @@ async fn filter_and() {
         vec![25, 31]
     );
 }
+
+#[tokio::test]
+async fn skip_undecodable_chunk() {
+    // ALP isn't registered in the default context, so the middle chunk fails to decode.
+    let alp = alp_encode(&PrimitiveArray::from(vec![1.5f64, 2.5, 3.5])).unwrap();
+    let floats = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1.0f64, 2.0]).into_array(),
+        alp.into_array(),
+        PrimitiveArray::from(vec![3.0f64, 4.0]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("floats", floats)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let failing = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await;
+    assert!(failing.is_err());
+
+    let skipped = Arc::new(AtomicUsize::new(0));
+    let skipped_in_callback = skipped.clone();
+    let array = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_error_policy(ErrorPolicy::SkipChunk)
+        .with_skipped_chunk_callback(SkippedChunkCallback::new(move |_, _| {
+            skipped_in_callback.fetch_add(1, Ordering::Relaxed);
+        }))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+
+    assert_eq!(skipped.load(Ordering::Relaxed), 1);
+    let floats = array
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(floats.maybe_null_slice::<f64>(), &[1.0, 2.0, 3.0, 4.0]);
+}
+
+#[tokio::test]
+async fn skip_chunk_of_one_column() {
+    // Only the middle chunk of `floats` fails to decode, its rows are dropped from `ints` as well
+    let alp = alp_encode(&PrimitiveArray::from(vec![1.5f64, 2.5, 3.5])).unwrap();
+    let floats = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1.0f64, 2.0]).into_array(),
+        alp.into_array(),
+        PrimitiveArray::from(vec![3.0f64, 4.0]).into_array(),
+    ])
+    .into_array();
+    let ints = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2]).into_array(),
+        PrimitiveArray::from(vec![10u32, 11, 12]).into_array(),
+        PrimitiveArray::from(vec![3u32, 4]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("floats", floats), ("ints", ints)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let skipped = Arc::new(Mutex::new(Vec::new()));
+    let skipped_in_callback = skipped.clone();
+    let array = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_error_policy(ErrorPolicy::SkipChunk)
+        .with_batch_size(2)
+        .with_skipped_chunk_callback(SkippedChunkCallback::new(move |rows, _| {
+            skipped_in_callback.lock().unwrap().push(rows);
+        }))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+
+    assert_eq!(*skipped.lock().unwrap(), vec![2..5]);
+    assert_eq!(
+        array
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<f64>(),
+        &[1.0, 2.0, 3.0, 4.0]
+    );
+    assert_eq!(
+        array
+            .field(1)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>(),
+        &[1, 2, 3, 4]
+    );
+}
+
+#[tokio::test]
+async fn shared_dictionary_round_trip() {
+    let strings = ChunkedArray::from_iter([
+        VarBinArray::from_iter(
+            [Some("ab"), None, Some("foo")],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array(),
+        VarBinArray::from_iter(
+            [Some("foo"), Some("bar")],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array(),
+        VarBinArray::from_iter(
+            [Some("baz"), Some("ab"), None],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array(),
+    ])
+    .into_array();
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3]).into_array(),
+        PrimitiveArray::from(vec![4u32, 5]).into_array(),
+        PrimitiveArray::from(vec![6u32, 7, 8]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("strings", strings), ("numbers", numbers)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new())
+        .with_shared_dictionaries(&["strings"])
+        .with_pruning_stats(&["strings"]);
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let stream = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_batch_size(3)
+        .build()
+        .await
+        .unwrap();
+    let batches = stream.try_collect::<Vec<_>>().await.unwrap();
+    assert_eq!(batches.len(), 3);
+
+    let mut strings = Vec::new();
+    for batch in batches {
+        let batch = batch.into_struct().unwrap();
+        let dict = DictArray::try_from(batch.field(0).unwrap()).unwrap();
+        // Null, "ab", "foo", "bar" and "baz"
+        assert_eq!(dict.values().len(), 5);
+        strings.extend(
+            dict.into_array()
+                .into_varbinview()
+                .unwrap()
+                .with_iterator(|iter| {
+                    iter.map(|s| s.map(|s| String::from_utf8(s.to_vec()).unwrap()))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap(),
+        );
+        assert!(batch.field(1).unwrap().into_primitive().is_ok());
+    }
+    assert_eq!(
+        strings,
+        [
+            Some("ab"),
+            None,
+            Some("foo"),
+            Some("foo"),
+            Some("bar"),
+            Some("baz"),
+            Some("ab"),
+            None
+        ]
+        .map(|s| s.map(String::from))
+    );
+
+    let filtered = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("strings"))),
+            Operator::Eq,
+            Arc::new(Literal::new("foo".into())),
+        ))))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+    assert_eq!(
+        filtered
+            .into_struct()
+            .unwrap()
+            .field(1)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>(),
+        &[3, 4]
+    );
+
+    // Chunk statistics describe the values rather than the codes
+    let layout = LayoutDescriptorReader::new(LayoutDeserializer::default())
+        .read_footer(&written, written.len() as u64)
+        .await
+        .unwrap();
+    let table = layout.chunk_metadata(&written, 0).await.unwrap().unwrap();
+    assert_eq!(
+        table.field_by_name("min").unwrap().dtype(),
+        &DType::Utf8(Nullability::Nullable)
+    );
+}
+
+/// Serves the footer but fails every other read.
+struct FooterOnlyReadAt(Vec<u8>);
+
+impl VortexReadAt for FooterOnlyReadAt {
+    async fn read_at_into(&self, pos: u64, buffer: BytesMut) -> io::Result<BytesMut> {
+        if pos as usize + buffer.len() == self.0.len() {
+            self.0.read_at_into(pos, buffer).await
+        } else {
+            Err(io::Error::new(io::ErrorKind::TimedOut, "throttled"))
+        }
+    }
+
+    async fn size(&self) -> u64 {
+        self.0.len() as u64
+    }
+}
+
+#[tokio::test]
+async fn read_error_context() {
+    let numbers = PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let err = LayoutReaderBuilder::new(FooterOnlyReadAt(written), LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Failed reading column 'numbers' bytes "));
+}
+
+#[tokio::test]
+async fn metrics_track_filtering() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+    let file_size = written.len() as u64;
+
+    let mut stream = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_batch_size(4)
+        .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("numbers"))),
+            Operator::Gt,
+            Arc::new(Literal::new(6u32.into())),
+        ))))
+        .build()
+        .await
+        .unwrap();
+    while let Some(array) = stream.next().await {
+        array.unwrap();
+    }
+
+    let metrics = stream.metrics();
+    assert_eq!(metrics.chunks_pruned, 1);
+    assert_eq!(metrics.rows_filtered, 6);
+    assert_eq!(metrics.rows_returned, 2);
+    assert!(metrics.bytes_read >= file_size);
+}
+
+#[tokio::test]
+async fn filter_after_empty_batch() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+        PrimitiveArray::from(vec![9u32, 10, 11, 12]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let mut stream = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_batch_size(4)
+        .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("numbers"))),
+            Operator::Gt,
+            Arc::new(Literal::new(6u32.into())),
+        ))))
+        .build()
+        .await
+        .unwrap();
+    let mut result = Vec::new();
+    while let Some(array) = stream.next().await {
+        let numbers = array
+            .unwrap()
+            .with_dyn(|a| a.as_struct_array_unchecked().field(0))
+            .unwrap();
+        result.extend_from_slice(numbers.into_primitive().unwrap().maybe_null_slice::<u32>());
+    }
+
+    assert_eq!(result, vec![7, 8, 9, 10, 11, 12]);
+}
+
+#[tokio::test]
+async fn read_with_custom_context() {
+    let alp = alp_encode(&PrimitiveArray::from(vec![1.5f64, 2.5, 3.5])).unwrap();
+    let st = StructArray::from_fields(&[("floats", alp.into_array())]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let array = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_context(Arc::new(Context::default().with_encoding(&ALPEncoding)))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+
+    let floats = array
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(floats.maybe_null_slice::<f64>(), &[1.5, 2.5, 3.5]);
+}
+
+#[tokio::test]
+async fn alp_with_patches_roundtrip() {
+    let values = PrimitiveArray::from_nullable_vec(vec![
+        Some(1.25f64),
+        None,
+        Some(std::f64::consts::PI),
+        Some(2.5),
+        Some(f64::NAN),
+        Some(-0.75),
+    ]);
+    let alp = alp_encode(&values).unwrap();
+    let exponents = alp.exponents();
+    assert!(alp.patches().is_some());
+    let st = StructArray::from_fields(&[("floats", alp.into_array())]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let array = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_context(Arc::new(Context::default().with_encoding(&ALPEncoding)))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+
+    let floats = ALPArray::try_from(array.into_struct().unwrap().field(0).unwrap()).unwrap();
+    assert_eq!(floats.exponents(), exponents);
+    assert!(floats.patches().is_some());
+    let floats = floats.into_primitive().unwrap();
+    assert_eq!(floats.dtype(), values.dtype());
+    assert_eq!(
+        floats
+            .iter::<f64>()
+            .map(|v| v.map(f64::to_bits))
+            .collect::<Vec<_>>(),
+        values
+            .iter::<f64>()
+            .map(|v| v.map(f64::to_bits))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)] // Compressing with the sampling compressor is too slow on miri
+async fn write_compressed_batches() {
+    let batches = (0..3)
+        .map(|b| {
+            StructArray::from_fields(&[(
+                "numbers",
+                PrimitiveArray::from((0..10_000u32).map(|i| b * 100 + i % 7).collect::<Vec<_>>())
+                    .into_array(),
+            )])
+            .unwrap()
+            .into_array()
+        })
+        .collect::<Vec<_>>();
+    let dtype = batches[0].dtype().clone();
+    let st = ChunkedArray::try_new(batches, dtype).unwrap().into_array();
+
+    let uncompressed = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.clone())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+    for layout in [LayoutStrategy::ChunkMajor, LayoutStrategy::ColumnMajor] {
+        let compressed = LayoutWriter::new(Vec::new())
+            .with_layout(layout)
+            .with_compressor(Arc::new(SamplingCompressor::default()))
+            .write_array_columns(st.clone())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+        assert!(compressed.len() < uncompressed.len() / 2);
+
+        let array = LayoutReaderBuilder::new(compressed, LayoutDeserializer::default())
+            .with_context(ALL_COMPRESSORS_CONTEXT.clone())
+            .build()
+            .await
+            .unwrap()
+            .read_all()
+            .await
+            .unwrap();
+        let numbers = array
+            .into_struct()
+            .unwrap()
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(numbers.len(), 30_000);
+        assert_eq!(numbers.maybe_null_slice::<u32>()[20_003], 203);
+    }
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)] // Compressing with the sampling compressor is too slow on miri
+async fn write_column_encoding() {
+    let st = StructArray::from_fields(&[(
+        "numbers",
+        PrimitiveArray::from((1000..2000u32).collect::<Vec<_>>()).into_array(),
+    )])
+    .unwrap()
+    .into_array();
+
+    let written = LayoutWriter::new(Vec::new())
+        .with_compressor(Arc::new(SamplingCompressor::default()))
+        .with_column_encoding("numbers", FoR::ID)
+        .write_array_columns(st.clone())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+    let numbers = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_context(ALL_COMPRESSORS_CONTEXT.clone())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap();
+    assert!(numbers.is_encoding(FoR::ID));
+
+    let alp = LayoutWriter::new(Vec::new())
+        .with_compressor(Arc::new(SamplingCompressor::default()))
+        .with_column_encoding("numbers", ALP::ID)
+        .write_array_columns(st.clone())
+        .await;
+    assert!(alp.is_err());
+
+    let no_compressor = LayoutWriter::new(Vec::new())
+        .with_column_encoding("numbers", FoR::ID)
+        .write_array_columns(st.clone())
+        .await;
+    assert!(no_compressor.is_err());
+
+    let unknown_column = LayoutWriter::new(Vec::new())
+        .with_compressor(Arc::new(SamplingCompressor::default()))
+        .with_column_encoding("nmubers", FoR::ID)
+        .write_array_columns(st)
+        .await;
+    assert!(unknown_column.is_err_and(|err| err.to_string().contains("nmubers")));
+
+    let not_struct = LayoutWriter::new(Vec::new())
+        .with_compressor(Arc::new(SamplingCompressor::default()))
+        .with_column_encoding("numbers", FoR::ID)
+        .write_array_columns(PrimitiveArray::from(vec![1u32, 2, 3]).into_array())
+        .await;
+    assert!(not_struct.is_err());
+}
+
+#[tokio::test]
+async fn column_major_layout() {
+    let st = || {
+        let batches = [(["ab", "foo"], [1u32, 2]), (["bar", "baz"], [3u32, 4])]
+            .into_iter()
+            .map(|(strings, numbers)| {
+                StructArray::from_fields(&[
+                    ("strings", VarBinArray::from(strings.to_vec()).into_array()),
+                    (
+                        "numbers",
+                        PrimitiveArray::from(numbers.to_vec()).into_array(),
+                    ),
+                ])
+                .unwrap()
+                .into_array()
+            })
+            .collect::<Vec<_>>();
+        let dtype = batches[0].dtype().clone();
+        ChunkedArray::try_new(batches, dtype).unwrap().into_array()
+    };
+
+    let chunk_major = LayoutWriter::new(Vec::new())
+        .write_array_columns(st())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+    let column_major = LayoutWriter::new(Vec::new())
+        .with_layout(LayoutStrategy::ColumnMajor)
+        .write_array_columns(st())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(chunk_major.len(), column_major.len());
+    assert!(chunk_major != column_major);
+
+    // Writing out the buffered columns after every batch lays them out chunk by chunk
+    let spilled = LayoutWriter::new(Vec::new())
+        .with_layout(LayoutStrategy::ColumnMajor)
+        .with_max_buffered_bytes(1)
+        .write_array_columns(st())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(spilled, chunk_major);
+
+    let array = LayoutReaderBuilder::new(column_major, LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    let numbers = array.field(1).unwrap().into_primitive().unwrap();
+    assert_eq!(numbers.maybe_null_slice::<u32>(), &[1, 2, 3, 4]);
+    let strings = array
+        .field(0)
+        .unwrap()
+        .into_varbinview()
+        .unwrap()
+        .with_iterator(|iter| {
+            iter.map(|s| unsafe { String::from_utf8_unchecked(s.unwrap().to_vec()) })
+                .collect::<Vec<_>>()
+        })
+        .unwrap();
+    assert_eq!(strings, vec!["ab", "foo", "bar", "baz"]);
+}
+
+#[tokio::test]
+async fn write_target_chunk_size() {
+    let batches = (0..10u32)
+        .map(|i| {
+            StructArray::from_fields(&[(
+                "numbers",
+                PrimitiveArray::from(vec![2 * i, 2 * i + 1]).into_array(),
+            )])
+            .unwrap()
+            .into_array()
+        })
+        .collect::<Vec<_>>();
+    let dtype = batches[0].dtype().clone();
+    let st = ChunkedArray::try_new(batches, dtype).unwrap().into_array();
+
+    let written = LayoutWriter::new(Vec::new())
+        .with_target_chunk_size(5)
+        .write_array_columns(st)
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let footer = LayoutDescriptorReader::new(LayoutDeserializer::default())
+        .read_footer(&written, written.len() as u64)
+        .await
+        .unwrap();
+    let chunks = footer.chunk_metadata(&written, 0).await.unwrap().unwrap();
+    let row_offsets = chunks
+        .field_by_name("row_offset")
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(row_offsets.maybe_null_slice::<u64>(), &[0, 6, 12, 18]);
+
+    let numbers = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_validation(true)
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(
+        numbers.maybe_null_slice::<u32>(),
+        (0..20).collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn bloom_filter_prunes_chunks() {
+    let strings = ChunkedArray::from_iter([
+        VarBinArray::from(vec!["ab", "foo", "bar", "baz"]).into_array(),
+        VarBinArray::from(vec!["ab", "foo", "bar", "baz"]).into_array(),
+    ])
+    .into_array();
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("strings", strings), ("numbers", numbers)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new()).with_bloom_filters(&["numbers"]);
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let mut stream = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("numbers"))),
+            Operator::Eq,
+            Arc::new(Literal::new(6u32.into())),
+        ))))
+        .build()
+        .await
+        .unwrap();
+    let mut batches = Vec::new();
+    while let Some(array) = stream.next().await {
+        batches.push(array.unwrap());
+    }
+
+    assert_eq!(stream.metrics().chunks_pruned, 1);
+    assert_eq!(batches.len(), 1);
+    let st = batches[0].clone().into_struct().unwrap();
+    assert_eq!(
+        st.field(1)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>(),
+        &[6]
+    );
+    st.field(0)
+        .unwrap()
+        .into_varbinview()
+        .unwrap()
+        .with_iterator(|iter| {
+            assert_eq!(
+                iter.flatten().map(|s| s.to_vec()).collect::<Vec<_>>(),
+                vec![b"foo".to_vec()]
+            );
+        })
+        .unwrap();
+}
+
+#[tokio::test]
+async fn explain_row_filter() {
+    let strings = ChunkedArray::from_iter([
+        VarBinArray::from(vec!["ab", "foo"]).into_array(),
+        VarBinArray::from(vec!["bar", "baz"]).into_array(),
+    ])
+    .into_array();
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2]).into_array(),
+        PrimitiveArray::from(vec![3u32, 4]).into_array(),
+    ])
+    .into_array();
+    let floats = ChunkedArray::from_iter([
+        alp_encode(&PrimitiveArray::from(vec![1.5f64, 2.5]))
+            .unwrap()
+            .into_array(),
+        alp_encode(&PrimitiveArray::from(vec![3.5f64, 4.5]))
+            .unwrap()
+            .into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[
+        ("strings", strings),
+        ("numbers", numbers),
+        ("floats", floats),
+    ])
+    .unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .with_bloom_filters(&["numbers"])
+        .with_pruning_stats(&["numbers"])
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+    let footer = LayoutDescriptorReader::new(
+        LayoutDeserializer::default()
+            .with_context(Arc::new(Context::default().with_encoding(&ALPEncoding))),
+    )
+    .read_footer(&written, written.len() as u64)
+    .await
+    .unwrap();
+
+    let numbers = Arc::new(Column::new(Field::from("numbers")));
+    let filter = RowFilter::new(Arc::new(BinaryExpr::new(
+        Arc::new(BinaryExpr::new(
+            Arc::new(Literal::new(3u32.into())),
+            Operator::Eq,
+            numbers.clone(),
+        )),
+        Operator::And,
+        Arc::new(BinaryExpr::new(
+            Arc::new(BinaryExpr::new(
+                Arc::new(Column::new(Field::from("strings"))),
+                Operator::Gt,
+                Arc::new(Literal::new("b".into())),
+            )),
+            Operator::And,
+            Arc::new(BinaryExpr::new(
+                Arc::new(BinaryExpr::new(numbers.clone(), Operator::Eq, numbers)),
+                Operator::And,
+                Arc::new(BinaryExpr::new(
+                    Arc::new(Column::new(Field::from("floats"))),
+                    Operator::Lt,
+                    Arc::new(Literal::new(2.0f64.into())),
+                )),
+            )),
+        )),
+    )));
+
+    let plan = filter.explain(&written, &footer).await.unwrap();
+    assert_eq!(
+        plan.predicates
+            .iter()
+            .map(|p| p.strategies.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            vec![
+                PredicateStrategy::ChunkPruning,
+                PredicateStrategy::BloomFilter,
+                PredicateStrategy::FullScan
+            ],
+            vec![PredicateStrategy::FullScan],
+            vec![PredicateStrategy::FullScan],
+            vec![PredicateStrategy::EncodedCompare],
+        ]
+    );
+}
+
+#[tokio::test]
+async fn prune_chunks_by_min_max() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2]).into_array(),
+        PrimitiveArray::from(vec![3u32, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .with_pruning_stats(&["numbers"])
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut stream = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("numbers"))),
+            Operator::Gte,
+            Arc::new(Literal::new(4u32.into())),
+        ))))
+        .build()
+        .await
+        .unwrap();
+    let mut result = Vec::new();
+    while let Some(array) = stream.next().await {
+        let numbers = array
+            .unwrap()
+            .with_dyn(|a| a.as_struct_array_unchecked().field(0))
+            .unwrap();
+        result.extend_from_slice(numbers.into_primitive().unwrap().maybe_null_slice::<u32>());
+    }
+
+    assert_eq!(result, vec![4, 5, 6]);
+    // The first chunk is never read, only the 3 of the second is filtered out
+    assert_eq!(stream.metrics().chunks_pruned, 1);
+    assert_eq!(stream.metrics().rows_filtered, 1);
+}
+
+#[tokio::test]
+async fn read_record_batches() {
+    let strings = ChunkedArray::from_iter([
+        VarBinArray::from(vec!["ab", "foo", "bar", "baz"]).into_array(),
+        VarBinArray::from(vec!["ab", "foo", "bar", "baz"]).into_array(),
+    ])
+    .into_array();
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("strings", strings), ("numbers", numbers)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let stream = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_batch_size(4)
+        .with_projection(Projection::new([1]))
+        .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("numbers"))),
+            Operator::Gt,
+            Arc::new(Literal::new(2u32.into())),
+        ))))
+        .build_arrow()
+        .await
+        .unwrap();
+    let schema = stream.schema();
+    assert_eq!(schema.fields().len(), 1);
+    assert_eq!(schema.field(0).name(), "numbers");
+
+    let batches = stream.try_collect::<Vec<_>>().await.unwrap();
+    assert_eq!(batches.len(), 2);
+    assert!(batches.iter().all(|b| Arc::ptr_eq(&b.schema(), &schema)));
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 6);
+}
+
+#[test]
+fn record_batch_reader() {
+    let st = StructArray::from_fields(&[
+        (
+            "numbers",
+            PrimitiveArray::from((0u32..10).collect::<Vec<_>>()).into_array(),
+        ),
+        ("strings", VarBinArray::from(vec!["a"; 10]).into_array()),
+    ])
+    .unwrap();
+    let written = block_on(async {
+        LayoutWriter::new(Vec::new())
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap()
+    });
+    let stream = block_on(
+        LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+            .with_batch_size(4)
+            .build(),
+    )
+    .unwrap();
+
+    let reader = stream.into_record_batch_reader(&BlockingRuntime).unwrap();
+    let schema = reader.schema();
+    assert_eq!(schema.fields().len(), 2);
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(batches.len(), 3);
+    assert!(batches.iter().all(|b| Arc::ptr_eq(&b.schema(), &schema)));
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 10);
+}
+
+#[tokio::test]
+async fn build_with_footer() {
+    let st = StructArray::from_fields(&[(
+        "numbers",
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+    )])
+    .unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+    let reader = Arc::new(CachingReadAt::new(written, 0));
+
+    let footer = LayoutDescriptorReader::new(LayoutDeserializer::default())
+        .read_footer(&reader, reader.size().await)
+        .await
+        .unwrap();
+    assert_eq!(
+        footer.dtype().unwrap(),
+        DType::Struct(
+            StructDType::new(
+                vec!["numbers".into()].into(),
+                vec![DType::Primitive(PType::U32, Nullability::NonNullable)]
+            ),
+            Nullability::NonNullable
+        )
+    );
+    assert_eq!(reader.misses(), 1);
+
+    let array = LayoutReaderBuilder::new(reader.clone(), LayoutDeserializer::default())
+        .with_footer(footer)
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+    assert_eq!(array.len(), 4);
+    // Only the data was read, the footer wasn't read again
+    assert_eq!(reader.misses(), 2);
+}
+
+#[tokio::test]
+async fn malformed_footer() {
+    let st = StructArray::from_fields(&[(
+        "numbers",
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+    )])
+    .unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    // Too short to hold a postscript
+    let eof = written[written.len() - 8..].to_vec();
+    let footer_reader = LayoutDescriptorReader::new(LayoutDeserializer::default());
+    assert!(footer_reader
+        .read_footer(&eof, eof.len() as u64)
+        .await
+        .is_err());
+
+    // Postscript offsets point past the start of the truncated file
+    let tail = written[written.len() - 40..].to_vec();
+    assert!(footer_reader
+        .read_footer(&tail, tail.len() as u64)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn read_with_buffer_pool() {
+    let numbers =
+        ChunkedArray::from_iter((0..8u32).map(|c| {
+            PrimitiveArray::from((c * 100..(c + 1) * 100).collect::<Vec<_>>()).into_array()
+        }))
+        .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    // Smaller than the file, so reads have to share and reclaim the arena
+    let mut stream = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_buffer_pool(1024)
+        .build()
+        .await
+        .unwrap();
+    let mut expected = 0u32..800;
+    while let Some(batch) = stream.next().await {
+        let numbers = batch
+            .unwrap()
+            .into_struct()
+            .unwrap()
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        for n in numbers.maybe_null_slice::<u32>() {
+            assert_eq!(Some(*n), expected.next());
+        }
+    }
+    assert_eq!(expected.next(), None);
+}
+
+#[tokio::test]
+async fn read_with_memory_pool() {
+    let numbers =
+        ChunkedArray::from_iter((0..8u32).map(|c| {
+            PrimitiveArray::from((c * 100..(c + 1) * 100).collect::<Vec<_>>()).into_array()
+        }))
+        .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let pool = MemoryPool::new(1 << 20);
+    let mut stream = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_memory_pool(pool.clone())
+        .build()
+        .await
+        .unwrap();
+    let mut batches = Vec::new();
+    while let Some(batch) = stream.next().await {
+        let batch = batch.unwrap();
+        // The bytes a batch was decoded from are charged while the caller holds it
+        assert!(pool.reserved() >= batch.nbytes());
+        batches.push(batch);
+    }
+    drop(stream);
+    assert_eq!(batches.iter().map(|b| b.len()).sum::<usize>(), 800);
+    let reserved = pool.reserved();
+    batches.pop();
+    assert!(pool.reserved() < reserved);
+    drop(batches);
+    assert_eq!(pool.reserved(), 0);
+
+    // Too small to hold a single chunk
+    let pool = MemoryPool::new(100);
+    let result = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_memory_pool(pool.clone())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await;
+    assert!(result.is_err());
+    assert_eq!(pool.reserved(), 0);
+}
+
+#[tokio::test]
+async fn read_aligned_buffers() {
+    let st = StructArray::from_fields(&[
+        ("a", PrimitiveArray::from(vec![1u8, 2, 3]).into_array()),
+        ("b", PrimitiveArray::from(vec![1u64, 2, 3]).into_array()),
+    ])
+    .unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    for builder in [
+        LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default()),
+        LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+            .with_buffer_pool(4096),
+    ] {
+        let st = builder
+            .build()
+            .await
+            .unwrap()
+            .read_all()
+            .await
+            .unwrap()
+            .into_struct()
+            .unwrap();
+        for field in 0..2 {
+            assert!(st
+                .field(field)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .is_aligned());
+        }
+    }
+}
+
+#[tokio::test]
+async fn read_lazy_decodes_accessed_columns() {
+    let strings = ChunkedArray::from_iter([
+        VarBinArray::from(vec!["ab", "foo", "bar", "baz"]).into_array(),
+        VarBinArray::from(vec!["ab", "foo", "bar", "baz"]).into_array(),
+    ])
+    .into_array();
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("strings", strings), ("numbers", numbers)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut stream = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .build_lazy()
+        .await
+        .unwrap();
+    let mut numbers = Vec::new();
+    while let Some(batch) = stream.try_next().await.unwrap() {
+        assert!(!batch.is_decoded(0));
+        assert!(!batch.is_decoded(1));
+
+        let column = batch.column_by_name("numbers").unwrap();
+        assert!(batch.is_decoded(1));
+        assert!(!batch.is_decoded(0));
+        numbers.extend_from_slice(column.into_primitive().unwrap().maybe_null_slice::<u32>());
+    }
+    assert_eq!(numbers, (1u32..=8).collect::<Vec<_>>());
+
+    // Lazy batches are filtered like eager ones once decoded
+    let filter = RowFilter::new(Arc::new(BinaryExpr::new(
+        Arc::new(Column::new(Field::from("numbers"))),
+        Operator::Gt,
+        Arc::new(Literal::new(6u32.into())),
+    )));
+    let batches: Vec<_> = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_row_filter(filter)
+        .build_lazy()
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].len(), 2);
+    let strings = batches[0].column(0).unwrap().into_varbinview().unwrap();
+    assert!(!batches[0].is_decoded(1));
+    assert_eq!(
+        strings
+            .with_iterator(|iter| iter
+                .map(|s| String::from_utf8(s.unwrap().to_vec()).unwrap())
+                .collect::<Vec<_>>())
+            .unwrap(),
+        vec!["bar", "baz"]
+    );
+}
+
+#[tokio::test]
+async fn write_histograms() {
+    let st = StructArray::from_fields(&[
+        (
+            "numbers",
+            PrimitiveArray::from((0u32..=1600).collect::<Vec<_>>()).into_array(),
+        ),
+        ("strings", VarBinArray::from(vec!["a"; 1601]).into_array()),
+    ])
+    .unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .with_histograms(&["numbers", "strings"])
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let st = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    let histogram = st
+        .field(0)
+        .unwrap()
+        .statistics()
+        .get_as::<Vec<u32>>(Stat::Histogram)
+        .unwrap();
+    assert_eq!(histogram.len(), HISTOGRAM_BUCKETS + 1);
+    assert_eq!(histogram_fraction_below(&histogram, 400), Some(0.25));
+    assert!(st
+        .field(1)
+        .unwrap()
+        .statistics()
+        .get(Stat::Histogram)
+        .is_none());
+}
+
+#[tokio::test]
+async fn read_chunk_histograms() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from_iter((0u32..=1600).map(Some)).into_array(),
+        PrimitiveArray::from_iter([None::<u32>; 3]).into_array(),
+    ])
+    .into_array();
+    let strings = ChunkedArray::from_iter([
+        VarBinArray::from(vec!["a"; 1601]).into_array(),
+        VarBinArray::from(vec!["b"; 3]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers), ("strings", strings)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .with_histograms(&["numbers"])
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let footer = LayoutDescriptorReader::new(LayoutDeserializer::default())
+        .read_footer(&written, written.len() as u64)
+        .await
+        .unwrap();
+    let histograms = footer.chunk_histograms(&written, 0).await.unwrap().unwrap();
+    assert_eq!(histograms.len(), 2);
+    let boundaries = Vec::<u32>::try_from(histograms[0].as_ref().unwrap()).unwrap();
+    assert_eq!(boundaries.len(), HISTOGRAM_BUCKETS + 1);
+    assert_eq!(histogram_fraction_below(&boundaries, 400), Some(0.25));
+    assert!(histograms[1].is_none());
+    assert!(footer
+        .chunk_histograms(&written, 1)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)] // This test is too slow on miri
+async fn write_distinct_counts() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from((0u64..3000).collect::<Vec<_>>()).into_array(),
+        PrimitiveArray::from((2000u64..5000).collect::<Vec<_>>()).into_array(),
+    ])
+    .into_array();
+    let strings = ChunkedArray::from_iter([
+        VarBinArray::from(["a", "b"].repeat(1500)).into_array(),
+        VarBinArray::from(["b", "c", "d"].repeat(1000)).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers), ("strings", strings)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .with_distinct_counts(&["numbers", "strings"])
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let st = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    let numbers = st
+        .field(0)
+        .unwrap()
+        .statistics()
+        .compute_approx_distinct()
+        .unwrap();
+    assert!((4750..5250).contains(&numbers), "{numbers}");
+    assert_eq!(
+        st.field(1).unwrap().statistics().compute_approx_distinct(),
+        Some(4)
+    );
+}
+
+#[tokio::test]
+async fn write_chunk_min_max() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![4u32, 1, 3]).into_array(),
+        PrimitiveArray::from(vec![8u32, 6, 7]).into_array(),
+    ])
+    .into_array();
+    let maybe_numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from_iter([Some(-1i64), None, Some(2)]).into_array(),
+        PrimitiveArray::from_iter([None::<i64>, None, None]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers), ("maybe_numbers", maybe_numbers)])
+        .unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .with_pruning_stats(&["numbers", "maybe_numbers"])
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let footer = LayoutDescriptorReader::new(LayoutDeserializer::default())
+        .read_footer(&written, written.len() as u64)
+        .await
+        .unwrap();
+
+    let numbers = footer.chunk_metadata(&written, 0).await.unwrap().unwrap();
+    let min = numbers
+        .field_by_name("min")
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(min.maybe_null_slice::<u32>(), &[1, 6]);
+    let max = numbers
+        .field_by_name("max")
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(max.maybe_null_slice::<u32>(), &[4, 8]);
+
+    let maybe_numbers = footer.chunk_metadata(&written, 1).await.unwrap().unwrap();
+    let min = maybe_numbers
+        .field_by_name("min")
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(min.iter::<i64>().collect::<Vec<_>>(), vec![Some(-1), None]);
+    let null_count = maybe_numbers
+        .field_by_name("null_count")
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(null_count.maybe_null_slice::<u64>(), &[1, 3]);
+}
+
+#[tokio::test]
+async fn read_projected_column_stats() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![4u32, 1, 3]).into_array(),
+        PrimitiveArray::from(vec![8u32, 6, 7]).into_array(),
+    ])
+    .into_array();
+    let maybe_numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from_iter([Some(-1i64), None, Some(2)]).into_array(),
+        PrimitiveArray::from_iter([None::<i64>, None, None]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers), ("maybe_numbers", maybe_numbers)])
+        .unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .with_pruning_stats(&["numbers", "maybe_numbers"])
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+    let footer = LayoutDescriptorReader::new(LayoutDeserializer::default())
+        .read_footer(&written, written.len() as u64)
+        .await
+        .unwrap();
+
+    let stats = footer
+        .projected_column_stats(
+            &written,
+            &Projection::Flat(vec![Field::Name("maybe_numbers".to_string())]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(stats.len(), 1);
+    let maybe_numbers = stats[0].as_ref().unwrap();
+    assert_eq!(
+        i64::try_from(maybe_numbers.get(Stat::Min).unwrap()).unwrap(),
+        -1
+    );
+    assert_eq!(
+        i64::try_from(maybe_numbers.get(Stat::Max).unwrap()).unwrap(),
+        2
+    );
+    assert_eq!(
+        u64::try_from(maybe_numbers.get(Stat::NullCount).unwrap()).unwrap(),
+        4
+    );
+
+    let all = footer
+        .projected_column_stats(&written, &Projection::All)
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(
+        u32::try_from(all[0].as_ref().unwrap().get(Stat::Max).unwrap()).unwrap(),
+        8
+    );
+    assert!(footer
+        .projected_column_stats(&written, &Projection::Flat(vec![Field::from("missing")]))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn filter_mode_mask() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let filter = RowFilter::new(Arc::new(BinaryExpr::new(
+        Arc::new(Column::new(Field::from("numbers"))),
+        Operator::Gt,
+        Arc::new(Literal::new(6u32.into())),
+    )));
+    assert!(
+        LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+            .with_row_filter(filter.clone())
+            .with_filter_mode(FilterMode::Mask)
+            .build()
+            .await
+            .is_err()
+    );
+
+    let mut stream = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_row_filter(filter)
+        .with_filter_mode(FilterMode::Mask)
+        .with_batch_size(4)
+        .with_pruned_chunks([0])
+        .build_lazy()
+        .await
+        .unwrap();
+    let batches: Vec<_> = (&mut stream).try_collect().await.unwrap();
+    assert_eq!(stream.metrics().rows_filtered, 0);
+    assert_eq!(stream.metrics().chunks_pruned, 0);
+
+    // The batch without matching rows is kept, with all rows of both batches, even if pruned
+    assert_eq!(batches.len(), 2);
+    let mut numbers = Vec::new();
+    let mut selection = Vec::new();
+    for batch in batches {
+        assert_eq!(batch.len(), 4);
+        numbers.extend_from_slice(
+            batch
+                .column(0)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u32>(),
+        );
+        selection.extend(
+            batch
+                .selection()
+                .unwrap()
+                .clone()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter(),
+        );
+    }
+    assert_eq!(numbers, (1u32..=8).collect::<Vec<_>>());
+    assert_eq!(
+        selection,
+        vec![false, false, false, false, false, false, true, true]
+    );
+}
+
+#[tokio::test]
+async fn resume_from_position() {
+    let numbers = ChunkedArray::from_iter(
+        (0u32..4)
+            .map(|c| PrimitiveArray::from((c * 4..c * 4 + 4).collect::<Vec<_>>()).into_array()),
+    )
+    .into_array();
+    let strings = ChunkedArray::from_iter([
+        VarBinArray::from(vec!["a"; 10]).into_array(),
+        VarBinArray::from(vec!["b"; 6]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers), ("strings", strings)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .with_layout(LayoutStrategy::ColumnMajor)
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let read_numbers = |batch: vortex::Array| {
+        batch
+            .into_struct()
+            .unwrap()
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>()
+            .to_vec()
+    };
+    let filter = || {
+        RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("numbers"))),
+            Operator::NotEq,
+            Arc::new(Literal::new(5u32.into())),
+        )))
+    };
+
+    let mut stream = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_row_filter(filter())
+        .with_batch_size(3)
+        .build()
+        .await
+        .unwrap();
+    let mut numbers = Vec::new();
+    for _ in 0..2 {
+        numbers.extend(read_numbers(stream.try_next().await.unwrap().unwrap()));
+    }
+    let position = stream.position();
+    drop(stream);
+    assert_eq!(position.current_offset, 6);
+    assert_eq!(
+        position.column(1),
+        Some(ChunkPosition {
+            chunk_idx: 0,
+            rows_read: 6
+        })
+    );
+
+    let mut stream = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_row_filter(filter())
+        .with_batch_size(3)
+        .resume_from(position)
+        .build()
+        .await
+        .unwrap();
+    while let Some(batch) = stream.try_next().await.unwrap() {
+        numbers.extend(read_numbers(batch));
+    }
+    assert_eq!(numbers, (0u32..16).filter(|n| *n != 5).collect::<Vec<_>>());
+    assert_eq!(stream.position().current_offset, 16);
+}
+
+#[tokio::test]
+async fn read_indexed_batches() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2]).into_array(),
+        PrimitiveArray::from(vec![3u32, 4, 5]).into_array(),
+        PrimitiveArray::from(vec![6u32]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let ordered = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_pruned_chunks([1])
+        .build_indexed(2)
+        .await
+        .unwrap()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(
+        ordered.iter().map(|b| b.chunk_idx).collect::<Vec<_>>(),
+        vec![0, 2]
+    );
+
+    let mut unordered = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_ordered(false)
+        .build_indexed(3)
+        .await
+        .unwrap()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    unordered.sort_by_key(|b| b.chunk_idx);
+    let values = unordered
+        .iter()
+        .map(|b| {
+            (
+                b.chunk_idx,
+                b.batch
+                    .clone()
+                    .into_struct()
+                    .unwrap()
+                    .field(0)
+                    .unwrap()
+                    .into_primitive()
+                    .unwrap()
+                    .maybe_null_slice::<u32>()
+                    .to_vec(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![(0, vec![1, 2]), (1, vec![3, 4, 5]), (2, vec![6])]
+    );
+
+    assert!(
+        LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+            .build_indexed(0)
+            .await
+            .is_err()
+    );
+    assert!(
+        LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+            .with_ordered(false)
+            .build()
+            .await
+            .is_err()
+    );
+}
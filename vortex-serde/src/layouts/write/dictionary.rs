@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use vortex::accessor::ArrayAccessor;
+use vortex::array::{ChunkedArray, ConstantArray, PrimitiveArray};
+use vortex::compute::take;
+use vortex::{Array, ArrayDType, Canonical, IntoArray, IntoCanonical};
+use vortex_dict::{dict_encode, NULL_CODE};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+/// Dictionary shared by all chunks of a column, whose codes are `u32`s.
+///
+/// Values are only ever appended, so the codes of the chunks written before a value was added stay
+/// valid. Like [`dict_encode`], code 0 of a nullable column is null.
+pub(crate) struct SharedDictionary {
+    dtype: DType,
+    codes: HashMap<Vec<u8>, u32>,
+    values: Vec<Array>,
+    len: usize,
+}
+
+impl SharedDictionary {
+    pub fn new(dtype: DType) -> Self {
+        let values = if dtype.is_nullable() {
+            vec![ConstantArray::new(Scalar::null(dtype.clone()), 1).into_array()]
+        } else {
+            Vec::new()
+        };
+        Self {
+            len: values.len(),
+            dtype,
+            codes: HashMap::new(),
+            values,
+        }
+    }
+
+    /// Codes of the values of `chunk`, adding the values the dictionary doesn't have yet
+    pub fn encode(&mut self, chunk: &Array) -> VortexResult<Array> {
+        let dict = dict_encode(chunk)?;
+        let chunk_values = dict.values();
+        let mut new_values = Vec::new();
+        // Code in the shared dictionary of every value of the chunk's dictionary
+        let mapping = value_keys(&chunk_values)?
+            .into_iter()
+            .enumerate()
+            .map(|(idx, key)| {
+                if self.dtype.is_nullable() && idx as u64 == NULL_CODE {
+                    return Ok(NULL_CODE as u32);
+                }
+                if let Some(code) = self.codes.get(&key) {
+                    return Ok(*code);
+                }
+                let Ok(code) = u32::try_from(self.len) else {
+                    vortex_bail!("Shared dictionary can't have more than {} values", u32::MAX)
+                };
+                self.codes.insert(key, code);
+                self.len += 1;
+                new_values.push(idx as u64);
+                Ok(code)
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        if !new_values.is_empty() {
+            self.values
+                .push(take(&chunk_values, PrimitiveArray::from(new_values))?);
+        }
+        take(PrimitiveArray::from(mapping), dict.codes())
+    }
+
+    /// All values of the dictionary, indexed by code
+    pub fn into_values(self) -> VortexResult<Array> {
+        ChunkedArray::try_new(self.values, self.dtype)?
+            .into_canonical()
+            .map(Array::from)
+    }
+}
+
+/// Bytes of every value of a dictionary, which identify it among values of the same type
+fn value_keys(values: &Array) -> VortexResult<Vec<Vec<u8>>> {
+    match values.clone().into_canonical()? {
+        Canonical::Primitive(p) => Ok(p
+            .buffer()
+            .chunks_exact(p.ptype().byte_width())
+            .map(<[u8]>::to_vec)
+            .collect()),
+        Canonical::VarBinView(v) => v.with_iterator(|iter| {
+            iter.map(|value| value.map(<[u8]>::to_vec).unwrap_or_default())
+                .collect()
+        }),
+        _ => vortex_bail!(
+            "Can't share a dictionary of values of type {}",
+            values.dtype()
+        ),
+    }
+}
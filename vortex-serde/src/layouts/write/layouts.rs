@@ -3,7 +3,8 @@ use flatbuffers::{FlatBufferBuilder, WIPOffset};
 use vortex_flatbuffers::{footer as fb, WriteFlatBuffer};
 
 use crate::layouts::{
-    LayoutId, CHUNKED_LAYOUT_ID, COLUMN_LAYOUT_ID, FLAT_LAYOUT_ID, INLINE_SCHEMA_LAYOUT_ID,
+    LayoutId, CHUNKED_LAYOUT_ID, COLUMN_LAYOUT_ID, DICT_LAYOUT_ID, FLAT_LAYOUT_ID,
+    INLINE_SCHEMA_LAYOUT_ID,
 };
 use crate::stream_writer::ByteRange;
 
@@ -25,6 +26,15 @@ impl Layout {
         }
     }
 
+    pub fn flat_with_metadata(buffer: ByteRange, metadata: Bytes) -> Self {
+        Self {
+            id: FLAT_LAYOUT_ID,
+            buffers: Some(vec![buffer]),
+            children: None,
+            metadata: Some(metadata),
+        }
+    }
+
     /// Create a chunked layout with children.
     ///
     /// has_metadata indicates whether first child is a layout containing metadata about other children.
@@ -46,6 +56,18 @@ impl Layout {
         }
     }
 
+    /// Create a layout of codes into a dictionary shared by all of them.
+    ///
+    /// First child is the flat layout of the dictionary's values, second the layout of the codes.
+    pub fn dict(values: ByteRange, codes: Layout) -> Self {
+        Self {
+            id: DICT_LAYOUT_ID,
+            buffers: None,
+            children: Some(vec![Layout::flat(values), codes]),
+            metadata: None,
+        }
+    }
+
     pub fn inlined_schema(children: Vec<Layout>, dtype_buffer: ByteRange) -> Self {
         Self {
             id: INLINE_SCHEMA_LAYOUT_ID,
@@ -1,5 +1,6 @@
-pub use writer::LayoutWriter;
+pub use writer::{LayoutStrategy, LayoutWriter};
 
+mod dictionary;
 mod footer;
-mod layouts;
+pub(crate) mod layouts;
 mod writer;
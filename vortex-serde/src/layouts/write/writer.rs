@@ -1,30 +1,73 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
 use std::{io, mem};
 
+use bytes::Bytes;
 use flatbuffers::FlatBufferBuilder;
 use futures::{Stream, TryStreamExt};
-use vortex::array::{ChunkedArray, StructArray};
+use itertools::Itertools;
+use log::warn;
+use vortex::array::{ChunkedArray, ConstantArray, StructArray};
+use vortex::compress::CompressionStrategy;
+use vortex::encoding::EncodingId;
+use vortex::stats::{ArrayStatistics, Stat, StatsSet, HISTOGRAM_BUCKETS};
 use vortex::stream::ArrayStream;
 use vortex::validity::Validity;
-use vortex::{Array, ArrayDType, IntoArray};
+use vortex::{Array, ArrayDType, IntoArray, IntoCanonical};
 use vortex_buffer::io_buf::IoBuf;
-use vortex_dtype::DType;
+use vortex_dtype::{DType, FieldName};
 use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult};
 use vortex_flatbuffers::WriteFlatBuffer;
+use vortex_scalar::{Scalar, ScalarValue};
 
 use crate::io::VortexWrite;
+use crate::layouts::bloom::BloomFilter;
+use crate::layouts::write::dictionary::SharedDictionary;
 use crate::layouts::write::footer::{Footer, Postscript};
 use crate::layouts::write::layouts::Layout;
 use crate::layouts::{EOF_SIZE, MAGIC_BYTES, VERSION};
 use crate::stream_writer::ByteRange;
 use crate::MessageWriter;
 
+/// Stats of every chunk written to its column's metadata table, for readers to prune chunks with
+const PRUNING_STATS: [Stat; 3] = [Stat::Min, Stat::Max, Stat::NullCount];
+
+/// Size of the arrays a column-major writer buffers before writing them out, by default
+const DEFAULT_MAX_BUFFERED_BYTES: usize = 256 << 20;
+
+/// Order in which the writer lays out column chunks in the file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LayoutStrategy {
+    /// Chunks of all columns for a batch are written next to each other, as soon as the batch is
+    /// written. Favours point lookups that need every column of a few rows.
+    #[default]
+    ChunkMajor,
+    /// All chunks of a column are written next to each other. Favours scans over a few columns,
+    /// but buffers written arrays in memory. Once they exceed
+    /// [`LayoutWriter::with_max_buffered_bytes`], the buffered columns are written out and
+    /// the following chunks of each column start a new run.
+    ColumnMajor,
+}
+
 pub struct LayoutWriter<W> {
     msgs: MessageWriter<W>,
 
     row_count: u64,
     dtype: Option<DType>,
     column_chunks: Vec<BatchOffsets>,
+    layout_strategy: LayoutStrategy,
+    pending_columns: Vec<Vec<Array>>,
+    pending_bytes: usize,
+    max_buffered_bytes: usize,
+    bloom_filter_columns: Vec<FieldName>,
+    pruning_stats_columns: Vec<FieldName>,
+    histogram_columns: Vec<FieldName>,
+    distinct_count_columns: Vec<FieldName>,
+    compressor: Option<Arc<dyn CompressionStrategy + Send + Sync>>,
+    column_encodings: Vec<(FieldName, EncodingId)>,
+    shared_dictionary_columns: Vec<FieldName>,
+    dictionaries: HashMap<usize, SharedDictionary>,
+    target_chunk_size: Option<usize>,
 }
 
 impl<W: VortexWrite> LayoutWriter<W> {
@@ -34,11 +77,210 @@ impl<W: VortexWrite> LayoutWriter<W> {
             dtype: None,
             column_chunks: Vec::new(),
             row_count: 0,
+            layout_strategy: LayoutStrategy::default(),
+            pending_columns: Vec::new(),
+            pending_bytes: 0,
+            max_buffered_bytes: DEFAULT_MAX_BUFFERED_BYTES,
+            bloom_filter_columns: Vec::new(),
+            pruning_stats_columns: Vec::new(),
+            histogram_columns: Vec::new(),
+            distinct_count_columns: Vec::new(),
+            compressor: None,
+            column_encodings: Vec::new(),
+            shared_dictionary_columns: Vec::new(),
+            dictionaries: HashMap::new(),
+            target_chunk_size: None,
+        }
+    }
+
+    /// Compress every chunk with `compressor` as it is written.
+    ///
+    /// Chunks are compressed one batch at a time, so the writer never holds more than a batch of
+    /// uncompressed data. Statistics of the chunks are computed before they are compressed.
+    pub fn with_compressor(
+        mut self,
+        compressor: Arc<dyn CompressionStrategy + Send + Sync>,
+    ) -> Self {
+        self.compressor = Some(compressor);
+        self
+    }
+
+    /// Merge runs of adjacent chunks with fewer than `rows` rows before writing them, see
+    /// [`ChunkedArray::coalesce`].
+    ///
+    /// Only chunks of a single written array are merged, arrays written separately always end up
+    /// in separate chunks.
+    pub fn with_target_chunk_size(mut self, rows: usize) -> Self {
+        self.target_chunk_size = Some(rows);
+        self
+    }
+
+    pub fn with_layout(mut self, layout_strategy: LayoutStrategy) -> Self {
+        self.layout_strategy = layout_strategy;
+        self
+    }
+
+    /// Write out the arrays buffered by a [`LayoutStrategy::ColumnMajor`] writer once they take up
+    /// more than `bytes`, 256 MiB by default.
+    ///
+    /// Buffered arrays are only written out between written arrays, so a single array larger than
+    /// `bytes` is still buffered whole.
+    pub fn with_max_buffered_bytes(mut self, bytes: usize) -> Self {
+        self.max_buffered_bytes = bytes;
+        self
+    }
+
+    /// Build a bloom filter over every chunk of the named columns, letting readers skip chunks
+    /// that can't match an equality filter.
+    ///
+    /// Bloom filters are only written if all columns end up with the same chunk boundaries.
+    pub fn with_bloom_filters<S: AsRef<str>>(mut self, columns: &[S]) -> Self {
+        self.bloom_filter_columns = columns.iter().map(|c| c.as_ref().into()).collect();
+        self
+    }
+
+    /// Store the min, max and null count of every chunk of the named columns in their metadata
+    /// tables, letting readers skip chunks that can't match a filter.
+    ///
+    /// Computing them may decode encoded chunks. The statistics are only written if all columns end
+    /// up with the same chunk boundaries.
+    pub fn with_pruning_stats<S: AsRef<str>>(mut self, columns: &[S]) -> Self {
+        self.pruning_stats_columns = columns.iter().map(|c| c.as_ref().into()).collect();
+        self
+    }
+
+    /// Store a histogram of the values of every chunk of the named columns in the chunk's
+    /// statistics and in the column's metadata table, letting readers estimate how many of its
+    /// rows match a range filter.
+    ///
+    /// Only columns of primitive type get histograms. Like min and max, they're only written to
+    /// the metadata table if all columns end up with the same chunk boundaries.
+    pub fn with_histograms<S: AsRef<str>>(mut self, columns: &[S]) -> Self {
+        self.histogram_columns = columns.iter().map(|c| c.as_ref().into()).collect();
+        self
+    }
+
+    /// Store a sketch of the distinct values of every chunk of the named columns in the chunk's
+    /// statistics, letting readers estimate the number of distinct values of the column.
+    ///
+    /// Only columns of primitive, string and binary type get sketches.
+    pub fn with_distinct_counts<S: AsRef<str>>(mut self, columns: &[S]) -> Self {
+        self.distinct_count_columns = columns.iter().map(|c| c.as_ref().into()).collect();
+        self
+    }
+
+    /// Compress every chunk of the named column into `encoding` rather than letting the compressor
+    /// choose one, requires [`with_compressor`](Self::with_compressor).
+    ///
+    /// Writing fails if the written arrays aren't structs with a column of that name, or if the
+    /// compressor can't produce the encoding for the column's chunks, e.g. because it doesn't
+    /// support the column's dtype.
+    pub fn with_column_encoding<S: AsRef<str>>(mut self, column: S, encoding: EncodingId) -> Self {
+        let column = FieldName::from(column.as_ref());
+        self.column_encodings.retain(|(name, _)| *name != column);
+        self.column_encodings.push((column, encoding));
+        self
+    }
+
+    /// Dictionary encode every chunk of the named columns with a single dictionary shared by all
+    /// chunks of the column, written once after them. Readers return the chunks of these columns as
+    /// [`DictArray`](vortex_dict::DictArray)s with the same values array, letting a whole column be
+    /// processed in dictionary space.
+    ///
+    /// Only columns of primitive, string and binary type can share a dictionary. The dictionary is
+    /// held in memory until the writer is finalized and can't have more than `u32::MAX` values.
+    /// Chunk statistics and bloom filters are still computed from the values of the chunks.
+    pub fn with_shared_dictionaries<S: AsRef<str>>(mut self, columns: &[S]) -> Self {
+        self.shared_dictionary_columns = columns.iter().map(|c| c.as_ref().into()).collect();
+        self
+    }
+
+    fn has_shared_dictionary(&self, column_idx: usize) -> bool {
+        self.column_in(column_idx, &self.shared_dictionary_columns)
+    }
+
+    /// Encoding forced for the column with [`with_column_encoding`](Self::with_column_encoding)
+    fn column_encoding(&self, column_idx: usize) -> Option<EncodingId> {
+        let Some(DType::Struct(s, _)) = &self.dtype else {
+            return None;
+        };
+        let name = s.names().get(column_idx)?;
+        self.column_encodings
+            .iter()
+            .find(|(column, _)| column == name)
+            .map(|(_, encoding)| *encoding)
+    }
+
+    /// Check that every column given to [`with_column_encoding`](Self::with_column_encoding)
+    /// exists in the written dtype
+    fn check_column_encodings(&self) -> VortexResult<()> {
+        let Some((column, _)) = self.column_encodings.first() else {
+            return Ok(());
+        };
+        let Some(DType::Struct(s, _)) = &self.dtype else {
+            vortex_bail!(
+                "Encoding forced for column {column} but the written array isn't a struct: {}",
+                self.dtype
+                    .as_ref()
+                    .vortex_expect("dtype is set before writing")
+            )
+        };
+        if let Some((column, _)) = self
+            .column_encodings
+            .iter()
+            .find(|(column, _)| !s.names().contains(column))
+        {
+            vortex_bail!("Encoding forced for unknown column {column}")
+        }
+        if let Some((column, _)) = self
+            .column_encodings
+            .iter()
+            .find(|(column, _)| self.shared_dictionary_columns.contains(column))
+        {
+            vortex_bail!("Encoding forced for column {column} that shares a dictionary")
+        }
+        Ok(())
+    }
+
+    fn has_bloom_filter(&self, column_idx: usize) -> bool {
+        self.column_in(column_idx, &self.bloom_filter_columns)
+    }
+
+    fn has_pruning_stats(&self, column_idx: usize) -> bool {
+        self.column_in(column_idx, &self.pruning_stats_columns)
+    }
+
+    /// Stats requested for the column, computed before writing each of its chunks
+    fn chunk_stats(&self, column_idx: usize) -> Vec<Stat> {
+        let mut stats = Vec::new();
+        if self.has_pruning_stats(column_idx) {
+            stats.extend(PRUNING_STATS);
+        }
+        if self.column_in(column_idx, &self.histogram_columns) {
+            stats.push(Stat::Histogram);
+        }
+        if self.column_in(column_idx, &self.distinct_count_columns) {
+            stats.push(Stat::ApproxDistinct);
+        }
+        stats
+    }
+
+    fn column_in(&self, column_idx: usize, columns: &[FieldName]) -> bool {
+        match &self.dtype {
+            Some(DType::Struct(s, _)) => s
+                .names()
+                .get(column_idx)
+                .is_some_and(|name| columns.contains(name)),
+            _ => false,
         }
     }
 
     pub async fn write_array_columns(self, array: Array) -> VortexResult<Self> {
         if let Ok(chunked) = ChunkedArray::try_from(&array) {
+            let chunked = match self.target_chunk_size {
+                Some(rows) => chunked.coalesce(rows)?,
+                None => chunked,
+            };
             self.write_array_columns_stream(chunked.array_stream())
                 .await
         } else {
@@ -54,39 +296,104 @@ impl<W: VortexWrite> LayoutWriter<W> {
         match self.dtype {
             None => self.dtype = Some(array_stream.dtype().clone()),
             Some(ref sd) => {
-                if sd != array_stream.dtype() {
+                if let Err(mismatch) = sd.is_compatible_with(array_stream.dtype()) {
                     vortex_bail!(
-                        "Expected all arrays in the stream to have the same dtype {}, found {}",
+                        "Expected all arrays in the stream to have the same dtype {}, found {}: {mismatch}",
                         sd,
                         array_stream.dtype()
                     )
                 }
             }
         }
+        self.check_column_encodings()?;
 
         while let Some(columns) = array_stream.try_next().await? {
             let st = StructArray::try_from(&columns)?;
             self.row_count += st.len() as u64;
             for (i, field) in st.children().enumerate() {
-                if let Ok(chunked_array) = ChunkedArray::try_from(field.clone()) {
-                    self.write_column_chunks(chunked_array.array_stream(), i)
+                let field = match (self.target_chunk_size, ChunkedArray::try_from(&field)) {
+                    (Some(rows), Ok(chunked)) => chunked.coalesce(rows)?.into_array(),
+                    _ => field,
+                };
+                if self.layout_strategy == LayoutStrategy::ColumnMajor {
+                    // Only hold on to the compressed chunks until the column is written
+                    let field = match ChunkedArray::try_from(field.clone()) {
+                        Ok(chunked) => ChunkedArray::try_new(
+                            chunked
+                                .chunks()
+                                .map(|c| self.compress_chunk(c, i))
+                                .try_collect()?,
+                            chunked.dtype().clone(),
+                        )?
+                        .into_array(),
+                        Err(_) => self.compress_chunk(field, i)?,
+                    };
+                    if self.pending_columns.len() <= i {
+                        self.pending_columns.push(Vec::new());
+                    }
+                    self.pending_bytes += field.nbytes();
+                    self.pending_columns[i].push(field);
+                } else if let Ok(chunked_array) = ChunkedArray::try_from(field.clone()) {
+                    self.write_column_chunks(chunked_array.array_stream(), i, true)
                         .await?
                 } else {
-                    self.write_column_chunks(field.into_array_stream(), i)
+                    self.write_column_chunks(field.into_array_stream(), i, true)
                         .await?
                 }
             }
+            if self.pending_bytes > self.max_buffered_bytes {
+                self.write_pending_columns().await?;
+            }
         }
 
         Ok(self)
     }
 
-    async fn write_column_chunks<S>(&mut self, mut stream: S, column_idx: usize) -> VortexResult<()>
+    /// Compute the statistics of the chunk and compress it, if the writer has a compressor
+    fn compress_chunk(&self, chunk: Array, column_idx: usize) -> VortexResult<Array> {
+        let encoding = self.column_encoding(column_idx);
+        let Some(compressor) = &self.compressor else {
+            if let Some(encoding) = encoding {
+                vortex_bail!("Column {column_idx} must be written as {encoding}, but the writer has no compressor")
+            }
+            return Ok(chunk);
+        };
+        compute_chunk_stats(&chunk, &self.chunk_stats(column_idx))?;
+        if self.has_shared_dictionary(column_idx) {
+            // Only the codes are compressed, once the chunk is encoded with the shared dictionary
+            return Ok(chunk);
+        }
+        let compressed = match encoding {
+            Some(encoding) => compressor.compress_with_encoding(&chunk, encoding)?,
+            None => compressor.compress(&chunk)?,
+        };
+        for (stat, value) in chunk.statistics().to_set() {
+            compressed.statistics().set(stat, value);
+        }
+        Ok(compressed)
+    }
+
+    async fn write_column_chunks<S>(
+        &mut self,
+        mut stream: S,
+        column_idx: usize,
+        compress: bool,
+    ) -> VortexResult<()>
     where
         S: Stream<Item = VortexResult<Array>> + Unpin,
     {
         let mut row_offsets: Vec<u64> = Vec::new();
         let mut byte_offsets = vec![self.msgs.tell()];
+        let mut bloom_filters = Vec::new();
+        let mut table_stats = Vec::new();
+        let with_bloom_filter = self.has_bloom_filter(column_idx);
+        let chunk_stats = self.chunk_stats(column_idx);
+        // Written to the column's metadata table, the other stats only to the chunk's
+        let table_stat_kinds = chunk_stats
+            .iter()
+            .copied()
+            .filter(|stat| PRUNING_STATS.contains(stat) || *stat == Stat::Histogram)
+            .collect::<Vec<_>>();
 
         let mut n_rows_written = match self.column_chunks.get(column_idx) {
             None => {
@@ -100,18 +407,71 @@ impl<W: VortexWrite> LayoutWriter<W> {
         };
 
         while let Some(chunk) = stream.try_next().await? {
+            #[cfg(feature = "tracing")]
+            let span = tracing::debug_span!(
+                "write_chunk",
+                column = column_idx,
+                rows = chunk.len(),
+                bytes = tracing::field::Empty
+            );
+            #[cfg(feature = "tracing")]
+            let chunk_begin = self.msgs.tell();
+
             n_rows_written += chunk.len() as u64;
             row_offsets.push(n_rows_written);
-            self.msgs.write_batch(chunk).await?;
+            if with_bloom_filter {
+                bloom_filters.push(BloomFilter::try_from_array(&chunk)?.to_bytes());
+            }
+            compute_chunk_stats(&chunk, &chunk_stats)?;
+            if !table_stat_kinds.is_empty() {
+                let mut stats = StatsSet::new();
+                stats.extend(
+                    table_stat_kinds.iter().filter_map(|stat| {
+                        chunk.statistics().get(*stat).map(|value| (*stat, value))
+                    }),
+                );
+                table_stats.push(stats);
+            }
+            let chunk = if compress {
+                self.compress_chunk(chunk, column_idx)?
+            } else {
+                chunk
+            };
+            let chunk = if self.has_shared_dictionary(column_idx) {
+                let codes = self
+                    .dictionaries
+                    .entry(column_idx)
+                    .or_insert_with(|| SharedDictionary::new(chunk.dtype().clone()))
+                    .encode(&chunk)?;
+                match &self.compressor {
+                    Some(compressor) => compressor.compress(&codes)?,
+                    None => codes,
+                }
+            } else {
+                chunk
+            };
+            let write_ft = self.msgs.write_batch(chunk);
+            #[cfg(feature = "tracing")]
+            let write_ft = tracing::Instrument::instrument(write_ft, span.clone());
+            write_ft.await?;
             byte_offsets.push(self.msgs.tell());
+
+            #[cfg(feature = "tracing")]
+            span.record("bytes", self.msgs.tell() - chunk_begin);
         }
 
         if let Some(batches) = self.column_chunks.get_mut(column_idx) {
             batches.row_offsets.extend(row_offsets);
             batches.batch_byte_offsets.push(byte_offsets);
+            batches.bloom_filters.extend(bloom_filters);
+            batches.table_stats.extend(table_stats);
         } else {
-            self.column_chunks
-                .push(BatchOffsets::new(row_offsets, vec![byte_offsets]));
+            self.column_chunks.push(BatchOffsets::new(
+                row_offsets,
+                vec![byte_offsets],
+                bloom_filters,
+                table_stats,
+            ));
         }
 
         Ok(())
@@ -119,28 +479,56 @@ impl<W: VortexWrite> LayoutWriter<W> {
 
     async fn write_metadata_arrays(&mut self) -> VortexResult<Layout> {
         let mut column_layouts = Vec::with_capacity(self.column_chunks.len());
-        for mut chunk in mem::take(&mut self.column_chunks) {
-            let mut chunks: VecDeque<Layout> = chunk
-                .batch_byte_offsets
+        // Readers skip the same chunk index in every column, which is only valid if they line up
+        let aligned_chunks = self
+            .column_chunks
+            .iter()
+            .map(|c| &c.row_offsets)
+            .all_equal();
+        if !aligned_chunks
+            && self
+                .column_chunks
                 .iter()
-                .flat_map(|byte_offsets| {
-                    byte_offsets
-                        .iter()
-                        .zip(byte_offsets.iter().skip(1))
-                        .map(|(begin, end)| Layout::flat(ByteRange::new(*begin, *end)))
-                })
-                .collect();
+                .any(|c| !c.bloom_filters.is_empty())
+        {
+            warn!("Columns have different chunk boundaries, bloom filters will not be written");
+        }
+        if !aligned_chunks && self.column_chunks.iter().any(|c| !c.table_stats.is_empty()) {
+            warn!("Columns have different chunk boundaries, chunk statistics will not be written");
+        }
+
+        for (column_idx, mut chunk) in mem::take(&mut self.column_chunks).into_iter().enumerate() {
+            let ranges = chunk.batch_byte_offsets.iter().flat_map(|byte_offsets| {
+                byte_offsets
+                    .iter()
+                    .zip(byte_offsets.iter().skip(1))
+                    .map(|(begin, end)| ByteRange::new(*begin, *end))
+            });
+            let mut chunks: VecDeque<Layout> = if aligned_chunks && !chunk.bloom_filters.is_empty()
+            {
+                ranges
+                    .zip_eq(mem::take(&mut chunk.bloom_filters))
+                    .map(|(range, bloom)| Layout::flat_with_metadata(range, bloom))
+                    .collect()
+            } else {
+                ranges.map(Layout::flat).collect()
+            };
             let len = chunk.row_offsets.len() - 1;
             chunk.row_offsets.truncate(len);
 
             assert_eq!(chunks.len(), chunk.row_offsets.len());
 
-            let metadata_array = StructArray::try_new(
-                ["row_offset".into()].into(),
-                vec![chunk.row_offsets.into_array()],
-                len,
-                Validity::NonNullable,
-            )?;
+            let mut names: Vec<FieldName> = vec!["row_offset".into()];
+            let mut fields = vec![chunk.row_offsets.into_array()];
+            // Readers skip the same chunks in every column based on these, like bloom filters
+            if aligned_chunks && !chunk.table_stats.is_empty() && chunk.table_stats.len() == len {
+                for (name, field) in table_stats_fields(&chunk.table_stats)? {
+                    names.push(name);
+                    fields.push(field);
+                }
+            }
+            let metadata_array =
+                StructArray::try_new(names.into(), fields, len, Validity::NonNullable)?;
 
             let dtype_begin = self.msgs.tell();
             self.msgs.write_dtype(metadata_array.dtype()).await?;
@@ -150,7 +538,23 @@ impl<W: VortexWrite> LayoutWriter<W> {
                 vec![Layout::flat(ByteRange::new(dtype_end, self.msgs.tell()))],
                 ByteRange::new(dtype_begin, dtype_end),
             ));
-            column_layouts.push(Layout::chunked(chunks.into(), true));
+            let chunked = Layout::chunked(chunks.into(), true);
+            match self.dictionaries.remove(&column_idx) {
+                Some(dictionary) => {
+                    let values = dictionary.into_values()?;
+                    let values = match &self.compressor {
+                        Some(compressor) => compressor.compress(&values)?,
+                        None => values,
+                    };
+                    let values_begin = self.msgs.tell();
+                    self.msgs.write_batch(values).await?;
+                    column_layouts.push(Layout::dict(
+                        ByteRange::new(values_begin, self.msgs.tell()),
+                        chunked,
+                    ));
+                }
+                None => column_layouts.push(chunked),
+            }
         }
 
         Ok(Layout::column(column_layouts))
@@ -171,7 +575,24 @@ impl<W: VortexWrite> LayoutWriter<W> {
         Ok(Postscript::new(schema_offset, footer_offset))
     }
 
+    async fn write_pending_columns(&mut self) -> VortexResult<()> {
+        self.pending_bytes = 0;
+        for (i, column) in mem::take(&mut self.pending_columns).into_iter().enumerate() {
+            for field in column {
+                if let Ok(chunked_array) = ChunkedArray::try_from(field.clone()) {
+                    self.write_column_chunks(chunked_array.array_stream(), i, false)
+                        .await?
+                } else {
+                    self.write_column_chunks(field.into_array_stream(), i, false)
+                        .await?
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub async fn finalize(mut self) -> VortexResult<W> {
+        self.write_pending_columns().await?;
         let top_level_layout = self.write_metadata_arrays().await?;
         let ps = self
             .write_footer(Footer::new(top_level_layout, self.row_count))
@@ -200,17 +621,132 @@ async fn write_fb_raw<W: VortexWrite, F: WriteFlatBuffer>(mut writer: W, fb: F)
     Ok(writer)
 }
 
+fn compute_chunk_stats(chunk: &Array, stats: &[Stat]) -> VortexResult<()> {
+    let mut canonical = None;
+    for stat in stats {
+        let supported = match stat {
+            Stat::Histogram => matches!(chunk.dtype(), DType::Primitive(..)),
+            Stat::ApproxDistinct => matches!(
+                chunk.dtype(),
+                DType::Primitive(..) | DType::Utf8(_) | DType::Binary(_)
+            ),
+            Stat::Min | Stat::Max | Stat::NullCount => matches!(
+                chunk.dtype(),
+                DType::Bool(_) | DType::Primitive(..) | DType::Utf8(_) | DType::Binary(_)
+            ),
+            _ => true,
+        };
+        if !supported || chunk.statistics().compute(*stat).is_some() {
+            continue;
+        }
+
+        // Encoded chunks may not compute the stat, their canonical form does
+        let canonical = match canonical {
+            Some(ref canonical) => canonical,
+            None => canonical.insert(Array::from(chunk.clone().into_canonical()?)),
+        };
+        if let Some(value) = canonical.statistics().compute(*stat) {
+            chunk.statistics().set(*stat, value);
+        }
+    }
+    Ok(())
+}
+
+/// `min`, `max`, `null_count` and `histogram` fields of a column's metadata table, for readers to
+/// prune chunks and estimate selectivity with. Chunks without one of the stats, e.g. because all
+/// their values are null, have a null one.
+///
+/// The histogram is a struct with a field per bucket boundary, named by its index.
+fn table_stats_fields(stats: &[StatsSet]) -> VortexResult<Vec<(FieldName, Array)>> {
+    let mut fields = Vec::new();
+    for (name, stat) in [("min", Stat::Min), ("max", Stat::Max)] {
+        let Some(dtype) = stats
+            .iter()
+            .find_map(|s| s.get(stat))
+            .map(|value| value.dtype().as_nullable())
+        else {
+            continue;
+        };
+        let values = stats_column(stats, &dtype, |s| s.get(stat).map(|v| v.value().clone()))?;
+        fields.push((name.into(), values));
+    }
+
+    let null_counts = stats
+        .iter()
+        .map(|s| s.get(Stat::NullCount).map(u64::try_from).transpose())
+        .collect::<VortexResult<Option<Vec<_>>>>()?;
+    if let Some(null_counts) = null_counts {
+        fields.push(("null_count".into(), null_counts.into_array()));
+    }
+
+    let histogram_dtype = stats
+        .iter()
+        .find_map(|s| s.get(Stat::Histogram))
+        .and_then(|histogram| match histogram.dtype() {
+            DType::List(element, _) => Some(element.as_nullable()),
+            _ => None,
+        });
+    if let Some(dtype) = histogram_dtype {
+        let (names, boundaries): (Vec<FieldName>, Vec<Array>) = (0..=HISTOGRAM_BUCKETS)
+            .map(|bucket| {
+                let boundary = stats_column(stats, &dtype, |s| {
+                    match s.get(Stat::Histogram).map(|h| h.value().clone()) {
+                        Some(ScalarValue::List(values)) => values.get(bucket).cloned(),
+                        _ => None,
+                    }
+                })?;
+                Ok((bucket.to_string().into(), boundary))
+            })
+            .collect::<VortexResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        let histogram =
+            StructArray::try_new(names.into(), boundaries, stats.len(), Validity::NonNullable)?;
+        fields.push(("histogram".into(), histogram.into_array()));
+    }
+    Ok(fields)
+}
+
+/// Canonical column with a row per chunk holding the value `value` picks from the chunk's stats
+fn stats_column(
+    stats: &[StatsSet],
+    dtype: &DType,
+    value: impl Fn(&StatsSet) -> Option<ScalarValue>,
+) -> VortexResult<Array> {
+    let values = stats
+        .iter()
+        .map(|s| {
+            let value = value(s)
+                .map(|value| Scalar::new(dtype.clone(), value))
+                .unwrap_or_else(|| Scalar::null(dtype.clone()));
+            ConstantArray::new(value, 1).into_array()
+        })
+        .collect();
+    Ok(Array::from(
+        ChunkedArray::try_new(values, dtype.clone())?.into_canonical()?,
+    ))
+}
+
 #[derive(Clone, Debug)]
 pub struct BatchOffsets {
     pub row_offsets: Vec<u64>,
     pub batch_byte_offsets: Vec<Vec<u64>>,
+    pub bloom_filters: Vec<Bytes>,
+    pub table_stats: Vec<StatsSet>,
 }
 
 impl BatchOffsets {
-    pub fn new(row_offsets: Vec<u64>, batch_byte_offsets: Vec<Vec<u64>>) -> Self {
+    pub fn new(
+        row_offsets: Vec<u64>,
+        batch_byte_offsets: Vec<Vec<u64>>,
+        bloom_filters: Vec<Bytes>,
+        table_stats: Vec<StatsSet>,
+    ) -> Self {
         Self {
             row_offsets,
             batch_byte_offsets,
+            bloom_filters,
+            table_stats,
         }
     }
 }
@@ -11,8 +11,7 @@ mod messages;
 pub mod stream_reader;
 pub mod stream_writer;
 pub use dtype_reader::*;
-
-pub const ALIGNMENT: usize = 64;
+pub use vortex_buffer::ALIGNMENT;
 
 #[cfg(test)]
 #[allow(clippy::panic_in_result_fn)]
@@ -26,25 +25,27 @@ mod test {
     use vortex::array::{ChunkedArray, PrimitiveArray, PrimitiveEncoding};
     use vortex::encoding::ArrayEncoding;
     use vortex::stream::ArrayStreamExt;
-    use vortex::{ArrayDType, Context, IntoArray};
+    use vortex::{Array, ArrayDType, Context, IntoArray, IntoArrayVariant};
     use vortex_error::VortexResult;
 
     use crate::io::FuturesAdapter;
     use crate::stream_reader::StreamArrayReader;
     use crate::stream_writer::StreamArrayWriter;
 
-    fn write_ipc<A: IntoArray>(array: A) -> Vec<u8> {
+    fn write_ipc(array: impl Into<Array>) -> Vec<u8> {
         block_on(async {
             StreamArrayWriter::new(vec![])
-                .write_array(array.into_array())
+                .write_array(array)
+                .await
+                .unwrap()
+                .finish()
                 .await
                 .unwrap()
-                .into_inner()
         })
     }
 
     #[test]
-    #[cfg_attr(miri, ignore)]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
     fn test_empty_index() -> VortexResult<()> {
         let data = PrimitiveArray::from((0i32..3_000_000).collect_vec());
         let buffer = write_ipc(data);
@@ -70,7 +71,35 @@ mod test {
     }
 
     #[test]
-    #[cfg_attr(miri, ignore)]
+    fn test_view_to_data() {
+        let data = PrimitiveArray::from(vec![1i32, 2, 3]);
+        assert!(data.as_ref().as_view().is_none());
+
+        let buffer = write_ipc(data);
+        let ctx = Arc::new(Context::default());
+        let array = block_on(async {
+            StreamArrayReader::try_new(FuturesAdapter(Cursor::new(buffer)), ctx)
+                .await
+                .unwrap()
+                .load_dtype()
+                .await
+                .unwrap()
+                .into_array_stream()
+                .collect_chunked()
+                .await
+                .unwrap()
+        });
+        let view = array.chunk(0).unwrap();
+        let owned = Array::from(view.as_view().unwrap().to_data());
+        assert!(owned.as_view().is_none());
+        assert_eq!(
+            owned.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1, 2, 3]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
     fn test_write_read_chunked() -> VortexResult<()> {
         let indices = PrimitiveArray::from(vec![
             10u32, 11, 12, 13, 100_000, 2_999_999, 2_999_999, 3_000_000,
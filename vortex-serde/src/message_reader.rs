@@ -6,7 +6,7 @@ use flatbuffers::{root, root_unchecked};
 use futures_util::stream::try_unfold;
 use vortex::stream::{ArrayStream, ArrayStreamAdapter};
 use vortex::{Array, ArrayView, Context, IntoArray};
-use vortex_buffer::Buffer;
+use vortex_buffer::{AlignedBuffer, Buffer};
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 use vortex_flatbuffers::message as fb;
@@ -14,21 +14,98 @@ use vortex_flatbuffers::message as fb;
 use crate::io::VortexRead;
 
 pub const FLATBUFFER_SIZE_LENGTH: usize = 4;
+/// Length prefix marking the end of a message stream.
+pub const END_OF_STREAM_MARKER: u32 = u32::MAX;
+
+/// Default bound on the size of a flatbuffer message, see [`MessageLimits`].
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 64 << 20;
+/// Default bound on the total size of the buffers of an array or of a page, see [`MessageLimits`].
+pub const DEFAULT_MAX_BUFFER_SIZE: usize = 1 << 30;
+
+/// Bounds on the sizes claimed by messages in a stream, checked before allocating for them.
+///
+/// Streams from untrusted sources can claim arbitrarily large messages, so by default messages are
+/// limited to [`DEFAULT_MAX_MESSAGE_SIZE`] and their buffers to [`DEFAULT_MAX_BUFFER_SIZE`], failing
+/// with an error instead of running out of memory. Streams of trusted sources with larger arrays
+/// can raise the limits, or lift them with [`MessageLimits::unlimited`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageLimits {
+    /// Largest flatbuffer message, excluding the buffers that follow it.
+    pub max_message_size: Option<usize>,
+    /// Largest total size of the buffers of an array or of a page.
+    pub max_buffer_size: Option<usize>,
+}
+
+impl Default for MessageLimits {
+    fn default() -> Self {
+        Self {
+            max_message_size: Some(DEFAULT_MAX_MESSAGE_SIZE),
+            max_buffer_size: Some(DEFAULT_MAX_BUFFER_SIZE),
+        }
+    }
+}
+
+impl MessageLimits {
+    /// No bounds on the sizes of messages, for streams from trusted sources only.
+    pub fn unlimited() -> Self {
+        Self {
+            max_message_size: None,
+            max_buffer_size: None,
+        }
+    }
+
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = Some(max_message_size);
+        self
+    }
+
+    pub fn with_max_buffer_size(mut self, max_buffer_size: usize) -> Self {
+        self.max_buffer_size = Some(max_buffer_size);
+        self
+    }
+
+    fn check_message_size(&self, size: usize) -> VortexResult<()> {
+        if let Some(max) = self.max_message_size.filter(|max| size > *max) {
+            vortex_bail!(InvalidSerde: "Message of {size} bytes exceeds the limit of {max} bytes")
+        }
+        Ok(())
+    }
+
+    fn check_buffer_size(&self, size: usize) -> VortexResult<()> {
+        if let Some(max) = self.max_buffer_size.filter(|max| size > *max) {
+            vortex_bail!(InvalidSerde: "Buffers of {size} bytes exceed the limit of {max} bytes")
+        }
+        Ok(())
+    }
+}
 
 pub struct MessageReader<R> {
     read: R,
+    limits: MessageLimits,
     message: BytesMut,
     prev_message: BytesMut,
     finished: bool,
+    end_of_stream: bool,
+    require_end_of_stream: bool,
+    validate_arrays: bool,
 }
 
 impl<R: VortexRead> MessageReader<R> {
     pub async fn try_new(read: R) -> VortexResult<Self> {
+        Self::try_new_with_limits(read, MessageLimits::default()).await
+    }
+
+    /// Create a reader that rejects messages exceeding `limits` before reading them.
+    pub async fn try_new_with_limits(read: R, limits: MessageLimits) -> VortexResult<Self> {
         let mut reader = Self {
             read,
+            limits,
             message: BytesMut::new(),
             prev_message: BytesMut::new(),
             finished: false,
+            end_of_stream: false,
+            require_end_of_stream: false,
+            validate_arrays: false,
         };
         reader.load_next_message().await?;
         Ok(reader)
@@ -48,15 +125,15 @@ impl<R: VortexRead> MessageReader<R> {
         };
 
         let len = buffer.get_u32_le();
-        if len == u32::MAX {
-            // Marker for no more messages.
+        if len == END_OF_STREAM_MARKER {
+            self.end_of_stream = true;
             return Ok(false);
         } else if len == 0 {
             vortex_bail!(InvalidSerde: "Invalid IPC stream")
         }
+        self.limits.check_message_size(len as usize)?;
 
-        buffer.reserve(len as usize);
-        unsafe { buffer.set_len(len as usize) };
+        buffer.resize(len as usize, 0);
         self.message = self.read.read_into(buffer).await?;
 
         // Validate that the message is valid a flatbuffer.
@@ -67,6 +144,30 @@ impl<R: VortexRead> MessageReader<R> {
         Ok(true)
     }
 
+    /// Whether reaching the end of the input without an end-of-stream marker is an error, which it
+    /// isn't by default as single messages and ranges of a stream are read without one.
+    pub fn require_end_of_stream(&mut self, required: bool) {
+        self.require_end_of_stream = required;
+    }
+
+    /// Check the invariants of every array read, see [`Array::validate`].
+    pub fn validate_arrays(&mut self) {
+        self.validate_arrays = true;
+    }
+
+    /// Whether the stream was terminated by an explicit end-of-stream marker.
+    pub fn saw_end_of_stream(&self) -> bool {
+        self.end_of_stream
+    }
+
+    /// Fail if the messages ran out without an end-of-stream marker and one is required.
+    fn check_truncated(&self) -> VortexResult<()> {
+        if self.finished && self.require_end_of_stream && !self.end_of_stream {
+            vortex_bail!(InvalidSerde: "Unexpected end of input, stream is missing its end-of-stream marker")
+        }
+        Ok(())
+    }
+
     fn peek(&self) -> Option<fb::Message> {
         if self.finished {
             return None;
@@ -88,6 +189,7 @@ impl<R: VortexRead> MessageReader<R> {
 
     pub async fn read_dtype(&mut self) -> VortexResult<DType> {
         if self.peek().and_then(|m| m.header_as_schema()).is_none() {
+            self.check_truncated()?;
             vortex_bail!("Expected schema message")
         }
 
@@ -105,22 +207,31 @@ impl<R: VortexRead> MessageReader<R> {
         .map_err(|e| vortex_err!(InvalidSerde: "Failed to parse DType: {}", e))
     }
 
+    /// Reads a dtype if the next message is a schema message.
+    pub async fn maybe_read_dtype(&mut self) -> VortexResult<Option<DType>> {
+        if self.peek().and_then(|m| m.header_as_schema()).is_none() {
+            return self.check_truncated().map(|_| None);
+        }
+        self.read_dtype().await.map(Some)
+    }
+
     pub async fn maybe_read_chunk(
         &mut self,
         ctx: Arc<Context>,
         dtype: DType,
     ) -> VortexResult<Option<Array>> {
         let all_buffers_size = match self.peek().and_then(|m| m.header_as_batch()) {
-            None => return Ok(None),
+            None => return self.check_truncated().map(|_| None),
             Some(chunk) => chunk.buffer_size() as usize,
         };
+        self.limits.check_buffer_size(all_buffers_size)?;
 
         let mut array_reader =
             ArrayBufferReader::from_fb_bytes(Buffer::from(self.message.clone().freeze()));
 
         // Issue a single read to grab all buffers
-        let mut all_buffers = BytesMut::with_capacity(all_buffers_size);
-        unsafe { all_buffers.set_len(all_buffers_size) };
+        // Buffers are written at aligned positions, so they stay aligned within an aligned read
+        let all_buffers = AlignedBuffer::zeroed(all_buffers_size).into_inner();
         let all_buffers = self.read.read_into(all_buffers).await?;
 
         if array_reader.read(all_buffers.freeze())?.is_some() {
@@ -128,7 +239,11 @@ impl<R: VortexRead> MessageReader<R> {
         };
 
         let _ = self.next().await?;
-        array_reader.into_array(ctx, dtype).map(Some)
+        let array = array_reader.into_array(ctx, dtype)?;
+        if self.validate_arrays {
+            array.validate()?;
+        }
+        Ok(Some(array))
     }
 
     pub fn array_stream(&mut self, ctx: Arc<Context>, dtype: DType) -> impl ArrayStream + '_ {
@@ -189,15 +304,14 @@ impl<R: VortexRead> MessageReader<R> {
 
     pub async fn maybe_read_page(&mut self) -> VortexResult<Option<Buffer>> {
         let Some(page_msg) = self.peek().and_then(|m| m.header_as_page()) else {
-            return Ok(None);
+            return self.check_truncated().map(|_| None);
         };
 
         let buffer_len = page_msg.buffer_size() as usize;
         let total_len = buffer_len + (page_msg.padding() as usize);
+        self.limits.check_buffer_size(total_len)?;
 
-        let mut buffer = BytesMut::with_capacity(total_len);
-        unsafe { buffer.set_len(total_len) }
-        buffer = self.read.read_into(buffer).await?;
+        let mut buffer = self.read.read_into(BytesMut::zeroed(total_len)).await?;
         buffer.truncate(buffer_len);
         let page_buffer = Ok(Some(Buffer::from(buffer.freeze())));
         let _ = self.next().await?;
@@ -246,6 +360,21 @@ impl ArrayBufferReader {
         }
     }
 
+    /// Length of the array serialized in `bytes`, read from its message without reading the buffers
+    pub fn array_len(bytes: &[u8]) -> VortexResult<usize> {
+        let fb_len = bytes
+            .get(..FLATBUFFER_SIZE_LENGTH)
+            .map(|mut len| len.get_u32_le() as usize)
+            .ok_or_else(|| vortex_err!(InvalidSerde: "Message is missing its length"))?;
+        let fb_bytes = bytes
+            .get(FLATBUFFER_SIZE_LENGTH..FLATBUFFER_SIZE_LENGTH + fb_len)
+            .ok_or_else(|| vortex_err!(InvalidSerde: "Message needs {fb_len} bytes but only {} remain", bytes.len() - FLATBUFFER_SIZE_LENGTH))?;
+        let batch = root::<fb::Message>(fb_bytes)?
+            .header_as_batch()
+            .ok_or_else(|| vortex_err!("Message was not a batch"))?;
+        Ok(batch.length() as usize)
+    }
+
     pub fn read(&mut self, mut bytes: Bytes) -> VortexResult<Option<usize>> {
         match self.state {
             ReadState::Init => {
@@ -257,13 +386,11 @@ impl ArrayBufferReader {
                 Ok(Some(bytes.get_u32_le() as usize))
             }
             ReadState::ReadingFb => {
-                // SAFETY: Assumes that any flatbuffer bytes passed have been validated.
-                //     This is currently the case in stream and file implementations.
-                let batch = unsafe {
-                    root_unchecked::<fb::Message>(&bytes)
-                        .header_as_batch()
-                        .ok_or_else(|| vortex_err!("Message was not a batch"))?
-                };
+                // Bytes come straight from the file, verify them once so that later accesses can
+                // skip verification
+                let batch = root::<fb::Message>(&bytes)?
+                    .header_as_batch()
+                    .ok_or_else(|| vortex_err!("Message was not a batch"))?;
                 let buffer_size = batch.buffer_size() as usize;
                 self.fb_msg = Some(Buffer::from(bytes));
                 self.state = ReadState::ReadingBuffers;
@@ -286,16 +413,23 @@ impl ArrayBufferReader {
                             .chain([all_buffers_size]),
                     )
                     .map(|(buffer, next_offset)| {
-                        let len = next_offset - buffer.offset() - buffer.padding() as u64;
+                        let len = next_offset
+                            .checked_sub(buffer.offset())
+                            .and_then(|l| l.checked_sub(buffer.padding() as u64))
+                            .map(|l| l as usize)
+                            .filter(|l| l + buffer.padding() as usize <= bytes.len())
+                            .ok_or_else(|| {
+                                vortex_err!(InvalidSerde: "Buffer at offset {} is out of bounds", buffer.offset())
+                            })?;
 
                         // Grab the buffer
-                        let data_buffer = bytes.split_to(len as usize);
+                        let data_buffer = bytes.split_to(len);
                         // Strip off any padding from the previous buffer
                         bytes.advance(buffer.padding() as usize);
 
-                        Buffer::from(data_buffer)
+                        Ok(Buffer::from(data_buffer))
                     })
-                    .collect::<Vec<_>>();
+                    .collect::<VortexResult<Vec<_>>>()?;
 
                 self.buffers = buffers;
                 self.state = ReadState::Finished;
@@ -350,7 +484,8 @@ mod test {
     use futures_executor::block_on;
     use vortex_buffer::Buffer;
 
-    use crate::{MessageReader, MessageWriter};
+    use crate::message_reader::DEFAULT_MAX_MESSAGE_SIZE;
+    use crate::{MessageLimits, MessageReader, MessageWriter};
 
     #[test]
     fn read_write_page() {
@@ -371,4 +506,36 @@ mod test {
             .unwrap();
         assert_eq!(read_page, Buffer::Bytes(Bytes::from("somevalue")));
     }
+
+    #[test]
+    fn message_limits() {
+        let mut writer = MessageWriter::new(Vec::new());
+        block_on(async { writer.write_page(Buffer::from(vec![0u8; 64])).await }).unwrap();
+        let written = writer.into_inner();
+
+        let read_page = |limits: MessageLimits| {
+            block_on(async {
+                MessageReader::try_new_with_limits(Cursor::new(written.as_slice()), limits)
+                    .await?
+                    .maybe_read_page()
+                    .await
+            })
+        };
+        assert!(read_page(MessageLimits::default().with_max_buffer_size(64))
+            .unwrap()
+            .is_some());
+        assert!(read_page(MessageLimits::default().with_max_buffer_size(32)).is_err());
+        assert!(read_page(MessageLimits::default().with_max_message_size(4)).is_err());
+        assert!(read_page(MessageLimits::unlimited()).unwrap().is_some());
+    }
+
+    #[test]
+    fn default_limits_reject_oversized_message() {
+        let claimed_len = u32::try_from(DEFAULT_MAX_MESSAGE_SIZE + 1).unwrap();
+        let stream = claimed_len.to_le_bytes();
+        let err = block_on(async { MessageReader::try_new(Cursor::new(stream.as_slice())).await })
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("exceeds the limit"), "{err}");
+    }
 }
@@ -10,7 +10,7 @@ use vortex_flatbuffers::WriteFlatBuffer;
 
 use crate::io::VortexWrite;
 use crate::messages::{IPCBatch, IPCMessage, IPCPage, IPCSchema};
-use crate::ALIGNMENT;
+use crate::{ALIGNMENT, END_OF_STREAM_MARKER};
 
 const ZEROS: [u8; 512] = [0u8; 512];
 
@@ -38,6 +38,11 @@ impl<W: VortexWrite> MessageWriter<W> {
         self.write
     }
 
+    /// Flush any bytes buffered by the underlying writer.
+    pub async fn flush(&mut self) -> io::Result<()> {
+        self.write.flush().await
+    }
+
     /// Returns the current position in the stream.
     pub fn tell(&self) -> u64 {
         self.pos
@@ -85,6 +90,12 @@ impl<W: VortexWrite> MessageWriter<W> {
         Ok(())
     }
 
+    /// Write the marker signalling that no more messages follow.
+    pub async fn write_end_of_stream(&mut self) -> io::Result<()> {
+        self.write_all(END_OF_STREAM_MARKER.to_le_bytes()).await?;
+        Ok(())
+    }
+
     pub async fn write_message<F: WriteFlatBuffer>(&mut self, flatbuffer: F) -> io::Result<()> {
         // We reuse the scratch buffer each time and then replace it at the end.
         // The scratch buffer may be missing if a previous write failed. We could use scopeguard
@@ -3,15 +3,32 @@ use std::sync::Arc;
 
 use futures_util::stream::try_unfold;
 use futures_util::Stream;
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncRead, BufReader};
 use vortex::stream::ArrayStream;
 use vortex::Context;
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_error::{VortexExpect as _, VortexResult};
 
+#[cfg(feature = "tokio")]
+use crate::io::TokioAdapter;
 use crate::io::VortexRead;
+pub use crate::message_reader::{MessageLimits, DEFAULT_MAX_BUFFER_SIZE, DEFAULT_MAX_MESSAGE_SIZE};
 use crate::MessageReader;
 
+/// Reads the arrays of a stream written by [`StreamArrayWriter`](crate::stream_writer::StreamArrayWriter).
+///
+/// The stream must end with the end-of-stream marker written by
+/// [`StreamArrayWriter::finish`](crate::stream_writer::StreamArrayWriter::finish), otherwise it is
+/// reported as truncated.
+///
+/// # Compatibility
+///
+/// Requiring the marker is a breaking change for existing data: streams written before the marker
+/// existed never have one, and fail to read with a truncation error once all of their arrays have
+/// been returned. They, and streams recovered with `into_inner` without finishing, have to be
+/// read with [`StreamArrayReader::allow_missing_end_of_stream`].
 pub struct StreamArrayReader<R: VortexRead> {
     msgs: MessageReader<R>,
     ctx: Arc<Context>,
@@ -19,14 +36,52 @@ pub struct StreamArrayReader<R: VortexRead> {
 }
 
 impl<R: VortexRead> StreamArrayReader<R> {
+    /// Create a reader issuing every read directly against `read`.
+    ///
+    /// This is the right choice for sources that are already in memory or buffered, e.g. a
+    /// `Cursor` or `BytesMut`. Raw files and sockets should use [`StreamArrayReader::try_new_buffered`]
+    /// to avoid issuing a syscall for every message header.
     pub async fn try_new(read: R, ctx: Arc<Context>) -> VortexResult<Self> {
+        Self::try_new_with_limits(read, ctx, MessageLimits::default()).await
+    }
+
+    /// Create a reader that fails on messages larger than `limits` instead of allocating for them,
+    /// for reading streams from untrusted sources.
+    pub async fn try_new_with_limits(
+        read: R,
+        ctx: Arc<Context>,
+        limits: MessageLimits,
+    ) -> VortexResult<Self> {
+        let mut msgs = MessageReader::try_new_with_limits(read, limits).await?;
+        msgs.require_end_of_stream(true);
         Ok(Self {
-            msgs: MessageReader::try_new(read).await?,
+            msgs,
             ctx,
             dtype: None,
         })
     }
 
+    /// Treat the end of the input as the end of the stream, for streams written without
+    /// [`StreamArrayWriter::finish`](crate::stream_writer::StreamArrayWriter::finish) and reads of
+    /// a range of a stream. By default, an input ending without the marker written by `finish` is
+    /// reported as truncated.
+    pub fn allow_missing_end_of_stream(mut self) -> Self {
+        self.msgs.require_end_of_stream(false);
+        self
+    }
+
+    /// Check the invariants of every array read, failing on corrupt input instead of returning
+    /// arrays that may panic when accessed, see [`Array::validate`](vortex::Array::validate).
+    pub fn validate_arrays(mut self) -> Self {
+        self.msgs.validate_arrays();
+        self
+    }
+
+    /// Whether the stream was terminated by an explicit end-of-stream marker.
+    pub fn saw_end_of_stream(&self) -> bool {
+        self.msgs.saw_end_of_stream()
+    }
+
     pub fn with_dtype(mut self, dtype: Arc<DType>) -> Self {
         assert!(self.dtype.is_none(), "DType already set");
         self.dtype = Some(dtype);
@@ -50,6 +105,19 @@ impl<R: VortexRead> StreamArrayReader<R> {
         self.msgs.array_stream(self.ctx.clone(), dtype)
     }
 
+    /// Reads the next array written to the stream along with its dtype.
+    ///
+    /// Streams may hold several arrays of different dtypes, one per
+    /// [`StreamArrayWriter::write_array`](crate::stream_writer::StreamArrayWriter::write_array)
+    /// call. Returns `None` once no more arrays follow.
+    pub async fn next_array_stream(&mut self) -> VortexResult<Option<impl ArrayStream + '_>> {
+        let Some(dtype) = self.msgs.maybe_read_dtype().await? else {
+            return Ok(None);
+        };
+        self.dtype = Some(Arc::new(dtype));
+        Ok(Some(self.array_stream()))
+    }
+
     pub fn into_array_stream(self) -> impl ArrayStream {
         let dtype = self
             .dtype
@@ -75,3 +143,15 @@ impl<R: VortexRead> StreamArrayReader<R> {
         })
     }
 }
+
+#[cfg(feature = "tokio")]
+impl<R: AsyncRead + Unpin> StreamArrayReader<TokioAdapter<BufReader<R>>> {
+    /// Create a reader that buffers up to `capacity` bytes of `read` at a time.
+    pub async fn try_new_buffered(
+        read: R,
+        capacity: usize,
+        ctx: Arc<Context>,
+    ) -> VortexResult<Self> {
+        Self::try_new(TokioAdapter(BufReader::with_capacity(capacity, read)), ctx).await
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::{Display, Formatter};
 
 use futures_util::{Stream, TryStreamExt};
+use log::warn;
 use vortex::array::ChunkedArray;
 use vortex::stream::ArrayStream;
 use vortex::Array;
@@ -14,11 +15,19 @@ use crate::MessageWriter;
 #[cfg(test)]
 mod tests;
 
+/// Writes arrays as a stream of messages, read back by
+/// [`StreamArrayReader`](crate::stream_reader::StreamArrayReader).
+///
+/// Streams have to be terminated with [`finish`](Self::finish), readers report a stream without
+/// the end-of-stream marker as truncated. Writing is async so the marker can't be written when the
+/// writer is dropped, instead dropping a writer that was neither finished nor given up with
+/// [`into_inner`](Self::into_inner) logs a warning.
 pub struct StreamArrayWriter<W: VortexWrite> {
     msgs: MessageWriter<W>,
 
     array_layouts: Vec<ArrayLayout>,
     page_ranges: Vec<ByteRange>,
+    unfinished: UnfinishedStream,
 }
 
 impl<W: VortexWrite> StreamArrayWriter<W> {
@@ -27,6 +36,7 @@ impl<W: VortexWrite> StreamArrayWriter<W> {
             msgs: MessageWriter::new(write),
             array_layouts: vec![],
             page_ranges: vec![],
+            unfinished: UnfinishedStream(true),
         }
     }
 
@@ -38,7 +48,29 @@ impl<W: VortexWrite> StreamArrayWriter<W> {
         &self.page_ranges
     }
 
-    pub fn into_inner(self) -> W {
+    /// Ensure all written messages have reached the underlying writer.
+    pub async fn flush(&mut self) -> VortexResult<()> {
+        Ok(self.msgs.flush().await?)
+    }
+
+    /// Terminate the stream with an end-of-stream marker and flush it, returning the underlying
+    /// writer.
+    ///
+    /// Readers can only tell a complete stream apart from a truncated one if it was finished.
+    pub async fn finish(mut self) -> VortexResult<W> {
+        self.msgs.write_end_of_stream().await?;
+        self.msgs.flush().await?;
+        self.unfinished.0 = false;
+        Ok(self.msgs.into_inner())
+    }
+
+    /// Recover the underlying writer without terminating the stream, callers should
+    /// [`flush`](Self::flush) buffered writers first.
+    ///
+    /// The stream can only be read back with
+    /// [`allow_missing_end_of_stream`](crate::stream_reader::StreamArrayReader::allow_missing_end_of_stream).
+    pub fn into_inner(mut self) -> W {
+        self.unfinished.0 = false;
         self.msgs.into_inner()
     }
 
@@ -80,7 +112,12 @@ impl<W: VortexWrite> StreamArrayWriter<W> {
         Ok(self)
     }
 
-    pub async fn write_array(self, array: Array) -> VortexResult<Self> {
+    /// Write an array as a stream of its chunks.
+    ///
+    /// Accepts anything that converts into an [`Array`], so typed arrays and owned `ArrayData` can
+    /// be written without converting them first.
+    pub async fn write_array(self, array: impl Into<Array>) -> VortexResult<Self> {
+        let array = array.into();
         if let Ok(chunked) = ChunkedArray::try_from(&array) {
             self.write_array_stream(chunked.array_stream()).await
         } else {
@@ -97,7 +134,19 @@ impl<W: VortexWrite> StreamArrayWriter<W> {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+/// Warns when dropped while set, i.e. when a [`StreamArrayWriter`] is dropped without finishing its
+/// stream. Kept as a field so that the writer itself can still be destructured.
+struct UnfinishedStream(bool);
+
+impl Drop for UnfinishedStream {
+    fn drop(&mut self) {
+        if self.0 && !std::thread::panicking() {
+            warn!("StreamArrayWriter dropped without calling finish, the stream has no end-of-stream marker");
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct ByteRange {
     pub begin: u64,
     pub end: u64,
@@ -4,10 +4,13 @@ use std::sync::Arc;
 use arrow_array::cast::AsArray as _;
 use arrow_array::types::Int32Type;
 use arrow_array::PrimitiveArray;
+use tokio::io::BufWriter;
+use vortex::array::{StructArray, VarBinArray};
 use vortex::arrow::FromArrowArray;
-use vortex::stream::ArrayStreamExt;
-use vortex::{Array, Context, IntoCanonical};
+use vortex::stream::{ArrayStream, ArrayStreamExt};
+use vortex::{Array, ArrayDType, Context, IntoArray, IntoCanonical};
 
+use crate::io::TokioAdapter;
 use crate::stream_reader::StreamArrayReader;
 use crate::stream_writer::StreamArrayWriter;
 
@@ -19,7 +22,9 @@ async fn broken_data() {
         .write_array(vortex_arr)
         .await
         .unwrap()
-        .into_inner();
+        .finish()
+        .await
+        .unwrap();
     let reader = StreamArrayReader::try_new(Cursor::new(written), Arc::new(Context::default()))
         .await
         .unwrap();
@@ -34,3 +39,135 @@ async fn broken_data() {
     let round_tripped = arr.into_canonical().unwrap().into_arrow().unwrap();
     assert_eq!(&arrow_arr, round_tripped.as_primitive::<Int32Type>());
 }
+
+#[tokio::test]
+async fn buffered_read() {
+    let array = vortex::array::PrimitiveArray::from((0..10_000).collect::<Vec<i32>>());
+    let written = StreamArrayWriter::new(Vec::new())
+        .write_array(array)
+        .await
+        .unwrap()
+        .finish()
+        .await
+        .unwrap();
+    let reader =
+        StreamArrayReader::try_new_buffered(written.as_slice(), 64, Arc::new(Context::default()))
+            .await
+            .unwrap();
+    let arr = reader
+        .load_dtype()
+        .await
+        .unwrap()
+        .into_array_stream()
+        .collect_chunked()
+        .await
+        .unwrap()
+        .into_canonical()
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(
+        arr.maybe_null_slice::<i32>(),
+        (0..10_000).collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn flush_buffered_writer() {
+    let array = vortex::array::PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+    let unbuffered = StreamArrayWriter::new(Vec::new())
+        .write_array(array.clone())
+        .await
+        .unwrap()
+        .into_inner();
+
+    let mut writer = StreamArrayWriter::new(TokioAdapter(BufWriter::new(Vec::new())))
+        .write_array(array)
+        .await
+        .unwrap();
+    writer.flush().await.unwrap();
+    let buffered = writer.into_inner().0.into_inner();
+    assert_eq!(buffered, unbuffered);
+}
+
+#[tokio::test]
+async fn detect_truncated_stream() {
+    let array = vortex::array::PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+    let finished = StreamArrayWriter::new(Vec::new())
+        .write_array(array.clone())
+        .await
+        .unwrap()
+        .finish()
+        .await
+        .unwrap();
+    let truncated = StreamArrayWriter::new(Vec::new())
+        .write_array(array)
+        .await
+        .unwrap()
+        .into_inner();
+
+    let reader = StreamArrayReader::try_new(Cursor::new(finished), Arc::new(Context::default()))
+        .await
+        .unwrap()
+        .validate_arrays()
+        .load_dtype()
+        .await
+        .unwrap();
+    let read = reader.into_array_stream().collect_chunked().await.unwrap();
+    assert_eq!(read.len(), 3);
+
+    let reader =
+        StreamArrayReader::try_new(Cursor::new(truncated.clone()), Arc::new(Context::default()))
+            .await
+            .unwrap()
+            .load_dtype()
+            .await
+            .unwrap();
+    assert!(reader.into_array_stream().collect_chunked().await.is_err());
+
+    let reader = StreamArrayReader::try_new(Cursor::new(truncated), Arc::new(Context::default()))
+        .await
+        .unwrap()
+        .allow_missing_end_of_stream()
+        .load_dtype()
+        .await
+        .unwrap();
+    let read = reader.into_array_stream().collect_chunked().await.unwrap();
+    assert_eq!(read.len(), 3);
+}
+
+#[tokio::test]
+async fn write_arrays_of_different_dtypes() {
+    let primitive = vortex::array::PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+    let strings = VarBinArray::from(vec!["a", "b"]).into_array();
+    let st = StructArray::from_fields(&[("strings", strings)])
+        .unwrap()
+        .into_array();
+    let written = StreamArrayWriter::new(Vec::new())
+        .write_array(primitive.clone())
+        .await
+        .unwrap()
+        .write_array(st.clone())
+        .await
+        .unwrap()
+        .finish()
+        .await
+        .unwrap();
+
+    let mut reader = StreamArrayReader::try_new(Cursor::new(written), Arc::new(Context::default()))
+        .await
+        .unwrap();
+    let mut read = Vec::new();
+    while let Some(stream) = reader.next_array_stream().await.unwrap() {
+        let dtype = stream.dtype().clone();
+        let array = stream.collect_chunked().await.unwrap();
+        assert_eq!(array.dtype(), &dtype);
+        read.push(array);
+    }
+
+    assert_eq!(read.len(), 2);
+    assert_eq!(read[0].dtype(), primitive.dtype());
+    assert_eq!(read[0].len(), 3);
+    assert_eq!(read[1].dtype(), st.dtype());
+    assert_eq!(read[1].len(), 2);
+}